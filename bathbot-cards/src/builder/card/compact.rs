@@ -0,0 +1,197 @@
+use skia_safe::{Rect, font_style::Slant, utils::text_utils::Align};
+
+use super::{
+    CardBuilder,
+    extras::EXTRAS_H,
+    header::HEADER_H,
+    info::{DrawableSkill, draw_pfp},
+};
+use crate::{
+    builder::font::FontBuilder, card::CardInner, error::InfoError, font::FontData, skills::Skills,
+};
+
+pub(crate) const COMPACT_W: i32 = 980;
+pub(crate) const COMPACT_H: i32 = HEADER_H + COMPACT_PAD + COMPACT_PANEL_H + COMPACT_DATE_H;
+
+/// Additional card height if extra stats are shown
+pub(crate) const COMPACT_EXTRAS_H: i32 = COMPACT_MARGIN + EXTRAS_H;
+
+const COMPACT_PAD: i32 = 40;
+const COMPACT_MARGIN: i32 = 13;
+const COMPACT_PANEL_H: i32 = 300;
+const COMPACT_LEFT_W: i32 = 500;
+const COMPACT_RIGHT_W: i32 = COMPACT_W - 2 * COMPACT_PAD - COMPACT_MARGIN - COMPACT_LEFT_W;
+const COMPACT_AVATAR_PAD: i32 = 20;
+const COMPACT_AVATAR_W: i32 = COMPACT_PANEL_H - 2 * COMPACT_AVATAR_PAD;
+const COMPACT_DATE_H: i32 = 70;
+
+impl CardBuilder<'_> {
+    /// Draws the avatar, ranks, level, and skills as a single row below the
+    /// header, followed by the extra stats and the date.
+    pub(crate) fn draw_compact_info(
+        &mut self,
+        card: &CardInner<'_>,
+        skills: &Skills,
+        font_data: &FontData,
+    ) -> Result<&mut Self, InfoError> {
+        draw_backgrounds(self)?;
+
+        let pfp_pos = (
+            COMPACT_PAD + COMPACT_AVATAR_PAD,
+            HEADER_H + COMPACT_PAD + COMPACT_AVATAR_PAD,
+        );
+
+        draw_pfp(self, card.pfp, pfp_pos, COMPACT_AVATAR_W)?;
+        draw_stats(self, card, font_data)?;
+        draw_skills(self, skills, font_data)?;
+
+        if card.has_extras() {
+            let pos_y = HEADER_H + COMPACT_PAD + COMPACT_PANEL_H + COMPACT_MARGIN;
+            let w = COMPACT_W - 2 * COMPACT_PAD;
+            self.draw_extras(card, (COMPACT_PAD, pos_y), w, font_data)?;
+        }
+
+        draw_date(self, card.date, font_data)?;
+
+        Ok(self)
+    }
+}
+
+fn draw_backgrounds(card: &mut CardBuilder<'_>) -> Result<(), InfoError> {
+    let rect = Rect::new(0.0, 0.0, COMPACT_LEFT_W as f32, COMPACT_PANEL_H as f32);
+    let paint = card.palette.panel(51).build();
+    let translate_x = COMPACT_PAD;
+    let translate_y = HEADER_H + COMPACT_PAD;
+
+    card.canvas
+        .translate((translate_x, translate_y))
+        .draw_round_rect(rect, 16.0, 16.0, &paint)
+        .translate((-translate_x, -translate_y));
+
+    let size = (COMPACT_RIGHT_W as f32, COMPACT_PANEL_H as f32);
+    let rect = Rect::new(0.0, 0.0, size.0, size.1);
+    let paint = card.palette.panel_gradient(size, (102, 45))?.build();
+    let translate_x = COMPACT_PAD + COMPACT_LEFT_W + COMPACT_MARGIN;
+
+    card.canvas
+        .translate((translate_x, translate_y))
+        .draw_round_rect(rect, 16.0, 16.0, &paint)
+        .translate((-translate_x, -translate_y));
+
+    Ok(())
+}
+
+fn draw_stats(
+    card: &mut CardBuilder<'_>,
+    data: &CardInner<'_>,
+    font_data: &FontData,
+) -> Result<(), InfoError> {
+    let label_font = FontBuilder::build(300, Slant::Italic, font_data, 27.0)?;
+    let label_paint = card.palette.text().alpha(168).build();
+
+    let value_font = FontBuilder::build(800, Slant::Upright, font_data, 40.0)?;
+    let value_paint = card.palette.text().build();
+
+    let global_rank = format!("#{}", card.int_buf.format(data.rank_global));
+    let country_rank = format!("#{}", card.int_buf.format(data.rank_country));
+    let level = format!("{:.2}", data.level);
+
+    let stats = [
+        ("Global", global_rank),
+        ("Country", country_rank),
+        ("Level", level),
+    ];
+
+    let pos_x = (COMPACT_PAD + 2 * COMPACT_AVATAR_PAD + COMPACT_AVATAR_W) as f32;
+    let mut pos_y = (HEADER_H + COMPACT_PAD + COMPACT_AVATAR_PAD) as f32;
+
+    for (label, value) in stats {
+        pos_y += label_font.size();
+        card.canvas
+            .draw_str(label, (pos_x, pos_y), &label_font, &label_paint);
+
+        pos_y += value_font.size();
+        card.canvas
+            .draw_str(&value, (pos_x, pos_y), &value_font, &value_paint);
+
+        pos_y += 15.0;
+    }
+
+    Ok(())
+}
+
+fn draw_skills(
+    card: &mut CardBuilder<'_>,
+    skills: &Skills,
+    font_data: &FontData,
+) -> Result<(), InfoError> {
+    let drawables = DrawableSkill::new_list(skills);
+
+    let name_font = FontBuilder::build(300, Slant::Italic, font_data, 30.0)?;
+    let value_font = FontBuilder::build(900, Slant::Upright, font_data, 44.0)?;
+
+    let paint = card.palette.text().build();
+    let accent = card.palette.accent().build();
+
+    let left_x = COMPACT_PAD + COMPACT_LEFT_W + COMPACT_MARGIN + 25;
+    let right_x = COMPACT_W - COMPACT_PAD - 25;
+
+    let row_h = COMPACT_PANEL_H / drawables.len() as i32;
+
+    for (skill, i) in drawables.into_iter().zip(0..) {
+        let DrawableSkill { name, value } = skill;
+
+        // Center of the current row
+        let center_y = HEADER_H + COMPACT_PAD + i * row_h + row_h / 2;
+
+        // Rectangle
+        let rect = Rect::new(0.0, 0.0, 4.0, 34.0);
+        let rect_y = center_y - 17;
+
+        card.canvas
+            .translate((left_x, rect_y))
+            .draw_rect(rect, &accent)
+            .translate((-left_x, -rect_y));
+
+        // Name
+        let name_x = (left_x + 16) as f32;
+        let name_y = (center_y + 11) as f32;
+        card.canvas
+            .draw_str(name, (name_x, name_y), &name_font, &paint);
+
+        // Value
+        let value = format!("{value:.2}");
+        let value_y = (center_y + 16) as f32;
+
+        card.canvas.draw_str_align(
+            &value,
+            (right_x as f32, value_y),
+            &value_font,
+            &paint,
+            Align::Right,
+        );
+    }
+
+    Ok(())
+}
+
+fn draw_date(
+    card: &mut CardBuilder<'_>,
+    date: &str,
+    font_data: &FontData,
+) -> Result<(), InfoError> {
+    let font = FontBuilder::build(200, Slant::Italic, font_data, 32.0)?;
+    let paint = card.palette.text().alpha(204).build();
+    let pos_x = card.w - COMPACT_PAD;
+    let pos_y = card.h - (COMPACT_DATE_H - 32) / 2;
+
+    card.canvas.draw_str_align(
+        date,
+        (pos_x as f32, pos_y as f32),
+        &font,
+        &paint,
+        Align::Right,
+    );
+
+    Ok(())
+}
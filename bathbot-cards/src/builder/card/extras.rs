@@ -0,0 +1,88 @@
+use skia_safe::{Rect, font_style::Slant, utils::text_utils::Align};
+
+use super::CardBuilder;
+use crate::{builder::font::FontBuilder, card::CardInner, error::InfoError, font::FontData};
+
+pub(crate) const EXTRAS_H: i32 = 100;
+
+impl CardBuilder<'_> {
+    /// Draws the optional extra stats as columns within a panel whose
+    /// top left corner is at `pos`.
+    pub(crate) fn draw_extras(
+        &mut self,
+        card: &CardInner<'_>,
+        (pos_x, pos_y): (i32, i32),
+        w: i32,
+        font_data: &FontData,
+    ) -> Result<&mut Self, InfoError> {
+        let mut columns = Vec::with_capacity(3);
+
+        if let Some(playcount) = card.playcount {
+            columns.push(("Playcount", self.int_buf.format(playcount).to_owned()));
+        }
+
+        if let Some(top_pp) = card.top_pp {
+            columns.push(("Top PP", format!("{top_pp:.2}pp")));
+        }
+
+        if let Some(mapper) = card.top_mapper {
+            columns.push(("Top mapper", mapper.to_owned()));
+        }
+
+        if columns.is_empty() {
+            return Ok(self);
+        }
+
+        // Background
+        let size = (w as f32, EXTRAS_H as f32);
+        let rect = Rect::new(0.0, 0.0, size.0, size.1);
+        let paint = self.palette.panel_gradient(size, (45, 102))?.build();
+
+        self.canvas
+            .translate((pos_x, pos_y))
+            .draw_round_rect(rect, 16.0, 16.0, &paint)
+            .translate((-pos_x, -pos_y));
+
+        let label_font = FontBuilder::build(300, Slant::Italic, font_data, 27.0)?;
+        let label_paint = self.palette.text().alpha(168).build();
+
+        let value_font = FontBuilder::build(800, Slant::Upright, font_data, 34.0)?;
+        let value_paint = self.palette.text().build();
+
+        let separator_paint = self.palette.text().alpha(51).build();
+
+        let column_w = w as f32 / columns.len() as f32;
+
+        for ((label, value), i) in columns.iter().zip(0..) {
+            let center_x = pos_x as f32 + column_w * (i as f32 + 0.5);
+
+            self.canvas.draw_str_align(
+                label,
+                (center_x, (pos_y + 38) as f32),
+                &label_font,
+                &label_paint,
+                Align::Center,
+            );
+
+            self.canvas.draw_str_align(
+                value,
+                (center_x, (pos_y + 80) as f32),
+                &value_font,
+                &value_paint,
+                Align::Center,
+            );
+
+            if i > 0 {
+                let separator_x = pos_x as f32 + column_w * i as f32;
+                let rect = Rect::new(0.0, 0.0, 3.0, (EXTRAS_H - 40) as f32);
+
+                self.canvas
+                    .translate((separator_x, (pos_y + 20) as f32))
+                    .draw_round_rect(rect, 3.0, 3.0, &separator_paint)
+                    .translate((-separator_x, -(pos_y + 20) as f32));
+            }
+        }
+
+        Ok(self)
+    }
+}
@@ -2,16 +2,9 @@ use std::{fs, path::PathBuf};
 
 use skia_safe::{Data, Image, RRect, Rect, Vector, font_style::Slant, utils::text_utils::Align};
 
-use super::{CardBuilder, H, W};
+use super::CardBuilder;
 use crate::{
-    builder::{
-        font::FontBuilder,
-        paint::{Gradient, PaintBuilder},
-    },
-    card::CardInner,
-    error::FooterError,
-    font::FontData,
-    svg::Svg,
+    builder::font::FontBuilder, card::CardInner, error::FooterError, font::FontData, svg::Svg,
 };
 
 pub(crate) const FOOTER_H: i32 = 184;
@@ -36,7 +29,8 @@ impl CardBuilder<'_> {
 }
 
 fn draw_background(card: &mut CardBuilder<'_>) -> Result<(), FooterError> {
-    let rect = Rect::new(0.0, 0.0, W as f32, FOOTER_H as f32);
+    let size = (card.w as f32, FOOTER_H as f32);
+    let rect = Rect::new(0.0, 0.0, size.0, size.1);
     let radii = [
         Vector::from((0.0, 0.0)),
         Vector::from((30.0, 30.0)),
@@ -44,19 +38,8 @@ fn draw_background(card: &mut CardBuilder<'_>) -> Result<(), FooterError> {
         Vector::from((0.0, 0.0)),
     ];
     let rrect = RRect::new_rect_radii(rect, &radii);
-
-    let start = Gradient {
-        pos: ((W / 2) as f32, 0.0),
-        argb: (153, 0, 0, 0),
-    };
-    let end = Gradient {
-        pos: ((W / 2) as f32, FOOTER_H as f32),
-        argb: (76, 0, 0, 0),
-    };
-
-    let paint = PaintBuilder::gradient(start, end)?.build();
-
-    let translate_y = H - FOOTER_H;
+    let paint = card.palette.panel_gradient(size, (153, 76))?.build();
+    let translate_y = card.h - FOOTER_H;
 
     card.canvas
         .translate((0, translate_y))
@@ -75,7 +58,7 @@ fn draw_logo(card: &mut CardBuilder<'_>, mut assets: PathBuf) -> Result<(), Foot
 
     let img = Image::from_encoded_with_alpha_type(data, None).ok_or(FooterError::Icon)?;
     let scale: f32 = FOOTER_H as f32 / FOOTER_LOGO_W as f32;
-    let y_pos = (scale.recip() * (H - FOOTER_H) as f32) as i32;
+    let y_pos = (scale.recip() * (card.h - FOOTER_H) as f32) as i32;
 
     card.canvas
         .scale((scale, scale))
@@ -89,9 +72,9 @@ fn draw_name(card: &mut CardBuilder<'_>, mut assets: PathBuf) -> Result<(), Foot
     assets.push("branding/text.svg");
     let bytes = fs::read(assets).map_err(FooterError::BrandingFile)?;
     let svg = Svg::parse(&bytes).map_err(FooterError::BrandingSvg)?;
-    let paint = PaintBuilder::rgb(255, 255, 255).anti_alias().build();
+    let paint = card.palette.text().anti_alias().build();
     let translate_x = FOOTER_H + FOOTER_TEXT_MARGIN;
-    let translate_y = H - FOOTER_H + (FOOTER_H - FOOTER_TEXT_H) / 2 + 1;
+    let translate_y = card.h - FOOTER_H + (FOOTER_H - FOOTER_TEXT_H) / 2 + 1;
     let scale_x = FOOTER_TEXT_H as f32 / svg.view_box_h as f32;
     let scale_y = FOOTER_TEXT_H as f32 / svg.view_box_h as f32;
 
@@ -111,9 +94,9 @@ fn draw_date(
     font_data: &FontData,
 ) -> Result<(), FooterError> {
     let font = FontBuilder::build(200, Slant::Italic, font_data, 45.0)?;
-    let paint = PaintBuilder::rgb(255, 255, 255).build();
-    let pos_x = W - FOOTER_DATE_MARGIN_RIGHT;
-    let pos_y = H - FOOTER_H + 63 + 45;
+    let paint = card.palette.text().build();
+    let pos_x = card.w - FOOTER_DATE_MARGIN_RIGHT;
+    let pos_y = card.h - FOOTER_H + 63 + 45;
 
     card.canvas.draw_str_align(
         date,
@@ -3,7 +3,6 @@ use std::{fs, path::PathBuf};
 use rosu_v2::model::GameMode;
 use skia_safe::{Data, Image, RRect, Rect, TextBlobBuilder, Vector, font_style::Slant, scalar};
 
-use super::W;
 use crate::{
     builder::{
         card::CardBuilder,
@@ -44,7 +43,8 @@ impl CardBuilder<'_> {
 }
 
 fn draw_background(card: &mut CardBuilder<'_>) -> Result<(), HeaderError> {
-    let rect = Rect::new(0.0, 0.0, W as f32, HEADER_H as f32);
+    let size = (card.w as f32, HEADER_H as f32);
+    let rect = Rect::new(0.0, 0.0, size.0, size.1);
     let radii = [
         Vector::from((0.0, 0.0)),
         Vector::from((0.0, 0.0)),
@@ -52,17 +52,7 @@ fn draw_background(card: &mut CardBuilder<'_>) -> Result<(), HeaderError> {
         Vector::from((30.0, 30.0)),
     ];
     let rrect = RRect::new_rect_radii(rect, &radii);
-
-    let start = Gradient {
-        pos: ((W / 2) as f32, 0.0),
-        argb: (171, 0, 0, 0),
-    };
-    let end = Gradient {
-        pos: ((W / 2) as f32, HEADER_H as f32),
-        argb: (204, 0, 0, 0),
-    };
-
-    let paint = PaintBuilder::gradient(start, end)?.build();
+    let paint = card.palette.panel_gradient(size, (171, 204))?.build();
     card.canvas.draw_rrect(rrect, &paint);
 
     Ok(())
@@ -76,10 +66,10 @@ fn draw_title(
 ) -> Result<Title, HeaderError> {
     let title_text = title.to_string();
 
-    let title_w = (W - HEADER_MODE_W - 2 * HEADER_PAD_LEFT) as f32;
+    let title_w = (card.w - HEADER_MODE_W - 2 * HEADER_PAD_LEFT) as f32;
 
     let font = FontBuilder::build(600, Slant::Italic, font_data, 50.0)?;
-    let paint = PaintBuilder::rgb(255, 255, 255).alpha(204).build();
+    let paint = card.palette.text().alpha(204).build();
 
     let space_iter = title_text
         .bytes()
@@ -138,7 +128,7 @@ fn draw_title(
     let blob = builder.make().ok_or(HeaderError::TitleTextBlob)?;
 
     let name_font = FontBuilder::build(800, Slant::Upright, font_data, 70.0)?;
-    let name_paint = PaintBuilder::rgb(255, 255, 255).build();
+    let name_paint = card.palette.text().build();
 
     let title_h = row_y + name_font.size() + (name_font.spacing() - name_font.size())
         - HEADER_NAME_MARGIN_TOP as f32;
@@ -196,7 +186,7 @@ fn draw_mode_background(card: &mut CardBuilder<'_>, mode: GameMode) -> Result<()
     };
     let paint = PaintBuilder::gradient(start, end)?.build();
 
-    let translate_x = W - HEADER_MODE_W;
+    let translate_x = card.w - HEADER_MODE_W;
     card.canvas
         .translate((translate_x, 0))
         .draw_rrect(rrect, &paint)
@@ -210,7 +200,7 @@ fn draw_mode_background(card: &mut CardBuilder<'_>, mode: GameMode) -> Result<()
     };
 
     let paint = PaintBuilder::rgb(r, g, b).alpha(64).build();
-    let translate_x = W - HEADER_MODE_W;
+    let translate_x = card.w - HEADER_MODE_W;
     card.canvas
         .translate((translate_x, 0))
         .draw_rrect(rrect, &paint)
@@ -236,12 +226,9 @@ fn draw_mode_icon(
     let bytes = fs::read(assets).map_err(HeaderError::ModeFile)?;
     let svg = Svg::parse(&bytes).map_err(HeaderError::ModeSvg)?;
 
-    let mode_paint = PaintBuilder::rgb(255, 255, 255)
-        .alpha(204)
-        .anti_alias()
-        .build();
+    let mode_paint = card.palette.text().alpha(204).anti_alias().build();
 
-    let translate_x = W - HEADER_MODE_W + (HEADER_MODE_W - svg.view_box_w) / 2;
+    let translate_x = card.w - HEADER_MODE_W + (HEADER_MODE_W - svg.view_box_w) / 2;
     let translate_y = (HEADER_MODE_H - svg.view_box_h) / 2;
 
     card.canvas
@@ -5,12 +5,9 @@ use skia_safe::{
     utils::text_utils::Align,
 };
 
-use super::{CardBuilder, H, W, footer::FOOTER_H, header::HEADER_H};
+use super::{CardBuilder, H, W, extras::EXTRAS_H, footer::FOOTER_H, header::HEADER_H};
 use crate::{
-    builder::{
-        font::FontBuilder,
-        paint::{Gradient, PaintBuilder},
-    },
+    builder::{font::FontBuilder, paint::PaintBuilder},
     card::CardInner,
     error::InfoError,
     font::FontData,
@@ -27,9 +24,11 @@ const INFO_LOWER_W: i32 = W - 2 * INFO_PAD;
 const INFO_LOWER_H: i32 =
     H - (HEADER_H + 2 * INFO_PAD + INFO_UPPER_H + INFO_LOWER_MARGIN + FOOTER_H);
 const INFO_AVATAR_W: i32 = INFO_UPPER_LEFT_W;
-const INFO_AVATAR_H: i32 = INFO_AVATAR_W;
 const INFO_GLOBAL_RANK_PAD: i32 = 20;
 
+/// Additional card height if extra stats are shown
+pub(crate) const INFO_EXTRAS_H: i32 = INFO_LOWER_MARGIN + EXTRAS_H;
+
 impl CardBuilder<'_> {
     pub(crate) fn draw_info(
         &mut self,
@@ -40,20 +39,36 @@ impl CardBuilder<'_> {
         draw_upper_left_background(self)?;
         draw_upper_right_background(self)?;
         draw_bottom_background(self)?;
-        draw_pfp(self, card.pfp)?;
+        draw_pfp(
+            self,
+            card.pfp,
+            (INFO_PAD, HEADER_H + INFO_PAD),
+            INFO_AVATAR_W,
+        )?;
         draw_global_rank(self, card.rank_global, font_data)?;
         draw_country_rank(self, card.rank_country, font_data)?;
         draw_skills(self, skills, font_data)?;
         draw_level(self, card.level, font_data)?;
         draw_medals(self, card.medals, card.total_medals, font_data)?;
 
+        if card.has_extras() {
+            let pos_x = INFO_PAD;
+            let pos_y = HEADER_H + INFO_PAD + INFO_UPPER_H + INFO_LOWER_MARGIN + INFO_LOWER_H;
+            self.draw_extras(
+                card,
+                (pos_x, pos_y + INFO_LOWER_MARGIN),
+                INFO_LOWER_W,
+                font_data,
+            )?;
+        }
+
         Ok(self)
     }
 }
 
 fn draw_upper_left_background(card: &mut CardBuilder<'_>) -> Result<(), InfoError> {
     let rect = Rect::new(0.0, 0.0, INFO_UPPER_LEFT_W as f32, INFO_UPPER_H as f32);
-    let paint = PaintBuilder::rgb(0, 0, 0).alpha(51).build();
+    let paint = card.palette.panel(51).build();
     let translate_x = INFO_PAD;
     let translate_y = HEADER_H + INFO_PAD;
 
@@ -66,17 +81,9 @@ fn draw_upper_left_background(card: &mut CardBuilder<'_>) -> Result<(), InfoErro
 }
 
 fn draw_upper_right_background(card: &mut CardBuilder<'_>) -> Result<(), InfoError> {
-    let rect = Rect::new(0.0, 0.0, INFO_UPPER_RIGHT_W as f32, INFO_UPPER_H as f32);
-
-    let start = Gradient {
-        pos: ((INFO_UPPER_RIGHT_W / 2) as f32, 0.0),
-        argb: (102, 0, 0, 0),
-    };
-    let end = Gradient {
-        pos: ((INFO_UPPER_RIGHT_W / 2) as f32, INFO_UPPER_H as f32),
-        argb: (45, 0, 0, 0),
-    };
-    let paint = PaintBuilder::gradient(start, end)?.build();
+    let size = (INFO_UPPER_RIGHT_W as f32, INFO_UPPER_H as f32);
+    let rect = Rect::new(0.0, 0.0, size.0, size.1);
+    let paint = card.palette.panel_gradient(size, (102, 45))?.build();
     let translate_x = INFO_PAD + INFO_UPPER_LEFT_W + INFO_UPPER_MARGIN;
     let translate_y = HEADER_H + INFO_PAD;
 
@@ -89,16 +96,9 @@ fn draw_upper_right_background(card: &mut CardBuilder<'_>) -> Result<(), InfoErr
 }
 
 fn draw_bottom_background(card: &mut CardBuilder<'_>) -> Result<(), InfoError> {
-    let rect = Rect::new(0.0, 0.0, INFO_LOWER_W as f32, INFO_LOWER_H as f32);
-    let start = Gradient {
-        pos: ((W / 2) as f32, 0.0),
-        argb: (45, 0, 0, 0),
-    };
-    let end = Gradient {
-        pos: ((W / 2) as f32, INFO_LOWER_H as f32),
-        argb: (102, 0, 0, 0),
-    };
-    let paint = PaintBuilder::gradient(start, end)?.build();
+    let size = (INFO_LOWER_W as f32, INFO_LOWER_H as f32);
+    let rect = Rect::new(0.0, 0.0, size.0, size.1);
+    let paint = card.palette.panel_gradient(size, (45, 102))?.build();
     let translate_x = INFO_PAD;
     let translate_y = HEADER_H + INFO_PAD + INFO_UPPER_H + INFO_LOWER_MARGIN;
 
@@ -110,7 +110,12 @@ fn draw_bottom_background(card: &mut CardBuilder<'_>) -> Result<(), InfoError> {
     Ok(())
 }
 
-fn draw_pfp(card: &mut CardBuilder<'_>, pfp: &[u8]) -> Result<(), InfoError> {
+pub(super) fn draw_pfp(
+    card: &mut CardBuilder<'_>,
+    pfp: &[u8],
+    (rect_x, rect_y): (i32, i32),
+    size: i32,
+) -> Result<(), InfoError> {
     // SAFETY: `pfp` has a longer lifetime than `Data`
     let data = unsafe { Data::new_bytes(pfp) };
 
@@ -122,19 +127,19 @@ fn draw_pfp(card: &mut CardBuilder<'_>, pfp: &[u8]) -> Result<(), InfoError> {
     } = img.dimensions();
 
     let max = cmp::max(img_w, img_h);
-    let scale = INFO_AVATAR_W as f32 / max as f32;
+    let scale = size as f32 / max as f32;
 
-    let offset_x = INFO_AVATAR_W as f32 - scale * img_w as f32;
-    let offset_y = INFO_AVATAR_H as f32 - scale * img_h as f32;
+    let offset_x = size as f32 - scale * img_w as f32;
+    let offset_y = size as f32 - scale * img_h as f32;
 
-    let pos_x = scale.recip() * (INFO_PAD as f32 + offset_x / 2.0);
-    let pos_y = scale.recip() * ((HEADER_H + INFO_PAD) as f32 + offset_y / 2.0);
+    let pos_x = scale.recip() * (rect_x as f32 + offset_x / 2.0);
+    let pos_y = scale.recip() * (rect_y as f32 + offset_y / 2.0);
 
     let rect = Rect::new(
-        INFO_PAD as f32,
-        (HEADER_H + INFO_PAD) as f32,
-        (INFO_PAD + INFO_AVATAR_W) as f32,
-        (HEADER_H + INFO_PAD + INFO_AVATAR_H) as f32,
+        rect_x as f32,
+        rect_y as f32,
+        (rect_x + size) as f32,
+        (rect_y + size) as f32,
     );
     let rrect = RRect::new_rect_xy(rect, 16.0, 16.0);
 
@@ -155,7 +160,7 @@ fn draw_global_rank(
     font_data: &FontData,
 ) -> Result<(), InfoError> {
     let rank = format!("#{rank}");
-    let paint = PaintBuilder::rgb(255, 255, 255).build();
+    let paint = card.palette.text().build();
     let font = FontBuilder::build(400, Slant::Italic, font_data, 32.0)?;
 
     let pos_x = INFO_PAD + INFO_GLOBAL_RANK_PAD;
@@ -179,7 +184,7 @@ fn draw_country_rank(
     font_data: &FontData,
 ) -> Result<(), InfoError> {
     let rank = format!("#{rank}");
-    let paint = PaintBuilder::rgb(255, 255, 255).build();
+    let paint = card.palette.text().build();
     let font = FontBuilder::build(300, Slant::Italic, font_data, 27.0)?;
 
     let pos_x = INFO_PAD + INFO_UPPER_LEFT_W - INFO_GLOBAL_RANK_PAD;
@@ -214,54 +219,19 @@ fn draw_skills(
     skills: &Skills,
     font_data: &FontData,
 ) -> Result<(), InfoError> {
-    struct DrawableSkill {
-        name: &'static str,
-        value: f64,
-    }
-
-    impl DrawableSkill {
-        fn new(name: &'static str, value: f64) -> Self {
-            Self { name, value }
-        }
-    }
-
-    let drawables = match skills {
-        Skills::Osu { acc, aim, speed } => {
-            let acc = DrawableSkill::new("ACCURACY", *acc);
-            let aim = DrawableSkill::new("AIM", *aim);
-            let speed = DrawableSkill::new("SPEED", *speed);
-
-            vec![acc, aim, speed]
-        }
-        Skills::Taiko { acc, strain } => {
-            let acc = DrawableSkill::new("ACCURACY", *acc);
-            let strain = DrawableSkill::new("STRAIN", *strain);
-
-            vec![acc, strain]
-        }
-        Skills::Catch { acc, movement } => {
-            let acc = DrawableSkill::new("ACCURACY", *acc);
-            let movement = DrawableSkill::new("MOVEMENT", *movement);
-
-            vec![acc, movement]
-        }
-        Skills::Mania { acc, strain } => {
-            let acc = DrawableSkill::new("ACCURACY", *acc);
-            let strain = DrawableSkill::new("STRAIN", *strain);
-
-            vec![acc, strain]
-        }
-    };
+    let drawables = DrawableSkill::new_list(skills);
 
     // `init_y`: y-pos of skill's rect
     // `margin`: pixels inbetween two rects' y-pos
     let (init_y, margin) = match drawables.len() {
         2 => (HEADER_H + INFO_PAD + 89, 270),
         3 => (HEADER_H + INFO_PAD + 44, 180),
+        4 => (HEADER_H + INFO_PAD + 22, 133),
         _ => unreachable!(),
     };
 
-    let paint = PaintBuilder::rgb(255, 255, 255).build();
+    let paint = card.palette.text().build();
+    let accent = card.palette.accent().build();
 
     let rect_x = INFO_PAD + INFO_UPPER_LEFT_W + INFO_UPPER_MARGIN + 30;
 
@@ -282,7 +252,7 @@ fn draw_skills(
 
         card.canvas
             .translate((rect_x, rect_y))
-            .draw_rect(rect, &paint)
+            .draw_rect(rect, &accent)
             .translate((-rect_x, -rect_y));
 
         // Name
@@ -320,7 +290,7 @@ fn draw_level(
     let percent = level.fract();
 
     let font = FontBuilder::build(300, Slant::Italic, font_data, 35.0)?;
-    let paint = PaintBuilder::rgb(255, 255, 255).alpha(168).build();
+    let paint = card.palette.text().alpha(168).build();
 
     let pos_x = INFO_PAD + 32;
     let pos_y = HEADER_H + INFO_PAD + INFO_UPPER_H + INFO_LOWER_MARGIN + INFO_LOWER_MARGIN + 46;
@@ -330,7 +300,7 @@ fn draw_level(
     let (level_w, _) = font.measure_str(level_text, Some(&paint));
 
     let font = FontBuilder::build(800, Slant::Italic, font_data, 35.0)?;
-    let paint = PaintBuilder::rgb(255, 255, 255).build();
+    let paint = card.palette.text().build();
     let pos_x = (INFO_PAD + 40) as f32 + level_w;
     let pos_y = HEADER_H + INFO_PAD + INFO_UPPER_H + INFO_LOWER_MARGIN + INFO_LOWER_MARGIN + 46;
     card.canvas
@@ -340,7 +310,7 @@ fn draw_level(
     // Bar
     let rect_w = (W - 2 * INFO_PAD - 86) as f32 - level_w - value_w;
     let rect = Rect::new(0.0, 0.0, rect_w, 3.0);
-    let paint = PaintBuilder::rgb(255, 255, 255).alpha(51).build();
+    let paint = card.palette.text().alpha(51).build();
     let translate_x = (INFO_PAD + 54) as f32 + level_w + value_w;
     let translate_y =
         HEADER_H + INFO_PAD + INFO_UPPER_H + INFO_LOWER_MARGIN + INFO_LOWER_MARGIN + 33;
@@ -351,7 +321,7 @@ fn draw_level(
         .translate((-translate_x, -translate_y as f32));
 
     let rect = Rect::new(0.0, 0.0, rect_w * percent, 9.0);
-    let paint = PaintBuilder::rgb(255, 255, 255).build();
+    let paint = card.palette.accent().build();
     let translate_x = (INFO_PAD + 54) as f32 + level_w + value_w;
     let translate_y =
         HEADER_H + INFO_PAD + INFO_UPPER_H + INFO_LOWER_MARGIN + INFO_LOWER_MARGIN + 30;
@@ -418,7 +388,7 @@ fn draw_medals(
 
     // Text left
     let font = FontBuilder::build(300, Slant::Italic, font_data, 35.0)?;
-    let paint = PaintBuilder::rgb(255, 255, 255).alpha(168).build();
+    let paint = card.palette.text().alpha(168).build();
     let pos_x = INFO_PAD + 32;
     let pos_y = HEADER_H + INFO_PAD + INFO_UPPER_H + INFO_LOWER_MARGIN + INFO_LOWER_MARGIN + 87;
     let medal_text = "Medals";
@@ -427,7 +397,7 @@ fn draw_medals(
     let (medal_w, _) = font.measure_str(medal_text, Some(&paint));
 
     let font = FontBuilder::build(800, Slant::Italic, font_data, 35.0)?;
-    let paint = PaintBuilder::rgb(255, 255, 255).alpha(168).build(); // simulating brightness
+    let paint = card.palette.text().alpha(168).build(); // simulating brightness
     let pos_x = (INFO_PAD + 40) as f32 + medal_w;
     let pos_y = HEADER_H + INFO_PAD + INFO_UPPER_H + INFO_LOWER_MARGIN + INFO_LOWER_MARGIN + 87;
     let medal_percent_str = format!("{}%", (percent * 100.0) as u32);
@@ -445,7 +415,7 @@ fn draw_medals(
 
     // Text right
     let font = FontBuilder::build(400, Slant::Upright, font_data, 30.0)?;
-    let paint = PaintBuilder::rgb(255, 255, 255).alpha(168).build();
+    let paint = card.palette.text().alpha(168).build();
     let pos_x = W - (INFO_PAD + 32);
     let pos_y = HEADER_H + INFO_PAD + INFO_UPPER_H + INFO_LOWER_MARGIN + INFO_LOWER_MARGIN + 86;
     let total_medals_str = format!("/{total_medals}");
@@ -460,7 +430,7 @@ fn draw_medals(
     let (total_medals_w, _) = font.measure_str(total_medals_str, Some(&paint));
 
     let font = FontBuilder::build(500, Slant::Upright, font_data, 30.0)?;
-    let paint = PaintBuilder::rgb(255, 255, 255).build();
+    let paint = card.palette.text().build();
     let pos_x = (W - (INFO_PAD + 32)) as f32 - total_medals_w;
     let pos_y = HEADER_H + INFO_PAD + INFO_UPPER_H + INFO_LOWER_MARGIN + INFO_LOWER_MARGIN + 86;
     let medals_str = card.int_buf.format(curr_medals);
@@ -573,3 +543,63 @@ fn draw_medals(
 
     Ok(())
 }
+
+pub(super) struct DrawableSkill {
+    pub(super) name: &'static str,
+    pub(super) value: f64,
+}
+
+impl DrawableSkill {
+    fn new(name: &'static str, value: f64) -> Self {
+        Self { name, value }
+    }
+
+    pub(super) fn new_list(skills: &Skills) -> Vec<Self> {
+        match skills {
+            Skills::Osu { acc, aim, speed } => {
+                let acc = Self::new("ACCURACY", *acc);
+                let aim = Self::new("AIM", *aim);
+                let speed = Self::new("SPEED", *speed);
+
+                vec![acc, aim, speed]
+            }
+            Skills::Taiko {
+                acc,
+                rhythm,
+                color,
+                stamina,
+            } => {
+                let acc = Self::new("ACCURACY", *acc);
+                let rhythm = Self::new("RHYTHM", *rhythm);
+                let color = Self::new("COLOUR", *color);
+                let stamina = Self::new("STAMINA", *stamina);
+
+                vec![acc, rhythm, color, stamina]
+            }
+            Skills::Catch {
+                acc,
+                movement,
+                precision,
+            } => {
+                let acc = Self::new("ACCURACY", *acc);
+                let movement = Self::new("MOVEMENT", *movement);
+                let precision = Self::new("PRECISION", *precision);
+
+                vec![acc, movement, precision]
+            }
+            Skills::Mania {
+                acc,
+                chord,
+                jack,
+                stream,
+            } => {
+                let acc = Self::new("ACCURACY", *acc);
+                let chord = Self::new("CHORD", *chord);
+                let jack = Self::new("JACK", *jack);
+                let stream = Self::new("STREAM", *stream);
+
+                vec![acc, chord, jack, stream]
+            }
+        }
+    }
+}
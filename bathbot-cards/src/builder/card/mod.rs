@@ -1,3 +1,5 @@
+mod compact;
+mod extras;
 mod footer;
 mod header;
 mod info;
@@ -5,9 +7,18 @@ mod info;
 use std::{fs, path::PathBuf};
 
 use itoa::Buffer;
-use skia_safe::{Canvas, Data, Image};
+use skia_safe::{Canvas, Data, Image, Rect};
 
-use crate::{error::BackgroundError, skills::CardTitle};
+use crate::{
+    builder::paint::{Gradient, PaintBuilder, Palette},
+    error::BackgroundError,
+    skills::CardTitle,
+};
+
+pub(crate) use self::{
+    compact::{COMPACT_EXTRAS_H, COMPACT_H, COMPACT_W},
+    info::INFO_EXTRAS_H,
+};
 
 pub(crate) const H: i32 = 1260;
 pub(crate) const W: i32 = 980;
@@ -15,13 +26,19 @@ pub(crate) const W: i32 = 980;
 pub(crate) struct CardBuilder<'c> {
     canvas: &'c Canvas,
     int_buf: Buffer,
+    palette: Palette,
+    w: i32,
+    h: i32,
 }
 
 impl<'a> CardBuilder<'a> {
-    pub(crate) fn new(canvas: &'a Canvas) -> Self {
+    pub(crate) fn new(canvas: &'a Canvas, palette: Palette, (w, h): (i32, i32)) -> Self {
         Self {
             canvas,
             int_buf: Buffer::new(),
+            palette,
+            w,
+            h,
         }
     }
 
@@ -30,6 +47,28 @@ impl<'a> CardBuilder<'a> {
         title: &CardTitle,
         mut assets: PathBuf,
     ) -> Result<&mut Self, BackgroundError> {
+        if let Some((start, end)) = self.palette.background {
+            let (r, g, b) = start;
+
+            let start = Gradient {
+                pos: (0.0, 0.0),
+                argb: (255, r, g, b),
+            };
+
+            let (r, g, b) = end;
+
+            let end = Gradient {
+                pos: (self.w as f32, self.h as f32),
+                argb: (255, r, g, b),
+            };
+
+            let paint = PaintBuilder::gradient(start, end)?.build();
+            let rect = Rect::new(0.0, 0.0, self.w as f32, self.h as f32);
+            self.canvas.draw_rect(rect, &paint);
+
+            return Ok(self);
+        }
+
         assets.push("backgrounds");
         assets.push(title.prefix.filename());
         let bytes = fs::read(assets).map_err(BackgroundError::File)?;
@@ -0,0 +1,90 @@
+use bathbot_model::CardLayout;
+use skia_safe::Canvas;
+
+use crate::{
+    builder::{
+        card::{COMPACT_EXTRAS_H, COMPACT_H, COMPACT_W, CardBuilder, H, INFO_EXTRAS_H, W},
+        paint::Palette,
+    },
+    card::{BathbotCard, ReadyToDraw},
+    error::CardError,
+    font::FontData,
+};
+
+pub(crate) fn layout(layout: CardLayout) -> &'static dyn Layout {
+    match layout {
+        CardLayout::Tall => &TallLayout,
+        CardLayout::Compact => &CompactLayout,
+    }
+}
+
+pub(crate) trait Layout {
+    /// Width and height of the card.
+    fn size(&self, card: &BathbotCard<'_, ReadyToDraw>) -> (i32, i32);
+
+    /// Draw all elements onto the canvas which is of size
+    /// [`size`](Layout::size).
+    fn draw(
+        &self,
+        canvas: &Canvas,
+        card: &BathbotCard<'_, ReadyToDraw>,
+        fonts: &FontData,
+    ) -> Result<(), CardError>;
+}
+
+struct TallLayout;
+
+impl Layout for TallLayout {
+    fn size(&self, card: &BathbotCard<'_, ReadyToDraw>) -> (i32, i32) {
+        if card.inner.has_extras() {
+            (W, H + INFO_EXTRAS_H)
+        } else {
+            (W, H)
+        }
+    }
+
+    fn draw(
+        &self,
+        canvas: &Canvas,
+        card: &BathbotCard<'_, ReadyToDraw>,
+        fonts: &FontData,
+    ) -> Result<(), CardError> {
+        let palette = Palette::new(card.inner.theme);
+
+        CardBuilder::new(canvas, palette, self.size(card))
+            .draw_background(&card.title, card.inner.assets.clone())?
+            .draw_header(card.skills.mode(), &card.inner, &card.title, fonts)?
+            .draw_info(&card.inner, &card.skills, fonts)?
+            .draw_footer(&card.inner, fonts)?;
+
+        Ok(())
+    }
+}
+
+struct CompactLayout;
+
+impl Layout for CompactLayout {
+    fn size(&self, card: &BathbotCard<'_, ReadyToDraw>) -> (i32, i32) {
+        if card.inner.has_extras() {
+            (COMPACT_W, COMPACT_H + COMPACT_EXTRAS_H)
+        } else {
+            (COMPACT_W, COMPACT_H)
+        }
+    }
+
+    fn draw(
+        &self,
+        canvas: &Canvas,
+        card: &BathbotCard<'_, ReadyToDraw>,
+        fonts: &FontData,
+    ) -> Result<(), CardError> {
+        let palette = Palette::new(card.inner.theme);
+
+        CardBuilder::new(canvas, palette, self.size(card))
+            .draw_background(&card.title, card.inner.assets.clone())?
+            .draw_header(card.skills.mode(), &card.inner, &card.title, fonts)?
+            .draw_compact_info(&card.inner, &card.skills, fonts)?;
+
+        Ok(())
+    }
+}
@@ -1,3 +1,4 @@
 pub(crate) mod card;
 pub(crate) mod font;
+pub(crate) mod layout;
 pub(crate) mod paint;
@@ -1,5 +1,6 @@
 use std::mem;
 
+use bathbot_model::CardTheme;
 use skia_safe::{BlurStyle, Color, MaskFilter, Paint, Shader, TileMode};
 
 use crate::error::PaintError;
@@ -73,3 +74,101 @@ impl PaintBuilder {
         Ok(Self { paint })
     }
 }
+
+pub(crate) type Rgb = (u8, u8, u8);
+
+pub(crate) struct Palette {
+    /// Color of texts and icons
+    pub(crate) text: Rgb,
+    /// Color of bars and other highlights
+    pub(crate) accent: Rgb,
+    /// Base color of the translucent panels
+    pub(crate) panel: Rgb,
+    /// Start and end color of the background gradient.
+    /// If `None`, the title's background image will be drawn instead.
+    pub(crate) background: Option<(Rgb, Rgb)>,
+}
+
+impl Palette {
+    pub(crate) const fn new(theme: CardTheme) -> Self {
+        match theme {
+            CardTheme::Classic => Self {
+                text: (255, 255, 255),
+                accent: (255, 255, 255),
+                panel: (0, 0, 0),
+                background: None,
+            },
+            CardTheme::Midnight => Self {
+                text: (230, 233, 255),
+                accent: (129, 140, 248),
+                panel: (8, 10, 35),
+                background: Some(((15, 23, 42), (49, 46, 129))),
+            },
+            CardTheme::Sunset => Self {
+                text: (255, 244, 235),
+                accent: (255, 183, 77),
+                panel: (60, 10, 30),
+                background: Some(((255, 94, 98), (106, 48, 147))),
+            },
+            CardTheme::Forest => Self {
+                text: (236, 253, 245),
+                accent: (134, 239, 172),
+                panel: (2, 30, 20),
+                background: Some(((6, 78, 59), (20, 83, 45))),
+            },
+            CardTheme::Ocean => Self {
+                text: (240, 249, 255),
+                accent: (125, 211, 252),
+                panel: (3, 20, 45),
+                background: Some(((2, 132, 199), (30, 58, 138))),
+            },
+            CardTheme::Sakura => Self {
+                text: (255, 255, 255),
+                accent: (255, 183, 213),
+                panel: (80, 20, 50),
+                background: Some(((244, 143, 177), (136, 84, 208))),
+            },
+        }
+    }
+
+    pub(crate) fn text(&self) -> PaintBuilder {
+        let (r, g, b) = self.text;
+
+        PaintBuilder::rgb(r, g, b)
+    }
+
+    pub(crate) fn accent(&self) -> PaintBuilder {
+        let (r, g, b) = self.accent;
+
+        PaintBuilder::rgb(r, g, b)
+    }
+
+    pub(crate) fn panel(&self, alpha: u8) -> PaintBuilder {
+        let (r, g, b) = self.panel;
+        let mut builder = PaintBuilder::rgb(r, g, b);
+        builder.alpha(alpha);
+
+        builder
+    }
+
+    /// Vertical gradient of the panel color for a rect of the given size.
+    pub(crate) fn panel_gradient(
+        &self,
+        (w, h): (f32, f32),
+        (start_alpha, end_alpha): (u8, u8),
+    ) -> Result<PaintBuilder, PaintError> {
+        let (r, g, b) = self.panel;
+
+        let start = Gradient {
+            pos: (w / 2.0, 0.0),
+            argb: (start_alpha, r, g, b),
+        };
+
+        let end = Gradient {
+            pos: (w / 2.0, h),
+            argb: (end_alpha, r, g, b),
+        };
+
+        PaintBuilder::gradient(start, end)
+    }
+}
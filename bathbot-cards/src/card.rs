@@ -1,14 +1,15 @@
 use std::{collections::HashMap, hash::BuildHasher, marker::PhantomData, mem, path::PathBuf};
 
+use bathbot_model::{CardLayout, CardTheme};
 use rosu_pp::any::DifficultyAttributes;
 use rosu_v2::model::{GameMode, score::Score};
 use skia_safe::{EncodedImageFormat, surfaces};
 
 use crate::{
-    builder::card::{CardBuilder, H, W},
+    builder::layout,
     error::CardError,
     font::FontData,
-    skills::{CardTitle, Skills},
+    skills::{CardTitle, ManiaPatterns, Skills},
 };
 
 pub struct UserNext;
@@ -24,6 +25,10 @@ pub(crate) type Maps<S> = HashMap<u32, RequiredAttributes, S>;
 pub struct RequiredAttributes {
     pub difficulty: DifficultyAttributes,
     pub od: f32,
+    /// Only required for mania. Analyzed from the map itself via
+    /// [`ManiaPatterns::new`] since the difficulty attributes lack the
+    /// necessary information.
+    pub patterns: Option<ManiaPatterns>,
 }
 
 pub struct BathbotCard<'a, Status> {
@@ -45,6 +50,17 @@ pub(crate) struct CardInner<'a> {
     pub(crate) flag: &'a [u8],
     pub(crate) date: &'a str,
     pub(crate) assets: PathBuf,
+    pub(crate) theme: CardTheme,
+    pub(crate) layout: CardLayout,
+    pub(crate) playcount: Option<u32>,
+    pub(crate) top_pp: Option<f32>,
+    pub(crate) top_mapper: Option<&'a str>,
+}
+
+impl CardInner<'_> {
+    pub(crate) fn has_extras(&self) -> bool {
+        self.playcount.is_some() || self.top_pp.is_some() || self.top_mapper.is_some()
+    }
 }
 
 impl<'a, Status> BathbotCard<'a, Status> {
@@ -130,16 +146,47 @@ impl<'a> BathbotCard<'a, AssetsPathNext> {
     }
 }
 
-impl BathbotCard<'_, ReadyToDraw> {
+impl<'a> BathbotCard<'a, ReadyToDraw> {
+    pub fn theme(&mut self, theme: CardTheme) -> &mut Self {
+        self.inner.theme = theme;
+
+        self
+    }
+
+    pub fn layout(&mut self, layout: CardLayout) -> &mut Self {
+        self.inner.layout = layout;
+
+        self
+    }
+
+    /// Show the playcount as extra stat.
+    pub fn playcount(&mut self, playcount: u32) -> &mut Self {
+        self.inner.playcount = Some(playcount);
+
+        self
+    }
+
+    /// Show the pp of the top score as extra stat.
+    pub fn top_pp(&mut self, pp: f32) -> &mut Self {
+        self.inner.top_pp = Some(pp);
+
+        self
+    }
+
+    /// Show the most occurring mapper in top scores as extra stat.
+    pub fn top_mapper(&mut self, mapper: &'a str) -> &mut Self {
+        self.inner.top_mapper = Some(mapper);
+
+        self
+    }
+
     pub fn draw(&self) -> Result<Vec<u8>, CardError> {
         let fonts = FontData::new(self.inner.assets.clone())?;
-        let mut surface = surfaces::raster_n32_premul((W, H)).ok_or(CardError::CreateSurface)?;
+        let layout = layout::layout(self.inner.layout);
+        let mut surface =
+            surfaces::raster_n32_premul(layout.size(self)).ok_or(CardError::CreateSurface)?;
 
-        CardBuilder::new(surface.canvas())
-            .draw_background(&self.title, self.inner.assets.clone())?
-            .draw_header(self.skills.mode(), &self.inner, &self.title, &fonts)?
-            .draw_info(&self.inner, &self.skills, &fonts)?
-            .draw_footer(&self.inner, &fonts)?;
+        layout.draw(surface.canvas(), self, &fonts)?;
 
         surface
             .image_snapshot()
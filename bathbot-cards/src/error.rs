@@ -44,6 +44,8 @@ pub enum BackgroundError {
     Image,
     #[error("Failed to read image file")]
     File(#[source] IoError),
+    #[error("Paint error")]
+    Paint(#[from] PaintError),
 }
 
 #[derive(Debug, ThisError)]
@@ -5,4 +5,9 @@ mod font;
 mod skills;
 mod svg;
 
-pub use self::card::{BathbotCard, RequiredAttributes};
+pub use bathbot_model::{CardLayout, CardTheme};
+
+pub use self::{
+    card::{BathbotCard, RequiredAttributes},
+    skills::ManiaPatterns,
+};
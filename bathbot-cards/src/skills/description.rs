@@ -5,6 +5,8 @@ use rosu_v2::{
     mods,
 };
 
+use super::{Skills, TitleSuffix};
+
 enum TitleDescription {
     NoMod,
     DoubleTime,
@@ -28,6 +30,14 @@ enum TitleDescription {
     Lazer,
     Key(usize),
     MultiKey,
+    Syncopated,
+    ColourReading,
+    IronWristed,
+    Hyperdashing,
+    PixelPerfect,
+    ChordSmashing,
+    JackHammering,
+    StreamRolling,
 }
 
 impl Display for TitleDescription {
@@ -55,6 +65,14 @@ impl Display for TitleDescription {
             Self::Lazer => "New-Skool",
             Self::Key(key) => return write!(f, "{key}K"),
             Self::MultiKey => "Multi-Key",
+            Self::Syncopated => "Syncopated",
+            Self::ColourReading => "Colour-Reading",
+            Self::IronWristed => "Iron-Wristed",
+            Self::Hyperdashing => "Hyperdashing",
+            Self::PixelPerfect => "Pixel-Perfect",
+            Self::ChordSmashing => "Chord-Smashing",
+            Self::JackHammering => "Jack-Hammering",
+            Self::StreamRolling => "Stream-Rolling",
         };
 
         f.write_str(desc)
@@ -79,7 +97,8 @@ impl TitleDescriptions {
     const SO_COUNT: usize = 20;
     const TC_COUNT: usize = 60;
 
-    pub(crate) fn new(mode: GameMode, scores: &[Score], legacy_scores: bool) -> Self {
+    pub(crate) fn new(skills: &Skills, scores: &[Score], legacy_scores: bool) -> Self {
+        let mode = skills.mode();
         let mut nomod = 0;
         let mut hidden = 0;
         let mut doubletime = 0;
@@ -217,7 +236,7 @@ impl TitleDescriptions {
             }
         }
 
-        if let [] | [TitleDescription::Lazer] = mods.as_slice() {
+        let mut descriptions = if let [] | [TitleDescription::Lazer] = mods.as_slice() {
             if nomod < Self::NO_NM_COUNT {
                 TitleDescription::FewNoMod.into()
             } else {
@@ -225,6 +244,74 @@ impl TitleDescriptions {
             }
         } else {
             mods
+        };
+
+        descriptions.push_skills(skills);
+
+        descriptions
+    }
+
+    /// Adds descriptions for all high skills except for accuracy and the
+    /// highest skill because those are already covered by the suffix.
+    fn push_skills(&mut self, skills: &Skills) {
+        let (acc, skills) = match skills {
+            Skills::Osu { .. } => return,
+            Skills::Taiko {
+                acc,
+                rhythm,
+                color,
+                stamina,
+            } => (
+                *acc,
+                vec![
+                    (*rhythm, TitleDescription::Syncopated),
+                    (*color, TitleDescription::ColourReading),
+                    (*stamina, TitleDescription::IronWristed),
+                ],
+            ),
+            Skills::Catch {
+                acc,
+                movement,
+                precision,
+            } => (
+                *acc,
+                vec![
+                    (*movement, TitleDescription::Hyperdashing),
+                    (*precision, TitleDescription::PixelPerfect),
+                ],
+            ),
+            Skills::Mania {
+                acc,
+                chord,
+                jack,
+                stream,
+            } => (
+                *acc,
+                vec![
+                    (*chord, TitleDescription::ChordSmashing),
+                    (*jack, TitleDescription::JackHammering),
+                    (*stream, TitleDescription::StreamRolling),
+                ],
+            ),
+        };
+
+        let max = skills.iter().fold(acc, |max, (skill, _)| max.max(*skill));
+        let skill_count = skills.len();
+
+        let mut high: Vec<_> = skills
+            .into_iter()
+            .filter(|(skill, _)| TitleSuffix::is_within_threshold(*skill, max))
+            .collect();
+
+        // All skills being high is already described by the suffix
+        if high.len() == skill_count && TitleSuffix::is_within_threshold(acc, max) {
+            return;
+        }
+
+        high.sort_unstable_by(|(a, _), (b, _)| b.total_cmp(a));
+
+        for (_, desc) in high.into_iter().skip(1) {
+            self.push(desc);
         }
     }
 
@@ -1,4 +1,5 @@
 mod description;
+mod patterns;
 mod prefix;
 mod suffix;
 mod title;
@@ -13,16 +14,52 @@ use rosu_pp::{
 };
 use rosu_v2::model::{GameMode, score::Score};
 
+pub use self::patterns::ManiaPatterns;
 pub(crate) use self::{
     description::TitleDescriptions, prefix::TitlePrefix, suffix::TitleSuffix, title::CardTitle,
 };
 use crate::card::Maps;
 
+/// Skill values of a user, derived from their top scores which are weighted
+/// the same way as pp.
+///
+/// Accuracy is the accuracy pp for osu!standard and taiko. For catch and
+/// mania it's based on the score's accuracy and the map's star rating.
 pub enum Skills {
-    Osu { acc: f64, aim: f64, speed: f64 },
-    Taiko { acc: f64, strain: f64 },
-    Catch { acc: f64, movement: f64 },
-    Mania { acc: f64, strain: f64 },
+    Osu {
+        acc: f64,
+        /// Aim pp of the performance attributes
+        aim: f64,
+        /// Speed pp of the performance attributes
+        speed: f64,
+    },
+    /// Rhythm, color, and stamina split the difficulty pp based on the
+    /// corresponding strain values of the difficulty attributes.
+    Taiko {
+        acc: f64,
+        rhythm: f64,
+        color: f64,
+        stamina: f64,
+    },
+    Catch {
+        acc: f64,
+        /// Total pp of the performance attributes since catch pp only
+        /// considers movement
+        movement: f64,
+        /// Not based on a pp component but on the stars and approach rate of
+        /// the difficulty attributes, scaled by the share of tiny droplets and
+        /// how many of them were caught.
+        precision: f64,
+    },
+    /// Chord, jack, and stream split the difficulty pp based on the map's
+    /// [`ManiaPatterns`]. Mania's difficulty attributes only contain a single
+    /// strain value so the patterns are analyzed from the map itself.
+    Mania {
+        acc: f64,
+        chord: f64,
+        jack: f64,
+        stream: f64,
+    },
 }
 
 impl Skills {
@@ -37,6 +74,16 @@ impl Skills {
             -101.0 * factor + 101.0
         };
 
+        // Splits a difficulty value into its skill components so that the
+        // most prevalent skill receives the full value.
+        let split = |difficulty: f64, skill: f64, max_skill: f64| {
+            if max_skill > 0.0 {
+                difficulty * skill / max_skill
+            } else {
+                difficulty
+            }
+        };
+
         match mode {
             GameMode::Osu => {
                 let mut acc = 0.0;
@@ -91,7 +138,9 @@ impl Skills {
             }
             GameMode::Taiko => {
                 let mut acc = 0.0;
-                let mut strain = 0.0;
+                let mut rhythm = 0.0;
+                let mut color = 0.0;
+                let mut stamina = 0.0;
                 let mut weight_sum = 0.0;
 
                 const ACC_NERF: f64 = 1.15;
@@ -120,23 +169,37 @@ impl Skills {
                     let difficulty_val = attrs.pp_difficulty / DIFFICULTY_NERF;
                     let weight = 0.95_f64.powi(i as i32);
 
+                    let diff = &attrs.difficulty;
+                    let max_skill = diff.rhythm.max(diff.color).max(diff.stamina);
+
                     acc += acc_val * weight;
-                    strain += difficulty_val * weight;
+                    rhythm += split(difficulty_val, diff.rhythm, max_skill) * weight;
+                    color += split(difficulty_val, diff.color, max_skill) * weight;
+                    stamina += split(difficulty_val, diff.stamina, max_skill) * weight;
                     weight_sum += weight;
                 }
 
                 acc = map(acc / weight_sum);
-                strain = map(strain / weight_sum);
-
-                Self::Taiko { acc, strain }
+                rhythm = map(rhythm / weight_sum);
+                color = map(color / weight_sum);
+                stamina = map(stamina / weight_sum);
+
+                Self::Taiko {
+                    acc,
+                    rhythm,
+                    color,
+                    stamina,
+                }
             }
             GameMode::Catch => {
                 let mut acc = 0.0;
                 let mut movement = 0.0;
+                let mut precision = 0.0;
                 let mut weight_sum = 0.0;
 
                 const ACC_BUFF: f64 = 1.7;
                 const MOVEMENT_NERF: f64 = 5.1;
+                const PRECISION_BUFF: f64 = 2.5;
 
                 for (i, score) in scores.iter().enumerate() {
                     let Some(attrs) = maps.remove(&score.map_id) else {
@@ -181,21 +244,49 @@ impl Skills {
                         * ACC_BUFF;
 
                     let movement_val = pp / MOVEMENT_NERF;
+
+                    // Precision rewards catching the tiny droplets of
+                    // droplet-heavy maps, especially with high approach rate
+                    let tiny_total =
+                        score.statistics.small_tick_hit + score.statistics.small_tick_miss;
+
+                    let tiny_acc = if tiny_total > 0 {
+                        score.statistics.small_tick_hit as f64 / tiny_total as f64
+                    } else {
+                        1.0
+                    };
+
+                    let tiny_share = difficulty.n_tiny_droplets as f64 / n_objects.max(1.0);
+
+                    let precision_val = difficulty.stars.powi(2)
+                        * (difficulty.ar / 9.0).max(0.0).sqrt()
+                        * (0.5 + tiny_share)
+                        * tiny_acc.powi(4)
+                        * PRECISION_BUFF;
+
                     let weight = 0.95_f64.powi(i as i32);
 
                     acc += acc_val * weight;
                     movement += movement_val * weight;
+                    precision += precision_val * weight;
                     weight_sum += weight;
                 }
 
                 acc = map(acc / weight_sum);
                 movement = map(movement / weight_sum);
+                precision = map(precision / weight_sum);
 
-                Self::Catch { acc, movement }
+                Self::Catch {
+                    acc,
+                    movement,
+                    precision,
+                }
             }
             GameMode::Mania => {
                 let mut acc = 0.0;
-                let mut strain = 0.0;
+                let mut chord = 0.0;
+                let mut jack = 0.0;
+                let mut stream = 0.0;
                 let mut weight_sum = 0.0;
 
                 const ACC_BUFF: f64 = 2.1;
@@ -215,6 +306,8 @@ impl Skills {
                         misses: score.statistics.miss,
                     };
 
+                    let patterns = attrs.patterns;
+
                     let attrs = ManiaPerformance::try_new(attrs.difficulty)
                         .unwrap()
                         .mods(score.mods.clone())
@@ -238,15 +331,33 @@ impl Skills {
                     let difficulty_val = attrs.pp_difficulty / DIFFICULTY_NERF;
                     let weight = 0.95_f64.powi(i as i32);
 
+                    // Without known patterns, all skills receive the full value
+                    let ManiaPatterns {
+                        chord: chord_share,
+                        jack: jack_share,
+                        stream: stream_share,
+                    } = patterns.unwrap_or_default();
+
+                    let max_share = chord_share.max(jack_share).max(stream_share);
+
                     acc += acc_val * weight;
-                    strain += difficulty_val * weight;
+                    chord += split(difficulty_val, chord_share, max_share) * weight;
+                    jack += split(difficulty_val, jack_share, max_share) * weight;
+                    stream += split(difficulty_val, stream_share, max_share) * weight;
                     weight_sum += weight;
                 }
 
                 acc = map(acc / weight_sum);
-                strain = map(strain / weight_sum);
-
-                Self::Mania { acc, strain }
+                chord = map(chord / weight_sum);
+                jack = map(jack / weight_sum);
+                stream = map(stream / weight_sum);
+
+                Self::Mania {
+                    acc,
+                    chord,
+                    jack,
+                    stream,
+                }
             }
         }
     }
@@ -0,0 +1,83 @@
+use rosu_pp::{Beatmap, model::mode::GameMode};
+
+/// Tendencies of a mania map's note patterns, each value between 0 and 1.
+#[derive(Copy, Clone, Debug, Default)]
+pub struct ManiaPatterns {
+    /// Share of notes that are part of a chord
+    pub(crate) chord: f64,
+    /// Share of dense row transitions that repeat a column
+    pub(crate) jack: f64,
+    /// Share of dense row transitions between single notes on different
+    /// columns
+    pub(crate) stream: f64,
+}
+
+impl ManiaPatterns {
+    /// Notes within this many ms are considered to be on the same row
+    const ROW_LENIENCY: f64 = 2.0;
+
+    /// Rows that are further apart than this many ms are not considered dense
+    const DENSE_GAP: f64 = 200.0;
+
+    /// Analyzes the note patterns of a mania map.
+    ///
+    /// This requires the [`Beatmap`] rather than its difficulty attributes
+    /// because mania's attributes only carry the aggregated strain and no
+    /// information about columns or note timings.
+    ///
+    /// Returns `None` if the map is not a native mania map.
+    pub fn new(map: &Beatmap) -> Option<Self> {
+        if map.mode != GameMode::Mania || map.is_convert || map.hit_objects.is_empty() {
+            return None;
+        }
+
+        let keys = (map.cs.round() as u32).clamp(1, 32);
+
+        // Columns of all notes grouped by rows as bitflags
+        let mut rows: Vec<(f64, u32)> = Vec::new();
+
+        for h in map.hit_objects.iter() {
+            let column = ((h.pos.x * keys as f32 / 512.0) as u32).min(keys - 1);
+
+            match rows.last_mut() {
+                Some((time, columns)) if h.start_time - *time <= Self::ROW_LENIENCY => {
+                    *columns |= 1 << column;
+                }
+                _ => rows.push((h.start_time, 1 << column)),
+            }
+        }
+
+        let chord_notes: u32 = rows
+            .iter()
+            .map(|(_, columns)| columns.count_ones())
+            .filter(|&count| count > 1)
+            .sum();
+
+        let mut jacks = 0;
+        let mut streams = 0;
+
+        for window in rows.windows(2) {
+            let [(prev_time, prev), (curr_time, curr)] = window else {
+                unreachable!()
+            };
+
+            if curr_time - prev_time > Self::DENSE_GAP {
+                continue;
+            }
+
+            if prev & curr > 0 {
+                jacks += 1;
+            } else if prev.count_ones() == 1 && curr.count_ones() == 1 {
+                streams += 1;
+            }
+        }
+
+        let transitions = (rows.len() - 1).max(1) as f64;
+
+        Some(Self {
+            chord: chord_notes as f64 / map.hit_objects.len() as f64,
+            jack: jacks as f64 / transitions,
+            stream: streams as f64 / transitions,
+        })
+    }
+}
@@ -10,6 +10,12 @@ pub(crate) enum TitleSuffix {
     Masher,
     Gamer,
     DropletDodger,
+    Metronome,
+    PatternReader,
+    PixelHunter,
+    Chordsmith,
+    Jackhammer,
+    Streamer,
 }
 
 impl TitleSuffix {
@@ -32,47 +38,60 @@ impl TitleSuffix {
         }
     }
 
-    pub(super) fn taiko(acc: f64, strain: f64, max: f64) -> Self {
-        let acc = Self::is_within_threshold(acc, max);
-        let strain = Self::is_within_threshold(strain, max);
+    pub(super) fn taiko(acc: f64, rhythm: f64, color: f64, stamina: f64, max: f64) -> Self {
+        let skills = [
+            (rhythm, Self::Metronome),
+            (color, Self::PatternReader),
+            (stamina, Self::Masher),
+        ];
 
-        match (acc, strain) {
-            (true, true) => Self::Gamer,
-            (true, false) => Self::RhythmEnjoyer,
-            (false, true) => Self::Masher,
-            (false, false) => unreachable!(),
-        }
+        Self::specialized(acc, skills, max)
     }
 
-    pub(super) fn catch(acc: f64, movement: f64, max: f64) -> Self {
-        let acc = Self::is_within_threshold(acc, max);
-        let movement = Self::is_within_threshold(movement, max);
+    pub(super) fn catch(acc: f64, movement: f64, precision: f64, max: f64) -> Self {
+        let skills = [
+            (movement, Self::DropletDodger),
+            (precision, Self::PixelHunter),
+        ];
 
-        match (acc, movement) {
-            (true, true) => Self::Gamer,
-            (true, false) => Self::RhythmEnjoyer,
-            (false, true) => Self::DropletDodger,
-            (false, false) => unreachable!(),
-        }
+        Self::specialized(acc, skills, max)
     }
 
-    pub(super) fn mania(acc: f64, strain: f64, max: f64) -> Self {
-        let acc = Self::is_within_threshold(acc, max);
-        let strain = Self::is_within_threshold(strain, max);
+    pub(super) fn mania(acc: f64, chord: f64, jack: f64, stream: f64, max: f64) -> Self {
+        let skills = [
+            (chord, Self::Chordsmith),
+            (jack, Self::Jackhammer),
+            (stream, Self::Streamer),
+        ];
 
-        match (acc, strain) {
-            (true, true) => Self::Gamer,
-            (true, false) => Self::RhythmEnjoyer,
-            (false, true) => Self::Masher,
-            (false, false) => unreachable!(),
+        Self::specialized(acc, skills, max)
+    }
+
+    /// If all skills are high, the suffix is [`TitleSuffix::Gamer`].
+    /// Otherwise, if only accuracy is high, it's
+    /// [`TitleSuffix::RhythmEnjoyer`], or the suffix of the highest other skill.
+    fn specialized<const N: usize>(acc: f64, skills: [(f64, Self); N], max: f64) -> Self {
+        let acc_high = Self::is_within_threshold(acc, max);
+
+        let all_high = skills
+            .iter()
+            .all(|(skill, _)| Self::is_within_threshold(*skill, max));
+
+        if acc_high && all_high {
+            return Self::Gamer;
         }
+
+        skills
+            .into_iter()
+            .filter(|(skill, _)| Self::is_within_threshold(*skill, max))
+            .max_by(|(a, _), (b, _)| a.total_cmp(b))
+            .map_or(Self::RhythmEnjoyer, |(_, suffix)| suffix)
     }
 
-    fn is_within_threshold(val: f64, max: f64) -> bool {
+    pub(super) fn is_within_threshold(val: f64, max: f64) -> bool {
         val / max > Self::THRESHOLD
     }
 }
-
 impl Display for TitleSuffix {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
         let suffix = match self {
@@ -85,6 +104,12 @@ impl Display for TitleSuffix {
             Self::Masher => "Masher",
             Self::Gamer => "Gamer",
             Self::DropletDodger => "Droplet Dodger",
+            Self::Metronome => "Metronome",
+            Self::PatternReader => "Pattern Reader",
+            Self::PixelHunter => "Pixel Hunter",
+            Self::Chordsmith => "Chordsmith",
+            Self::Jackhammer => "Jackhammer",
+            Self::Streamer => "Streamer",
         };
 
         f.write_str(suffix)
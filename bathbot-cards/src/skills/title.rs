@@ -18,25 +18,42 @@ impl CardTitle {
 
                 (max, TitleSuffix::osu(*acc, *aim, *speed, max))
             }
-            Skills::Taiko { acc, strain } => {
-                let max = acc.max(*strain);
+            Skills::Taiko {
+                acc,
+                rhythm,
+                color,
+                stamina,
+            } => {
+                let max = acc.max(*rhythm).max(*color).max(*stamina);
 
-                (max, TitleSuffix::taiko(*acc, *strain, max))
+                (
+                    max,
+                    TitleSuffix::taiko(*acc, *rhythm, *color, *stamina, max),
+                )
             }
-            Skills::Catch { acc, movement } => {
-                let max = acc.max(*movement);
+            Skills::Catch {
+                acc,
+                movement,
+                precision,
+            } => {
+                let max = acc.max(*movement).max(*precision);
 
-                (max, TitleSuffix::catch(*acc, *movement, max))
+                (max, TitleSuffix::catch(*acc, *movement, *precision, max))
             }
-            Skills::Mania { acc, strain } => {
-                let max = acc.max(*strain);
+            Skills::Mania {
+                acc,
+                chord,
+                jack,
+                stream,
+            } => {
+                let max = acc.max(*chord).max(*jack).max(*stream);
 
-                (max, TitleSuffix::mania(*acc, *strain, max))
+                (max, TitleSuffix::mania(*acc, *chord, *jack, *stream, max))
             }
         };
 
         let prefix = TitlePrefix::new(max);
-        let description = TitleDescriptions::new(skills.mode(), scores, legacy_scores);
+        let description = TitleDescriptions::new(skills, scores, legacy_scores);
 
         Self {
             prefix,
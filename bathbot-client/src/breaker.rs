@@ -0,0 +1,192 @@
+use std::{
+    sync::Mutex,
+    time::{Duration, Instant},
+};
+
+use crate::{metrics::ClientMetrics, site::Site};
+
+/// Amount of consecutive failed requests after which a site is short-circuited
+const FAILURE_THRESHOLD: u32 = 5;
+
+/// How long a site is short-circuited before a probe request is let through
+const OPEN_DURATION: Duration = Duration::from_secs(30);
+
+/// State of a site's circuit breaker.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[repr(u8)]
+pub(crate) enum CircuitState {
+    /// Requests pass through
+    Closed = 0,
+    /// Requests are short-circuited
+    Open = 1,
+    /// A single probe request is in flight to check if the site recovered
+    HalfOpen = 2,
+}
+
+pub(crate) struct CircuitBreakers {
+    inner: Box<[CircuitBreaker]>,
+}
+
+impl CircuitBreakers {
+    pub fn new() -> Self {
+        let inner = Site::ALL
+            .iter()
+            .map(|&site| {
+                ClientMetrics::circuit_state(site, CircuitState::Closed);
+
+                CircuitBreaker::default()
+            })
+            .collect();
+
+        Self { inner }
+    }
+
+    /// Returns whether a request to the site may be sent.
+    pub fn acquire(&self, site: Site) -> bool {
+        self.update(site, |breaker| breaker.acquire(Instant::now()))
+    }
+
+    /// Marks a request to the site as successful.
+    pub fn success(&self, site: Site) {
+        self.update(site, Breaker::success)
+    }
+
+    /// Marks a request to the site as failed.
+    pub fn failure(&self, site: Site) {
+        self.update(site, |breaker| breaker.failure(Instant::now()))
+    }
+
+    fn update<F, T>(&self, site: Site, f: F) -> T
+    where
+        F: FnOnce(&mut Breaker) -> T,
+    {
+        let mut breaker = self.inner[site as usize]
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+
+        let prev = breaker.state();
+        let res = f(&mut *breaker);
+        let curr = breaker.state();
+
+        if prev != curr {
+            drop(breaker);
+
+            if curr == CircuitState::Open {
+                warn!(
+                    site = site.as_str(),
+                    "Short-circuiting site after repeated failures"
+                );
+            }
+
+            ClientMetrics::circuit_state(site, curr);
+        }
+
+        res
+    }
+}
+
+type CircuitBreaker = Mutex<Breaker>;
+
+#[derive(Copy, Clone, Debug)]
+enum Breaker {
+    Closed { failures: u32 },
+    Open { since: Instant },
+    HalfOpen { since: Instant },
+}
+
+impl Default for Breaker {
+    fn default() -> Self {
+        Self::Closed { failures: 0 }
+    }
+}
+
+impl Breaker {
+    fn state(&self) -> CircuitState {
+        match self {
+            Self::Closed { .. } => CircuitState::Closed,
+            Self::Open { .. } => CircuitState::Open,
+            Self::HalfOpen { .. } => CircuitState::HalfOpen,
+        }
+    }
+
+    fn acquire(&mut self, now: Instant) -> bool {
+        match *self {
+            Self::Closed { .. } => true,
+            // Once the site had some time to recover, let a probe through.
+            // Probes that never report back, e.g. because their future was
+            // dropped, are replaced after the same duration.
+            Self::Open { since } | Self::HalfOpen { since } if now - since >= OPEN_DURATION => {
+                *self = Self::HalfOpen { since: now };
+
+                true
+            }
+            Self::Open { .. } | Self::HalfOpen { .. } => false,
+        }
+    }
+
+    fn success(&mut self) {
+        *self = Self::Closed { failures: 0 };
+    }
+
+    fn failure(&mut self, now: Instant) {
+        match *self {
+            Self::Closed { failures } if failures + 1 < FAILURE_THRESHOLD => {
+                *self = Self::Closed {
+                    failures: failures + 1,
+                }
+            }
+            Self::Closed { .. } | Self::HalfOpen { .. } => *self = Self::Open { since: now },
+            Self::Open { .. } => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_opens_after_threshold() {
+        let now = Instant::now();
+        let mut breaker = Breaker::default();
+
+        for _ in 0..FAILURE_THRESHOLD - 1 {
+            breaker.failure(now);
+            assert_eq!(breaker.state(), CircuitState::Closed);
+        }
+
+        breaker.success();
+        breaker.failure(now);
+        assert_eq!(breaker.state(), CircuitState::Closed);
+
+        for _ in 0..FAILURE_THRESHOLD - 1 {
+            breaker.failure(now);
+        }
+
+        assert_eq!(breaker.state(), CircuitState::Open);
+        assert!(!breaker.acquire(now));
+    }
+
+    #[test]
+    fn test_half_open_probe() {
+        let now = Instant::now();
+        let mut breaker = Breaker::Open { since: now };
+
+        let later = now + OPEN_DURATION;
+        assert!(breaker.acquire(later));
+        assert_eq!(breaker.state(), CircuitState::HalfOpen);
+
+        // Only one probe at a time
+        assert!(!breaker.acquire(later));
+
+        breaker.failure(later);
+        assert_eq!(breaker.state(), CircuitState::Open);
+
+        let even_later = later + OPEN_DURATION;
+        assert!(breaker.acquire(even_later));
+
+        breaker.success();
+        assert_eq!(breaker.state(), CircuitState::Closed);
+        assert!(breaker.acquire(even_later));
+    }
+}
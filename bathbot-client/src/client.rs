@@ -4,7 +4,7 @@ use bytes::Bytes;
 use eyre::{Result, WrapErr};
 use http_body_util::{BodyExt, Collected, Full};
 use hyper::{
-    Method, Request, Response,
+    Method, Request, Response, StatusCode,
     body::Incoming,
     header::{AUTHORIZATION, CONTENT_LENGTH, CONTENT_TYPE, USER_AGENT},
 };
@@ -13,9 +13,14 @@ use hyper_util::{
     client::legacy::{Builder, Client as HyperClient, Error as HyperError, connect::HttpConnector},
     rt::TokioExecutor,
 };
+use tokio::time::sleep;
 
 use crate::{
-    ClientError, MY_USER_AGENT, Ratelimiters, Site, metrics::ClientMetrics, multipart::Multipart,
+    ClientError, MY_USER_AGENT, Ratelimiters, Site,
+    breaker::CircuitBreakers,
+    metrics::ClientMetrics,
+    multipart::Multipart,
+    response_cache::{ResponseCache, Validators},
 };
 
 pub(crate) type InnerClient = HyperClient<HttpsConnector<HttpConnector>, Body>;
@@ -27,6 +32,24 @@ pub struct Client {
     twitch: bathbot_model::TwitchData,
     github_auth: Box<str>,
     ratelimiters: Ratelimiters,
+    breakers: CircuitBreakers,
+    response_cache: Option<ResponseCache>,
+}
+
+/// Outcome of a single GET attempt
+enum GetAttempt {
+    Done(Result<GetResponse, ClientError>),
+    /// Failed in a way that might resolve itself when retrying
+    Transient(ClientError),
+}
+
+enum GetResponse {
+    Modified {
+        bytes: Bytes,
+        validators: Validators,
+    },
+    /// The cached response that was revalidated is still up to date
+    NotModified,
 }
 
 impl Client {
@@ -56,12 +79,21 @@ impl Client {
         Ok(Self {
             client,
             ratelimiters: Ratelimiters::new(),
+            breakers: CircuitBreakers::new(),
+            response_cache: None,
             #[cfg(feature = "twitch")]
             twitch,
             github_auth: format!("Bearer {github_token}").into_boxed_str(),
         })
     }
 
+    /// Cache GET responses of sites as configured in the [`ResponseCache`].
+    pub fn with_response_cache(mut self, response_cache: ResponseCache) -> Self {
+        self.response_cache = Some(response_cache);
+
+        self
+    }
+
     pub(crate) async fn ratelimit(&self, site: Site) {
         self.ratelimiters.get(site).acquire_one().await
     }
@@ -74,18 +106,97 @@ impl Client {
         let url = url.as_ref();
         trace!("GET request to url {url}");
 
+        #[cfg(not(feature = "twitch"))]
+        if site == Site::Twitch {
+            return Err(ClientError::Report(eyre::Report::msg(
+                "twitch request without twitch feature",
+            )));
+        }
+
+        let cache = self.response_cache.as_ref();
+
+        let cached = match cache {
+            Some(cache) => cache.get(site, url).await,
+            None => None,
+        };
+
+        if let Some(ref cached) = cached
+            && cached.is_fresh()
+        {
+            ClientMetrics::cache_hit(site, false);
+
+            return Ok(cached.bytes.clone());
+        }
+
+        if !self.breakers.acquire(site) {
+            ClientMetrics::short_circuit(site);
+
+            return Err(ClientError::CircuitOpen);
+        }
+
+        let validators = cached.as_ref().map(|cached| &cached.validators);
+        let mut backoff = site.retry_policy().backoff();
+
+        let res = loop {
+            let err = match self.try_get_request(url, site, validators).await {
+                GetAttempt::Done(res) => break res,
+                GetAttempt::Transient(err) => err,
+            };
+
+            let Some(duration) = backoff.next() else {
+                self.breakers.failure(site);
+
+                return Err(err);
+            };
+
+            debug!(
+                ?err,
+                site = site.as_str(),
+                "Transient GET failure; retrying in {duration:?}"
+            );
+
+            ClientMetrics::retry(site);
+            sleep(duration).await;
+        };
+
+        self.breakers.success(site);
+
+        match res? {
+            GetResponse::Modified { bytes, validators } => {
+                if let Some(cache) = cache {
+                    cache.store(site, url, bytes.clone(), validators).await;
+                }
+
+                Ok(bytes)
+            }
+            GetResponse::NotModified => {
+                let Some(mut cached) = cached else {
+                    return Err(eyre!("Received 304 without conditional request to {url}").into());
+                };
+
+                ClientMetrics::cache_hit(site, true);
+
+                if let Some(cache) = cache {
+                    cache.refresh(site, url, &mut cached).await;
+                }
+
+                Ok(cached.bytes)
+            }
+        }
+    }
+
+    async fn try_get_request(
+        &self,
+        url: &str,
+        site: Site,
+        validators: Option<&Validators>,
+    ) -> GetAttempt {
         let req = Request::builder()
             .uri(url)
             .method(Method::GET)
             .header(USER_AGENT, MY_USER_AGENT);
 
         let req = match site {
-            #[cfg(not(feature = "twitch"))]
-            Site::Twitch => {
-                return Err(ClientError::Report(eyre::Report::msg(
-                    "twitch request without twitch feature",
-                )));
-            }
             #[cfg(feature = "twitch")]
             Site::Twitch => req
                 .header("Client-ID", self.twitch.client_id.clone())
@@ -96,22 +207,48 @@ impl Client {
             _ => req,
         };
 
-        let req = req
+        let req = match validators {
+            Some(validators) => validators.apply(req),
+            None => req,
+        };
+
+        let req = match req
             .body(Body::default())
-            .wrap_err("failed to build GET request")?;
+            .wrap_err("failed to build GET request")
+        {
+            Ok(req) => req,
+            Err(err) => return GetAttempt::Done(Err(err.into())),
+        };
 
-        let (response, start) = self
+        let (response, start) = match self
             .send_request(req, site)
             .await
-            .wrap_err("failed to receive GET response")?;
+            .wrap_err("failed to receive GET response")
+        {
+            Ok(tuple) => tuple,
+            Err(err) => return GetAttempt::Transient(err.into()),
+        };
 
         let status = response.status();
+
+        if status == StatusCode::NOT_MODIFIED && validators.is_some() {
+            ClientMetrics::observe(site, status, start.elapsed());
+
+            return GetAttempt::Done(Ok(GetResponse::NotModified));
+        }
+
+        let transient = status.is_server_error() || status == StatusCode::TOO_MANY_REQUESTS;
+        let validators = Validators::from_headers(response.headers());
         let bytes_res = Self::error_for_status(response, url).await;
 
         let latency = start.elapsed();
         ClientMetrics::observe(site, status, latency);
 
-        bytes_res
+        match bytes_res {
+            Ok(bytes) => GetAttempt::Done(Ok(GetResponse::Modified { bytes, validators })),
+            Err(err) if transient => GetAttempt::Transient(err),
+            Err(err) => GetAttempt::Done(Err(err)),
+        }
     }
 
     pub(crate) async fn make_multipart_post_request(
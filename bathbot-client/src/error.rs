@@ -9,6 +9,8 @@ pub enum ClientError {
     NotFound,
     #[error("status code 429 - ratelimited")]
     Ratelimited,
+    #[error("site is short-circuited after repeated failures")]
+    CircuitOpen,
     #[error(transparent)]
     Report(#[from] Report),
 }
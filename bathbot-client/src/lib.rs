@@ -4,6 +4,7 @@ extern crate eyre;
 #[macro_use]
 extern crate tracing;
 
+mod breaker;
 mod client;
 mod discord;
 mod error;
@@ -17,11 +18,18 @@ mod osustats;
 mod osutrack;
 mod relax;
 mod respektive;
+mod response_cache;
+mod retry;
 mod site;
 mod snipe;
 mod twitch;
 
-use self::site::{Ratelimiters, Site};
-pub use self::{client::Client, error::ClientError};
+use self::site::Ratelimiters;
+pub use self::{
+    client::Client,
+    error::ClientError,
+    response_cache::{CacheBackend, CacheFuture, MemoryCache, ResponseCache},
+    site::Site,
+};
 
 static MY_USER_AGENT: &str = env!("CARGO_PKG_NAME");
@@ -1,12 +1,16 @@
 use std::time::Duration;
 
 use hyper::StatusCode;
-use metrics::{counter, describe_counter, describe_histogram, histogram};
+use metrics::{counter, describe_counter, describe_gauge, describe_histogram, gauge, histogram};
 
-use crate::site::Site;
+use crate::{breaker::CircuitState, site::Site};
 
 const CLIENT_RESPONSE_TIME: &str = "client_response_time";
 const CLIENT_INTERNAL_ERRORS: &str = "client_internal_errors";
+const CLIENT_RETRIES: &str = "client_retries";
+const CLIENT_SHORT_CIRCUITS: &str = "client_short_circuits";
+const CLIENT_CIRCUIT_STATE: &str = "client_circuit_state";
+const CLIENT_CACHE_HITS: &str = "client_cache_hits";
 
 pub(crate) struct ClientMetrics;
 
@@ -21,6 +25,26 @@ impl ClientMetrics {
             CLIENT_INTERNAL_ERRORS,
             "Number of times an internal error occurred"
         );
+
+        describe_counter!(
+            CLIENT_RETRIES,
+            "Number of times a request was retried after a transient failure"
+        );
+
+        describe_counter!(
+            CLIENT_SHORT_CIRCUITS,
+            "Number of requests rejected by an open circuit breaker"
+        );
+
+        describe_gauge!(
+            CLIENT_CIRCUIT_STATE,
+            "Circuit breaker state per site; 0 = closed, 1 = open, 2 = half-open"
+        );
+
+        describe_counter!(
+            CLIENT_CACHE_HITS,
+            "Number of GET requests served from the response cache"
+        );
     }
 
     pub(crate) fn observe(site: Site, status: StatusCode, latency: Duration) {
@@ -35,4 +59,22 @@ impl ClientMetrics {
     pub(crate) fn internal_error(site: Site) {
         counter!(CLIENT_INTERNAL_ERRORS, "site" => site.as_str()).increment(1);
     }
+
+    pub(crate) fn retry(site: Site) {
+        counter!(CLIENT_RETRIES, "site" => site.as_str()).increment(1);
+    }
+
+    pub(crate) fn short_circuit(site: Site) {
+        counter!(CLIENT_SHORT_CIRCUITS, "site" => site.as_str()).increment(1);
+    }
+
+    pub(crate) fn circuit_state(site: Site, state: CircuitState) {
+        gauge!(CLIENT_CIRCUIT_STATE, "site" => site.as_str()).set(state as u8 as f64);
+    }
+
+    pub(crate) fn cache_hit(site: Site, revalidated: bool) {
+        let kind = if revalidated { "revalidated" } else { "fresh" };
+
+        counter!(CLIENT_CACHE_HITS, "site" => site.as_str(), "kind" => kind).increment(1);
+    }
 }
@@ -0,0 +1,385 @@
+use std::{
+    collections::HashMap,
+    pin::Pin,
+    sync::Mutex,
+    time::{Duration, Instant, SystemTime, UNIX_EPOCH},
+};
+
+use bytes::Bytes;
+use eyre::Result;
+use http::request::Builder as RequestBuilder;
+use hyper::{
+    HeaderMap,
+    header::{ETAG, IF_MODIFIED_SINCE, IF_NONE_MATCH, LAST_MODIFIED},
+};
+
+use crate::site::Site;
+
+/// Prefix of all keys that are passed to a [`CacheBackend`]
+const KEY_PREFIX: &str = "client_response:";
+
+/// Entries with validators are kept this many times their TTL so that they
+/// can still be revalidated once they're no longer fresh.
+const REVALIDATE_FACTOR: u32 = 4;
+
+pub type CacheFuture<'a, T> = Pin<Box<dyn Future<Output = T> + Send + 'a>>;
+
+/// Storage for raw response bytes.
+pub trait CacheBackend: Send + Sync + 'static {
+    /// Fetch the bytes stored for the key, if any.
+    fn get<'a>(&'a self, key: &'a str) -> CacheFuture<'a, Result<Option<Vec<u8>>>>;
+
+    /// Store the bytes for the key and evict them after the given duration.
+    fn set<'a>(
+        &'a self,
+        key: &'a str,
+        bytes: Vec<u8>,
+        expire: Duration,
+    ) -> CacheFuture<'a, Result<()>>;
+}
+
+/// Caches successful GET responses for the configured [`Site`]s.
+pub struct ResponseCache {
+    backend: Box<dyn CacheBackend>,
+    ttls: Box<[Option<Duration>]>,
+}
+
+impl ResponseCache {
+    /// Create a new cache without any site being cached yet.
+    pub fn new(backend: impl CacheBackend) -> Self {
+        Self {
+            backend: Box::new(backend),
+            ttls: vec![None; Site::ALL.len()].into_boxed_slice(),
+        }
+    }
+
+    /// Cache responses of the site for the given duration.
+    pub fn site_ttl(mut self, site: Site, ttl: Duration) -> Self {
+        self.ttls[site as usize] = Some(ttl);
+
+        self
+    }
+
+    pub(crate) async fn get(&self, site: Site, url: &str) -> Option<CachedResponse> {
+        self.ttls[site as usize]?;
+
+        match self.backend.get(&Self::key(url)).await {
+            Ok(Some(bytes)) => CachedResponse::decode(bytes),
+            Ok(None) => None,
+            Err(err) => {
+                warn!(?err, site = site.as_str(), "Failed to get cached response");
+
+                None
+            }
+        }
+    }
+
+    pub(crate) async fn store(&self, site: Site, url: &str, bytes: Bytes, validators: Validators) {
+        let Some(ttl) = self.ttls[site as usize] else {
+            return;
+        };
+
+        let entry = CachedResponse {
+            bytes,
+            validators,
+            fresh_until: unix_secs() + ttl.as_secs(),
+        };
+
+        self.store_entry(site, url, &entry, ttl).await;
+    }
+
+    /// Mark a revalidated entry as fresh again.
+    pub(crate) async fn refresh(&self, site: Site, url: &str, entry: &mut CachedResponse) {
+        let Some(ttl) = self.ttls[site as usize] else {
+            return;
+        };
+
+        entry.fresh_until = unix_secs() + ttl.as_secs();
+        self.store_entry(site, url, entry, ttl).await;
+    }
+
+    async fn store_entry(&self, site: Site, url: &str, entry: &CachedResponse, ttl: Duration) {
+        let expire = if entry.validators.is_empty() {
+            ttl
+        } else {
+            ttl * REVALIDATE_FACTOR
+        };
+
+        let key = Self::key(url);
+        let set_fut = self.backend.set(&key, entry.encode(), expire);
+
+        if let Err(err) = set_fut.await {
+            warn!(
+                ?err,
+                site = site.as_str(),
+                "Failed to store cached response"
+            );
+        }
+    }
+
+    fn key(url: &str) -> String {
+        format!("{KEY_PREFIX}{url}")
+    }
+}
+
+/// Headers that allow for conditional requests.
+#[derive(Default)]
+pub(crate) struct Validators {
+    etag: Option<Box<str>>,
+    last_modified: Option<Box<str>>,
+}
+
+impl Validators {
+    pub(crate) fn from_headers(headers: &HeaderMap) -> Self {
+        let get = |name| {
+            headers
+                .get(name)
+                .and_then(|value| value.to_str().ok())
+                .map(Box::from)
+        };
+
+        Self {
+            etag: get(ETAG),
+            last_modified: get(LAST_MODIFIED),
+        }
+    }
+
+    pub(crate) fn apply(&self, mut req: RequestBuilder) -> RequestBuilder {
+        if let Some(ref etag) = self.etag {
+            req = req.header(IF_NONE_MATCH, etag.as_ref());
+        }
+
+        if let Some(ref last_modified) = self.last_modified {
+            req = req.header(IF_MODIFIED_SINCE, last_modified.as_ref());
+        }
+
+        req
+    }
+
+    fn is_empty(&self) -> bool {
+        self.etag.is_none() && self.last_modified.is_none()
+    }
+}
+
+pub(crate) struct CachedResponse {
+    pub(crate) bytes: Bytes,
+    pub(crate) validators: Validators,
+    /// Unix timestamp in seconds until which the entry may be used without
+    /// revalidation
+    fresh_until: u64,
+}
+
+impl CachedResponse {
+    pub(crate) fn is_fresh(&self) -> bool {
+        unix_secs() < self.fresh_until
+    }
+
+    /// Layout: `fresh_until` as little-endian u64, then etag and
+    /// last-modified each prefixed by their length + 1 as little-endian u16
+    /// (0 meaning absent), followed by the body.
+    fn encode(&self) -> Vec<u8> {
+        let Validators {
+            etag,
+            last_modified,
+        } = &self.validators;
+
+        let validators_len = etag.as_ref().map_or(0, |etag| etag.len())
+            + last_modified.as_ref().map_or(0, |date| date.len());
+
+        let mut buf = Vec::with_capacity(12 + validators_len + self.bytes.len());
+        buf.extend_from_slice(&self.fresh_until.to_le_bytes());
+
+        for validator in [etag, last_modified] {
+            match validator {
+                Some(value) => {
+                    buf.extend_from_slice(&(value.len() as u16 + 1).to_le_bytes());
+                    buf.extend_from_slice(value.as_bytes());
+                }
+                None => buf.extend_from_slice(&0_u16.to_le_bytes()),
+            }
+        }
+
+        buf.extend_from_slice(&self.bytes);
+
+        buf
+    }
+
+    fn decode(bytes: Vec<u8>) -> Option<Self> {
+        let fresh_until = u64::from_le_bytes(bytes.get(..8)?.try_into().ok()?);
+        let mut idx = 8;
+
+        let mut validator = || {
+            let len = u16::from_le_bytes(bytes.get(idx..idx + 2)?.try_into().ok()?) as usize;
+            idx += 2;
+
+            if len == 0 {
+                return Some(None);
+            }
+
+            let value = std::str::from_utf8(bytes.get(idx..idx + len - 1)?).ok()?;
+            idx += len - 1;
+
+            Some(Some(Box::from(value)))
+        };
+
+        let etag = validator()?;
+        let last_modified = validator()?;
+
+        Some(Self {
+            bytes: Bytes::from(bytes).slice(idx..),
+            validators: Validators {
+                etag,
+                last_modified,
+            },
+            fresh_until,
+        })
+    }
+}
+
+/// In-memory [`CacheBackend`] that evicts the least recently used entry once
+/// its capacity is reached.
+pub struct MemoryCache {
+    inner: Mutex<MemoryCacheInner>,
+}
+
+impl MemoryCache {
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            inner: Mutex::new(MemoryCacheInner {
+                entries: HashMap::with_capacity(capacity),
+                capacity,
+                tick: 0,
+            }),
+        }
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, MemoryCacheInner> {
+        self.inner
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+impl CacheBackend for MemoryCache {
+    fn get<'a>(&'a self, key: &'a str) -> CacheFuture<'a, Result<Option<Vec<u8>>>> {
+        let bytes = self.lock().get(key, Instant::now());
+
+        Box::pin(async move { Ok(bytes) })
+    }
+
+    fn set<'a>(
+        &'a self,
+        key: &'a str,
+        bytes: Vec<u8>,
+        expire: Duration,
+    ) -> CacheFuture<'a, Result<()>> {
+        self.lock().set(key, bytes, Instant::now() + expire);
+
+        Box::pin(async { Ok(()) })
+    }
+}
+
+struct MemoryCacheInner {
+    entries: HashMap<Box<str>, MemoryEntry>,
+    capacity: usize,
+    tick: u64,
+}
+
+struct MemoryEntry {
+    bytes: Vec<u8>,
+    expires_at: Instant,
+    last_used: u64,
+}
+
+impl MemoryCacheInner {
+    fn get(&mut self, key: &str, now: Instant) -> Option<Vec<u8>> {
+        let entry = self.entries.get_mut(key)?;
+
+        if entry.expires_at <= now {
+            self.entries.remove(key);
+
+            return None;
+        }
+
+        self.tick += 1;
+        entry.last_used = self.tick;
+
+        Some(entry.bytes.clone())
+    }
+
+    fn set(&mut self, key: &str, bytes: Vec<u8>, expires_at: Instant) {
+        if self.capacity == 0 {
+            return;
+        }
+
+        if self.entries.len() >= self.capacity && !self.entries.contains_key(key) {
+            let lru = self
+                .entries
+                .iter()
+                .min_by_key(|(_, entry)| entry.last_used)
+                .map(|(key, _)| key.clone());
+
+            if let Some(lru) = lru {
+                self.entries.remove(&lru);
+            }
+        }
+
+        self.tick += 1;
+
+        let entry = MemoryEntry {
+            bytes,
+            expires_at,
+            last_used: self.tick,
+        };
+
+        self.entries.insert(Box::from(key), entry);
+    }
+}
+
+fn unix_secs() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |duration| duration.as_secs())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_encode_decode() {
+        let entry = CachedResponse {
+            bytes: Bytes::from_static(b"body"),
+            validators: Validators {
+                etag: Some(Box::from("\"abc\"")),
+                last_modified: None,
+            },
+            fresh_until: 123,
+        };
+
+        let decoded = CachedResponse::decode(entry.encode()).unwrap();
+
+        assert_eq!(decoded.bytes, entry.bytes);
+        assert_eq!(decoded.validators.etag, entry.validators.etag);
+        assert_eq!(decoded.validators.last_modified, None);
+        assert_eq!(decoded.fresh_until, 123);
+    }
+
+    #[test]
+    fn test_memory_lru() {
+        let now = Instant::now();
+        let later = now + Duration::from_secs(60);
+
+        let mut cache = MemoryCache::new(2).inner.into_inner().unwrap();
+
+        cache.set("a", vec![1], later);
+        cache.set("b", vec![2], later);
+        assert_eq!(cache.get("a", now), Some(vec![1]));
+
+        cache.set("c", vec![3], later);
+        assert_eq!(cache.get("b", now), None);
+        assert_eq!(cache.get("a", now), Some(vec![1]));
+        assert_eq!(cache.get("c", now), Some(vec![3]));
+        assert_eq!(cache.get("c", later), None);
+    }
+}
@@ -0,0 +1,61 @@
+use std::time::Duration;
+
+use bathbot_util::ExponentialBackoff;
+
+use crate::site::Site;
+
+/// How often and how patiently a failed request to a [`Site`] is retried.
+#[derive(Copy, Clone, Debug)]
+pub(crate) struct RetryPolicy {
+    retries: usize,
+    factor: u32,
+    max_delay: u64,
+}
+
+impl RetryPolicy {
+    /// Don't retry at all, e.g. because the caller already handles retries.
+    const NONE: Self = Self {
+        retries: 0,
+        factor: 0,
+        max_delay: 0,
+    };
+
+    /// A single retry for sites that are generally reliable.
+    const DEFAULT: Self = Self {
+        retries: 1,
+        factor: 500,
+        max_delay: 1000,
+    };
+
+    /// Multiple retries for third-party sites that tend to hiccup.
+    const FLAKY: Self = Self {
+        retries: 3,
+        factor: 250,
+        max_delay: 4000,
+    };
+
+    /// Delays to wait before each retry attempt.
+    pub(crate) fn backoff(self) -> impl Iterator<Item = Duration> {
+        ExponentialBackoff::new(2)
+            .factor(self.factor)
+            .max_delay(self.max_delay)
+            .take(self.retries)
+    }
+}
+
+impl Site {
+    pub(crate) const fn retry_policy(self) -> RetryPolicy {
+        match self {
+            Self::Huismetbenen
+            | Self::KittenRoleplay
+            | Self::Osekai
+            | Self::OsuStats
+            | Self::OsuTrack
+            | Self::Relax
+            | Self::Respektive => RetryPolicy::FLAKY,
+            // Map downloads are retried by the map manager itself
+            Self::OsuMapFile => RetryPolicy::NONE,
+            _ => RetryPolicy::DEFAULT,
+        }
+    }
+}
@@ -28,6 +28,8 @@ macro_rules! sites {
         }
 
         impl Site {
+            pub(crate) const ALL: &'static [Self] = &[$( Self::$variant, )*];
+
             pub fn as_str(self) -> &'static str {
                 match self {
                     $( Self::$variant => stringify!($variant), )*
@@ -62,6 +64,7 @@ sites! {
     OsuBadge -> 10,
     OsuMapFile -> 2,
     OsuMapsetCover -> 10,
+    OsuMapsetPreview -> 5,
     OsuMedalIcon -> 25,
     OsuProfile -> 1,
     OsuStats -> 2,
@@ -127,14 +127,33 @@ pub fn command(attr: TokenStream, input: TokenStream) -> TokenStream {
 /// The function that's denoted with this attribute must have the signature
 /// `async fn(Arc<Context>, InteractionCommand) -> Result<()>`.
 ///
-/// Must specify `name = "..."` and optionally `dm_permission = ...` and
-/// `flags(...)`.
+/// Must specify `name = "..."` and optionally `dm_permission = ...`,
+/// `bucket(...)`, and `flags(...)`.
 #[proc_macro_attribute]
 pub fn msg_command(attr: TokenStream, input: TokenStream) -> TokenStream {
     let attrs = parse_macro_input!(attr as message::CommandAttrs);
     let fun = parse_macro_input!(input as message::CommandFun);
 
-    match message::impl_cmd(attrs, fun) {
+    match message::impl_cmd(attrs, fun, message::MenuKind::Message) {
+        Ok(result) => result.into(),
+        Err(err) => err.to_compile_error().into(),
+    }
+}
+
+/// Create a static MessageCommand `{uppercased_name}_USER` that shows up in
+/// the context menu of users.
+///
+/// The function that's denoted with this attribute must have the signature
+/// `async fn(InteractionCommand) -> Result<()>`.
+///
+/// Must specify `name = "..."` and optionally `dm_permission = ...`,
+/// `bucket(...)`, and `flags(...)`.
+#[proc_macro_attribute]
+pub fn user_command(attr: TokenStream, input: TokenStream) -> TokenStream {
+    let attrs = parse_macro_input!(attr as message::CommandAttrs);
+    let fun = parse_macro_input!(input as message::CommandFun);
+
+    match message::impl_cmd(attrs, fun, message::MenuKind::User) {
         Ok(result) => result.into(),
         Err(err) => err.to_compile_error().into(),
     }
@@ -4,11 +4,16 @@ use syn::{
     parse::{Parse, ParseStream},
 };
 
-use crate::{flags::Flags, util::PunctuatedExt};
+use crate::{
+    bucket::Bucket,
+    flags::Flags,
+    util::{AsOption, PunctuatedExt},
+};
 
 pub struct CommandAttrs {
     pub name: LitStr,
     pub dm_permission: Option<LitBool>,
+    pub bucket: AsOption<Bucket>,
     pub flags: Flags,
 }
 
@@ -18,6 +23,7 @@ impl Parse for CommandAttrs {
 
         let mut attr_name = None;
         let mut dm_permission = None;
+        let mut bucket = None;
         let mut flags = None;
 
         for meta in metas {
@@ -50,10 +56,15 @@ impl Parse for CommandAttrs {
                     }
                 }
                 Meta::List(meta) => {
-                    if meta.path.is_ident("flags") {
+                    if meta.path.is_ident("bucket") {
+                        bucket = Some(meta.parse_args()?);
+                    } else if meta.path.is_ident("flags") {
                         flags = Some(meta.parse_args()?);
                     } else {
-                        return Err(Error::new_spanned(meta.path, "expected `flags`"));
+                        return Err(Error::new_spanned(
+                            meta.path,
+                            "expected `bucket` or `flags`",
+                        ));
                     }
                 }
                 Meta::Path(_) => {}
@@ -64,6 +75,7 @@ impl Parse for CommandAttrs {
             name: attr_name
                 .ok_or_else(|| Error::new(Span::call_site(), "must specify `name = \"...\"`"))?,
             dm_permission,
+            bucket: AsOption(bucket),
             flags: flags.unwrap_or_default(),
         })
     }
@@ -7,10 +7,18 @@ pub use self::{attrs::CommandAttrs, command::CommandFun};
 mod attrs;
 mod command;
 
-pub fn impl_cmd(attrs: CommandAttrs, fun: CommandFun) -> Result<TokenStream> {
+/// The target of a context menu command
+#[derive(Copy, Clone)]
+pub enum MenuKind {
+    Message,
+    User,
+}
+
+pub fn impl_cmd(attrs: CommandAttrs, fun: CommandFun, kind: MenuKind) -> Result<TokenStream> {
     let CommandAttrs {
         name: attr_name,
         dm_permission,
+        bucket,
         flags,
     } = attrs;
 
@@ -23,9 +31,15 @@ pub fn impl_cmd(attrs: CommandAttrs, fun: CommandFun) -> Result<TokenStream> {
 
     let cmd_name_str = cmd_name.to_string();
 
+    let (suffix, command_type) = match kind {
+        MenuKind::Message => ("MSG", quote!(Message)),
+        MenuKind::User => ("USER", quote!(User)),
+    };
+
     let static_name = format_ident!(
-        "{}_MSG",
+        "{}_{}",
         cmd_name_str.to_uppercase(),
+        suffix,
         span = cmd_name.span()
     );
 
@@ -45,6 +59,7 @@ pub fn impl_cmd(attrs: CommandAttrs, fun: CommandFun) -> Result<TokenStream> {
     let tokens = quote! {
         #[linkme::distributed_slice(crate::core::commands::interaction::__MSG_COMMANDS)]
         pub static #static_name: #path = #path {
+            bucket: #bucket,
             create: #create,
             exec: #exec,
             flags: #flags,
@@ -63,7 +78,7 @@ pub fn impl_cmd(attrs: CommandAttrs, fun: CommandFun) -> Result<TokenStream> {
                 dm_permission: None,
                 guild_id: None,
                 id: None,
-                kind: ::twilight_model::application::command::CommandType::Message,
+                kind: ::twilight_model::application::command::CommandType::#command_type,
                 name: #attr_name.to_owned(),
                 name_localizations: None,
                 nsfw: None,
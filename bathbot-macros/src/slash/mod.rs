@@ -23,19 +23,26 @@ pub fn derive(input: DeriveInput) -> Result<TokenStream> {
     let static_name = format_ident!("{}", name_str.to_uppercase(), span = name.span());
     let slash_cmd = format_ident!("slash_{}", name_str.to_lowercase(), span = name.span());
     let exec = format_ident!("{slash_cmd}__", span = name.span());
+    let create = format_ident!("{slash_cmd}_create__", span = name.span());
     let path = quote!(crate::core::commands::interaction::SlashCommand);
 
     let tokens = quote! {
         #[linkme::distributed_slice(crate::core::commands::interaction::__SLASH_COMMANDS)]
         pub static #static_name: #path = #path {
             bucket: #bucket,
-            create: #name::create_command,
+            create: #create,
             exec: #exec,
             flags: #flags,
             name: #name_lit,
             id: std::sync::OnceLock::new(),
         };
 
+        fn #create() -> ::twilight_interactions::command::ApplicationCommandData {
+            crate::core::locale::localize_command(
+                <#name as ::twilight_interactions::command::CreateCommand>::create_command(),
+            )
+        }
+
         fn #exec(
             command: crate::util::interaction::InteractionCommand,
         ) -> crate::core::commands::interaction::CommandResult {
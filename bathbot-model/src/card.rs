@@ -0,0 +1,78 @@
+use twilight_interactions::command::{CommandOption, CreateOption};
+
+/// Color theme of a card.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, CommandOption, CreateOption)]
+#[repr(u8)]
+pub enum CardTheme {
+    /// White on black panels above the title's background image
+    #[default]
+    #[option(name = "Classic", value = "classic")]
+    Classic = 0,
+    #[option(name = "Midnight", value = "midnight")]
+    Midnight = 1,
+    #[option(name = "Sunset", value = "sunset")]
+    Sunset = 2,
+    #[option(name = "Forest", value = "forest")]
+    Forest = 3,
+    #[option(name = "Ocean", value = "ocean")]
+    Ocean = 4,
+    #[option(name = "Sakura", value = "sakura")]
+    Sakura = 5,
+}
+
+impl From<CardTheme> for i16 {
+    #[inline]
+    fn from(theme: CardTheme) -> Self {
+        theme as Self
+    }
+}
+
+impl TryFrom<i16> for CardTheme {
+    type Error = ();
+
+    #[inline]
+    fn try_from(value: i16) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(Self::Classic),
+            1 => Ok(Self::Midnight),
+            2 => Ok(Self::Sunset),
+            3 => Ok(Self::Forest),
+            4 => Ok(Self::Ocean),
+            5 => Ok(Self::Sakura),
+            _ => Err(()),
+        }
+    }
+}
+
+/// Arrangement of the elements on a card.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, CommandOption, CreateOption)]
+#[repr(u8)]
+pub enum CardLayout {
+    /// Large avatar and skill values with medals and branding below
+    #[default]
+    #[option(name = "Tall", value = "tall")]
+    Tall = 0,
+    /// Avatar, ranks, and skill values within a single row
+    #[option(name = "Compact", value = "compact")]
+    Compact = 1,
+}
+
+impl From<CardLayout> for i16 {
+    #[inline]
+    fn from(layout: CardLayout) -> Self {
+        layout as Self
+    }
+}
+
+impl TryFrom<i16> for CardLayout {
+    type Error = ();
+
+    #[inline]
+    fn try_from(value: i16) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(Self::Tall),
+            1 => Ok(Self::Compact),
+            _ => Err(()),
+        }
+    }
+}
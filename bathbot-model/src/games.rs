@@ -18,10 +18,80 @@ pub struct HlGameScore {
     pub highscore: i32,
 }
 
+#[derive(Copy, Clone, Default, CommandOption, CreateOption)]
+pub enum BgGameKind {
+    #[default]
+    #[option(name = "Background", value = "background")]
+    Background = 0,
+    #[option(name = "Song", value = "song")]
+    Song = 1,
+}
+
 #[derive(Copy, Clone, CommandOption, CreateOption)]
 pub enum HlVersion {
     #[option(name = "Score PP", value = "score_pp")]
     ScorePp = 0,
+    #[option(name = "Global rank", value = "global_rank")]
+    GlobalRank = 1,
+    #[option(name = "Star rating", value = "star_rating")]
+    StarRating = 2,
+    #[option(name = "Mapset playcount", value = "mapset_playcount")]
+    MapsetPlaycount = 3,
+    #[option(name = "Medal rarity", value = "medal_rarity")]
+    MedalRarity = 4,
+}
+
+impl BgGameKind {
+    pub const ALL: [Self; 2] = [Self::Background, Self::Song];
+
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Background => "background",
+            Self::Song => "song",
+        }
+    }
+}
+
+impl TryFrom<i16> for BgGameKind {
+    type Error = i16;
+
+    fn try_from(kind: i16) -> Result<Self, Self::Error> {
+        Self::ALL
+            .into_iter()
+            .find(|&variant| variant as i16 == kind)
+            .ok_or(kind)
+    }
+}
+
+impl HlVersion {
+    pub const ALL: [Self; 5] = [
+        Self::ScorePp,
+        Self::GlobalRank,
+        Self::StarRating,
+        Self::MapsetPlaycount,
+        Self::MedalRarity,
+    ];
+
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::ScorePp => "score_pp",
+            Self::GlobalRank => "global_rank",
+            Self::StarRating => "star_rating",
+            Self::MapsetPlaycount => "mapset_playcount",
+            Self::MedalRarity => "medal_rarity",
+        }
+    }
+}
+
+impl TryFrom<i16> for HlVersion {
+    type Error = i16;
+
+    fn try_from(version: i16) -> Result<Self, Self::Error> {
+        Self::ALL
+            .into_iter()
+            .find(|&variant| variant as i16 == version)
+            .ok_or(version)
+    }
 }
 
 bitflags::bitflags! {
@@ -1,3 +1,4 @@
+mod card;
 mod country_code;
 mod deser;
 mod either;
@@ -25,8 +26,8 @@ pub mod twilight;
 pub mod rkyv_util;
 
 pub use self::{
-    country_code::*, deser::ModeAsSeed, either::Either, games::*, github::*, huismetbenen::*,
-    kittenroleplay::*, osekai::*, osu::*, osu_stats::*, osutrack::*,
+    card::*, country_code::*, deser::ModeAsSeed, either::Either, games::*, github::*,
+    huismetbenen::*, kittenroleplay::*, osekai::*, osu::*, osu_stats::*, osutrack::*,
     personal_best::PersonalBestIndex, ranking_entries::*, relax::*, respektive::*, score_slim::*,
     twitch::*, user_stats::*,
 };
@@ -6,8 +6,8 @@ use time::OffsetDateTime;
 use twilight_model::id::{Id, marker::GuildMarker};
 
 use crate::{
-    BgGameScore, HlGameScore, HlVersion, UserModeStatsColumn, UserStatsColumn, UserStatsEntries,
-    UserStatsEntry, twilight::util::ImageHashRkyv,
+    BgGameKind, BgGameScore, HlGameScore, HlVersion, UserModeStatsColumn, UserStatsColumn,
+    UserStatsEntries, UserStatsEntry, twilight::util::ImageHashRkyv,
 };
 
 pub struct RankingEntry<V> {
@@ -184,6 +184,7 @@ impl From<UserStatsEntries> for RankingEntries {
 pub enum RankingKind {
     BgScores {
         global: bool,
+        kind: BgGameKind,
         scores: Vec<BgGameScore>,
     },
     Commands {
@@ -248,11 +249,12 @@ impl EmbedHeader {
 impl RankingKind {
     pub fn embed_header(&self) -> EmbedHeader {
         match self {
-            Self::BgScores { global, .. } => {
-                let text = if *global {
-                    "Global leaderboard for correct guesses"
-                } else {
-                    "Server leaderboard for correct guesses"
+            Self::BgScores { global, kind, .. } => {
+                let text = match (global, kind) {
+                    (true, BgGameKind::Background) => "Global leaderboard for correct guesses",
+                    (false, BgGameKind::Background) => "Server leaderboard for correct guesses",
+                    (true, BgGameKind::Song) => "Global leaderboard for correct song guesses",
+                    (false, BgGameKind::Song) => "Server leaderboard for correct song guesses",
                 };
 
                 EmbedHeader::Author(AuthorBuilder::new(text))
@@ -265,6 +267,12 @@ impl RankingKind {
             Self::HlScores { version, .. } => {
                 let text = match version {
                     HlVersion::ScorePp => "Server leaderboard for Higherlower (Score PP)",
+                    HlVersion::GlobalRank => "Server leaderboard for Higherlower (Global rank)",
+                    HlVersion::StarRating => "Server leaderboard for Higherlower (Star rating)",
+                    HlVersion::MapsetPlaycount => {
+                        "Server leaderboard for Higherlower (Mapset playcount)"
+                    }
+                    HlVersion::MedalRarity => "Server leaderboard for Higherlower (Medal rarity)",
                 };
 
                 EmbedHeader::Author(AuthorBuilder::new(text))
@@ -64,6 +64,10 @@ pub struct TwitchStream {
     #[serde(rename = "user_name")]
     #[rkyv(with = DerefAsString)]
     pub username: Box<str>,
+    /// Name of the category, empty if none is set
+    #[serde(default)]
+    #[rkyv(with = DerefAsString)]
+    pub game_name: Box<str>,
     #[serde(rename = "type", deserialize_with = "get_live")]
     #[rkyv(niche = Bool)]
     pub live: bool,
@@ -1,3 +1,5 @@
+use std::str::FromStr;
+
 use time::OffsetDateTime;
 use twilight_interactions::command::{CommandOption, CreateOption};
 
@@ -99,3 +101,59 @@ pub enum UserModeStatsColumn {
     #[option(name = "Total hits", value = "total_hits")]
     TotalHits,
 }
+
+impl FromStr for UserStatsColumn {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "badges" => Ok(Self::Badges),
+            "comments" => Ok(Self::Comments),
+            "followers" => Ok(Self::Followers),
+            "forum_posts" => Ok(Self::ForumPosts),
+            "graveyard_mapsets" => Ok(Self::GraveyardMapsets),
+            "join_date" => Ok(Self::JoinDate),
+            "kudosu_available" => Ok(Self::KudosuAvailable),
+            "kudosu_total" => Ok(Self::KudosuTotal),
+            "loved_mapsets" => Ok(Self::LovedMapsets),
+            "mapping_followers" => Ok(Self::Subscribers),
+            "medals" => Ok(Self::Medals),
+            "namechanges" => Ok(Self::Namechanges),
+            "played_maps" => Ok(Self::PlayedMaps),
+            "ranked_mapsets" => Ok(Self::RankedMapsets),
+            _ => Err(()),
+        }
+    }
+}
+
+impl FromStr for UserModeStatsColumn {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "acc" => Ok(Self::Accuracy),
+            "avg_hits" => Ok(Self::AverageHits),
+            "count_ssh" => Ok(Self::CountSsh),
+            "count_ss" => Ok(Self::CountSs),
+            "total_ss" => Ok(Self::TotalSs),
+            "count_sh" => Ok(Self::CountSh),
+            "count_s" => Ok(Self::CountS),
+            "total_s" => Ok(Self::TotalS),
+            "count_a" => Ok(Self::CountA),
+            "level" => Ok(Self::Level),
+            "max_combo" => Ok(Self::MaxCombo),
+            "playcount" => Ok(Self::Playcount),
+            "playtime" => Ok(Self::Playtime),
+            "pp" => Ok(Self::Pp),
+            "pp_per_month" => Ok(Self::PpPerMonth),
+            "country_rank" => Ok(Self::RankCountry),
+            "global_rank" => Ok(Self::RankGlobal),
+            "replays" => Ok(Self::ReplaysWatched),
+            "ranked_score" => Ok(Self::ScoreRanked),
+            "total_score" => Ok(Self::ScoreTotal),
+            "global_firsts" => Ok(Self::ScoresFirst),
+            "total_hits" => Ok(Self::TotalHits),
+            _ => Err(()),
+        }
+    }
+}
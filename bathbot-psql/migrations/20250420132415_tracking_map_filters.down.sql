@@ -0,0 +1,7 @@
+ALTER TABLE tracked_osu_users
+    DROP COLUMN mods_include,
+    DROP COLUMN mods_exclude,
+    DROP COLUMN min_stars,
+    DROP COLUMN max_stars,
+    DROP COLUMN mapper_id,
+    DROP COLUMN map_status;
\ No newline at end of file
@@ -0,0 +1,7 @@
+ALTER TABLE tracked_osu_users
+    ADD COLUMN mods_include INT4,
+    ADD COLUMN mods_exclude INT4,
+    ADD COLUMN min_stars    FLOAT4,
+    ADD COLUMN max_stars    FLOAT4,
+    ADD COLUMN mapper_id    INT4,
+    ADD COLUMN map_status   INT2;
\ No newline at end of file
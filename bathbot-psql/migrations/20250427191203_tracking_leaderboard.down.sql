@@ -0,0 +1,3 @@
+DROP INDEX tracked_osu_leaderboard_users_channel_index;
+
+DROP TABLE tracked_osu_leaderboard_users;
\ No newline at end of file
@@ -0,0 +1,9 @@
+CREATE TABLE IF NOT EXISTS tracked_osu_leaderboard_users (
+    user_id    INT4 NOT NULL,
+    gamemode   INT2 NOT NULL,
+    channel_id INT8 NOT NULL,
+    max_rank   INT2 NOT NULL,
+    PRIMARY KEY (user_id, gamemode, channel_id)
+);
+
+CREATE INDEX tracked_osu_leaderboard_users_channel_index ON tracked_osu_leaderboard_users (channel_id);
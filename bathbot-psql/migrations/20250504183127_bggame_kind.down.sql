@@ -0,0 +1,7 @@
+DELETE FROM bggame_scores WHERE game_kind <> 0;
+
+ALTER TABLE bggame_scores DROP CONSTRAINT bggame_scores_pkey;
+
+ALTER TABLE bggame_scores DROP COLUMN game_kind;
+
+ALTER TABLE bggame_scores ADD PRIMARY KEY (discord_id);
@@ -0,0 +1,5 @@
+ALTER TABLE bggame_scores ADD COLUMN game_kind INT2 NOT NULL DEFAULT 0;
+
+ALTER TABLE bggame_scores DROP CONSTRAINT bggame_scores_pkey;
+
+ALTER TABLE bggame_scores ADD PRIMARY KEY (discord_id, game_kind);
@@ -0,0 +1,3 @@
+ALTER TABLE user_configs DROP COLUMN card_theme;
+ALTER TABLE user_configs DROP COLUMN card_layout;
+ALTER TABLE user_configs DROP COLUMN card_stats;
\ No newline at end of file
@@ -0,0 +1,3 @@
+ALTER TABLE user_configs ADD COLUMN card_theme INT2;
+ALTER TABLE user_configs ADD COLUMN card_layout INT2;
+ALTER TABLE user_configs ADD COLUMN card_stats BOOLEAN;
\ No newline at end of file
@@ -0,0 +1,4 @@
+DROP TABLE osu_scores_archive_users;
+DROP TABLE osu_scores_performance;
+DROP TABLE osu_scores;
+ALTER TABLE guild_configs DROP COLUMN score_archive;
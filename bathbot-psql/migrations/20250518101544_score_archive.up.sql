@@ -0,0 +1,40 @@
+ALTER TABLE guild_configs ADD COLUMN score_archive BOOLEAN;
+
+CREATE TABLE IF NOT EXISTS osu_scores (
+    score_id      INT8 NOT NULL,
+    user_id       INT4 NOT NULL,
+    map_id        INT4 NOT NULL,
+    gamemode      INT2 NOT NULL,
+    mods          INT4 NOT NULL,
+    score         INT8 NOT NULL,
+    classic_score INT8 NOT NULL,
+    maxcombo      INT4 NOT NULL,
+    grade         INT2 NOT NULL,
+    accuracy      FLOAT4 NOT NULL,
+    countgeki     INT4 NOT NULL,
+    count300      INT4 NOT NULL,
+    countkatu     INT4 NOT NULL,
+    count100      INT4 NOT NULL,
+    count50       INT4 NOT NULL,
+    countmiss     INT4 NOT NULL,
+    passed        BOOL NOT NULL,
+    set_on_lazer  BOOL NOT NULL,
+    ended_at      TIMESTAMPTZ NOT NULL,
+    PRIMARY KEY (score_id)
+);
+
+CREATE INDEX osu_scores_user_mode_ended_index ON osu_scores (user_id, gamemode, ended_at DESC);
+
+CREATE TABLE IF NOT EXISTS osu_scores_performance (
+    score_id INT8 NOT NULL,
+    pp       FLOAT8,
+    PRIMARY KEY (score_id)
+);
+
+-- Guilds due to which a user's scores are archived so that scores can be
+-- removed once no opted-in guild remains
+CREATE TABLE IF NOT EXISTS osu_scores_archive_users (
+    user_id  INT4 NOT NULL,
+    guild_id INT8 NOT NULL,
+    PRIMARY KEY (user_id, guild_id)
+);
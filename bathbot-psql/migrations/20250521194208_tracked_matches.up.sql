@@ -0,0 +1,7 @@
+CREATE TABLE IF NOT EXISTS tracked_osu_matches (
+    match_id    INT4 NOT NULL,
+    channel_id  INT8 NOT NULL,
+    msg_id      INT8 NOT NULL,
+    embed_count INT4 NOT NULL,
+    PRIMARY KEY (match_id, channel_id)
+);
@@ -0,0 +1,7 @@
+CREATE TABLE IF NOT EXISTS api_keys (
+    key_id              SERIAL PRIMARY KEY,
+    name                VARCHAR(64) NOT NULL UNIQUE,
+    key_hash            BYTEA NOT NULL UNIQUE,
+    requests_per_minute INT4 NOT NULL,
+    created_at          TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
@@ -0,0 +1,2 @@
+ALTER TABLE user_configs ADD COLUMN language INT2;
+ALTER TABLE guild_configs ADD COLUMN language INT2;
\ No newline at end of file
@@ -0,0 +1,2 @@
+DROP TABLE guild_digest_snapshots;
+DROP TABLE guild_digests;
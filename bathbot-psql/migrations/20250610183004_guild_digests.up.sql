@@ -0,0 +1,18 @@
+CREATE TABLE IF NOT EXISTS guild_digests (
+    guild_id        INT8 NOT NULL,
+    channel_id      INT8 NOT NULL,
+    frequency       INT2 NOT NULL,
+    last_posted     TIMESTAMPTZ,
+    failed_attempts INT2 NOT NULL DEFAULT 0,
+    PRIMARY KEY (guild_id)
+);
+
+CREATE TABLE IF NOT EXISTS guild_digest_snapshots (
+    guild_id    INT8 NOT NULL,
+    taken_at    TIMESTAMPTZ NOT NULL,
+    user_id     INT4 NOT NULL,
+    gamemode    INT2 NOT NULL,
+    pp          FLOAT4 NOT NULL,
+    global_rank INT4 NOT NULL,
+    PRIMARY KEY (guild_id, taken_at, user_id, gamemode)
+);
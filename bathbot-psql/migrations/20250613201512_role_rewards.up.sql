@@ -0,0 +1,9 @@
+CREATE TABLE IF NOT EXISTS guild_role_rewards (
+    guild_id  INT8 NOT NULL,
+    role_id   INT8 NOT NULL,
+    kind      INT2 NOT NULL,
+    gamemode  INT2,
+    min_value FLOAT8,
+    max_value FLOAT8,
+    PRIMARY KEY (guild_id, role_id)
+);
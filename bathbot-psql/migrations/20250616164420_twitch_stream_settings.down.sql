@@ -0,0 +1,7 @@
+ALTER TABLE tracked_twitch_streams
+  DROP COLUMN osu_only,
+  DROP COLUMN keyword,
+  DROP COLUMN template,
+  DROP COLUMN role_id,
+  DROP COLUMN vod_edit,
+  DROP COLUMN notif_msg_id;
@@ -0,0 +1,7 @@
+ALTER TABLE tracked_twitch_streams
+  ADD COLUMN osu_only BOOLEAN NOT NULL DEFAULT FALSE,
+  ADD COLUMN keyword VARCHAR(64),
+  ADD COLUMN template VARCHAR(1000),
+  ADD COLUMN role_id INT8,
+  ADD COLUMN vod_edit BOOLEAN NOT NULL DEFAULT FALSE,
+  ADD COLUMN notif_msg_id INT8;
@@ -0,0 +1,9 @@
+DROP TABLE osu_map_leaderboard_snapshot_scores;
+
+DROP INDEX osu_map_leaderboard_snapshots_map_index;
+
+DROP TABLE osu_map_leaderboard_snapshots;
+
+DROP INDEX tracked_osu_maps_channel_index;
+
+DROP TABLE tracked_osu_maps;
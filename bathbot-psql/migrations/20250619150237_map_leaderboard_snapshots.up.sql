@@ -0,0 +1,31 @@
+CREATE TABLE IF NOT EXISTS tracked_osu_maps (
+    map_id     INT4 NOT NULL,
+    gamemode   INT2 NOT NULL,
+    channel_id INT8 NOT NULL,
+    guild_id   INT8,
+    max_rank   INT2 NOT NULL,
+    PRIMARY KEY (map_id, gamemode, channel_id)
+);
+
+CREATE INDEX tracked_osu_maps_channel_index ON tracked_osu_maps (channel_id);
+
+CREATE TABLE IF NOT EXISTS osu_map_leaderboard_snapshots (
+    snapshot_id SERIAL NOT NULL,
+    map_id      INT4 NOT NULL,
+    gamemode    INT2 NOT NULL,
+    taken_at    TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (snapshot_id)
+);
+
+CREATE INDEX osu_map_leaderboard_snapshots_map_index ON osu_map_leaderboard_snapshots (map_id, gamemode);
+
+CREATE TABLE IF NOT EXISTS osu_map_leaderboard_snapshot_scores (
+    snapshot_id INT4 NOT NULL REFERENCES osu_map_leaderboard_snapshots (snapshot_id) ON DELETE CASCADE,
+    pos         INT2 NOT NULL,
+    user_id     INT4 NOT NULL,
+    username    VARCHAR(32) NOT NULL,
+    score_id    INT8 NOT NULL,
+    score       INT8 NOT NULL,
+    pp          FLOAT4,
+    PRIMARY KEY (snapshot_id, pos)
+);
@@ -0,0 +1,3 @@
+ALTER TABLE user_map_bookmarks
+    DROP COLUMN folder,
+    DROP COLUMN note;
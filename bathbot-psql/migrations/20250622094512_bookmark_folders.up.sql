@@ -0,0 +1,3 @@
+ALTER TABLE user_map_bookmarks
+    ADD COLUMN folder VARCHAR(32),
+    ADD COLUMN note   VARCHAR(200);
@@ -0,0 +1,2 @@
+DROP TABLE IF EXISTS guild_daily_challenge_reminders;
+DROP TABLE IF EXISTS osu_daily_challenge_results;
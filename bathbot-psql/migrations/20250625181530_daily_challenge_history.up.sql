@@ -0,0 +1,21 @@
+-- Score values are only known for placements on the room's leaderboard
+CREATE TABLE IF NOT EXISTS osu_daily_challenge_results (
+    user_id     INT4 NOT NULL,
+    date        DATE NOT NULL,
+    room_id     INT8 NOT NULL,
+    username    VARCHAR(32) NOT NULL,
+    total_score INT8,
+    accuracy    FLOAT4,
+    attempts    INT4,
+    pos         INT4,
+    PRIMARY KEY (user_id, date)
+);
+
+CREATE INDEX osu_daily_challenge_results_date_index ON osu_daily_challenge_results (date);
+
+CREATE TABLE IF NOT EXISTS guild_daily_challenge_reminders (
+    guild_id   INT8 NOT NULL,
+    channel_id INT8 NOT NULL,
+    last_date  DATE NOT NULL,
+    PRIMARY KEY (guild_id)
+);
@@ -0,0 +1,2 @@
+DROP TABLE IF EXISTS tracked_osu_mapper_mapsets;
+DROP TABLE IF EXISTS tracked_osu_mappers;
@@ -0,0 +1,18 @@
+CREATE TABLE IF NOT EXISTS tracked_osu_mappers (
+    mapper_id  INT4 NOT NULL,
+    channel_id INT8 NOT NULL,
+    guild_id   INT8,
+    added_by   INT8 NOT NULL,
+    PRIMARY KEY (mapper_id, channel_id)
+);
+
+CREATE INDEX tracked_osu_mappers_channel_index ON tracked_osu_mappers (channel_id);
+
+CREATE TABLE IF NOT EXISTS tracked_osu_mapper_mapsets (
+    mapset_id   INT4 NOT NULL,
+    mapper_id   INT4 NOT NULL,
+    rank_status INT2 NOT NULL,
+    PRIMARY KEY (mapset_id)
+);
+
+CREATE INDEX tracked_osu_mapper_mapsets_mapper_index ON tracked_osu_mapper_mapsets (mapper_id);
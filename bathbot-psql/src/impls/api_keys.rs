@@ -0,0 +1,85 @@
+use eyre::{Result, WrapErr};
+use sha2::{Digest, Sha256};
+
+use crate::{Database, model::api_key::DbApiKey};
+
+impl Database {
+    /// Keys are only stored as hash so the given key is hashed for the lookup
+    pub async fn select_api_key(&self, key: &str) -> Result<Option<DbApiKey>> {
+        let key_hash = hash_api_key(key);
+
+        let query = sqlx::query_as!(
+            DbApiKey,
+            r#"
+SELECT
+  key_id,
+  name,
+  requests_per_minute,
+  created_at
+FROM
+  api_keys
+WHERE
+  key_hash = $1"#,
+            key_hash.as_slice()
+        );
+
+        query
+            .fetch_optional(self)
+            .await
+            .wrap_err("Failed to fetch optional")
+    }
+
+    /// Returns whether a new entry was inserted
+    pub async fn insert_api_key(
+        &self,
+        name: &str,
+        key: &str,
+        requests_per_minute: u32,
+    ) -> Result<bool> {
+        let key_hash = hash_api_key(key);
+
+        let query = sqlx::query!(
+            r#"
+INSERT INTO api_keys (name, key_hash, requests_per_minute)
+VALUES
+  ($1, $2, $3)
+ON CONFLICT
+  (name)
+DO
+  NOTHING"#,
+            name,
+            key_hash.as_slice(),
+            requests_per_minute as i32,
+        );
+
+        let res = query
+            .execute(self)
+            .await
+            .wrap_err("Failed to execute query")?;
+
+        Ok(res.rows_affected() > 0)
+    }
+
+    /// Returns whether an entry was deleted
+    pub async fn delete_api_key(&self, name: &str) -> Result<bool> {
+        let query = sqlx::query!(
+            r#"
+DELETE FROM
+  api_keys
+WHERE
+  name = $1"#,
+            name
+        );
+
+        let res = query
+            .execute(self)
+            .await
+            .wrap_err("Failed to execute query")?;
+
+        Ok(res.rows_affected() > 0)
+    }
+}
+
+fn hash_api_key(key: &str) -> [u8; 32] {
+    Sha256::digest(key.as_bytes()).into()
+}
@@ -14,6 +14,8 @@ impl Database {
             r#"
 SELECT 
   bookmarks.insert_date, 
+  bookmarks.folder, 
+  bookmarks.note, 
   maps.map_id, 
   maps.mapset_id, 
   maps.user_id AS mapper_id, 
@@ -29,6 +31,7 @@ SELECT
   maps.ar, 
   maps.bpm, 
   maps.gamemode, 
+  maps.checksum, 
   mapsets.artist, 
   mapsets.title, 
   mapsets.creator, 
@@ -42,7 +45,9 @@ FROM
   (
     SELECT 
       map_id, 
-      insert_date 
+      insert_date, 
+      folder, 
+      note 
     FROM 
       user_map_bookmarks 
     WHERE 
@@ -64,7 +69,8 @@ FROM
       od, 
       ar, 
       bpm, 
-      gamemode 
+      gamemode, 
+      checksum 
     FROM 
       osu_maps
   ) AS maps ON bookmarks.map_id = maps.map_id 
@@ -120,6 +126,9 @@ ORDER BY
                 genre: parse_genre(row.genre_id),
                 language: parse_language(row.language_id),
                 cover_url: row.cover.into_boxed_str(),
+                checksum: row.checksum.into_boxed_str(),
+                folder: row.folder.map(String::into_boxed_str),
+                note: row.note.map(String::into_boxed_str),
             };
 
             bookmarks.push(bookmark);
@@ -165,4 +174,62 @@ WHERE
 
         Ok(())
     }
+
+    /// Returns whether the bookmark exists
+    pub async fn update_user_bookmark_folder(
+        &self,
+        user_id: Id<UserMarker>,
+        map_id: u32,
+        folder: Option<&str>,
+    ) -> Result<bool> {
+        let query = sqlx::query!(
+            r#"
+UPDATE 
+  user_map_bookmarks 
+SET 
+  folder = $3 
+WHERE 
+  user_id = $1 
+  AND map_id = $2"#,
+            user_id.get() as i64,
+            map_id as i32,
+            folder
+        );
+
+        let res = query
+            .execute(self)
+            .await
+            .wrap_err("Failed to execute query")?;
+
+        Ok(res.rows_affected() > 0)
+    }
+
+    /// Returns whether the bookmark exists
+    pub async fn update_user_bookmark_note(
+        &self,
+        user_id: Id<UserMarker>,
+        map_id: u32,
+        note: Option<&str>,
+    ) -> Result<bool> {
+        let query = sqlx::query!(
+            r#"
+UPDATE 
+  user_map_bookmarks 
+SET 
+  note = $3 
+WHERE 
+  user_id = $1 
+  AND map_id = $2"#,
+            user_id.get() as i64,
+            map_id as i32,
+            note
+        );
+
+        let res = query
+            .execute(self)
+            .await
+            .wrap_err("Failed to execute query")?;
+
+        Ok(res.rows_affected() > 0)
+    }
 }
@@ -31,7 +31,9 @@ SELECT
   render_button, 
   allow_custom_skins, 
   hide_medal_solution, 
-  score_data 
+  score_data, 
+  score_archive, 
+  language 
 FROM 
   guild_configs"#
         );
@@ -63,6 +65,8 @@ FROM
             allow_custom_skins,
             hide_medal_solution,
             score_data,
+            score_archive,
+            language,
         } = config;
 
         let authorities = rkyv::util::with_arena(|arena| {
@@ -80,10 +84,11 @@ INSERT INTO guild_configs (
   guild_id, authorities, prefixes, allow_songs, 
   retries, list_size, 
   render_button, allow_custom_skins, 
-  hide_medal_solution, score_data
+  hide_medal_solution, score_data, 
+  score_archive, language
 ) 
 VALUES 
-  ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)
+  ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12)
 ON CONFLICT
   (guild_id)
 DO 
@@ -97,7 +102,9 @@ SET
   render_button = $7, 
   allow_custom_skins = $8, 
   hide_medal_solution = $9, 
-  score_data = $10"#,
+  score_data = $10, 
+  score_archive = $11, 
+  language = $12"#,
             guild_id.get() as i64,
             &authorities as &[u8],
             Json(prefixes) as _,
@@ -108,6 +115,8 @@ SET
             *allow_custom_skins,
             hide_medal_solution.map(i16::from),
             score_data.map(i16::from),
+            *score_archive,
+            language.map(i16::from),
         );
 
         query
@@ -1,2 +1,3 @@
 pub mod guild;
+pub mod role_rewards;
 pub mod user;
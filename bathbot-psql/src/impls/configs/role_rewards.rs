@@ -0,0 +1,155 @@
+use eyre::{Result, WrapErr};
+use twilight_model::id::{
+    Id,
+    marker::{GuildMarker, RoleMarker},
+};
+
+use crate::{
+    Database,
+    model::configs::{DbRoleReward, DbRoleRewardStats, RoleRewardKind},
+};
+
+impl Database {
+    pub async fn select_all_role_rewards(&self) -> Result<Vec<DbRoleReward>> {
+        let query = sqlx::query_as!(
+            DbRoleReward,
+            r#"
+SELECT
+  guild_id,
+  role_id,
+  kind,
+  gamemode,
+  min_value,
+  max_value
+FROM
+  guild_role_rewards"#
+        );
+
+        query.fetch_all(self).await.wrap_err("Failed to fetch all")
+    }
+
+    pub async fn select_guild_role_rewards(
+        &self,
+        guild_id: Id<GuildMarker>,
+    ) -> Result<Vec<DbRoleReward>> {
+        let query = sqlx::query_as!(
+            DbRoleReward,
+            r#"
+SELECT
+  guild_id,
+  role_id,
+  kind,
+  gamemode,
+  min_value,
+  max_value
+FROM
+  guild_role_rewards
+WHERE
+  guild_id = $1"#,
+            guild_id.get() as i64
+        );
+
+        query.fetch_all(self).await.wrap_err("Failed to fetch all")
+    }
+
+    pub async fn upsert_role_reward(
+        &self,
+        guild_id: Id<GuildMarker>,
+        role_id: Id<RoleMarker>,
+        kind: RoleRewardKind,
+        gamemode: Option<i16>,
+        min_value: Option<f64>,
+        max_value: Option<f64>,
+    ) -> Result<()> {
+        let query = sqlx::query!(
+            r#"
+INSERT INTO guild_role_rewards (
+  guild_id, role_id, kind, gamemode, min_value, max_value
+)
+VALUES
+  ($1, $2, $3, $4, $5, $6)
+ON CONFLICT
+  (guild_id, role_id)
+DO
+  UPDATE
+SET
+  kind = $3,
+  gamemode = $4,
+  min_value = $5,
+  max_value = $6"#,
+            guild_id.get() as i64,
+            role_id.get() as i64,
+            i16::from(kind),
+            gamemode,
+            min_value,
+            max_value,
+        );
+
+        query
+            .execute(self)
+            .await
+            .wrap_err("Failed to execute query")?;
+
+        Ok(())
+    }
+
+    /// Returns whether an entry was deleted
+    pub async fn delete_role_reward(
+        &self,
+        guild_id: Id<GuildMarker>,
+        role_id: Id<RoleMarker>,
+    ) -> Result<bool> {
+        let query = sqlx::query!(
+            r#"
+DELETE FROM
+  guild_role_rewards
+WHERE
+  guild_id = $1
+  AND role_id = $2"#,
+            guild_id.get() as i64,
+            role_id.get() as i64,
+        );
+
+        let res = query
+            .execute(self)
+            .await
+            .wrap_err("Failed to execute query")?;
+
+        Ok(res.rows_affected() > 0)
+    }
+
+    /// Cached stats of all linked users among the given discord ids.
+    pub async fn select_role_reward_stats(
+        &self,
+        discord_ids: &[i64],
+    ) -> Result<Vec<DbRoleRewardStats>> {
+        let query = sqlx::query_as!(
+            DbRoleRewardStats,
+            r#"
+SELECT
+  configs.discord_id,
+  stats.badges,
+  stats.medals,
+  mode_stats.gamemode AS "gamemode?",
+  mode_stats.pp AS "pp?",
+  mode_stats.global_rank AS "global_rank?",
+  mode_stats.country_rank AS "country_rank?"
+FROM
+  (
+    SELECT
+      discord_id,
+      osu_id
+    FROM
+      user_configs
+    WHERE
+      discord_id = ANY($1)
+      AND osu_id IS NOT NULL
+  ) AS configs
+  JOIN osu_user_stats AS stats ON configs.osu_id = stats.user_id
+  LEFT JOIN osu_user_mode_stats AS mode_stats ON configs.osu_id = mode_stats.user_id"#,
+            discord_ids
+        );
+
+        query.fetch_all(self).await.wrap_err("Failed to fetch all")
+    }
+}
@@ -8,7 +8,7 @@ use twilight_model::id::{Id, marker::UserMarker};
 
 use crate::{
     Database,
-    model::configs::{DbSkinEntry, DbUserConfig, OsuUserId, SkinEntry, UserConfig},
+    model::configs::{DbSkinEntry, DbUserConfig, Language, OsuUserId, SkinEntry, UserConfig},
 };
 
 impl Database {
@@ -28,7 +28,11 @@ SELECT
   twitch_id, 
   timezone_seconds, 
   render_button, 
-  score_data 
+  score_data, 
+  card_theme, 
+  card_layout, 
+  card_stats, 
+  language 
 FROM 
   user_configs 
 WHERE 
@@ -68,6 +72,28 @@ WHERE
         Ok(osu_id.map(|id| id as u32))
     }
 
+    /// osu! user ids of all linked users among the given discord ids.
+    pub async fn select_osu_ids_by_discord_ids(&self, discord_ids: &[i64]) -> Result<Vec<u32>> {
+        let query = sqlx::query!(
+            r#"
+SELECT
+  osu_id AS "osu_id!"
+FROM
+  user_configs
+WHERE
+  discord_id = ANY($1)
+  AND osu_id IS NOT NULL"#,
+            discord_ids
+        );
+
+        let rows = query
+            .fetch_all(self)
+            .await
+            .wrap_err("failed to fetch all")?;
+
+        Ok(rows.into_iter().map(|row| row.osu_id as u32).collect())
+    }
+
     pub async fn select_all_skins(&self) -> Result<Vec<SkinEntry>> {
         let query = sqlx::query_as!(
             DbSkinEntry,
@@ -262,6 +288,10 @@ FROM
             timezone,
             render_button,
             score_data,
+            card_theme,
+            card_layout,
+            card_stats,
+            language,
         } = config;
 
         let query = sqlx::query!(
@@ -269,10 +299,15 @@ FROM
 INSERT INTO user_configs (
   discord_id, osu_id, gamemode, twitch_id, 
   retries, score_embed, list_size, 
-  timezone_seconds, render_button, score_data
+  timezone_seconds, render_button, score_data, 
+  card_theme, card_layout, card_stats, 
+  language
 ) 
 VALUES 
-  ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10) ON CONFLICT (discord_id) DO 
+  (
+    $1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, 
+    $12, $13, $14
+  ) ON CONFLICT (discord_id) DO 
 UPDATE 
 SET 
   osu_id = $2, 
@@ -283,7 +318,11 @@ SET
   list_size = $7, 
   timezone_seconds = $8, 
   render_button = $9, 
-  score_data = $10"#,
+  score_data = $10, 
+  card_theme = $11, 
+  card_layout = $12, 
+  card_stats = $13, 
+  language = $14"#,
             user_id.get() as i64,
             osu.map(|id| id as i32),
             mode.map(|mode| mode as i16) as Option<i16>,
@@ -294,6 +333,10 @@ SET
             timezone.map(UtcOffset::whole_seconds),
             *render_button,
             score_data.map(i16::from),
+            card_theme.map(i16::from),
+            card_layout.map(i16::from),
+            *card_stats,
+            language.map(i16::from),
         );
 
         query
@@ -402,4 +445,27 @@ WHERE
 
         Ok(row_opt.and_then(|row| row.gamemode.map(|mode| GameMode::from(mode as u8))))
     }
+
+    pub async fn select_user_language(&self, user_id: Id<UserMarker>) -> Result<Option<Language>> {
+        let query = sqlx::query!(
+            r#"
+SELECT 
+  language 
+FROM 
+  user_configs 
+WHERE 
+  discord_id = $1"#,
+            user_id.get() as i64
+        );
+
+        let row_opt = query
+            .fetch_optional(self)
+            .await
+            .wrap_err("failed to fetch optional")?;
+
+        Ok(row_opt
+            .and_then(|row| row.language)
+            .map(Language::try_from)
+            .and_then(Result::ok))
+    }
 }
@@ -0,0 +1,323 @@
+use eyre::{Result, WrapErr};
+use time::{Duration, OffsetDateTime};
+use twilight_model::id::{Id, marker::GuildMarker};
+
+use crate::{
+    Database,
+    model::digest::{DbDigestEntry, DbDigestStatsEntry, DbGuildDigest, DigestFrequency},
+};
+
+impl Database {
+    pub async fn select_guild_digests(&self) -> Result<Vec<DbGuildDigest>> {
+        let query = sqlx::query_as!(
+            DbGuildDigest,
+            r#"
+SELECT
+  guild_id,
+  channel_id,
+  frequency,
+  last_posted
+FROM
+  guild_digests"#
+        );
+
+        query.fetch_all(self).await.wrap_err("Failed to fetch all")
+    }
+
+    pub async fn select_guild_digest(
+        &self,
+        guild_id: Id<GuildMarker>,
+    ) -> Result<Option<DbGuildDigest>> {
+        let query = sqlx::query_as!(
+            DbGuildDigest,
+            r#"
+SELECT
+  guild_id,
+  channel_id,
+  frequency,
+  last_posted
+FROM
+  guild_digests
+WHERE
+  guild_id = $1"#,
+            guild_id.get() as i64
+        );
+
+        query
+            .fetch_optional(self)
+            .await
+            .wrap_err("Failed to fetch optional")
+    }
+
+    /// Changing the channel or frequency keeps the previous snapshot so that
+    /// the next digest still shows the progress since the last one.
+    pub async fn upsert_guild_digest(
+        &self,
+        guild_id: Id<GuildMarker>,
+        channel_id: u64,
+        frequency: DigestFrequency,
+    ) -> Result<()> {
+        let query = sqlx::query!(
+            r#"
+INSERT INTO guild_digests (guild_id, channel_id, frequency)
+VALUES
+  ($1, $2, $3)
+ON CONFLICT
+  (guild_id)
+DO
+  UPDATE
+SET
+  channel_id = $2,
+  frequency = $3,
+  failed_attempts = 0"#,
+            guild_id.get() as i64,
+            channel_id as i64,
+            i16::from(frequency),
+        );
+
+        query
+            .execute(self)
+            .await
+            .wrap_err("Failed to execute query")?;
+
+        Ok(())
+    }
+
+    /// Returns whether an entry was deleted
+    pub async fn delete_guild_digest(&self, guild_id: Id<GuildMarker>) -> Result<bool> {
+        let mut tx = self.begin().await.wrap_err("Failed to begin transaction")?;
+
+        let query = sqlx::query!(
+            r#"
+DELETE FROM
+  guild_digest_snapshots
+WHERE
+  guild_id = $1"#,
+            guild_id.get() as i64
+        );
+
+        query
+            .execute(&mut *tx)
+            .await
+            .wrap_err("Failed to execute snapshot query")?;
+
+        let query = sqlx::query!(
+            r#"
+DELETE FROM
+  guild_digests
+WHERE
+  guild_id = $1"#,
+            guild_id.get() as i64
+        );
+
+        let res = query
+            .execute(&mut *tx)
+            .await
+            .wrap_err("Failed to execute digest query")?;
+
+        tx.commit().await.wrap_err("Failed to commit transaction")?;
+
+        Ok(res.rows_affected() > 0)
+    }
+
+    /// The guild's latest snapshot.
+    pub async fn select_guild_digest_snapshot(
+        &self,
+        guild_id: Id<GuildMarker>,
+    ) -> Result<Vec<DbDigestEntry>> {
+        let query = sqlx::query_as!(
+            DbDigestEntry,
+            r#"
+SELECT
+  user_id,
+  gamemode,
+  pp,
+  global_rank
+FROM
+  guild_digest_snapshots
+WHERE
+  guild_id = $1
+  AND taken_at = (
+    SELECT
+      MAX(taken_at)
+    FROM
+      guild_digest_snapshots
+    WHERE
+      guild_id = $1
+  )"#,
+            guild_id.get() as i64
+        );
+
+        query.fetch_all(self).await.wrap_err("Failed to fetch all")
+    }
+
+    /// Cached mode stats of all linked users among the given discord ids.
+    pub async fn select_guild_digest_stats(
+        &self,
+        discord_ids: &[i64],
+    ) -> Result<Vec<DbDigestStatsEntry>> {
+        let query = sqlx::query_as!(
+            DbDigestStatsEntry,
+            r#"
+SELECT DISTINCT ON (stats.user_id, stats.gamemode)
+  stats.user_id,
+  names.username,
+  stats.gamemode,
+  stats.pp,
+  stats.global_rank
+FROM
+  (
+    SELECT
+      osu_id
+    FROM
+      user_configs
+    WHERE
+      discord_id = ANY($1)
+      AND osu_id IS NOT NULL
+  ) AS configs
+  JOIN osu_user_names AS names ON configs.osu_id = names.user_id
+  JOIN osu_user_mode_stats AS stats ON configs.osu_id = stats.user_id"#,
+            discord_ids
+        );
+
+        query.fetch_all(self).await.wrap_err("Failed to fetch all")
+    }
+
+    /// Add a snapshot with the given entries and mark the digest as posted.
+    ///
+    /// Snapshots older than a year are removed.
+    pub async fn insert_guild_digest_snapshot(
+        &self,
+        guild_id: Id<GuildMarker>,
+        entries: &[DbDigestStatsEntry],
+        posted_at: OffsetDateTime,
+    ) -> Result<()> {
+        let guild_id = guild_id.get() as i64;
+
+        let mut vec_user_id = Vec::with_capacity(entries.len());
+        let mut vec_gamemode = Vec::with_capacity(entries.len());
+        let mut vec_pp = Vec::with_capacity(entries.len());
+        let mut vec_global_rank = Vec::with_capacity(entries.len());
+
+        for entry in entries {
+            vec_user_id.push(entry.user_id);
+            vec_gamemode.push(entry.gamemode);
+            vec_pp.push(entry.pp);
+            vec_global_rank.push(entry.global_rank);
+        }
+
+        let mut tx = self.begin().await.wrap_err("Failed to begin transaction")?;
+
+        let query = sqlx::query!(
+            r#"
+DELETE FROM
+  guild_digest_snapshots
+WHERE
+  guild_id = $1
+  AND taken_at < $2"#,
+            guild_id,
+            posted_at - Duration::days(365),
+        );
+
+        query
+            .execute(&mut *tx)
+            .await
+            .wrap_err("Failed to execute delete query")?;
+
+        let query = sqlx::query!(
+            r#"
+INSERT INTO guild_digest_snapshots (
+  guild_id, taken_at, user_id, gamemode, pp, global_rank
+)
+SELECT
+  $1, $2, *
+FROM
+  UNNEST($3::INT4[], $4::INT2[], $5::FLOAT4[], $6::INT4[])"#,
+            guild_id,
+            posted_at,
+            &vec_user_id,
+            &vec_gamemode,
+            &vec_pp,
+            &vec_global_rank,
+        );
+
+        query
+            .execute(&mut *tx)
+            .await
+            .wrap_err("Failed to execute insert query")?;
+
+        let query = sqlx::query!(
+            r#"
+UPDATE
+  guild_digests
+SET
+  last_posted = $2,
+  failed_attempts = 0
+WHERE
+  guild_id = $1"#,
+            guild_id,
+            posted_at,
+        );
+
+        query
+            .execute(&mut *tx)
+            .await
+            .wrap_err("Failed to execute update query")?;
+
+        tx.commit().await.wrap_err("Failed to commit transaction")?;
+
+        Ok(())
+    }
+
+    /// Record that a digest could not be posted and return how many attempts
+    /// failed in a row.
+    pub async fn increment_guild_digest_failures(&self, guild_id: Id<GuildMarker>) -> Result<i16> {
+        let query = sqlx::query!(
+            r#"
+UPDATE
+  guild_digests
+SET
+  failed_attempts = failed_attempts + 1
+WHERE
+  guild_id = $1
+RETURNING
+  failed_attempts"#,
+            guild_id.get() as i64
+        );
+
+        let row = query
+            .fetch_one(self)
+            .await
+            .wrap_err("Failed to fetch one")?;
+
+        Ok(row.failed_attempts)
+    }
+
+    /// Give up on the current digest so that the next one is due after the
+    /// usual interval. The latest snapshot is kept for the next digest.
+    pub async fn skip_guild_digest(
+        &self,
+        guild_id: Id<GuildMarker>,
+        skipped_at: OffsetDateTime,
+    ) -> Result<()> {
+        let query = sqlx::query!(
+            r#"
+UPDATE
+  guild_digests
+SET
+  last_posted = $2,
+  failed_attempts = 0
+WHERE
+  guild_id = $1"#,
+            guild_id.get() as i64,
+            skipped_at,
+        );
+
+        query
+            .execute(self)
+            .await
+            .wrap_err("Failed to execute query")?;
+
+        Ok(())
+    }
+}
@@ -3,26 +3,35 @@ use std::mem;
 use bathbot_model::BgGameScore;
 use eyre::{Result, WrapErr};
 use rosu_v2::prelude::GameMode;
+use twilight_model::id::{Id, marker::UserMarker};
 
 use crate::{
     Database,
-    model::games::{DbBgGameScore, DbMapTagEntry, DbMapTagsParams},
+    model::games::{DbBgGameScore, DbBgGameUserScore, DbMapTagEntry, DbMapTagsParams},
 };
 
 impl Database {
-    pub async fn increment_bggame_scores(&self, user_ids: &[i64], amounts: &[i32]) -> Result<()> {
+    pub async fn increment_bggame_scores(
+        &self,
+        user_ids: &[i64],
+        amounts: &[i32],
+        game_kind: i16,
+    ) -> Result<()> {
         let query = sqlx::query!(
             r#"
-INSERT INTO bggame_scores (discord_id, score) 
+INSERT INTO bggame_scores (discord_id, game_kind, score) 
 SELECT
-  *
+  discord_id, 
+  $3, 
+  score 
 FROM
-  UNNEST($1::INT8[], $2::INT4[]) ON CONFLICT (discord_id) DO 
+  UNNEST($1::INT8[], $2::INT4[]) AS scores(discord_id, score) ON CONFLICT (discord_id, game_kind) DO 
 UPDATE 
 SET 
   score = bggame_scores.score + excluded.score"#,
             user_ids,
             amounts,
+            game_kind,
         );
 
         query
@@ -33,7 +42,7 @@ SET
         Ok(())
     }
 
-    pub async fn select_bggame_scores(&self) -> Result<Vec<BgGameScore>> {
+    pub async fn select_bggame_scores(&self, game_kind: i16) -> Result<Vec<BgGameScore>> {
         let query = sqlx::query_as!(
             DbBgGameScore,
             r#"
@@ -41,7 +50,10 @@ SELECT
   discord_id, 
   score 
 FROM 
-  bggame_scores"#
+  bggame_scores 
+WHERE 
+  game_kind = $1"#,
+            game_kind
         );
 
         let scores = query
@@ -53,6 +65,27 @@ FROM
         Ok(unsafe { mem::transmute::<Vec<DbBgGameScore>, Vec<BgGameScore>>(scores) })
     }
 
+    /// Scores of a user for each kind of bg game
+    pub async fn select_bggame_user_scores(
+        &self,
+        user_id: Id<UserMarker>,
+    ) -> Result<Vec<DbBgGameUserScore>> {
+        let query = sqlx::query_as!(
+            DbBgGameUserScore,
+            r#"
+SELECT
+  game_kind,
+  score
+FROM
+  bggame_scores
+WHERE
+  discord_id = $1"#,
+            user_id.get() as i64
+        );
+
+        query.fetch_all(self).await.wrap_err("Failed to fetch all")
+    }
+
     pub async fn upsert_map_tag(
         &self,
         mapset_id: u32,
@@ -4,7 +4,10 @@ use bathbot_model::HlGameScore;
 use eyre::{Result, WrapErr};
 use twilight_model::id::{Id, marker::UserMarker};
 
-use crate::{Database, model::games::DbHlGameScore};
+use crate::{
+    Database,
+    model::games::{DbHlGameScore, DbHlGameUserScore},
+};
 
 impl Database {
     pub async fn select_higherlower_scores_by_version(
@@ -59,6 +62,27 @@ WHERE
         Ok(row_opt.map_or(0, |row| row.highscore as u32))
     }
 
+    /// Highscores of a user for each higherlower version
+    pub async fn select_higherlower_user_highscores(
+        &self,
+        user_id: Id<UserMarker>,
+    ) -> Result<Vec<DbHlGameUserScore>> {
+        let query = sqlx::query_as!(
+            DbHlGameUserScore,
+            r#"
+SELECT
+  game_version,
+  highscore
+FROM
+  higherlower_scores
+WHERE
+  discord_id = $1"#,
+            user_id.get() as i64
+        );
+
+        query.fetch_all(self).await.wrap_err("Failed to fetch all")
+    }
+
     /// Returns whether the score is a new highscore
     pub async fn upsert_higherlower_highscore(
         &self,
@@ -1,5 +1,7 @@
+mod api_keys;
 mod bookmarks;
 mod configs;
+mod digests;
 mod games;
 mod osu;
 mod tracked_streams;
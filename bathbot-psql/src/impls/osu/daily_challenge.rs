@@ -0,0 +1,330 @@
+use eyre::{Result, WrapErr};
+use time::Date;
+use twilight_model::id::{Id, marker::GuildMarker};
+
+use crate::{
+    Database,
+    model::osu::{DbDailyChallengeReminder, DbDailyChallengeResult, DbDailyChallengeTotals},
+};
+
+impl Database {
+    /// Only results of users that are linked to a discord user are stored.
+    ///
+    /// Known score values are not overwritten by missing ones.
+    pub async fn upsert_daily_challenge_results(
+        &self,
+        room_id: u64,
+        results: &[DbDailyChallengeResult],
+    ) -> Result<()> {
+        let mut vec_user_id = Vec::with_capacity(results.len());
+        let mut vec_date = Vec::with_capacity(results.len());
+        let mut vec_username = Vec::with_capacity(results.len());
+        let mut vec_total_score = Vec::with_capacity(results.len());
+        let mut vec_accuracy = Vec::with_capacity(results.len());
+        let mut vec_attempts = Vec::with_capacity(results.len());
+        let mut vec_pos = Vec::with_capacity(results.len());
+
+        for result in results {
+            vec_user_id.push(result.user_id);
+            vec_date.push(result.date);
+            vec_username.push(result.username.as_str());
+            vec_total_score.push(result.total_score);
+            vec_accuracy.push(result.accuracy);
+            vec_attempts.push(result.attempts);
+            vec_pos.push(result.pos);
+        }
+
+        let query = sqlx::query!(
+            r#"
+INSERT INTO osu_daily_challenge_results (
+  user_id, date, room_id, username, total_score, accuracy, attempts, pos
+)
+SELECT
+  results.user_id,
+  results.date,
+  $1,
+  results.username,
+  results.total_score,
+  results.accuracy,
+  results.attempts,
+  results.pos
+FROM
+  UNNEST(
+    $2::INT4[], $3::DATE[], $4::VARCHAR[], $5::INT8[], $6::FLOAT4[],
+    $7::INT4[], $8::INT4[]
+  ) AS results(
+    user_id, date, username, total_score, accuracy, attempts, pos
+  )
+WHERE
+  results.user_id IN (
+    SELECT
+      osu_id
+    FROM
+      user_configs
+    WHERE
+      osu_id IS NOT NULL
+  )
+ON CONFLICT
+  (user_id, date)
+DO
+  UPDATE
+SET
+  room_id = $1,
+  username = excluded.username,
+  total_score = COALESCE(excluded.total_score, osu_daily_challenge_results.total_score),
+  accuracy = COALESCE(excluded.accuracy, osu_daily_challenge_results.accuracy),
+  attempts = COALESCE(excluded.attempts, osu_daily_challenge_results.attempts),
+  pos = COALESCE(excluded.pos, osu_daily_challenge_results.pos)"#,
+            room_id as i64,
+            &vec_user_id,
+            &vec_date,
+            &vec_username as _,
+            &vec_total_score as _,
+            &vec_accuracy as _,
+            &vec_attempts as _,
+            &vec_pos as _,
+        );
+
+        query
+            .execute(self)
+            .await
+            .wrap_err("Failed to execute query")?;
+
+        Ok(())
+    }
+
+    /// All stored results of the user, oldest first.
+    pub async fn select_daily_challenge_history(
+        &self,
+        user_id: u32,
+    ) -> Result<Vec<DbDailyChallengeResult>> {
+        let query = sqlx::query_as!(
+            DbDailyChallengeResult,
+            r#"
+SELECT
+  user_id,
+  date,
+  username,
+  total_score,
+  accuracy,
+  attempts,
+  pos
+FROM
+  osu_daily_challenge_results
+WHERE
+  user_id = $1
+ORDER BY
+  date ASC"#,
+            user_id as i32
+        );
+
+        query.fetch_all(self).await.wrap_err("Failed to fetch all")
+    }
+
+    /// Results of linked users among the given discord ids on the given date,
+    /// best first.
+    pub async fn select_guild_daily_challenge_results(
+        &self,
+        discord_ids: &[i64],
+        date: Date,
+    ) -> Result<Vec<DbDailyChallengeResult>> {
+        let query = sqlx::query_as!(
+            DbDailyChallengeResult,
+            r#"
+SELECT
+  user_id,
+  date,
+  username,
+  total_score,
+  accuracy,
+  attempts,
+  pos
+FROM
+  osu_daily_challenge_results
+WHERE
+  date = $2
+  AND user_id IN (
+    SELECT
+      osu_id
+    FROM
+      user_configs
+    WHERE
+      discord_id = ANY($1)
+      AND osu_id IS NOT NULL
+  )
+ORDER BY
+  total_score DESC NULLS LAST"#,
+            discord_ids,
+            date,
+        );
+
+        query.fetch_all(self).await.wrap_err("Failed to fetch all")
+    }
+
+    /// Accumulated results of linked users among the given discord ids,
+    /// highest total score first.
+    pub async fn select_guild_daily_challenge_totals(
+        &self,
+        discord_ids: &[i64],
+    ) -> Result<Vec<DbDailyChallengeTotals>> {
+        let query = sqlx::query_as!(
+            DbDailyChallengeTotals,
+            r#"
+SELECT
+  user_id,
+  (ARRAY_AGG(username ORDER BY date DESC))[1] AS "username!",
+  COUNT(*) AS "days!",
+  COALESCE(SUM(total_score), 0)::INT8 AS "total_score!",
+  AVG(accuracy)::FLOAT4 AS accuracy,
+  MIN(pos) AS best_pos
+FROM
+  osu_daily_challenge_results
+WHERE
+  user_id IN (
+    SELECT
+      osu_id
+    FROM
+      user_configs
+    WHERE
+      discord_id = ANY($1)
+      AND osu_id IS NOT NULL
+  )
+GROUP BY
+  user_id
+ORDER BY
+  COALESCE(SUM(total_score), 0) DESC"#,
+            discord_ids
+        );
+
+        query.fetch_all(self).await.wrap_err("Failed to fetch all")
+    }
+
+    /// Linked osu! users among the given discord ids that have no stored
+    /// result on the given date.
+    pub async fn select_daily_challenge_missing_users(
+        &self,
+        discord_ids: &[i64],
+        date: Date,
+    ) -> Result<Vec<u32>> {
+        let query = sqlx::query!(
+            r#"
+SELECT DISTINCT
+  osu_id AS "osu_id!"
+FROM
+  user_configs
+WHERE
+  discord_id = ANY($1)
+  AND osu_id IS NOT NULL
+  AND osu_id NOT IN (
+    SELECT
+      user_id
+    FROM
+      osu_daily_challenge_results
+    WHERE
+      date = $2
+  )"#,
+            discord_ids,
+            date,
+        );
+
+        let user_ids = query
+            .fetch_all(self)
+            .await
+            .wrap_err("Failed to fetch all")?
+            .into_iter()
+            .map(|row| row.osu_id as u32)
+            .collect();
+
+        Ok(user_ids)
+    }
+
+    pub async fn select_daily_challenge_reminders(&self) -> Result<Vec<DbDailyChallengeReminder>> {
+        let query = sqlx::query_as!(
+            DbDailyChallengeReminder,
+            r#"
+SELECT
+  guild_id,
+  channel_id,
+  last_date
+FROM
+  guild_daily_challenge_reminders"#
+        );
+
+        query.fetch_all(self).await.wrap_err("Failed to fetch all")
+    }
+
+    /// The guild counts as already reminded about the room of `date`.
+    pub async fn upsert_daily_challenge_reminder(
+        &self,
+        guild_id: Id<GuildMarker>,
+        channel_id: u64,
+        date: Date,
+    ) -> Result<()> {
+        let query = sqlx::query!(
+            r#"
+INSERT INTO guild_daily_challenge_reminders (guild_id, channel_id, last_date)
+VALUES
+  ($1, $2, $3)
+ON CONFLICT
+  (guild_id)
+DO
+  UPDATE
+SET
+  channel_id = $2"#,
+            guild_id.get() as i64,
+            channel_id as i64,
+            date,
+        );
+
+        query
+            .execute(self)
+            .await
+            .wrap_err("Failed to execute query")?;
+
+        Ok(())
+    }
+
+    pub async fn update_daily_challenge_reminder_date(
+        &self,
+        guild_id: Id<GuildMarker>,
+        date: Date,
+    ) -> Result<()> {
+        let query = sqlx::query!(
+            r#"
+UPDATE
+  guild_daily_challenge_reminders
+SET
+  last_date = $2
+WHERE
+  guild_id = $1"#,
+            guild_id.get() as i64,
+            date,
+        );
+
+        query
+            .execute(self)
+            .await
+            .wrap_err("Failed to execute query")?;
+
+        Ok(())
+    }
+
+    /// Returns whether an entry was deleted
+    pub async fn delete_daily_challenge_reminder(&self, guild_id: Id<GuildMarker>) -> Result<bool> {
+        let query = sqlx::query!(
+            r#"
+DELETE FROM
+  guild_daily_challenge_reminders
+WHERE
+  guild_id = $1"#,
+            guild_id.get() as i64
+        );
+
+        let res = query
+            .execute(self)
+            .await
+            .wrap_err("Failed to execute query")?;
+
+        Ok(res.rows_affected() > 0)
+    }
+}
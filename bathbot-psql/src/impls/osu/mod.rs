@@ -1,8 +1,13 @@
+pub mod daily_challenge;
 pub mod map;
 pub mod mapset;
 pub mod name;
 pub mod rank_pp;
 pub mod render;
 pub mod score;
+pub mod tracked_leaderboard_users;
+pub mod tracked_mappers;
+pub mod tracked_maps;
+pub mod tracked_matches;
 pub mod tracked_users;
 pub mod user;
@@ -1,7 +1,10 @@
+use bathbot_util::ScoreExt;
 use eyre::{Result, WrapErr};
-use rosu_v2::prelude::Score;
+use rosu_v2::prelude::{GameMode, Score};
+use sqlx::{Executor, Postgres};
+use time::OffsetDateTime;
 
-use crate::database::Database;
+use crate::{database::Database, model::osu::DbScore};
 
 impl Database {
     pub async fn insert_scores_mapsets(&self, scores: &[Score]) -> Result<()> {
@@ -19,4 +22,430 @@ impl Database {
 
         Ok(())
     }
+
+    /// Archive scores so they can be looked up even after the osu!api no
+    /// longer provides them.
+    ///
+    /// `members` contains the user id and guild id for each guild due to
+    /// which a user's scores are archived.
+    pub async fn insert_scores(&self, scores: &[Score], members: &[(u32, u64)]) -> Result<()> {
+        let mut tx = self.begin().await.wrap_err("Failed to begin transaction")?;
+
+        let (vec_user_id, vec_guild_id): (Vec<_>, Vec<_>) = members
+            .iter()
+            .map(|&(user_id, guild_id)| (user_id as i32, guild_id as i64))
+            .unzip();
+
+        let query = sqlx::query!(
+            r#"
+INSERT INTO osu_scores_archive_users (user_id, guild_id)
+SELECT
+  *
+FROM
+  UNNEST($1::INT4[], $2::INT8[])
+ON CONFLICT
+  (user_id, guild_id)
+DO
+  NOTHING"#,
+            &vec_user_id,
+            &vec_guild_id,
+        );
+
+        query
+            .execute(&mut *tx)
+            .await
+            .wrap_err("Failed to insert archive users")?;
+
+        for chunk in scores.chunks(100) {
+            Self::insert_scores_chunk(&mut *tx, chunk)
+                .await
+                .wrap_err("Failed to insert scores")?;
+
+            Self::upsert_scores_performance(&mut *tx, chunk)
+                .await
+                .wrap_err("Failed to upsert scores performance")?;
+        }
+
+        tx.commit().await.wrap_err("Failed to commit transaction")?;
+
+        Ok(())
+    }
+
+    async fn insert_scores_chunk<'c, E>(executor: E, scores: &[Score]) -> Result<()>
+    where
+        E: Executor<'c, Database = Postgres>,
+    {
+        let len = scores.len();
+
+        let mut vec_score_id = Vec::with_capacity(len);
+        let mut vec_user_id = Vec::with_capacity(len);
+        let mut vec_map_id = Vec::with_capacity(len);
+        let mut vec_gamemode = Vec::with_capacity(len);
+        let mut vec_mods = Vec::with_capacity(len);
+        let mut vec_score = Vec::with_capacity(len);
+        let mut vec_classic_score = Vec::with_capacity(len);
+        let mut vec_maxcombo = Vec::with_capacity(len);
+        let mut vec_grade = Vec::with_capacity(len);
+        let mut vec_accuracy = Vec::with_capacity(len);
+        let mut vec_countgeki = Vec::with_capacity(len);
+        let mut vec_count300 = Vec::with_capacity(len);
+        let mut vec_countkatu = Vec::with_capacity(len);
+        let mut vec_count100 = Vec::with_capacity(len);
+        let mut vec_count50 = Vec::with_capacity(len);
+        let mut vec_countmiss = Vec::with_capacity(len);
+        let mut vec_passed = Vec::with_capacity(len);
+        let mut vec_set_on_lazer = Vec::with_capacity(len);
+        let mut vec_ended_at = Vec::with_capacity(len);
+
+        for score in scores {
+            vec_score_id.push(score.id as i64);
+            vec_user_id.push(score.user_id as i32);
+            vec_map_id.push(score.map_id as i32);
+            vec_gamemode.push(score.mode as i16);
+            vec_mods.push(score.mods.bits() as i32);
+            vec_score.push(i64::from(score.score));
+            vec_classic_score.push(score.classic_score as i64);
+            vec_maxcombo.push(score.max_combo as i32);
+            vec_grade.push(score.grade as i16);
+            vec_accuracy.push(score.accuracy);
+            vec_countgeki.push(score.count_geki() as i32);
+            vec_count300.push(score.count_300() as i32);
+            vec_countkatu.push(score.count_katu() as i32);
+            vec_count100.push(score.count_100() as i32);
+            vec_count50.push(score.count_50() as i32);
+            vec_countmiss.push(score.count_miss() as i32);
+            vec_passed.push(score.passed);
+            vec_set_on_lazer.push(score.set_on_lazer);
+            vec_ended_at.push(score.ended_at);
+        }
+
+        let query = sqlx::query!(
+            r#"
+INSERT INTO osu_scores (
+  score_id, user_id, map_id, gamemode, mods, score, classic_score,
+  maxcombo, grade, accuracy, countgeki, count300, countkatu,
+  count100, count50, countmiss, passed, set_on_lazer, ended_at
+)
+SELECT
+  *
+FROM
+  UNNEST(
+    $1::INT8[], $2::INT4[], $3::INT4[], $4::INT2[], $5::INT4[],
+    $6::INT8[], $7::INT8[], $8::INT4[], $9::INT2[], $10::FLOAT4[],
+    $11::INT4[], $12::INT4[], $13::INT4[], $14::INT4[], $15::INT4[],
+    $16::INT4[], $17::BOOL[], $18::BOOL[], $19::TIMESTAMPTZ[]
+  )
+ON CONFLICT
+  (score_id)
+DO
+  NOTHING"#,
+            &vec_score_id,
+            &vec_user_id,
+            &vec_map_id,
+            &vec_gamemode,
+            &vec_mods,
+            &vec_score,
+            &vec_classic_score,
+            &vec_maxcombo,
+            &vec_grade,
+            &vec_accuracy,
+            &vec_countgeki,
+            &vec_count300,
+            &vec_countkatu,
+            &vec_count100,
+            &vec_count50,
+            &vec_countmiss,
+            &vec_passed,
+            &vec_set_on_lazer,
+            &vec_ended_at,
+        );
+
+        query
+            .execute(executor)
+            .await
+            .wrap_err("Failed to execute query")?;
+
+        Ok(())
+    }
+
+    async fn upsert_scores_performance<'c, E>(executor: E, scores: &[Score]) -> Result<()>
+    where
+        E: Executor<'c, Database = Postgres>,
+    {
+        let (vec_score_id, vec_pp): (Vec<_>, Vec<_>) = scores
+            .iter()
+            .map(|score| (score.id as i64, score.pp.map(f64::from)))
+            .unzip();
+
+        let query = sqlx::query!(
+            r#"
+INSERT INTO osu_scores_performance (score_id, pp)
+SELECT
+  *
+FROM
+  UNNEST($1::INT8[], $2::FLOAT8[])
+ON CONFLICT
+  (score_id)
+DO
+  UPDATE
+SET
+  pp = COALESCE(excluded.pp, osu_scores_performance.pp)"#,
+            &vec_score_id,
+            &vec_pp as _,
+        );
+
+        query
+            .execute(executor)
+            .await
+            .wrap_err("Failed to execute query")?;
+
+        Ok(())
+    }
+
+    /// The archived passed score with the most pp on each map of a user.
+    pub async fn select_archived_top_scores(
+        &self,
+        user_id: u32,
+        mode: GameMode,
+    ) -> Result<Vec<DbScore>> {
+        let query = sqlx::query_as!(
+            DbScore,
+            r#"
+SELECT DISTINCT ON (map_id)
+  score_id,
+  user_id,
+  map_id,
+  gamemode,
+  mods,
+  score,
+  classic_score,
+  maxcombo,
+  grade,
+  accuracy,
+  countgeki,
+  count300,
+  countkatu,
+  count100,
+  count50,
+  countmiss,
+  passed,
+  set_on_lazer,
+  ended_at,
+  pp
+FROM
+  osu_scores
+  JOIN osu_scores_performance USING (score_id)
+WHERE
+  user_id = $1
+  AND gamemode = $2
+  AND passed
+  AND pp IS NOT NULL
+ORDER BY
+  map_id,
+  pp DESC"#,
+            user_id as i32,
+            mode as i16,
+        );
+
+        query.fetch_all(self).await.wrap_err("Failed to fetch all")
+    }
+
+    /// Archived scores of a user that ended before `before`, most recent
+    /// first.
+    pub async fn select_archived_recent_scores(
+        &self,
+        user_id: u32,
+        mode: GameMode,
+        before: OffsetDateTime,
+        include_fails: bool,
+        limit: usize,
+    ) -> Result<Vec<DbScore>> {
+        let query = sqlx::query_as!(
+            DbScore,
+            r#"
+SELECT
+  score_id,
+  user_id,
+  map_id,
+  gamemode,
+  mods,
+  score,
+  classic_score,
+  maxcombo,
+  grade,
+  accuracy,
+  countgeki,
+  count300,
+  countkatu,
+  count100,
+  count50,
+  countmiss,
+  passed,
+  set_on_lazer,
+  ended_at,
+  pp
+FROM
+  osu_scores
+  LEFT JOIN osu_scores_performance USING (score_id)
+WHERE
+  user_id = $1
+  AND gamemode = $2
+  AND ended_at < $3
+  AND (passed OR $4)
+ORDER BY
+  ended_at DESC
+LIMIT
+  $5"#,
+            user_id as i32,
+            mode as i16,
+            before,
+            include_fails,
+            limit as i64,
+        );
+
+        query.fetch_all(self).await.wrap_err("Failed to fetch all")
+    }
+
+    /// Stop archiving a user's scores due to the given guild.
+    ///
+    /// The scores themselves are removed during the next retention if no
+    /// other guild archives them.
+    pub async fn delete_score_archive_user(&self, user_id: u32, guild_id: u64) -> Result<()> {
+        let query = sqlx::query!(
+            r#"
+DELETE FROM
+  osu_scores_archive_users
+WHERE
+  user_id = $1
+  AND guild_id = $2"#,
+            user_id as i32,
+            guild_id as i64,
+        );
+
+        query
+            .execute(self)
+            .await
+            .wrap_err("Failed to execute query")?;
+
+        Ok(())
+    }
+
+    /// Delete archived scores that ended before `cutoff`, that exceed the
+    /// `max_per_user` most recent scores of a user in a mode, or whose user
+    /// is no longer archived by any of the given `guilds`.
+    ///
+    /// Returns the amount of deleted scores.
+    pub async fn delete_outdated_scores(
+        &self,
+        cutoff: OffsetDateTime,
+        max_per_user: usize,
+        guilds: &[u64],
+    ) -> Result<u64> {
+        let guilds: Vec<_> = guilds.iter().map(|&guild_id| guild_id as i64).collect();
+
+        let mut tx = self.begin().await.wrap_err("Failed to begin transaction")?;
+
+        let opted_out_query = sqlx::query!(
+            r#"
+DELETE FROM
+  osu_scores_archive_users
+WHERE
+  guild_id != ALL($1)"#,
+            &guilds,
+        );
+
+        opted_out_query
+            .execute(&mut *tx)
+            .await
+            .wrap_err("Failed to delete opted out guilds")?;
+
+        let unarchived_query = sqlx::query!(
+            r#"
+DELETE FROM
+  osu_scores AS scores
+WHERE
+  NOT EXISTS (
+    SELECT
+    FROM
+      osu_scores_archive_users
+    WHERE
+      user_id = scores.user_id
+  )"#
+        );
+
+        let unarchived = unarchived_query
+            .execute(&mut *tx)
+            .await
+            .wrap_err("Failed to delete unarchived scores")?
+            .rows_affected();
+
+        let expired_query = sqlx::query!(
+            r#"
+DELETE FROM
+  osu_scores
+WHERE
+  ended_at < $1"#,
+            cutoff,
+        );
+
+        let expired = expired_query
+            .execute(&mut *tx)
+            .await
+            .wrap_err("Failed to delete expired scores")?
+            .rows_affected();
+
+        let excess_query = sqlx::query!(
+            r#"
+DELETE FROM
+  osu_scores
+WHERE
+  score_id IN (
+    SELECT
+      score_id
+    FROM
+      (
+        SELECT
+          score_id,
+          ROW_NUMBER() OVER (
+            PARTITION BY user_id, gamemode
+            ORDER BY ended_at DESC
+          ) AS idx
+        FROM
+          osu_scores
+      ) AS ranked
+    WHERE
+      idx > $1
+  )"#,
+            max_per_user as i64,
+        );
+
+        let excess = excess_query
+            .execute(&mut *tx)
+            .await
+            .wrap_err("Failed to delete excess scores")?
+            .rows_affected();
+
+        let performance_query = sqlx::query!(
+            r#"
+DELETE FROM
+  osu_scores_performance AS performance
+WHERE
+  NOT EXISTS (
+    SELECT
+    FROM
+      osu_scores
+    WHERE
+      score_id = performance.score_id
+  )"#
+        );
+
+        performance_query
+            .execute(&mut *tx)
+            .await
+            .wrap_err("Failed to delete orphaned performance")?;
+
+        tx.commit().await.wrap_err("Failed to commit transaction")?;
+
+        Ok(unarchived + expired + excess)
+    }
 }
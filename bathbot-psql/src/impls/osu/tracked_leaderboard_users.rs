@@ -0,0 +1,131 @@
+use eyre::{Result, WrapErr};
+use rosu_v2::prelude::GameMode;
+
+use crate::{Database, model::osu::DbTrackedOsuLeaderboardUser};
+
+impl Database {
+    pub async fn select_tracked_osu_leaderboard_users(
+        &self,
+    ) -> Result<Vec<DbTrackedOsuLeaderboardUser>> {
+        let query = sqlx::query_as!(
+            DbTrackedOsuLeaderboardUser,
+            r#"
+SELECT
+  user_id,
+  gamemode,
+  channel_id,
+  max_rank
+FROM
+  tracked_osu_leaderboard_users"#
+        );
+
+        query.fetch_all(self).await.wrap_err("Failed to fetch all")
+    }
+
+    pub async fn select_tracked_osu_leaderboard_users_channel(
+        &self,
+        channel_id: u64,
+    ) -> Result<Vec<DbTrackedOsuLeaderboardUser>> {
+        let query = sqlx::query_as!(
+            DbTrackedOsuLeaderboardUser,
+            r#"
+SELECT
+  user_id,
+  gamemode,
+  channel_id,
+  max_rank
+FROM
+  tracked_osu_leaderboard_users
+WHERE
+  channel_id = $1"#,
+            channel_id as i64
+        );
+
+        query.fetch_all(self).await.wrap_err("Failed to fetch all")
+    }
+
+    pub async fn upsert_tracked_osu_leaderboard_user(
+        &self,
+        user_id: u32,
+        mode: GameMode,
+        channel_id: u64,
+        max_rank: u8,
+    ) -> Result<()> {
+        let query = sqlx::query!(
+            r#"
+INSERT INTO tracked_osu_leaderboard_users (
+  user_id, gamemode, channel_id, max_rank
+)
+VALUES
+  ($1, $2, $3, $4)
+ON CONFLICT
+  (user_id, gamemode, channel_id)
+DO
+  UPDATE
+SET
+  max_rank = $4"#,
+            user_id as i32,
+            mode as i16,
+            channel_id as i64,
+            max_rank as i16,
+        );
+
+        query
+            .execute(self)
+            .await
+            .wrap_err("Failed to execute query")?;
+
+        Ok(())
+    }
+
+    pub async fn delete_tracked_osu_leaderboard_user(
+        &self,
+        user_id: u32,
+        mode: Option<GameMode>,
+        channel_id: u64,
+    ) -> Result<()> {
+        let query = sqlx::query!(
+            r#"
+DELETE FROM 
+  tracked_osu_leaderboard_users
+WHERE
+  user_id = $1
+  AND ($2::INT2 is NULL OR gamemode = $2)
+  AND channel_id = $3"#,
+            user_id as i32,
+            mode.map(|mode| mode as i16),
+            channel_id as i64
+        );
+
+        query
+            .execute(self)
+            .await
+            .wrap_err("Failed to execute query")?;
+
+        Ok(())
+    }
+
+    pub async fn delete_tracked_osu_leaderboard_channel(
+        &self,
+        channel_id: u64,
+        mode: Option<GameMode>,
+    ) -> Result<()> {
+        let query = sqlx::query!(
+            r#"
+DELETE FROM 
+  tracked_osu_leaderboard_users
+WHERE 
+  channel_id = $1
+  AND ($2::INT2 IS NULL OR gamemode = $2)"#,
+            channel_id as i64,
+            mode.map(|mode| mode as i16) as _,
+        );
+
+        query
+            .execute(self)
+            .await
+            .wrap_err("Failed to execute query")?;
+
+        Ok(())
+    }
+}
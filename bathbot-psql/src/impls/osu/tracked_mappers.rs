@@ -0,0 +1,226 @@
+use eyre::{Result, WrapErr};
+use sqlx::{Executor, Postgres};
+
+use crate::{
+    Database,
+    model::osu::{DbTrackedMapperMapset, DbTrackedOsuMapper},
+};
+
+impl Database {
+    pub async fn select_tracked_osu_mappers(&self) -> Result<Vec<DbTrackedOsuMapper>> {
+        let query = sqlx::query_as!(
+            DbTrackedOsuMapper,
+            r#"
+SELECT
+  mapper_id,
+  channel_id,
+  guild_id,
+  added_by
+FROM
+  tracked_osu_mappers"#
+        );
+
+        query.fetch_all(self).await.wrap_err("Failed to fetch all")
+    }
+
+    pub async fn select_tracked_osu_mappers_channel(
+        &self,
+        channel_id: u64,
+    ) -> Result<Vec<DbTrackedOsuMapper>> {
+        let query = sqlx::query_as!(
+            DbTrackedOsuMapper,
+            r#"
+SELECT
+  mapper_id,
+  channel_id,
+  guild_id,
+  added_by
+FROM
+  tracked_osu_mappers
+WHERE
+  channel_id = $1"#,
+            channel_id as i64
+        );
+
+        query.fetch_all(self).await.wrap_err("Failed to fetch all")
+    }
+
+    pub async fn upsert_tracked_osu_mapper(
+        &self,
+        mapper_id: u32,
+        channel_id: u64,
+        guild_id: Option<u64>,
+        added_by: u64,
+    ) -> Result<()> {
+        let query = sqlx::query!(
+            r#"
+INSERT INTO tracked_osu_mappers (
+  mapper_id, channel_id, guild_id, added_by
+)
+VALUES
+  ($1, $2, $3, $4)
+ON CONFLICT
+  (mapper_id, channel_id)
+DO
+  UPDATE
+SET
+  guild_id = $3,
+  added_by = $4"#,
+            mapper_id as i32,
+            channel_id as i64,
+            guild_id.map(|id| id as i64),
+            added_by as i64,
+        );
+
+        query
+            .execute(self)
+            .await
+            .wrap_err("Failed to execute query")?;
+
+        Ok(())
+    }
+
+    /// Returns whether an entry was deleted
+    pub async fn delete_tracked_osu_mapper(&self, mapper_id: u32, channel_id: u64) -> Result<bool> {
+        let mut tx = self.begin().await.wrap_err("Failed to begin transaction")?;
+
+        let query = sqlx::query!(
+            r#"
+DELETE FROM
+  tracked_osu_mappers
+WHERE
+  mapper_id = $1
+  AND channel_id = $2"#,
+            mapper_id as i32,
+            channel_id as i64
+        );
+
+        let res = query
+            .execute(&mut *tx)
+            .await
+            .wrap_err("Failed to execute mapper query")?;
+
+        Self::delete_unfollowed_mapper_mapsets(&mut *tx).await?;
+        tx.commit().await.wrap_err("Failed to commit transaction")?;
+
+        Ok(res.rows_affected() > 0)
+    }
+
+    pub async fn delete_tracked_osu_mappers_channel(&self, channel_id: u64) -> Result<()> {
+        let mut tx = self.begin().await.wrap_err("Failed to begin transaction")?;
+
+        let query = sqlx::query!(
+            r#"
+DELETE FROM
+  tracked_osu_mappers
+WHERE
+  channel_id = $1"#,
+            channel_id as i64
+        );
+
+        query
+            .execute(&mut *tx)
+            .await
+            .wrap_err("Failed to execute mapper query")?;
+
+        Self::delete_unfollowed_mapper_mapsets(&mut *tx).await?;
+        tx.commit().await.wrap_err("Failed to commit transaction")?;
+
+        Ok(())
+    }
+
+    /// Forget the mapsets of mappers that are no longer followed anywhere.
+    async fn delete_unfollowed_mapper_mapsets<'c, E>(executor: E) -> Result<()>
+    where
+        E: Executor<'c, Database = Postgres>,
+    {
+        let query = sqlx::query!(
+            r#"
+DELETE FROM
+  tracked_osu_mapper_mapsets
+WHERE
+  mapper_id NOT IN (
+    SELECT
+      mapper_id
+    FROM
+      tracked_osu_mappers
+  )"#
+        );
+
+        query
+            .execute(executor)
+            .await
+            .wrap_err("Failed to execute mapset query")?;
+
+        Ok(())
+    }
+
+    pub async fn select_tracked_mapper_mapsets(
+        &self,
+        mapper_id: u32,
+    ) -> Result<Vec<DbTrackedMapperMapset>> {
+        let query = sqlx::query_as!(
+            DbTrackedMapperMapset,
+            r#"
+SELECT
+  mapset_id,
+  rank_status
+FROM
+  tracked_osu_mapper_mapsets
+WHERE
+  mapper_id = $1"#,
+            mapper_id as i32
+        );
+
+        query.fetch_all(self).await.wrap_err("Failed to fetch all")
+    }
+
+    /// Store the rank status of the mapsets.
+    ///
+    /// If `overwrite` is `false`, statuses of mapsets that are already stored
+    /// remain unchanged.
+    pub async fn upsert_tracked_mapper_mapsets(
+        &self,
+        mapper_id: u32,
+        mapsets: &[DbTrackedMapperMapset],
+        overwrite: bool,
+    ) -> Result<()> {
+        let mut vec_mapset_id = Vec::with_capacity(mapsets.len());
+        let mut vec_rank_status = Vec::with_capacity(mapsets.len());
+
+        for mapset in mapsets {
+            vec_mapset_id.push(mapset.mapset_id);
+            vec_rank_status.push(mapset.rank_status);
+        }
+
+        let query = sqlx::query!(
+            r#"
+INSERT INTO tracked_osu_mapper_mapsets (mapset_id, mapper_id, rank_status)
+SELECT
+  mapsets.mapset_id,
+  $1,
+  mapsets.rank_status
+FROM
+  UNNEST($2::INT4[], $3::INT2[]) AS mapsets(mapset_id, rank_status)
+ON CONFLICT
+  (mapset_id)
+DO
+  UPDATE
+SET
+  rank_status = excluded.rank_status
+WHERE
+  $4::BOOL"#,
+            mapper_id as i32,
+            &vec_mapset_id,
+            &vec_rank_status,
+            overwrite,
+        );
+
+        query
+            .execute(self)
+            .await
+            .wrap_err("Failed to execute query")?;
+
+        Ok(())
+    }
+}
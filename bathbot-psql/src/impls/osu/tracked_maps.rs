@@ -0,0 +1,292 @@
+use eyre::{Result, WrapErr};
+use rosu_v2::prelude::GameMode;
+
+use crate::{
+    Database,
+    model::osu::{DbMapLeaderboardScore, DbMapLeaderboardSnapshot, DbTrackedOsuMap},
+};
+
+impl Database {
+    pub async fn select_tracked_osu_maps(&self) -> Result<Vec<DbTrackedOsuMap>> {
+        let query = sqlx::query_as!(
+            DbTrackedOsuMap,
+            r#"
+SELECT
+  map_id,
+  gamemode,
+  channel_id,
+  guild_id,
+  max_rank
+FROM
+  tracked_osu_maps"#
+        );
+
+        query.fetch_all(self).await.wrap_err("Failed to fetch all")
+    }
+
+    pub async fn select_tracked_osu_maps_channel(
+        &self,
+        channel_id: u64,
+    ) -> Result<Vec<DbTrackedOsuMap>> {
+        let query = sqlx::query_as!(
+            DbTrackedOsuMap,
+            r#"
+SELECT
+  map_id,
+  gamemode,
+  channel_id,
+  guild_id,
+  max_rank
+FROM
+  tracked_osu_maps
+WHERE
+  channel_id = $1"#,
+            channel_id as i64
+        );
+
+        query.fetch_all(self).await.wrap_err("Failed to fetch all")
+    }
+
+    pub async fn upsert_tracked_osu_map(
+        &self,
+        map_id: u32,
+        mode: GameMode,
+        channel_id: u64,
+        guild_id: Option<u64>,
+        max_rank: u8,
+    ) -> Result<()> {
+        let query = sqlx::query!(
+            r#"
+INSERT INTO tracked_osu_maps (
+  map_id, gamemode, channel_id, guild_id, max_rank
+)
+VALUES
+  ($1, $2, $3, $4, $5)
+ON CONFLICT
+  (map_id, gamemode, channel_id)
+DO
+  UPDATE
+SET
+  guild_id = $4,
+  max_rank = $5"#,
+            map_id as i32,
+            mode as i16,
+            channel_id as i64,
+            guild_id.map(|id| id as i64),
+            max_rank as i16,
+        );
+
+        query
+            .execute(self)
+            .await
+            .wrap_err("Failed to execute query")?;
+
+        Ok(())
+    }
+
+    /// Returns whether an entry was deleted
+    pub async fn delete_tracked_osu_map(
+        &self,
+        map_id: u32,
+        mode: Option<GameMode>,
+        channel_id: u64,
+    ) -> Result<bool> {
+        let query = sqlx::query!(
+            r#"
+DELETE FROM
+  tracked_osu_maps
+WHERE
+  map_id = $1
+  AND ($2::INT2 IS NULL OR gamemode = $2)
+  AND channel_id = $3"#,
+            map_id as i32,
+            mode.map(|mode| mode as i16),
+            channel_id as i64
+        );
+
+        let res = query
+            .execute(self)
+            .await
+            .wrap_err("Failed to execute query")?;
+
+        Ok(res.rows_affected() > 0)
+    }
+
+    pub async fn delete_tracked_osu_maps_channel(&self, channel_id: u64) -> Result<()> {
+        let query = sqlx::query!(
+            r#"
+DELETE FROM
+  tracked_osu_maps
+WHERE
+  channel_id = $1"#,
+            channel_id as i64
+        );
+
+        query
+            .execute(self)
+            .await
+            .wrap_err("Failed to execute query")?;
+
+        Ok(())
+    }
+
+    /// The most recent snapshots of a map's leaderboard, latest first.
+    pub async fn select_map_leaderboard_snapshots(
+        &self,
+        map_id: u32,
+        mode: GameMode,
+        limit: usize,
+    ) -> Result<Vec<DbMapLeaderboardSnapshot>> {
+        let query = sqlx::query_as!(
+            DbMapLeaderboardSnapshot,
+            r#"
+SELECT
+  snapshot_id,
+  taken_at
+FROM
+  osu_map_leaderboard_snapshots
+WHERE
+  map_id = $1
+  AND gamemode = $2
+ORDER BY
+  snapshot_id DESC
+LIMIT
+  $3"#,
+            map_id as i32,
+            mode as i16,
+            limit as i64
+        );
+
+        query.fetch_all(self).await.wrap_err("Failed to fetch all")
+    }
+
+    pub async fn select_map_leaderboard_snapshot_scores(
+        &self,
+        snapshot_id: i32,
+    ) -> Result<Vec<DbMapLeaderboardScore>> {
+        let query = sqlx::query_as!(
+            DbMapLeaderboardScore,
+            r#"
+SELECT
+  pos,
+  user_id,
+  username,
+  score_id,
+  score,
+  pp
+FROM
+  osu_map_leaderboard_snapshot_scores
+WHERE
+  snapshot_id = $1
+ORDER BY
+  pos"#,
+            snapshot_id
+        );
+
+        query.fetch_all(self).await.wrap_err("Failed to fetch all")
+    }
+
+    /// Store a new snapshot and only keep the `keep` most recent snapshots of
+    /// the map.
+    pub async fn insert_map_leaderboard_snapshot(
+        &self,
+        map_id: u32,
+        mode: GameMode,
+        scores: &[DbMapLeaderboardScore],
+        keep: usize,
+    ) -> Result<()> {
+        let mut vec_pos = Vec::with_capacity(scores.len());
+        let mut vec_user_id = Vec::with_capacity(scores.len());
+        let mut vec_username = Vec::with_capacity(scores.len());
+        let mut vec_score_id = Vec::with_capacity(scores.len());
+        let mut vec_score = Vec::with_capacity(scores.len());
+        let mut vec_pp = Vec::with_capacity(scores.len());
+
+        for score in scores {
+            vec_pos.push(score.pos);
+            vec_user_id.push(score.user_id);
+            vec_username.push(score.username.as_str());
+            vec_score_id.push(score.score_id);
+            vec_score.push(score.score);
+            vec_pp.push(score.pp);
+        }
+
+        let mut tx = self.begin().await.wrap_err("Failed to begin transaction")?;
+
+        let query = sqlx::query!(
+            r#"
+INSERT INTO osu_map_leaderboard_snapshots (map_id, gamemode)
+VALUES
+  ($1, $2)
+RETURNING
+  snapshot_id"#,
+            map_id as i32,
+            mode as i16,
+        );
+
+        let snapshot_id = query
+            .fetch_one(&mut *tx)
+            .await
+            .wrap_err("Failed to execute snapshot query")?
+            .snapshot_id;
+
+        let query = sqlx::query!(
+            r#"
+INSERT INTO osu_map_leaderboard_snapshot_scores (
+  snapshot_id, pos, user_id, username, score_id, score, pp
+)
+SELECT
+  $1, *
+FROM
+  UNNEST(
+    $2::INT2[], $3::INT4[], $4::VARCHAR[], $5::INT8[], $6::INT8[], $7::FLOAT4[]
+  )"#,
+            snapshot_id,
+            &vec_pos,
+            &vec_user_id,
+            &vec_username as _,
+            &vec_score_id,
+            &vec_score,
+            &vec_pp as _,
+        );
+
+        query
+            .execute(&mut *tx)
+            .await
+            .wrap_err("Failed to execute scores query")?;
+
+        let query = sqlx::query!(
+            r#"
+DELETE FROM
+  osu_map_leaderboard_snapshots
+WHERE
+  map_id = $1
+  AND gamemode = $2
+  AND snapshot_id NOT IN (
+    SELECT
+      snapshot_id
+    FROM
+      osu_map_leaderboard_snapshots
+    WHERE
+      map_id = $1
+      AND gamemode = $2
+    ORDER BY
+      snapshot_id DESC
+    LIMIT
+      $3
+  )"#,
+            map_id as i32,
+            mode as i16,
+            keep as i64,
+        );
+
+        query
+            .execute(&mut *tx)
+            .await
+            .wrap_err("Failed to execute prune query")?;
+
+        tx.commit().await.wrap_err("Failed to commit transaction")?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,98 @@
+use eyre::{Result, WrapErr};
+
+use crate::{Database, model::osu::DbTrackedOsuMatch};
+
+impl Database {
+    pub async fn select_tracked_osu_matches(&self) -> Result<Vec<DbTrackedOsuMatch>> {
+        let query = sqlx::query_as!(
+            DbTrackedOsuMatch,
+            r#"
+SELECT
+  match_id,
+  channel_id,
+  msg_id,
+  embed_count
+FROM
+  tracked_osu_matches"#
+        );
+
+        query.fetch_all(self).await.wrap_err("Failed to fetch all")
+    }
+
+    pub async fn upsert_tracked_osu_match(
+        &self,
+        match_id: u32,
+        channel_id: u64,
+        msg_id: u64,
+        embed_count: usize,
+    ) -> Result<()> {
+        let query = sqlx::query!(
+            r#"
+INSERT INTO tracked_osu_matches (
+  match_id, channel_id, msg_id, embed_count
+)
+VALUES
+  ($1, $2, $3, $4)
+ON CONFLICT
+  (match_id, channel_id)
+DO
+  UPDATE
+SET
+  msg_id = $3,
+  embed_count = $4"#,
+            match_id as i32,
+            channel_id as i64,
+            msg_id as i64,
+            embed_count as i32,
+        );
+
+        query
+            .execute(self)
+            .await
+            .wrap_err("Failed to execute query")?;
+
+        Ok(())
+    }
+
+    pub async fn delete_tracked_osu_match_channel(
+        &self,
+        match_id: u32,
+        channel_id: u64,
+    ) -> Result<()> {
+        let query = sqlx::query!(
+            r#"
+DELETE FROM
+  tracked_osu_matches
+WHERE
+  match_id = $1
+  AND channel_id = $2"#,
+            match_id as i32,
+            channel_id as i64,
+        );
+
+        query
+            .execute(self)
+            .await
+            .wrap_err("Failed to execute query")?;
+
+        Ok(())
+    }
+
+    pub async fn delete_tracked_osu_match(&self, match_id: u32) -> Result<()> {
+        let query = sqlx::query!(
+            r#"
+DELETE FROM
+  tracked_osu_matches
+WHERE
+  match_id = $1"#,
+            match_id as i32,
+        );
+
+        query
+            .execute(self)
+            .await
+            .wrap_err("Failed to execute query")?;
+
+        Ok(())
+    }
+}
@@ -50,7 +50,13 @@ SELECT
   min_pp,
   max_pp,
   min_combo_percent,
-  max_combo_percent
+  max_combo_percent,
+  mods_include,
+  mods_exclude,
+  min_stars,
+  max_stars,
+  mapper_id,
+  map_status
 FROM
   tracked_osu_users
 WHERE
@@ -70,10 +76,15 @@ WHERE
             r#"
 INSERT INTO tracked_osu_users (
   user_id, gamemode, channel_id, min_index, max_index,
-  min_pp, max_pp, min_combo_percent, max_combo_percent
+  min_pp, max_pp, min_combo_percent, max_combo_percent,
+  mods_include, mods_exclude, min_stars, max_stars, mapper_id,
+  map_status
 )
 VALUES
-  ($1, $2, $3, $4, $5, $6, $7, $8, $9)
+  (
+    $1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14,
+    $15
+  )
 ON CONFLICT
   (user_id, gamemode, channel_id)
 DO
@@ -84,7 +95,13 @@ SET
     min_pp = $6,
     max_pp = $7,
     min_combo_percent = $8,
-    max_combo_percent = $9"#,
+    max_combo_percent = $9,
+    mods_include = $10,
+    mods_exclude = $11,
+    min_stars = $12,
+    max_stars = $13,
+    mapper_id = $14,
+    map_status = $15"#,
             user.user_id,
             user.gamemode,
             channel_id as i64,
@@ -94,6 +111,12 @@ SET
             user.max_pp,
             user.min_combo_percent,
             user.max_combo_percent,
+            user.mods_include,
+            user.mods_exclude,
+            user.min_stars,
+            user.max_stars,
+            user.mapper_id,
+            user.map_status,
         );
 
         query
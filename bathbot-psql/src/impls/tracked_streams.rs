@@ -7,7 +7,7 @@ use eyre::{Result, WrapErr};
 use futures::StreamExt;
 use twilight_model::id::{Id, marker::ChannelMarker};
 
-use crate::database::Database;
+use crate::{database::Database, model::tracked_stream::DbTrackedTwitchStream};
 
 impl Database {
     pub async fn select_tracked_twitch_streams<S>(
@@ -110,4 +110,125 @@ WHERE
 
         Ok(res.rows_affected() > 0)
     }
+
+    /// Tracking entries of all channels that track the given twitch user
+    pub async fn select_tracked_twitch_stream_channels(
+        &self,
+        user: u64,
+    ) -> Result<Vec<DbTrackedTwitchStream>> {
+        let query = sqlx::query_as!(
+            DbTrackedTwitchStream,
+            r#"
+SELECT
+  channel_id,
+  user_id,
+  osu_only,
+  keyword,
+  template,
+  role_id,
+  vod_edit,
+  notif_msg_id
+FROM
+  tracked_twitch_streams
+WHERE
+  user_id = $1"#,
+            user as i64,
+        );
+
+        query.fetch_all(self).await.wrap_err("failed to fetch all")
+    }
+
+    pub async fn select_tracked_twitch_stream(
+        &self,
+        channel: Id<ChannelMarker>,
+        user: u64,
+    ) -> Result<Option<DbTrackedTwitchStream>> {
+        let query = sqlx::query_as!(
+            DbTrackedTwitchStream,
+            r#"
+SELECT
+  channel_id,
+  user_id,
+  osu_only,
+  keyword,
+  template,
+  role_id,
+  vod_edit,
+  notif_msg_id
+FROM
+  tracked_twitch_streams
+WHERE
+  channel_id = $1
+  AND user_id = $2"#,
+            channel.get() as i64,
+            user as i64,
+        );
+
+        query
+            .fetch_optional(self)
+            .await
+            .wrap_err("failed to fetch optional")
+    }
+
+    pub async fn update_tracked_twitch_stream_settings(
+        &self,
+        entry: &DbTrackedTwitchStream,
+    ) -> Result<()> {
+        let query = sqlx::query!(
+            r#"
+UPDATE
+  tracked_twitch_streams
+SET
+  osu_only = $3,
+  keyword = $4,
+  template = $5,
+  role_id = $6,
+  vod_edit = $7
+WHERE
+  channel_id = $1
+  AND user_id = $2"#,
+            entry.channel_id,
+            entry.user_id,
+            entry.osu_only,
+            entry.keyword,
+            entry.template,
+            entry.role_id,
+            entry.vod_edit,
+        );
+
+        query
+            .execute(self)
+            .await
+            .wrap_err("failed to execute query")?;
+
+        Ok(())
+    }
+
+    pub async fn update_tracked_twitch_stream_notif(
+        &self,
+        channel: Id<ChannelMarker>,
+        user: u64,
+        msg_id: Option<u64>,
+    ) -> Result<()> {
+        let query = sqlx::query!(
+            r#"
+UPDATE
+  tracked_twitch_streams
+SET
+  notif_msg_id = $3
+WHERE
+  channel_id = $1
+  AND user_id = $2"#,
+            channel.get() as i64,
+            user as i64,
+            msg_id.map(|msg_id| msg_id as i64),
+        );
+
+        query
+            .execute(self)
+            .await
+            .wrap_err("failed to execute query")?;
+
+        Ok(())
+    }
 }
@@ -0,0 +1,8 @@
+use time::OffsetDateTime;
+
+pub struct DbApiKey {
+    pub key_id: i32,
+    pub name: String,
+    pub requests_per_minute: i32,
+    pub created_at: OffsetDateTime,
+}
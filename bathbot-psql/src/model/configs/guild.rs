@@ -1,6 +1,6 @@
 use sqlx::types::JsonValue;
 
-use super::{Authorities, HideSolutions, Retries, ScoreData, list_size::ListSize};
+use super::{Authorities, HideSolutions, Language, Retries, ScoreData, list_size::ListSize};
 
 pub struct DbGuildConfig {
     pub guild_id: i64,
@@ -13,6 +13,8 @@ pub struct DbGuildConfig {
     pub allow_custom_skins: Option<bool>,
     pub hide_medal_solution: Option<i16>,
     pub score_data: Option<i16>,
+    pub score_archive: Option<bool>,
+    pub language: Option<i16>,
 }
 
 #[derive(Clone)]
@@ -26,6 +28,8 @@ pub struct GuildConfig {
     pub allow_custom_skins: Option<bool>,
     pub hide_medal_solution: Option<HideSolutions>,
     pub score_data: Option<ScoreData>,
+    pub score_archive: Option<bool>,
+    pub language: Option<Language>,
 }
 
 impl GuildConfig {
@@ -44,6 +48,8 @@ impl Default for GuildConfig {
             allow_custom_skins: Default::default(),
             hide_medal_solution: Default::default(),
             score_data: Default::default(),
+            score_archive: Default::default(),
+            language: Default::default(),
         }
     }
 }
@@ -62,6 +68,8 @@ impl From<DbGuildConfig> for GuildConfig {
             allow_custom_skins,
             hide_medal_solution,
             score_data,
+            score_archive,
+            language,
         } = config;
 
         let authorities = Authorities::deserialize(&authorities);
@@ -90,6 +98,8 @@ impl From<DbGuildConfig> for GuildConfig {
                 .map(HideSolutions::try_from)
                 .and_then(Result::ok),
             score_data: score_data.map(ScoreData::try_from).and_then(Result::ok),
+            score_archive,
+            language: language.map(Language::try_from).and_then(Result::ok),
         }
     }
 }
@@ -0,0 +1,54 @@
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+#[repr(u8)]
+pub enum Language {
+    #[default]
+    English = 0,
+    German = 1,
+    French = 2,
+}
+
+impl Language {
+    pub const ALL: [Self; 3] = [Self::English, Self::German, Self::French];
+
+    /// The locale as used by discord.
+    ///
+    /// See <https://discord.com/developers/docs/reference#locales>
+    pub const fn locale(self) -> &'static str {
+        match self {
+            Self::English => "en-US",
+            Self::German => "de",
+            Self::French => "fr",
+        }
+    }
+
+    /// Parse a discord locale.
+    pub fn from_locale(locale: &str) -> Option<Self> {
+        match locale {
+            "en-US" | "en-GB" => Some(Self::English),
+            "de" => Some(Self::German),
+            "fr" => Some(Self::French),
+            _ => None,
+        }
+    }
+}
+
+impl From<Language> for i16 {
+    #[inline]
+    fn from(language: Language) -> Self {
+        language as Self
+    }
+}
+
+impl TryFrom<i16> for Language {
+    type Error = ();
+
+    #[inline]
+    fn try_from(value: i16) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(Self::English),
+            1 => Ok(Self::German),
+            2 => Ok(Self::French),
+            _ => Err(()),
+        }
+    }
+}
@@ -1,9 +1,13 @@
+pub use bathbot_model::{CardLayout, CardTheme};
+
 pub use self::{
     authorities::{Authorities, Authority},
     guild::{DbGuildConfig, GuildConfig},
     hide_solutions::HideSolutions,
+    language::Language,
     list_size::ListSize,
     retries::Retries,
+    role_reward::{DbRoleReward, DbRoleRewardStats, RoleRewardKind},
     score_data::ScoreData,
     skin::{DbSkinEntry, SkinEntry},
     user::{DbUserConfig, OsuId, OsuUserId, OsuUsername, UserConfig},
@@ -12,8 +16,10 @@ pub use self::{
 mod authorities;
 mod guild;
 mod hide_solutions;
+mod language;
 mod list_size;
 mod retries;
+mod role_reward;
 mod score_data;
 mod skin;
 mod user;
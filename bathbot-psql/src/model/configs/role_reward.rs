@@ -0,0 +1,66 @@
+use twilight_interactions::command::{CommandOption, CreateOption};
+
+pub struct DbRoleReward {
+    pub guild_id: i64,
+    pub role_id: i64,
+    pub kind: i16,
+    pub gamemode: Option<i16>,
+    pub min_value: Option<f64>,
+    pub max_value: Option<f64>,
+}
+
+/// Cached stats of a linked member, one row per cached mode
+pub struct DbRoleRewardStats {
+    pub discord_id: i64,
+    pub badges: i32,
+    pub medals: i32,
+    pub gamemode: Option<i16>,
+    pub pp: Option<f32>,
+    pub global_rank: Option<i32>,
+    pub country_rank: Option<i32>,
+}
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq, CommandOption, CreateOption)]
+#[repr(u8)]
+pub enum RoleRewardKind {
+    #[option(name = "Global rank", value = "global_rank")]
+    GlobalRank = 0,
+    #[option(name = "Country rank", value = "country_rank")]
+    CountryRank = 1,
+    #[option(name = "PP", value = "pp")]
+    Pp = 2,
+    #[option(name = "Badges", value = "badges")]
+    Badges = 3,
+    #[option(name = "Medals", value = "medals")]
+    Medals = 4,
+}
+
+impl RoleRewardKind {
+    /// Whether the stat is specific to a mode
+    pub fn requires_mode(self) -> bool {
+        matches!(self, Self::GlobalRank | Self::CountryRank | Self::Pp)
+    }
+}
+
+impl From<RoleRewardKind> for i16 {
+    #[inline]
+    fn from(kind: RoleRewardKind) -> Self {
+        kind as Self
+    }
+}
+
+impl TryFrom<i16> for RoleRewardKind {
+    type Error = ();
+
+    #[inline]
+    fn try_from(value: i16) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(Self::GlobalRank),
+            1 => Ok(Self::CountryRank),
+            2 => Ok(Self::Pp),
+            3 => Ok(Self::Badges),
+            4 => Ok(Self::Medals),
+            _ => Err(()),
+        }
+    }
+}
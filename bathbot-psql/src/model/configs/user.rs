@@ -3,7 +3,7 @@ use rosu_v2::prelude::{GameMode, Username};
 use sqlx::types::Json;
 use time::UtcOffset;
 
-use super::{Retries, ScoreData, list_size::ListSize};
+use super::{CardLayout, CardTheme, Language, Retries, ScoreData, list_size::ListSize};
 
 pub struct DbUserConfig {
     pub list_size: Option<i16>,
@@ -15,6 +15,10 @@ pub struct DbUserConfig {
     pub timezone_seconds: Option<i32>,
     pub render_button: Option<bool>,
     pub score_data: Option<i16>,
+    pub card_theme: Option<i16>,
+    pub card_layout: Option<i16>,
+    pub card_stats: Option<bool>,
+    pub language: Option<i16>,
 }
 
 pub trait OsuId {
@@ -46,6 +50,10 @@ pub struct UserConfig<O: OsuId> {
     pub timezone: Option<UtcOffset>,
     pub render_button: Option<bool>,
     pub score_data: Option<ScoreData>,
+    pub card_theme: Option<CardTheme>,
+    pub card_layout: Option<CardLayout>,
+    pub card_stats: Option<bool>,
+    pub language: Option<Language>,
 }
 
 impl<O: OsuId> Default for UserConfig<O> {
@@ -61,6 +69,10 @@ impl<O: OsuId> Default for UserConfig<O> {
             timezone: None,
             render_button: None,
             score_data: None,
+            card_theme: None,
+            card_layout: None,
+            card_stats: None,
+            language: None,
         }
     }
 }
@@ -78,6 +90,10 @@ impl From<DbUserConfig> for UserConfig<OsuUserId> {
             timezone_seconds,
             render_button,
             score_data,
+            card_theme,
+            card_layout,
+            card_stats,
+            language,
         } = config;
 
         Self {
@@ -92,6 +108,10 @@ impl From<DbUserConfig> for UserConfig<OsuUserId> {
                 .map(Result::unwrap),
             render_button,
             score_data: score_data.map(ScoreData::try_from).and_then(Result::ok),
+            card_theme: card_theme.map(CardTheme::try_from).and_then(Result::ok),
+            card_layout: card_layout.map(CardLayout::try_from).and_then(Result::ok),
+            card_stats,
+            language: language.map(Language::try_from).and_then(Result::ok),
         }
     }
 }
@@ -0,0 +1,65 @@
+use time::{Duration, OffsetDateTime};
+use twilight_interactions::command::{CommandOption, CreateOption};
+
+pub struct DbGuildDigest {
+    pub guild_id: i64,
+    pub channel_id: i64,
+    pub frequency: i16,
+    /// `None` if no digest has been posted yet
+    pub last_posted: Option<OffsetDateTime>,
+}
+
+/// Stats of a linked guild member at the time of a digest
+pub struct DbDigestEntry {
+    pub user_id: i32,
+    pub gamemode: i16,
+    pub pp: f32,
+    pub global_rank: i32,
+}
+
+/// Current stats of a linked guild member
+pub struct DbDigestStatsEntry {
+    pub user_id: i32,
+    pub username: String,
+    pub gamemode: i16,
+    pub pp: f32,
+    pub global_rank: i32,
+}
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq, CommandOption, CreateOption)]
+#[repr(u8)]
+pub enum DigestFrequency {
+    #[option(name = "Daily", value = "daily")]
+    Daily = 0,
+    #[option(name = "Weekly", value = "weekly")]
+    Weekly = 1,
+}
+
+impl DigestFrequency {
+    pub fn duration(self) -> Duration {
+        match self {
+            Self::Daily => Duration::days(1),
+            Self::Weekly => Duration::weeks(1),
+        }
+    }
+}
+
+impl From<DigestFrequency> for i16 {
+    #[inline]
+    fn from(frequency: DigestFrequency) -> Self {
+        frequency as Self
+    }
+}
+
+impl TryFrom<i16> for DigestFrequency {
+    type Error = ();
+
+    #[inline]
+    fn try_from(value: i16) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(Self::Daily),
+            1 => Ok(Self::Weekly),
+            _ => Err(()),
+        }
+    }
+}
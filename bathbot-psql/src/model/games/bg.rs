@@ -10,6 +10,11 @@ pub struct DbBgGameScore {
     pub score: i32,
 }
 
+pub struct DbBgGameUserScore {
+    pub game_kind: i16,
+    pub score: i32,
+}
+
 pub struct MapsetTagsEntries {
     pub mode: GameMode,
     pub tags: Vec<DbMapTagEntry>,
@@ -5,3 +5,8 @@ pub struct DbHlGameScore {
     pub discord_id: i64,
     pub highscore: i32,
 }
+
+pub struct DbHlGameUserScore {
+    pub game_version: i16,
+    pub highscore: i32,
+}
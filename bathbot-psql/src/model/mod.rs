@@ -1,4 +1,7 @@
+pub mod api_key;
 pub mod configs;
+pub mod digest;
 pub mod games;
 pub mod osu;
 pub mod render;
+pub mod tracked_stream;
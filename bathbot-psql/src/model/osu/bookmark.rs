@@ -27,4 +27,7 @@ pub struct MapBookmark {
     pub genre: Genre,
     pub language: Language,
     pub cover_url: Box<str>,
+    pub checksum: Box<str>,
+    pub folder: Option<Box<str>>,
+    pub note: Option<Box<str>>,
 }
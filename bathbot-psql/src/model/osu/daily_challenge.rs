@@ -0,0 +1,35 @@
+use time::Date;
+
+/// Participation of a user in a daily challenge.
+///
+/// Score values are `None` if the user did not place on the room's
+/// leaderboard.
+pub struct DbDailyChallengeResult {
+    pub user_id: i32,
+    pub date: Date,
+    pub username: String,
+    pub total_score: Option<i64>,
+    pub accuracy: Option<f32>,
+    pub attempts: Option<i32>,
+    /// Position on the room's leaderboard
+    pub pos: Option<i32>,
+}
+
+/// Accumulated results of a user across all stored days
+pub struct DbDailyChallengeTotals {
+    pub user_id: i32,
+    /// Name of the user's most recent result
+    pub username: String,
+    pub days: i64,
+    /// Sum of all known scores
+    pub total_score: i64,
+    pub accuracy: Option<f32>,
+    pub best_pos: Option<i32>,
+}
+
+pub struct DbDailyChallengeReminder {
+    pub guild_id: i64,
+    pub channel_id: i64,
+    /// Date of the latest room that the guild was reminded about
+    pub last_date: Date,
+}
@@ -1,7 +1,16 @@
-pub use self::{bookmark::*, map::*, mapset::*, tracked_user::*, user::*};
+pub use self::{
+    bookmark::*, daily_challenge::*, map::*, mapset::*, score::*, tracked_leaderboard_user::*,
+    tracked_map::*, tracked_mapper::*, tracked_match::*, tracked_user::*, user::*,
+};
 
 mod bookmark;
+mod daily_challenge;
 mod map;
 mod mapset;
+mod score;
+mod tracked_leaderboard_user;
+mod tracked_map;
+mod tracked_mapper;
+mod tracked_match;
 mod tracked_user;
 mod user;
@@ -0,0 +1,125 @@
+use rosu_v2::prelude::{
+    GameMode, GameModsIntermode, Grade, Score, ScoreStatistics, UserAttributes,
+};
+use time::OffsetDateTime;
+
+pub struct DbScore {
+    pub score_id: i64,
+    pub user_id: i32,
+    pub map_id: i32,
+    pub gamemode: i16,
+    pub mods: i32,
+    pub score: i64,
+    pub classic_score: i64,
+    pub maxcombo: i32,
+    pub grade: i16,
+    pub accuracy: f32,
+    pub countgeki: i32,
+    pub count300: i32,
+    pub countkatu: i32,
+    pub count100: i32,
+    pub count50: i32,
+    pub countmiss: i32,
+    pub passed: bool,
+    pub set_on_lazer: bool,
+    pub ended_at: OffsetDateTime,
+    pub pp: Option<f64>,
+}
+
+impl DbScore {
+    fn statistics(&self, mode: GameMode) -> ScoreStatistics {
+        let great = self.count300 as u32;
+        let miss = self.countmiss as u32;
+
+        match mode {
+            GameMode::Osu => ScoreStatistics {
+                great,
+                ok: self.count100 as u32,
+                meh: self.count50 as u32,
+                miss,
+                ..Default::default()
+            },
+            GameMode::Taiko => ScoreStatistics {
+                great,
+                ok: self.count100 as u32,
+                miss,
+                ..Default::default()
+            },
+            GameMode::Catch => ScoreStatistics {
+                great,
+                large_tick_hit: self.count100 as u32,
+                small_tick_hit: self.count50 as u32,
+                small_tick_miss: self.countkatu as u32,
+                miss,
+                ..Default::default()
+            },
+            GameMode::Mania => ScoreStatistics {
+                perfect: self.countgeki as u32,
+                great,
+                good: self.countkatu as u32,
+                ok: self.count100 as u32,
+                meh: self.count50 as u32,
+                miss,
+                ..Default::default()
+            },
+        }
+    }
+}
+
+impl From<DbScore> for Score {
+    fn from(score: DbScore) -> Self {
+        let mode = GameMode::from(score.gamemode as u8);
+        let statistics = score.statistics(mode);
+
+        let grade = match score.grade {
+            1 => Grade::D,
+            2 => Grade::C,
+            3 => Grade::B,
+            4 => Grade::A,
+            5 => Grade::S,
+            6 => Grade::SH,
+            7 => Grade::X,
+            8 => Grade::XH,
+            _ => Grade::F,
+        };
+
+        Self {
+            set_on_lazer: score.set_on_lazer,
+            classic_score: score.classic_score as u64,
+            ranked: None,
+            preserve: None,
+            processed: None,
+            maximum_statistics: ScoreStatistics::default(),
+            mods: GameModsIntermode::from_bits(score.mods as u32).with_mode(mode),
+            statistics,
+            map_id: score.map_id as u32,
+            best_id: None,
+            id: score.score_id as u64,
+            grade,
+            kind: Box::default(),
+            user_id: score.user_id as u32,
+            accuracy: score.accuracy,
+            build_id: None,
+            ended_at: score.ended_at,
+            has_replay: false,
+            is_perfect_combo: false,
+            legacy_perfect: None,
+            legacy_score_id: None,
+            legacy_score: 0,
+            max_combo: score.maxcombo as u32,
+            passed: score.passed,
+            pp: score.pp.map(|pp| pp as f32),
+            mode,
+            started_at: None,
+            score: score.score as u32,
+            replay: false,
+            current_user_attributes: UserAttributes { pin: None },
+            total_score_without_mods: None,
+            map: None,
+            mapset: None,
+            rank_global: None,
+            user: None,
+            weight: None,
+        }
+    }
+}
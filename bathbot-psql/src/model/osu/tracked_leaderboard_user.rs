@@ -0,0 +1,6 @@
+pub struct DbTrackedOsuLeaderboardUser {
+    pub user_id: i32,
+    pub gamemode: i16,
+    pub channel_id: i64,
+    pub max_rank: i16,
+}
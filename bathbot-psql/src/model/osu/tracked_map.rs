@@ -0,0 +1,23 @@
+use time::OffsetDateTime;
+
+pub struct DbTrackedOsuMap {
+    pub map_id: i32,
+    pub gamemode: i16,
+    pub channel_id: i64,
+    pub guild_id: Option<i64>,
+    pub max_rank: i16,
+}
+
+pub struct DbMapLeaderboardSnapshot {
+    pub snapshot_id: i32,
+    pub taken_at: OffsetDateTime,
+}
+
+pub struct DbMapLeaderboardScore {
+    pub pos: i16,
+    pub user_id: i32,
+    pub username: String,
+    pub score_id: i64,
+    pub score: i64,
+    pub pp: Option<f32>,
+}
@@ -0,0 +1,13 @@
+pub struct DbTrackedOsuMapper {
+    pub mapper_id: i32,
+    pub channel_id: i64,
+    pub guild_id: Option<i64>,
+    /// Discord user that followed the mapper
+    pub added_by: i64,
+}
+
+/// Rank status of a followed mapper's mapset as of the last check
+pub struct DbTrackedMapperMapset {
+    pub mapset_id: i32,
+    pub rank_status: i16,
+}
@@ -0,0 +1,8 @@
+pub struct DbTrackedOsuMatch {
+    pub match_id: i32,
+    pub channel_id: i64,
+    /// Last message in the channel
+    pub msg_id: i64,
+    /// Amount of embeds that were sent up to and including the last message
+    pub embed_count: i32,
+}
@@ -10,6 +10,12 @@ pub struct DbTrackedOsuUser {
     pub max_pp: Option<f32>,
     pub min_combo_percent: Option<f32>,
     pub max_combo_percent: Option<f32>,
+    pub mods_include: Option<i32>,
+    pub mods_exclude: Option<i32>,
+    pub min_stars: Option<f32>,
+    pub max_stars: Option<f32>,
+    pub mapper_id: Option<i32>,
+    pub map_status: Option<i16>,
     pub last_pp: f32,
     pub last_updated: OffsetDateTime,
 }
@@ -23,4 +29,10 @@ pub struct DbTrackedOsuUserInChannel {
     pub max_pp: Option<f32>,
     pub min_combo_percent: Option<f32>,
     pub max_combo_percent: Option<f32>,
+    pub mods_include: Option<i32>,
+    pub mods_exclude: Option<i32>,
+    pub min_stars: Option<f32>,
+    pub max_stars: Option<f32>,
+    pub mapper_id: Option<i32>,
+    pub map_status: Option<i16>,
 }
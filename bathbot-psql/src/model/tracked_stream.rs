@@ -0,0 +1,17 @@
+pub struct DbTrackedTwitchStream {
+    pub channel_id: i64,
+    pub user_id: i64,
+    /// Only notify if the stream's category is osu!
+    pub osu_only: bool,
+    /// Only notify if the stream's title contains this keyword
+    pub keyword: Option<String>,
+    /// Message content with placeholders
+    pub template: Option<String>,
+    /// Role to mention in the notification
+    pub role_id: Option<i64>,
+    /// Whether the notification should be edited to the VOD once the stream
+    /// ends
+    pub vod_edit: bool,
+    /// Notification of the currently ongoing stream
+    pub notif_msg_id: Option<i64>,
+}
@@ -2,12 +2,17 @@
 extern crate tracing;
 
 mod middleware;
+mod provider;
 mod routes;
 mod server;
 mod standby;
 mod state;
 
 pub use self::{
+    provider::{
+        AccuracyPp, ApiError, ApiFuture, ApiKey, ApiProvider, BgScore, GameScores, HlHighscore,
+        LeaderboardEntry, LeaderboardQuery, LeaderboardValue, MapPp, PpQuery, TrackedUser,
+    },
     server::Server,
     standby::{AuthenticationStandby, AuthenticationStandbyError},
     state::AppStateBuilder,
@@ -0,0 +1,123 @@
+use std::{
+    sync::Arc,
+    time::{Duration, Instant},
+};
+
+use axum::{
+    Json,
+    body::Body,
+    extract::State,
+    http::{
+        HeaderValue, StatusCode,
+        header::{AUTHORIZATION, RETRY_AFTER},
+    },
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+use bathbot_util::IntHasher;
+use flexmap::std::StdMutexMap;
+use hyper::Request;
+
+use crate::{
+    provider::{ApiKey, ErrorBody},
+    state::AppState,
+};
+
+/// Duration after which the request count of a key resets
+const WINDOW: Duration = Duration::from_secs(60);
+
+/// Requires a valid API key through the `Authorization: Bearer <key>` header
+/// and enforces the key's rate limit.
+pub async fn check_api_key(
+    State(state): State<Arc<AppState>>,
+    req: Request<Body>,
+    next: Next,
+) -> Response {
+    let key = req
+        .headers()
+        .get(AUTHORIZATION)
+        .and_then(|header| header.to_str().ok())
+        .and_then(|header| header.strip_prefix("Bearer "));
+
+    let Some(key) = key else {
+        return error_response(StatusCode::UNAUTHORIZED, "Missing API key");
+    };
+
+    let api_key = match state.api.api_key(key.trim()).await {
+        Ok(Some(api_key)) => api_key,
+        Ok(None) => return error_response(StatusCode::UNAUTHORIZED, "Invalid API key"),
+        Err(err) => return err.into_response(),
+    };
+
+    if let Err(retry_after) = state.api_ratelimiter.check(&api_key) {
+        debug!(name = api_key.name.as_ref(), "API key hit rate limit");
+
+        let mut res = error_response(StatusCode::TOO_MANY_REQUESTS, "Rate limit exceeded");
+
+        // Rounding up so clients don't retry too early
+        let secs = retry_after.as_secs() + 1;
+        res.headers_mut()
+            .insert(RETRY_AFTER, HeaderValue::from(secs));
+
+        return res;
+    }
+
+    next.run(req).await
+}
+
+fn error_response(status: StatusCode, msg: &str) -> Response {
+    let body = ErrorBody {
+        error: msg.to_owned(),
+    };
+
+    (status, Json(body)).into_response()
+}
+
+/// Fixed-window rate limiter per API key
+pub struct ApiRatelimiter {
+    windows: StdMutexMap<u32, RatelimitWindow, IntHasher>,
+}
+
+#[derive(Default)]
+struct RatelimitWindow {
+    start: Option<Instant>,
+    count: u32,
+}
+
+impl Default for ApiRatelimiter {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            windows: StdMutexMap::with_shard_amount_and_hasher(4, IntHasher),
+        }
+    }
+}
+
+impl ApiRatelimiter {
+    /// Count a request for the key.
+    ///
+    /// Returns the remaining duration of the window if the key exceeded
+    /// its limit.
+    fn check(&self, key: &ApiKey) -> Result<(), Duration> {
+        let now = Instant::now();
+        let mut guard = self.windows.own(key.key_id);
+        let window = guard.entry().or_default();
+
+        let start = match window.start {
+            Some(start) if now.duration_since(start) < WINDOW => start,
+            _ => {
+                window.count = 0;
+
+                *window.start.insert(now)
+            }
+        };
+
+        if window.count >= key.requests_per_minute {
+            return Err(WINDOW.saturating_sub(now.duration_since(start)));
+        }
+
+        window.count += 1;
+
+        Ok(())
+    }
+}
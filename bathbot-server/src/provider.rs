@@ -0,0 +1,222 @@
+use std::{future::Future, pin::Pin};
+
+use axum::{
+    Json,
+    extract::rejection::{PathRejection, QueryRejection},
+    http::StatusCode,
+    response::{IntoResponse, Response},
+};
+use eyre::Report;
+use rosu_v2::prelude::GameMode;
+use serde::{Deserialize, Serialize};
+
+pub type ApiFuture<'a, T> = Pin<Box<dyn Future<Output = Result<T, ApiError>> + Send + 'a>>;
+
+/// Provides the data for the `/api/v1` routes.
+///
+/// The server itself has no access to the database or the bot's caches so
+/// the bot implements this trait and hands it to the server on startup.
+pub trait ApiProvider: Send + Sync {
+    /// Look up the given key. Returns `None` if the key is unknown.
+    fn api_key<'a>(&'a self, key: &'a str) -> ApiFuture<'a, Option<ApiKey>>;
+
+    /// Leaderboard of all linked members of a guild.
+    fn guild_leaderboard(
+        &self,
+        guild_id: u64,
+        query: LeaderboardQuery,
+    ) -> ApiFuture<'_, Vec<LeaderboardEntry>>;
+
+    /// Scores of a discord user in the bg and higherlower games.
+    fn game_scores(&self, user_id: u64) -> ApiFuture<'_, GameScores>;
+
+    /// All osu! users that are tracked in a channel.
+    fn tracked_users(&self, channel_id: u64) -> ApiFuture<'_, Vec<TrackedUser>>;
+
+    /// Star rating and pp values of a map.
+    fn map_pp(&self, map_id: u32, query: PpQuery) -> ApiFuture<'_, MapPp>;
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum ApiError {
+    #[error("bad request: {0}")]
+    BadRequest(String),
+    #[error("not found: {0}")]
+    NotFound(&'static str),
+    /// The bot has not finished starting up yet
+    #[error("unavailable")]
+    Unavailable,
+    #[error("internal error")]
+    Internal(#[source] Report),
+}
+
+impl IntoResponse for ApiError {
+    fn into_response(self) -> Response {
+        let (status, error) = match self {
+            Self::BadRequest(msg) => (StatusCode::BAD_REQUEST, msg),
+            Self::NotFound(msg) => (StatusCode::NOT_FOUND, msg.to_owned()),
+            Self::Unavailable => {
+                let msg = "Not ready yet, try again later".to_owned();
+
+                (StatusCode::SERVICE_UNAVAILABLE, msg)
+            }
+            Self::Internal(err) => {
+                error!(?err, "API request failed");
+
+                let msg = "Internal server error".to_owned();
+
+                (StatusCode::INTERNAL_SERVER_ERROR, msg)
+            }
+        };
+
+        (status, Json(ErrorBody { error })).into_response()
+    }
+}
+
+impl From<Report> for ApiError {
+    fn from(err: Report) -> Self {
+        Self::Internal(err)
+    }
+}
+
+impl From<PathRejection> for ApiError {
+    fn from(rejection: PathRejection) -> Self {
+        Self::BadRequest(rejection.body_text())
+    }
+}
+
+impl From<QueryRejection> for ApiError {
+    fn from(rejection: QueryRejection) -> Self {
+        Self::BadRequest(rejection.body_text())
+    }
+}
+
+#[derive(Serialize)]
+pub(crate) struct ErrorBody {
+    pub error: String,
+}
+
+#[derive(Clone)]
+pub struct ApiKey {
+    pub key_id: u32,
+    pub name: Box<str>,
+    pub requests_per_minute: u32,
+}
+
+#[derive(Deserialize)]
+pub struct LeaderboardQuery {
+    /// Column of the leaderboard e.g. `pp` or `medals`
+    pub kind: String,
+    /// If not specified, only stats across all modes are available
+    #[serde(default, deserialize_with = "deserialize_mode")]
+    pub mode: Option<GameMode>,
+    /// Two-letter country code
+    pub country: Option<String>,
+}
+
+#[derive(Serialize)]
+pub struct LeaderboardEntry {
+    pub username: String,
+    pub country_code: String,
+    pub value: LeaderboardValue,
+}
+
+#[derive(Serialize)]
+#[serde(untagged)]
+pub enum LeaderboardValue {
+    Int(i64),
+    Float(f32),
+}
+
+#[derive(Serialize)]
+pub struct GameScores {
+    pub bg: Vec<BgScore>,
+    pub higherlower: Vec<HlHighscore>,
+}
+
+#[derive(Serialize)]
+pub struct BgScore {
+    pub kind: &'static str,
+    pub score: u32,
+}
+
+#[derive(Serialize)]
+pub struct HlHighscore {
+    pub version: &'static str,
+    pub highscore: u32,
+}
+
+#[derive(Serialize)]
+pub struct TrackedUser {
+    pub user_id: u32,
+    pub username: Option<String>,
+    #[serde(serialize_with = "serialize_mode")]
+    pub mode: GameMode,
+    pub min_index: Option<u8>,
+    pub max_index: Option<u8>,
+    pub min_pp: Option<f32>,
+    pub max_pp: Option<f32>,
+    pub min_combo_percent: Option<f32>,
+    pub max_combo_percent: Option<f32>,
+}
+
+#[derive(Deserialize)]
+pub struct PpQuery {
+    /// Mod acronyms e.g. `HDDT`
+    pub mods: Option<String>,
+    /// Convert the map to this mode
+    #[serde(default, deserialize_with = "deserialize_mode")]
+    pub mode: Option<GameMode>,
+    /// Only calculate pp for this accuracy instead of the defaults
+    pub acc: Option<f64>,
+}
+
+#[derive(Serialize)]
+pub struct MapPp {
+    pub map_id: u32,
+    #[serde(serialize_with = "serialize_mode")]
+    pub mode: GameMode,
+    pub mods: String,
+    pub stars: f64,
+    pub max_pp: f64,
+    pub pp: Vec<AccuracyPp>,
+}
+
+#[derive(Serialize)]
+pub struct AccuracyPp {
+    pub accuracy: f64,
+    pub pp: f64,
+}
+
+fn mode_str(mode: GameMode) -> &'static str {
+    match mode {
+        GameMode::Osu => "osu",
+        GameMode::Taiko => "taiko",
+        GameMode::Catch => "fruits",
+        GameMode::Mania => "mania",
+    }
+}
+
+fn serialize_mode<S: serde::Serializer>(mode: &GameMode, s: S) -> Result<S::Ok, S::Error> {
+    s.serialize_str(mode_str(*mode))
+}
+
+fn deserialize_mode<'de, D: serde::Deserializer<'de>>(d: D) -> Result<Option<GameMode>, D::Error> {
+    let Some(mode) = Option::<String>::deserialize(d)? else {
+        return Ok(None);
+    };
+
+    let mode = match mode.as_str() {
+        "osu" | "0" => GameMode::Osu,
+        "taiko" | "1" => GameMode::Taiko,
+        "fruits" | "catch" | "ctb" | "2" => GameMode::Catch,
+        "mania" | "3" => GameMode::Mania,
+        _ => {
+            return Err(serde::de::Error::custom(
+                "expected one of `osu`, `taiko`, `fruits`, or `mania`",
+            ));
+        }
+    };
+
+    Ok(Some(mode))
+}
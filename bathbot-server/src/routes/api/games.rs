@@ -0,0 +1,20 @@
+use std::sync::Arc;
+
+use axum::{
+    Json,
+    extract::{Path, State, rejection::PathRejection},
+};
+
+use crate::{
+    provider::{ApiError, GameScores},
+    state::AppState,
+};
+
+pub async fn get_game_scores(
+    State(state): State<Arc<AppState>>,
+    path: Result<Path<u64>, PathRejection>,
+) -> Result<Json<GameScores>, ApiError> {
+    let Path(user_id) = path?;
+
+    state.api.game_scores(user_id).await.map(Json)
+}
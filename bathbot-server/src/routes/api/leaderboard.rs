@@ -0,0 +1,25 @@
+use std::sync::Arc;
+
+use axum::{
+    Json,
+    extract::{
+        Path, Query, State,
+        rejection::{PathRejection, QueryRejection},
+    },
+};
+
+use crate::{
+    provider::{ApiError, LeaderboardEntry, LeaderboardQuery},
+    state::AppState,
+};
+
+pub async fn get_guild_leaderboard(
+    State(state): State<Arc<AppState>>,
+    path: Result<Path<u64>, PathRejection>,
+    query: Result<Query<LeaderboardQuery>, QueryRejection>,
+) -> Result<Json<Vec<LeaderboardEntry>>, ApiError> {
+    let Path(guild_id) = path?;
+    let Query(query) = query?;
+
+    state.api.guild_leaderboard(guild_id, query).await.map(Json)
+}
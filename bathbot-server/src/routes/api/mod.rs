@@ -0,0 +1,5 @@
+pub mod games;
+pub mod leaderboard;
+pub mod openapi;
+pub mod pp;
+pub mod tracked;
@@ -0,0 +1,8 @@
+use axum::http::{HeaderName, header::CONTENT_TYPE};
+
+/// OpenAPI description of the `/api/v1` routes
+const OPENAPI: &str = include_str!("openapi.json");
+
+pub async fn get_openapi() -> ([(HeaderName, &'static str); 1], &'static str) {
+    ([(CONTENT_TYPE, "application/json")], OPENAPI)
+}
@@ -0,0 +1,31 @@
+use std::sync::Arc;
+
+use axum::{
+    Json,
+    extract::{
+        Path, Query, State,
+        rejection::{PathRejection, QueryRejection},
+    },
+};
+
+use crate::{
+    provider::{ApiError, MapPp, PpQuery},
+    state::AppState,
+};
+
+pub async fn get_map_pp(
+    State(state): State<Arc<AppState>>,
+    path: Result<Path<u32>, PathRejection>,
+    query: Result<Query<PpQuery>, QueryRejection>,
+) -> Result<Json<MapPp>, ApiError> {
+    let Path(map_id) = path?;
+    let Query(query) = query?;
+
+    if query.acc.is_some_and(|acc| !(0.0..=100.0).contains(&acc)) {
+        return Err(ApiError::BadRequest(
+            "Accuracy must be between 0 and 100".to_owned(),
+        ));
+    }
+
+    state.api.map_pp(map_id, query).await.map(Json)
+}
@@ -0,0 +1,20 @@
+use std::sync::Arc;
+
+use axum::{
+    Json,
+    extract::{Path, State, rejection::PathRejection},
+};
+
+use crate::{
+    provider::{ApiError, TrackedUser},
+    state::AppState,
+};
+
+pub async fn get_tracked_users(
+    State(state): State<Arc<AppState>>,
+    path: Result<Path<u64>, PathRejection>,
+) -> Result<Json<Vec<TrackedUser>>, ApiError> {
+    let Path(channel_id) = path?;
+
+    state.api.tracked_users(channel_id).await.map(Json)
+}
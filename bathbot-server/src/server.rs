@@ -18,8 +18,12 @@ use tracing::Span;
 
 use crate::{
     AppStateBuilder,
-    middleware::metrics::track_metrics,
+    middleware::{api_key::check_api_key, metrics::track_metrics},
     routes::{
+        api::{
+            games::get_game_scores, leaderboard::get_guild_leaderboard, openapi::get_openapi,
+            pp::get_map_pp, tracked::get_tracked_users,
+        },
         auth::{osu::auth_osu, twitch::auth_twitch},
         guild_count::get_guild_count,
         metrics::get_metrics,
@@ -95,6 +99,7 @@ impl Server {
             .route("/metrics", get(get_metrics))
             .route("/guild_count", get(get_guild_count))
             .nest("/auth", Self::auth_app(website_path))
+            .nest("/api/v1", Self::api_app(Arc::clone(&state)))
             .route("/osudirect/{mapset_id}", get(redirect_osudirect))
             .layer(CorsLayer::permissive())
             .layer(middleware::from_fn_with_state(state, track_metrics))
@@ -118,4 +123,17 @@ impl Server {
                 ),
             )
     }
+
+    fn api_app(state: Arc<AppState>) -> Router<Arc<AppState>> {
+        let authorized = Router::new()
+            .route("/guilds/{guild_id}/leaderboard", get(get_guild_leaderboard))
+            .route("/users/{user_id}/games", get(get_game_scores))
+            .route("/channels/{channel_id}/tracked", get(get_tracked_users))
+            .route("/maps/{map_id}/pp", get(get_map_pp))
+            .layer(middleware::from_fn_with_state(state, check_api_key));
+
+        Router::new()
+            .route("/openapi.json", get(get_openapi))
+            .merge(authorized)
+    }
 }
@@ -13,7 +13,9 @@ use hyper_util::{
 use metrics::describe_histogram;
 use metrics_exporter_prometheus::PrometheusHandle;
 
-use crate::standby::AuthenticationStandby;
+use crate::{
+    middleware::api_key::ApiRatelimiter, provider::ApiProvider, standby::AuthenticationStandby,
+};
 
 pub struct AppState {
     pub client: HyperClient<HttpsConnector<HttpConnector>, Empty<Bytes>>,
@@ -26,6 +28,8 @@ pub struct AppState {
     pub twitch_token: Box<str>,
     pub redirect_base: Box<str>,
     pub standby: Arc<AuthenticationStandby>,
+    pub api: Arc<dyn ApiProvider>,
+    pub api_ratelimiter: ApiRatelimiter,
 }
 
 pub struct AppStateBuilder {
@@ -37,6 +41,7 @@ pub struct AppStateBuilder {
     pub twitch_client_id: String,
     pub twitch_token: String,
     pub redirect_base: String,
+    pub api: Arc<dyn ApiProvider>,
 }
 
 impl AppStateBuilder {
@@ -50,6 +55,7 @@ impl AppStateBuilder {
             twitch_client_id,
             twitch_token,
             redirect_base,
+            api,
         } = self;
 
         let crypto_provider = rustls::crypto::ring::default_provider();
@@ -91,6 +97,8 @@ impl AppStateBuilder {
             twitch_token: twitch_token.into_boxed_str(),
             redirect_base: redirect_base.into_boxed_str(),
             standby,
+            api,
+            api_ratelimiter: ApiRatelimiter::default(),
         };
 
         Ok((state, website_path))
@@ -0,0 +1,91 @@
+//! Writing of osu!stable `collection.db` files.
+//!
+//! See <https://github.com/ppy/osu/wiki/Legacy-database-file-structure#collectiondb>
+
+/// Client version that is written into the header. osu! does not validate it.
+const VERSION: i32 = 20150203;
+
+pub struct Collection<'a> {
+    pub name: &'a str,
+    /// MD5 hashes of the maps
+    pub checksums: Vec<&'a str>,
+}
+
+/// Serialize the collections in the `collection.db` format.
+pub fn write_collection_db(collections: &[Collection<'_>]) -> Vec<u8> {
+    let mut bytes = Vec::with_capacity(8 + collections.len() * 64);
+
+    bytes.extend_from_slice(&VERSION.to_le_bytes());
+    bytes.extend_from_slice(&(collections.len() as i32).to_le_bytes());
+
+    for collection in collections {
+        write_string(&mut bytes, collection.name);
+        bytes.extend_from_slice(&(collection.checksums.len() as i32).to_le_bytes());
+
+        for checksum in collection.checksums.iter() {
+            write_string(&mut bytes, checksum);
+        }
+    }
+
+    bytes
+}
+
+fn write_string(bytes: &mut Vec<u8>, s: &str) {
+    if s.is_empty() {
+        bytes.push(0x00);
+
+        return;
+    }
+
+    bytes.push(0x0B);
+    write_uleb128(bytes, s.len());
+    bytes.extend_from_slice(s.as_bytes());
+}
+
+fn write_uleb128(bytes: &mut Vec<u8>, mut value: usize) {
+    loop {
+        let byte = (value & 0x7F) as u8;
+        value >>= 7;
+
+        if value == 0 {
+            bytes.push(byte);
+
+            return;
+        }
+
+        bytes.push(byte | 0x80);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_uleb128() {
+        let mut bytes = Vec::new();
+        write_uleb128(&mut bytes, 5);
+        write_uleb128(&mut bytes, 300);
+
+        assert_eq!(bytes, [0x05, 0xAC, 0x02]);
+    }
+
+    #[test]
+    fn test_collection_db() {
+        let collections = [Collection {
+            name: "farm",
+            checksums: vec!["abc", ""],
+        }];
+
+        let bytes = write_collection_db(&collections);
+
+        let mut expected = Vec::new();
+        expected.extend_from_slice(&VERSION.to_le_bytes());
+        expected.extend_from_slice(&1_i32.to_le_bytes());
+        expected.extend_from_slice(&[0x0B, 0x04, b'f', b'a', b'r', b'm']);
+        expected.extend_from_slice(&2_i32.to_le_bytes());
+        expected.extend_from_slice(&[0x0B, 0x03, b'a', b'b', b'c', 0x00]);
+
+        assert_eq!(bytes, expected);
+    }
+}
@@ -11,11 +11,13 @@ mod mods_fmt;
 mod msg_origin;
 mod tourney_badges;
 
+pub mod collection_db;
 pub mod constants;
 pub mod datetime;
 pub mod html;
 pub mod matcher;
 pub mod numbers;
+pub mod osr;
 pub mod osu;
 pub mod query;
 pub mod string_cmp;
@@ -247,6 +247,4 @@ define_regex! {
     OSU_SCORE_URL_MATCHER: r"https://osu\.ppy\.sh/scores/(?:(osu|taiko|mania|fruits)/)?(\d+)";
 
     APPROVED_SKIN_SITE: r"^https://(?:(?:www\.)?(?:drive\.google\.com|dropbox\.com|mega\.nz|mediafire\.com|(?:gist\.)?github\.com)/.*$|(?:skins\.osuck\.net/skins|osu\.ppy\.sh/community/forums/topics)/\d+.*|link.issou.best/skin/\d+$|skins\.osuck\.net/(?:authors|users)/\d+)";
-
-    pub QUERY_SYNTAX_REGEX: r#"\b(?P<key>\w+)(?P<op>(:|=|(>|<)(:|=)?))(?P<value>(".*")|(\S*))"#;
 }
@@ -0,0 +1,430 @@
+//! Parsing of `.osr` replay files.
+//!
+//! See <https://osu.ppy.sh/wiki/en/Client/File_formats/osr_%28file_format%29>
+
+use std::io::{Cursor, Error as IoError, Result as IoResult, Write};
+
+use eyre::{ContextCompat, Result, WrapErr, bail};
+use rosu_v2::prelude::{GameMode, GameModsIntermode};
+use time::OffsetDateTime;
+
+/// Windows ticks at the unix epoch
+const UNIX_EPOCH_TICKS: i64 = 621_355_968_000_000_000;
+
+/// Time value of the frame that contains the RNG seed instead of input
+const SEED_FRAME_TIME: i64 = -12345;
+
+/// Maximum size of decompressed frame data that will be processed.
+///
+/// Regular replays stay well below 1MB even for long maps.
+const MAX_FRAMES_LEN: usize = 16 * 1024 * 1024;
+
+/// Maximum memory the LZMA decoder may use for its dictionary
+const LZMA_MEMLIMIT: usize = 16 * 1024 * 1024;
+
+pub struct Replay {
+    pub mode: GameMode,
+    pub version: i32,
+    pub map_md5: Box<str>,
+    pub username: Box<str>,
+    pub replay_md5: Box<str>,
+    pub count_300: u16,
+    pub count_100: u16,
+    pub count_50: u16,
+    pub count_geki: u16,
+    pub count_katu: u16,
+    pub count_miss: u16,
+    pub score: u32,
+    pub max_combo: u16,
+    pub perfect: bool,
+    pub mods: GameModsIntermode,
+    pub life_bar: Vec<LifeBarPoint>,
+    pub timestamp: OffsetDateTime,
+    pub frames: Vec<ReplayFrame>,
+    /// RNG seed used for mania's random mod, if present
+    pub seed: Option<i32>,
+    pub score_id: u64,
+}
+
+impl Replay {
+    pub fn parse(bytes: &[u8]) -> Result<Self> {
+        let mut reader = Reader::new(bytes);
+
+        let mode = match reader.u8()? {
+            mode @ 0..=3 => GameMode::from(mode),
+            mode => bail!("Invalid mode {mode}"),
+        };
+
+        let version = reader.i32()?;
+        let map_md5 = reader.string().wrap_err("Failed to read map md5")?;
+        let username = reader.string().wrap_err("Failed to read username")?;
+        let replay_md5 = reader.string().wrap_err("Failed to read replay md5")?;
+        let count_300 = reader.u16()?;
+        let count_100 = reader.u16()?;
+        let count_50 = reader.u16()?;
+        let count_geki = reader.u16()?;
+        let count_katu = reader.u16()?;
+        let count_miss = reader.u16()?;
+        let score = reader.i32()? as u32;
+        let max_combo = reader.u16()?;
+        let perfect = reader.u8()? == 1;
+        let mods = GameModsIntermode::from_bits(reader.i32()? as u32);
+        let life_bar = reader.string().wrap_err("Failed to read life bar")?;
+        let ticks = reader.i64()?;
+
+        let compressed_len = reader.i32()?;
+
+        let compressed = usize::try_from(compressed_len)
+            .ok()
+            .and_then(|len| reader.take(len))
+            .wrap_err("Invalid length of compressed replay data")?;
+
+        // Older replays may end right after the compressed data
+        let score_id = reader.i64().map_or(0, |id| id as u64);
+
+        let timestamp = ticks
+            .checked_sub(UNIX_EPOCH_TICKS)
+            .and_then(|ticks| OffsetDateTime::from_unix_timestamp_nanos(ticks as i128 * 100).ok())
+            .wrap_err("Invalid timestamp")?;
+
+        let (frames, seed) = parse_frames(compressed).wrap_err("Failed to parse frames")?;
+
+        Ok(Self {
+            mode,
+            version,
+            map_md5,
+            username,
+            replay_md5,
+            count_300,
+            count_100,
+            count_50,
+            count_geki,
+            count_katu,
+            count_miss,
+            score,
+            max_combo,
+            perfect,
+            mods,
+            life_bar: parse_life_bar(&life_bar),
+            timestamp,
+            frames,
+            seed,
+            score_id,
+        })
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct LifeBarPoint {
+    /// Milliseconds into the song
+    pub time: i32,
+    /// Health between 0.0 and 1.0
+    pub health: f32,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct ReplayFrame {
+    /// Milliseconds into the song
+    pub time: i64,
+    /// Milliseconds since the previous frame
+    pub delta: i64,
+    pub x: f32,
+    pub y: f32,
+    pub keys: ReplayKeys,
+}
+
+/// Pressed buttons of a frame.
+///
+/// For osu!mania the bits denote the pressed columns instead.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+pub struct ReplayKeys(u32);
+
+impl ReplayKeys {
+    pub const M1: u32 = 1 << 0;
+    pub const M2: u32 = 1 << 1;
+    pub const K1: u32 = 1 << 2;
+    pub const K2: u32 = 1 << 3;
+    pub const SMOKE: u32 = 1 << 4;
+
+    pub const fn bits(self) -> u32 {
+        self.0
+    }
+
+    pub const fn contains(self, key: u32) -> bool {
+        self.0 & key == key
+    }
+
+    /// Whether any button that can hit an object is pressed
+    pub const fn any_hit(self) -> bool {
+        self.0 & (Self::M1 | Self::M2 | Self::K1 | Self::K2) != 0
+    }
+}
+
+fn parse_life_bar(life_bar: &str) -> Vec<LifeBarPoint> {
+    life_bar
+        .split(',')
+        .filter_map(|point| {
+            let (time, health) = point.split_once('|')?;
+
+            Some(LifeBarPoint {
+                time: time.parse().ok()?,
+                health: health.parse().ok()?,
+            })
+        })
+        .collect()
+}
+
+fn parse_frames(compressed: &[u8]) -> Result<(Vec<ReplayFrame>, Option<i32>)> {
+    if compressed.is_empty() {
+        return Ok((Vec::new(), None));
+    }
+
+    // The LZMA header consists of a properties byte, four bytes for the
+    // dictionary size, and eight bytes for the unpacked size which is all
+    // ones if the size is unknown.
+    if let Some(unpacked_size) = compressed
+        .get(5..13)
+        .and_then(|bytes| bytes.try_into().ok())
+        .map(u64::from_le_bytes)
+        .filter(|&size| size != u64::MAX && size > MAX_FRAMES_LEN as u64)
+    {
+        bail!("Frame data too large ({unpacked_size} bytes)");
+    }
+
+    let options = lzma_rs::decompress::Options {
+        memlimit: Some(LZMA_MEMLIMIT),
+        ..Default::default()
+    };
+
+    // The header may lie about the size so the output is limited too
+    let mut writer = LimitedWriter::new(MAX_FRAMES_LEN);
+
+    lzma_rs::lzma_decompress_with_options(&mut Cursor::new(compressed), &mut writer, &options)
+        .wrap_err("Failed to decompress")?;
+
+    let decompressed = String::from_utf8(writer.bytes).wrap_err("Frames are not valid UTF-8")?;
+
+    parse_frames_str(&decompressed)
+}
+
+fn parse_frames_str(frames_str: &str) -> Result<(Vec<ReplayFrame>, Option<i32>)> {
+    let mut frames = Vec::with_capacity(frames_str.len() / 16);
+    let mut seed = None;
+    let mut time: i64 = 0;
+
+    for frame in frames_str.split(',').filter(|frame| !frame.is_empty()) {
+        let mut split = frame.split('|');
+
+        let mut next = || split.next().wrap_err("Missing frame value");
+
+        let delta: i64 = next()?.parse().wrap_err("Invalid frame time")?;
+        let x: f32 = next()?.parse().wrap_err("Invalid frame x")?;
+        let y: f32 = next()?.parse().wrap_err("Invalid frame y")?;
+        let keys = next()?;
+
+        if delta == SEED_FRAME_TIME {
+            seed = keys.parse().ok();
+
+            continue;
+        }
+
+        let keys = keys.parse::<f32>().wrap_err("Invalid frame keys")? as u32;
+        time = time.checked_add(delta).wrap_err("Frame time overflow")?;
+
+        frames.push(ReplayFrame {
+            time,
+            delta,
+            x,
+            y,
+            keys: ReplayKeys(keys),
+        });
+    }
+
+    Ok((frames, seed))
+}
+
+/// Collects written bytes and fails once they exceed a limit.
+struct LimitedWriter {
+    bytes: Vec<u8>,
+    limit: usize,
+}
+
+impl LimitedWriter {
+    fn new(limit: usize) -> Self {
+        Self {
+            bytes: Vec::new(),
+            limit,
+        }
+    }
+}
+
+impl Write for LimitedWriter {
+    fn write(&mut self, buf: &[u8]) -> IoResult<usize> {
+        if self.bytes.len() + buf.len() > self.limit {
+            return Err(IoError::other("Frame data too large"));
+        }
+
+        self.bytes.extend_from_slice(buf);
+
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> IoResult<()> {
+        Ok(())
+    }
+}
+
+struct Reader<'a> {
+    bytes: &'a [u8],
+}
+
+impl<'a> Reader<'a> {
+    fn new(bytes: &'a [u8]) -> Self {
+        Self { bytes }
+    }
+
+    fn take(&mut self, len: usize) -> Option<&'a [u8]> {
+        if self.bytes.len() < len {
+            return None;
+        }
+
+        let (taken, rest) = self.bytes.split_at(len);
+        self.bytes = rest;
+
+        Some(taken)
+    }
+
+    fn array<const N: usize>(&mut self) -> Result<[u8; N]> {
+        self.take(N)
+            .and_then(|bytes| bytes.try_into().ok())
+            .wrap_err("Unexpected end of replay")
+    }
+
+    fn u8(&mut self) -> Result<u8> {
+        self.array().map(u8::from_le_bytes)
+    }
+
+    fn u16(&mut self) -> Result<u16> {
+        self.array().map(u16::from_le_bytes)
+    }
+
+    fn i32(&mut self) -> Result<i32> {
+        self.array().map(i32::from_le_bytes)
+    }
+
+    fn i64(&mut self) -> Result<i64> {
+        self.array().map(i64::from_le_bytes)
+    }
+
+    fn uleb128(&mut self) -> Result<usize> {
+        let mut value = 0;
+        let mut shift = 0;
+
+        loop {
+            let byte = self.u8()?;
+            value |= ((byte & 0x7F) as usize) << shift;
+
+            if byte & 0x80 == 0 {
+                return Ok(value);
+            }
+
+            shift += 7;
+
+            if shift >= usize::BITS {
+                bail!("ULEB128 value too large");
+            }
+        }
+    }
+
+    fn string(&mut self) -> Result<Box<str>> {
+        match self.u8()? {
+            0x00 => Ok(Box::default()),
+            0x0B => {
+                let len = self.uleb128()?;
+                let bytes = self.take(len).wrap_err("Unexpected end of replay")?;
+
+                std::str::from_utf8(bytes)
+                    .map(Box::from)
+                    .wrap_err("String is not valid UTF-8")
+            }
+            prefix => bail!("Invalid string prefix {prefix:#x}"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_frames() {
+        let (frames, seed) = parse_frames_str(
+            "0|256|-500|0,-1|256|-500|0,12|100.5|200|5,8|101|201|0,-12345|0|0|42,",
+        )
+        .unwrap();
+
+        assert_eq!(seed, Some(42));
+        assert_eq!(frames.len(), 4);
+        assert_eq!(frames[2].time, 11);
+        assert_eq!(frames[2].x, 100.5);
+        assert!(frames[2].keys.contains(ReplayKeys::K1));
+        assert_eq!(frames[3].time, 19);
+        assert!(!frames[3].keys.any_hit());
+    }
+
+    #[test]
+    fn test_frames_time_overflow() {
+        let frames = format!("{max}|0|0|0,1|0|0|0,", max = i64::MAX);
+
+        assert!(parse_frames_str(&frames).is_err());
+    }
+
+    #[test]
+    fn test_frames_too_large() {
+        let mut compressed = vec![0x5D, 0x00, 0x00, 0x10, 0x00];
+        compressed.extend_from_slice(&u64::MAX.to_le_bytes()[..7]);
+        compressed.push(0x01);
+        compressed.extend_from_slice(&[0; 8]);
+
+        let err = parse_frames(&compressed).unwrap_err();
+        assert!(err.to_string().contains("too large"));
+    }
+
+    #[test]
+    fn test_limited_writer() {
+        let mut writer = LimitedWriter::new(4);
+
+        assert!(writer.write_all(b"abc").is_ok());
+        assert!(writer.write_all(b"de").is_err());
+        assert_eq!(writer.bytes, b"abc");
+    }
+
+    #[test]
+    fn test_strings() {
+        let bytes = [0x0B, 0x03, b'a', b'b', b'c', 0x00];
+        let mut reader = Reader::new(&bytes);
+
+        assert_eq!(reader.string().unwrap().as_ref(), "abc");
+        assert_eq!(reader.string().unwrap().as_ref(), "");
+        assert!(reader.string().is_err());
+    }
+
+    #[test]
+    fn test_life_bar() {
+        let points = parse_life_bar("0|1,1500|0.75,");
+
+        assert_eq!(
+            points,
+            [
+                LifeBarPoint {
+                    time: 0,
+                    health: 1.0
+                },
+                LifeBarPoint {
+                    time: 1500,
+                    health: 0.75
+                },
+            ]
+        );
+    }
+}
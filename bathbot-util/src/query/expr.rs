@@ -0,0 +1,494 @@
+use std::{iter::Peekable, vec::IntoIter};
+
+use super::{FilterCriteria, IFilterCriteria, Searchable, operator::Operator, separate_content};
+use crate::CowUtils;
+
+/// Parsed filter query.
+///
+/// Terms are ANDed by default. Supported syntax:
+///   - `OR` (or `||`) between terms or groups
+///   - `AND` (or `&&`) to be explicit about a conjunction
+///   - `NOT` or a leading `-` to negate a term or group
+///   - `key!=value` as shorthand for `NOT key=value`
+///   - parentheses to group terms
+///
+/// Adjacent non-negated terms are merged into a single [`FilterCriteria`] so
+/// that queries without boolean logic evaluate just like a plain list of
+/// terms.
+pub enum FilterExpr<F> {
+    Criteria(FilterCriteria<F>),
+    Not(Box<FilterExpr<F>>),
+    And(Vec<FilterExpr<F>>),
+    Or(Vec<FilterExpr<F>>),
+}
+
+impl<F: Default> Default for FilterExpr<F> {
+    fn default() -> Self {
+        Self::Criteria(FilterCriteria::default())
+    }
+}
+
+impl<'q, F: IFilterCriteria<'q>> FilterExpr<F> {
+    pub(super) fn new(query: &'q str) -> Self {
+        let mut tokens = tokenize(query).into_iter().peekable();
+
+        parse_or(&mut tokens, 0)
+    }
+
+    pub fn display(&self, content: &mut String) {
+        if let Self::Criteria(criteria) = self {
+            criteria.display(content);
+        } else {
+            separate_content(content);
+            self.write_node(content, None);
+        }
+    }
+
+    fn write_node(&self, content: &mut String, parent: Option<Parent>) {
+        match self {
+            Self::Criteria(criteria) => {
+                let mut node = String::new();
+                criteria.display(&mut node);
+
+                if node.is_empty() {
+                    node.push_str("`<any>`");
+                }
+
+                let wrap = parent.is_some_and(|parent| parent != Parent::And);
+
+                if wrap && node.contains(" • ") {
+                    content.push('(');
+                    content.push_str(&node);
+                    content.push(')');
+                } else {
+                    content.push_str(&node);
+                }
+            }
+            Self::Not(node) => {
+                content.push_str("**NOT** ");
+                node.write_node(content, Some(Parent::Not));
+            }
+            Self::And(nodes) => {
+                let wrap = parent.is_some_and(|parent| parent != Parent::And);
+                Self::write_nodes(content, nodes, " • ", Parent::And, wrap);
+            }
+            Self::Or(nodes) => {
+                let wrap = parent.is_some_and(|parent| parent != Parent::Or);
+                Self::write_nodes(content, nodes, " **OR** ", Parent::Or, wrap);
+            }
+        }
+    }
+
+    fn write_nodes(
+        content: &mut String,
+        nodes: &[Self],
+        separator: &str,
+        kind: Parent,
+        wrap: bool,
+    ) {
+        if wrap {
+            content.push('(');
+        }
+
+        let mut iter = nodes.iter();
+
+        if let Some(node) = iter.next() {
+            node.write_node(content, Some(kind));
+
+            for node in iter {
+                content.push_str(separator);
+                node.write_node(content, Some(kind));
+            }
+        }
+
+        if wrap {
+            content.push(')');
+        }
+    }
+}
+
+impl<F> FilterExpr<F> {
+    /// Whether the item satisfies the expression.
+    pub fn matches<S>(&self, item: &S) -> bool
+    where
+        S: Searchable<F> + ?Sized,
+    {
+        self.evaluate(|criteria| item.matches(criteria))
+    }
+
+    /// Evaluate the expression by checking each [`FilterCriteria`] through
+    /// the given function.
+    pub fn evaluate(&self, mut f: impl FnMut(&FilterCriteria<F>) -> bool) -> bool {
+        self.eval(&mut f)
+    }
+
+    fn eval<M>(&self, f: &mut M) -> bool
+    where
+        M: FnMut(&FilterCriteria<F>) -> bool,
+    {
+        match self {
+            Self::Criteria(criteria) => f(criteria),
+            Self::Not(node) => !node.eval(f),
+            Self::And(nodes) => nodes.iter().all(|node| node.eval(f)),
+            Self::Or(nodes) => nodes.iter().any(|node| node.eval(f)),
+        }
+    }
+
+    /// Whether any [`FilterCriteria`] of the expression satisfies the given
+    /// function.
+    pub fn any_criteria(&self, f: impl Fn(&F) -> bool) -> bool {
+        fn any<F>(expr: &FilterExpr<F>, f: &impl Fn(&F) -> bool) -> bool {
+            match expr {
+                FilterExpr::Criteria(criteria) => f(&**criteria),
+                FilterExpr::Not(node) => any(node, f),
+                FilterExpr::And(nodes) | FilterExpr::Or(nodes) => {
+                    nodes.iter().any(|node| any(node, f))
+                }
+            }
+        }
+
+        any(self, &f)
+    }
+}
+
+#[derive(Copy, Clone, PartialEq, Eq)]
+enum Parent {
+    And,
+    Or,
+    Not,
+}
+
+enum Token<'q> {
+    Open,
+    Close,
+    And,
+    Or,
+    Not,
+    Term(&'q str),
+}
+
+fn tokenize(query: &str) -> Vec<Token<'_>> {
+    let mut tokens = Vec::new();
+    let mut chars = query.char_indices().peekable();
+
+    while let Some(&(start, c)) = chars.peek() {
+        match c {
+            '(' => {
+                chars.next();
+                tokens.push(Token::Open);
+            }
+            ')' => {
+                chars.next();
+                tokens.push(Token::Close);
+            }
+            '-' if query[start + 1..]
+                .starts_with(|c: char| c.is_alphabetic() || "(\"".contains(c)) =>
+            {
+                chars.next();
+                tokens.push(Token::Not);
+            }
+            _ if c.is_whitespace() => {
+                chars.next();
+            }
+            _ => {
+                let mut end = query.len();
+                let mut in_quotes = false;
+
+                while let Some(&(i, c)) = chars.peek() {
+                    if c == '"' {
+                        in_quotes = !in_quotes;
+                    } else if !in_quotes && (c.is_whitespace() || c == '(' || c == ')') {
+                        end = i;
+
+                        break;
+                    }
+
+                    chars.next();
+                }
+
+                let token = match &query[start..end] {
+                    "AND" | "&&" => Token::And,
+                    "OR" | "||" => Token::Or,
+                    "NOT" => Token::Not,
+                    term => Token::Term(term),
+                };
+
+                tokens.push(token);
+            }
+        }
+    }
+
+    tokens
+}
+
+type Tokens<'q> = Peekable<IntoIter<Token<'q>>>;
+
+fn parse_or<'q, F: IFilterCriteria<'q>>(tokens: &mut Tokens<'q>, depth: usize) -> FilterExpr<F> {
+    let mut nodes = Vec::new();
+
+    loop {
+        match parse_and::<F>(tokens, depth) {
+            FilterExpr::Criteria(criteria) if criteria.is_empty() => {}
+            FilterExpr::Or(inner) => nodes.extend(inner),
+            node => nodes.push(node),
+        }
+
+        if let Some(Token::Or) = tokens.peek() {
+            tokens.next();
+        } else {
+            break;
+        }
+    }
+
+    match nodes.len() {
+        0 => FilterExpr::default(),
+        1 => nodes.swap_remove(0),
+        _ => FilterExpr::Or(nodes),
+    }
+}
+
+fn parse_and<'q, F: IFilterCriteria<'q>>(tokens: &mut Tokens<'q>, depth: usize) -> FilterExpr<F> {
+    let mut criteria = FilterCriteria::<F>::default();
+    let mut nodes = Vec::new();
+
+    loop {
+        match tokens.peek() {
+            None | Some(Token::Or) => break,
+            Some(Token::Close) if depth > 0 => break,
+            // Unmatched closing parentheses are ignored
+            Some(Token::Close | Token::And) => {
+                tokens.next();
+            }
+            Some(_) => match parse_unary(tokens, depth, &mut criteria) {
+                Some(FilterExpr::And(inner)) => nodes.extend(inner),
+                Some(node) => nodes.push(node),
+                None => {}
+            },
+        }
+    }
+
+    if !criteria.is_empty() {
+        nodes.insert(0, FilterExpr::Criteria(criteria));
+    }
+
+    match nodes.len() {
+        0 => FilterExpr::default(),
+        1 => nodes.swap_remove(0),
+        _ => FilterExpr::And(nodes),
+    }
+}
+
+/// Parses a single term, negation, or group.
+///
+/// Plain terms are merged into the given criteria in which case `None` is
+/// returned.
+fn parse_unary<'q, F: IFilterCriteria<'q>>(
+    tokens: &mut Tokens<'q>,
+    depth: usize,
+    criteria: &mut FilterCriteria<F>,
+) -> Option<FilterExpr<F>> {
+    match tokens.peek()? {
+        Token::Not => {
+            tokens.next();
+
+            let mut negated = FilterCriteria::<F>::default();
+
+            let node = match parse_unary(tokens, depth, &mut negated) {
+                Some(node) => node,
+                None if negated.is_empty() => return None,
+                None => FilterExpr::Criteria(negated),
+            };
+
+            Some(FilterExpr::Not(Box::new(node)))
+        }
+        Token::Open => {
+            tokens.next();
+            let node = parse_or::<F>(tokens, depth + 1);
+
+            if let Some(Token::Close) = tokens.peek() {
+                tokens.next();
+            }
+
+            match node {
+                FilterExpr::Criteria(inner) if inner.is_empty() => None,
+                node => Some(node),
+            }
+        }
+        &Token::Term(term) => {
+            tokens.next();
+
+            parse_term(term, criteria)
+        }
+        Token::Close | Token::And | Token::Or => None,
+    }
+}
+
+fn parse_term<'q, F: IFilterCriteria<'q>>(
+    term: &'q str,
+    criteria: &mut FilterCriteria<F>,
+) -> Option<FilterExpr<F>> {
+    if let Some((key, op, negated, value)) = split_term(term) {
+        let key = key.cow_to_ascii_lowercase();
+        let value = value.cow_to_ascii_lowercase();
+
+        if !negated {
+            if criteria.inner_mut().try_parse_key_value(key, value, op) {
+                return None;
+            }
+        } else {
+            let mut inner = F::default();
+
+            if inner.try_parse_key_value(key, value, op) {
+                let node = FilterExpr::Criteria(FilterCriteria::new(inner));
+
+                return Some(FilterExpr::Not(Box::new(node)));
+            }
+        }
+    }
+
+    criteria.push_search_term(term.trim_matches('"'));
+
+    None
+}
+
+/// Splits a term into its key, operator, whether the operator is negated,
+/// and value.
+fn split_term(term: &str) -> Option<(&str, Operator, bool, &str)> {
+    let key_len = term.find(|c: char| !(c.is_alphanumeric() || c == '_'))?;
+
+    if key_len == 0 {
+        return None;
+    }
+
+    let (key, rest) = term.split_at(key_len);
+
+    let (op, negated, op_len) = match rest.as_bytes() {
+        [b'!', b'=' | b':', ..] => (Operator::Equal, true, 2),
+        [b'>', b'=' | b':', ..] => (Operator::GreaterOrEqual, false, 2),
+        [b'<', b'=' | b':', ..] => (Operator::LessOrEqual, false, 2),
+        [b'=' | b':', ..] => (Operator::Equal, false, 1),
+        [b'>', ..] => (Operator::Greater, false, 1),
+        [b'<', ..] => (Operator::Less, false, 1),
+        _ => return None,
+    };
+
+    Some((key, op, negated, &rest[op_len..]))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::query::RegularCriteria;
+
+    struct Map {
+        stars: f32,
+        artist: &'static str,
+        title: &'static str,
+    }
+
+    impl Searchable<RegularCriteria<'_>> for Map {
+        fn matches(&self, criteria: &FilterCriteria<RegularCriteria<'_>>) -> bool {
+            let mut matches = true;
+
+            matches &= criteria.stars.contains(self.stars);
+            matches &= criteria.artist.matches(self.artist);
+            matches &= criteria.title.matches(self.title);
+
+            if matches && criteria.has_search_terms() {
+                let terms = [self.artist, self.title];
+
+                matches &= criteria
+                    .search_terms()
+                    .all(|term| terms.iter().any(|searchable| searchable.contains(term)));
+            }
+
+            matches
+        }
+    }
+
+    const MAPS: [Map; 3] = [
+        Map {
+            stars: 7.2,
+            artist: "camellia",
+            title: "ghost",
+        },
+        Map {
+            stars: 5.5,
+            artist: "camellia",
+            title: "exit this earth's atomosphere",
+        },
+        Map {
+            stars: 6.1,
+            artist: "xi",
+            title: "freedom dive",
+        },
+    ];
+
+    fn filter(query: &str) -> Vec<&'static str> {
+        let expr = RegularCriteria::create(query);
+
+        MAPS.iter()
+            .filter(|map| expr.matches(*map))
+            .map(|map| map.title)
+            .collect()
+    }
+
+    #[test]
+    fn plain_terms() {
+        let expr = RegularCriteria::create("stars>5 artist=camellia ghost");
+        assert!(matches!(expr, FilterExpr::Criteria(_)));
+
+        assert_eq!(filter("stars>5 artist=camellia ghost"), ["ghost"]);
+        assert_eq!(filter(""), filter("()"));
+        assert_eq!(filter("").len(), MAPS.len());
+    }
+
+    #[test]
+    fn or() {
+        assert_eq!(filter("stars>7 OR artist=xi"), ["ghost", "freedom dive"]);
+        assert_eq!(filter("stars>7 or artist=xi"), Vec::<&str>::new());
+    }
+
+    #[test]
+    fn not() {
+        assert_eq!(filter("artist!=camellia"), ["freedom dive"]);
+        assert_eq!(filter("NOT artist=camellia"), ["freedom dive"]);
+        assert_eq!(filter("camellia -ghost"), ["exit this earth's atomosphere"]);
+    }
+
+    #[test]
+    fn groups() {
+        assert_eq!(filter("(stars>7 OR artist=xi) -(dive)"), ["ghost"]);
+        assert_eq!(
+            filter("stars<7 (artist=xi OR title=ghost))"),
+            ["freedom dive"]
+        );
+    }
+
+    #[test]
+    fn quoted_parens() {
+        let tokens = tokenize("(title=\"ghost (tv size)\" OR \"(xi)\")");
+        let terms: Vec<_> = tokens
+            .iter()
+            .filter_map(|token| match token {
+                Token::Term(term) => Some(*term),
+                _ => None,
+            })
+            .collect();
+
+        assert_eq!(terms, ["title=\"ghost (tv size)\"", "\"(xi)\""]);
+        assert_eq!(filter("(title=\"ghost)\" OR artist=xi)"), ["freedom dive"]);
+    }
+
+    #[test]
+    fn display() {
+        let mut content = String::new();
+        RegularCriteria::create("(artist=camellia title=ghost) OR NOT title=\"freedom dive\"")
+            .display(&mut content);
+
+        assert_eq!(
+            content,
+            "(`Artist: camellia` • `Title: ghost`) **OR** **NOT** `Title: freedom dive`"
+        );
+    }
+}
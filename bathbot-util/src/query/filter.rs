@@ -1,7 +1,6 @@
 use std::{borrow::Cow, ops::Deref};
 
-use super::{operator::Operator, separate_content};
-use crate::{CowUtils, matcher::QUERY_SYNTAX_REGEX};
+use super::{FilterExpr, operator::Operator, separate_content};
 
 pub trait IFilterCriteria<'q>: Sized + Default {
     fn try_parse_key_value(&mut self, key: Cow<'q, str>, value: Cow<'q, str>, op: Operator)
@@ -12,79 +11,39 @@ pub trait IFilterCriteria<'q>: Sized + Default {
 
     fn display(&self, content: &mut String);
 
-    fn create(query: &'q str) -> FilterCriteria<Self> {
-        FilterCriteria::new(query)
+    fn create(query: &'q str) -> FilterExpr<Self> {
+        FilterExpr::new(query)
     }
 }
 
+/// A conjunction of `key<op>value` terms and search text.
+///
+/// Leaf node of a [`FilterExpr`].
 #[derive(Default)]
 pub struct FilterCriteria<F> {
     inner: F,
-    search_text: Box<str>,
+    search_text: String,
 }
 
-impl<'q, F: IFilterCriteria<'q>> FilterCriteria<F> {
-    fn new(query: &'q str) -> Self {
-        let mut search_text = query.to_owned();
-        let mut inner = F::default();
-        let mut removed = 0;
-
-        for capture in QUERY_SYNTAX_REGEX.captures_iter(query) {
-            let Some(key_match) = capture.name("key") else {
-                continue;
-            };
-
-            let Some(value_match) = capture.name("value") else {
-                continue;
-            };
-
-            let key = key_match.as_str().cow_to_ascii_lowercase();
-            let op = Operator::from(&capture["op"]);
-            let value = value_match.as_str().cow_to_ascii_lowercase();
-
-            if inner.try_parse_key_value(key, value, op) {
-                let range = key_match.start() - removed..value_match.end() - removed;
-                search_text.replace_range(range, "");
-                removed += value_match.end() - key_match.start();
-            }
+impl<F> FilterCriteria<F> {
+    pub(super) fn new(inner: F) -> Self {
+        Self {
+            inner,
+            search_text: String::new(),
         }
+    }
 
-        fn adjust_search_text(search_text: &mut String) {
-            // Index of the last non-whitespace char
-            let mut trunc_idx = search_text
-                .char_indices()
-                .rev()
-                .find_map(|(i, c)| (!c.is_whitespace()).then(|| i + c.len_utf8()))
-                .unwrap_or(0);
-
-            // Index of the first non-whitespace char
-            let start = search_text
-                .char_indices()
-                .find_map(|(i, c)| (!c.is_whitespace()).then_some(i))
-                .filter(|&i| i > 0);
-
-            // If there is whitespace at the front, rotate to the left until
-            // the string starts with the first non-whitespace char
-            if let Some(shift) = start {
-                // SAFETY: The shift is given by .char_indices which is a valid idx
-                unsafe { search_text.as_bytes_mut() }.rotate_left(shift);
-                trunc_idx -= shift;
-            }
-
-            // Truncate the whitespace
-            if trunc_idx < search_text.len() {
-                search_text.truncate(trunc_idx);
-            }
+    pub(super) fn inner_mut(&mut self) -> &mut F {
+        &mut self.inner
+    }
 
-            search_text.make_ascii_lowercase();
+    pub(super) fn push_search_term(&mut self, term: &str) {
+        if !self.search_text.is_empty() {
+            self.search_text.push(' ');
         }
 
-        adjust_search_text(&mut search_text);
-
-        Self {
-            inner,
-            search_text: search_text.into_boxed_str(),
-        }
+        self.search_text.push_str(term);
+        self.search_text.make_ascii_lowercase();
     }
 
     pub fn has_search_terms(&self) -> bool {
@@ -94,6 +53,12 @@ impl<'q, F: IFilterCriteria<'q>> FilterCriteria<F> {
     pub fn search_terms(&self) -> impl Iterator<Item = &str> {
         self.search_text.split_whitespace()
     }
+}
+
+impl<'q, F: IFilterCriteria<'q>> FilterCriteria<F> {
+    pub(super) fn is_empty(&self) -> bool {
+        !(self.inner.any_field() || self.has_search_terms())
+    }
 
     pub fn display(&self, content: &mut String) {
         self.inner.display(content);
@@ -1,12 +1,13 @@
 use std::borrow::Cow;
 
+use rosu_v2::prelude::RankStatus;
 use time::Date;
 
-use super::{display_range, display_text};
+use super::{display_range, display_text, display_value};
 use crate::query::{
     IFilterCriteria,
     operator::Operator,
-    optional::{OptionalRange, OptionalText},
+    optional::{OptionalRange, OptionalText, OptionalValue},
 };
 
 #[derive(Default)]
@@ -21,12 +22,16 @@ pub struct BookmarkCriteria<'q> {
     pub insert_date: OptionalRange<Date>,
     pub ranked_date: OptionalRange<Date>,
 
+    pub status: OptionalValue<RankStatus>,
+
     pub artist: OptionalText<'q>,
     pub title: OptionalText<'q>,
     pub version: OptionalText<'q>,
 
     pub language: OptionalText<'q>,
     pub genre: OptionalText<'q>,
+
+    pub folder: OptionalText<'q>,
 }
 
 impl<'q> IFilterCriteria<'q> for BookmarkCriteria<'q> {
@@ -47,11 +52,13 @@ impl<'q> IFilterCriteria<'q> for BookmarkCriteria<'q> {
             "bookmarked" | "bookmarkdate" | "bookmark_date" | "insertdate" | "insert_date" => {
                 self.insert_date.try_update_date(op, &value)
             }
+            "status" => self.status.try_update_status(op, &value),
             "artist" => self.artist.try_update(op, value),
             "title" => self.title.try_update(op, value),
             "difficulty" | "version" | "diff" => self.version.try_update(op, value),
             "language" | "lang" => self.language.try_update(op, value),
             "genre" => self.genre.try_update(op, value),
+            "folder" => self.folder.try_update(op, value),
             _ => false,
         }
     }
@@ -66,11 +73,13 @@ impl<'q> IFilterCriteria<'q> for BookmarkCriteria<'q> {
             bpm,
             insert_date,
             ranked_date,
+            status,
             artist,
             title,
             version,
             language,
             genre,
+            folder,
         } = self;
 
         !(ar.is_empty()
@@ -81,11 +90,13 @@ impl<'q> IFilterCriteria<'q> for BookmarkCriteria<'q> {
             && bpm.is_empty()
             && insert_date.is_empty()
             && ranked_date.is_empty()
+            && status.is_empty()
             && artist.is_empty()
             && title.is_empty()
             && version.is_empty()
             && language.is_empty()
-            && genre.is_empty())
+            && genre.is_empty()
+            && folder.is_empty())
     }
 
     fn display(&self, content: &mut String) {
@@ -98,11 +109,13 @@ impl<'q> IFilterCriteria<'q> for BookmarkCriteria<'q> {
             bpm,
             insert_date,
             ranked_date,
+            status,
             artist,
             title,
             version,
             language,
             genre,
+            folder,
         } = self;
 
         display_range(content, "AR", ar);
@@ -118,8 +131,10 @@ impl<'q> IFilterCriteria<'q> for BookmarkCriteria<'q> {
 
         display_range(content, "Date", insert_date);
         display_range(content, "Ranked", ranked_date);
+        display_value(content, "Status", status);
 
         display_text(content, "Language", language);
         display_text(content, "Genre", genre);
+        display_text(content, "Folder", folder);
     }
 }
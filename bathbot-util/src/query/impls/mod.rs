@@ -3,7 +3,7 @@ use std::fmt::{Debug, Write};
 pub use self::{bookmark::BookmarkCriteria, regular::RegularCriteria, top::TopCriteria};
 use super::{
     operator::Operator,
-    optional::{OptionalRange, OptionalText},
+    optional::{OptionalMods, OptionalRange, OptionalText, OptionalValue},
     separate_content,
 };
 
@@ -41,6 +41,23 @@ where
     }
 }
 
+fn display_value<T: PartialEq>(content: &mut String, name: &str, value: &OptionalValue<T>)
+where
+    OptionalValue<T>: Debug,
+{
+    if !value.is_empty() {
+        separate_content(content);
+        let _ = write!(content, "`{name}: {value:?}`");
+    }
+}
+
+fn display_mods(content: &mut String, mods: &OptionalMods) {
+    if !mods.is_empty() {
+        separate_content(content);
+        let _ = write!(content, "`Mods: {mods:?}`");
+    }
+}
+
 fn display_text(content: &mut String, name: &str, text: &OptionalText<'_>) {
     if !text.is_empty() {
         separate_content(content);
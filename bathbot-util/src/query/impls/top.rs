@@ -1,17 +1,19 @@
 use std::borrow::Cow;
 
+use rosu_v2::prelude::RankStatus;
 use time::Date;
 
-use super::{display_range, display_text};
+use super::{display_mods, display_range, display_text, display_value};
 use crate::query::{
     IFilterCriteria,
     operator::Operator,
-    optional::{OptionalRange, OptionalText},
+    optional::{GradeLetter, OptionalMods, OptionalRange, OptionalText, OptionalValue},
 };
 
 #[derive(Default)]
 pub struct TopCriteria<'q> {
     pub pp: OptionalRange<f32>,
+    pub pp_fc: OptionalRange<f32>,
     pub stars: OptionalRange<f32>,
     pub ar: OptionalRange<f32>,
     pub cs: OptionalRange<f32>,
@@ -24,6 +26,11 @@ pub struct TopCriteria<'q> {
     pub combo: OptionalRange<u32>,
     pub miss: OptionalRange<u32>,
     pub keys: OptionalRange<f32>,
+    pub grade: OptionalRange<GradeLetter>,
+
+    pub mods: OptionalMods,
+    pub status: OptionalValue<RankStatus>,
+    pub lazer: OptionalValue<bool>,
 
     pub date: OptionalRange<Date>,
     pub ranked_date: OptionalRange<Date>,
@@ -43,6 +50,7 @@ impl<'q> IFilterCriteria<'q> for TopCriteria<'q> {
     ) -> bool {
         match key.as_ref() {
             "pp" => self.pp.try_update(op, &value, 0.005),
+            "pp_fc" | "ppfc" | "fcpp" | "fc_pp" => self.pp_fc.try_update(op, &value, 0.005),
             "star" | "stars" => self.stars.try_update(op, &value, 0.005),
             "ar" => self.ar.try_update(op, &value, 0.005),
             "dr" | "hp" => self.hp.try_update(op, &value, 0.005),
@@ -57,6 +65,10 @@ impl<'q> IFilterCriteria<'q> for TopCriteria<'q> {
                 self.miss.try_update(op, &value, 0)
             }
             "key" | "keys" => self.keys.try_update(op, &value, 0.5),
+            "grade" => self.grade.try_update_grade(op, &value),
+            "mods" | "mod" => self.mods.try_update(op, &value),
+            "status" => self.status.try_update_status(op, &value),
+            "lazer" => self.lazer.try_update_bool(op, &value),
 
             "date" | "scoredate" | "ended_at" => self.date.try_update_date(op, &value),
             "ranked" | "rankeddate" | "ranked_date" => self.ranked_date.try_update_date(op, &value),
@@ -72,6 +84,7 @@ impl<'q> IFilterCriteria<'q> for TopCriteria<'q> {
     fn any_field(&self) -> bool {
         let Self {
             pp,
+            pp_fc,
             stars,
             ar,
             cs,
@@ -84,6 +97,10 @@ impl<'q> IFilterCriteria<'q> for TopCriteria<'q> {
             combo,
             miss,
             keys,
+            grade,
+            mods,
+            status,
+            lazer,
             date,
             ranked_date,
             artist,
@@ -93,6 +110,7 @@ impl<'q> IFilterCriteria<'q> for TopCriteria<'q> {
         } = self;
 
         !(pp.is_empty()
+            && pp_fc.is_empty()
             && stars.is_empty()
             && ar.is_empty()
             && cs.is_empty()
@@ -105,6 +123,10 @@ impl<'q> IFilterCriteria<'q> for TopCriteria<'q> {
             && combo.is_empty()
             && miss.is_empty()
             && keys.is_empty()
+            && grade.is_empty()
+            && mods.is_empty()
+            && status.is_empty()
+            && lazer.is_empty()
             && date.is_empty()
             && ranked_date.is_empty()
             && artist.is_empty()
@@ -116,6 +138,7 @@ impl<'q> IFilterCriteria<'q> for TopCriteria<'q> {
     fn display(&self, content: &mut String) {
         let Self {
             pp,
+            pp_fc,
             stars,
             ar,
             cs,
@@ -128,6 +151,10 @@ impl<'q> IFilterCriteria<'q> for TopCriteria<'q> {
             combo,
             miss,
             keys,
+            grade,
+            mods,
+            status,
+            lazer,
             date,
             ranked_date,
             artist,
@@ -143,12 +170,17 @@ impl<'q> IFilterCriteria<'q> for TopCriteria<'q> {
         display_range(content, "Length", length);
         display_range(content, "Stars", stars);
         display_range(content, "PP", pp);
+        display_range(content, "PP if FC", pp_fc);
         display_range(content, "BPM", bpm);
         display_range(content, "Accuracy", acc);
         display_range(content, "Combo", combo);
         display_range(content, "Misses", miss);
         display_range(content, "Score", score);
         display_range(content, "Keys", keys);
+        display_range(content, "Grade", grade);
+        display_mods(content, mods);
+        display_value(content, "Status", status);
+        display_value(content, "Lazer", lazer);
 
         display_text(content, "Artist", artist);
         display_text(content, "Title", title);
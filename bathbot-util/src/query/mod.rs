@@ -1,10 +1,11 @@
+mod expr;
 mod filter;
 mod impls;
 mod operator;
 mod optional;
 mod searchable;
 
-pub use self::{filter::*, impls::*, operator::Operator, searchable::*};
+pub use self::{expr::FilterExpr, filter::*, impls::*, operator::Operator, searchable::*};
 
 fn separate_content(content: &mut String) {
     if !content.is_empty() {
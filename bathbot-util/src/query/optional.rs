@@ -7,10 +7,15 @@ use std::{
     time::Duration,
 };
 
+use rosu_v2::prelude::{GameMods, GameModsIntermode, Grade, RankStatus};
 use time::Date;
 
 use super::operator::Operator;
-use crate::{CowUtils, datetime::DATE_FORMAT};
+use crate::{
+    CowUtils,
+    datetime::DATE_FORMAT,
+    osu::{ModSelection, ModsResult},
+};
 
 #[derive(Default)]
 pub struct OptionalText<'q> {
@@ -151,6 +156,220 @@ impl<T: PartialOrd> OptionalRange<T> {
     }
 }
 
+/// Grade letter without distinction between silver and gold
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum GradeLetter {
+    F,
+    D,
+    C,
+    B,
+    A,
+    S,
+    X,
+}
+
+impl From<Grade> for GradeLetter {
+    fn from(grade: Grade) -> Self {
+        match grade {
+            Grade::F => Self::F,
+            Grade::D => Self::D,
+            Grade::C => Self::C,
+            Grade::B => Self::B,
+            Grade::A => Self::A,
+            Grade::S | Grade::SH => Self::S,
+            Grade::X | Grade::XH => Self::X,
+        }
+    }
+}
+
+impl FromStr for GradeLetter {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "f" => Ok(Self::F),
+            "d" => Ok(Self::D),
+            "c" => Ok(Self::C),
+            "b" => Ok(Self::B),
+            "a" => Ok(Self::A),
+            "s" | "sh" => Ok(Self::S),
+            "x" | "xh" | "ss" | "ssh" => Ok(Self::X),
+            _ => Err(()),
+        }
+    }
+}
+
+impl Display for GradeLetter {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            Self::X => f.write_str("SS"),
+            _ => Debug::fmt(self, f),
+        }
+    }
+}
+
+impl OptionalRange<GradeLetter> {
+    pub fn try_update_grade(&mut self, op: Operator, value: &str) -> bool {
+        let Ok(grade) = value.parse() else {
+            return false;
+        };
+
+        match op {
+            Operator::Equal => {
+                self.min = Some(grade);
+                self.max = Some(grade);
+                self.is_lower_inclusive = true;
+                self.is_upper_inclusive = true;
+            }
+            Operator::Less => self.max = Some(grade),
+            Operator::LessOrEqual => {
+                self.max = Some(grade);
+                self.is_upper_inclusive = true;
+            }
+            Operator::Greater => self.min = Some(grade),
+            Operator::GreaterOrEqual => {
+                self.min = Some(grade);
+                self.is_lower_inclusive = true;
+            }
+        }
+
+        true
+    }
+
+    pub fn contains_grade(&self, grade: Grade) -> bool {
+        self.contains(GradeLetter::from(grade))
+    }
+}
+
+/// A value that can only be compared for equality
+pub struct OptionalValue<T> {
+    value: Option<T>,
+}
+
+impl<T> Default for OptionalValue<T> {
+    #[inline]
+    fn default() -> Self {
+        Self { value: None }
+    }
+}
+
+impl<T: PartialEq> OptionalValue<T> {
+    pub fn is_empty(&self) -> bool {
+        self.value.is_none()
+    }
+
+    pub fn matches(&self, value: T) -> bool {
+        self.value.as_ref().is_none_or(|v| *v == value)
+    }
+}
+
+impl OptionalValue<bool> {
+    pub fn try_update_bool(&mut self, op: Operator, value: &str) -> bool {
+        let value = match (op, value) {
+            (Operator::Equal, "true" | "yes" | "y" | "1") => true,
+            (Operator::Equal, "false" | "no" | "n" | "0") => false,
+            _ => return false,
+        };
+
+        self.value = Some(value);
+
+        true
+    }
+}
+
+impl OptionalValue<RankStatus> {
+    pub fn try_update_status(&mut self, op: Operator, value: &str) -> bool {
+        let status = match (op, value) {
+            (Operator::Equal, "ranked") => RankStatus::Ranked,
+            (Operator::Equal, "approved") => RankStatus::Approved,
+            (Operator::Equal, "qualified") => RankStatus::Qualified,
+            (Operator::Equal, "loved") => RankStatus::Loved,
+            (Operator::Equal, "pending") => RankStatus::Pending,
+            (Operator::Equal, "wip") => RankStatus::WIP,
+            (Operator::Equal, "graveyard") => RankStatus::Graveyard,
+            _ => return false,
+        };
+
+        self.value = Some(status);
+
+        true
+    }
+}
+
+impl Debug for OptionalValue<bool> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self.value {
+            Some(true) => f.write_str("yes"),
+            Some(false) => f.write_str("no"),
+            None => f.write_str("<none>"),
+        }
+    }
+}
+
+impl Debug for OptionalValue<RankStatus> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self.value {
+            Some(ref status) => Debug::fmt(status, f),
+            None => f.write_str("<none>"),
+        }
+    }
+}
+
+#[derive(Default)]
+pub struct OptionalMods {
+    selection: Option<ModSelection>,
+}
+
+impl OptionalMods {
+    pub fn is_empty(&self) -> bool {
+        self.selection.is_none()
+    }
+
+    pub fn matches(&self, mods: &GameMods) -> bool {
+        self.selection
+            .as_ref()
+            .is_none_or(|selection| selection.filter_mods(mods))
+    }
+
+    /// `=` takes acronyms as well as the `+mods`, `+mods!`, and `-mods!`
+    /// syntax, `>=` includes the given mods.
+    pub fn try_update(&mut self, op: Operator, value: &str) -> bool {
+        let selection = match op {
+            Operator::Equal if value == "nm" => ModSelection::Exact(GameModsIntermode::new()),
+            Operator::Equal => match ModSelection::parse(Some(value)) {
+                ModsResult::Mods(selection) => selection,
+                ModsResult::None | ModsResult::Invalid => return false,
+            },
+            Operator::Greater | Operator::GreaterOrEqual => {
+                match GameModsIntermode::try_from_acronyms(value) {
+                    Some(mods) => ModSelection::Include(mods),
+                    None => return false,
+                }
+            }
+            Operator::Less | Operator::LessOrEqual => return false,
+        };
+
+        self.selection = Some(selection);
+
+        true
+    }
+}
+
+impl Debug for OptionalMods {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self.selection {
+            Some(ModSelection::Include(ref mods)) if mods.is_empty() => f.write_str("NM"),
+            Some(ModSelection::Exact(ref mods)) if mods.is_empty() => f.write_str("NM"),
+            Some(ModSelection::Include(ref mods)) => write!(f, "+{mods}"),
+            Some(ModSelection::Exact(ref mods)) => write!(f, "+{mods}!"),
+            Some(ModSelection::Exclude { ref mods, nomod }) => {
+                write!(f, "-{mods}{}!", if nomod { "NM" } else { "" })
+            }
+            None => f.write_str("<none>"),
+        }
+    }
+}
+
 impl Debug for OptionalRange<f32> {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
         fmt_float(self, f)
@@ -169,6 +388,12 @@ impl Debug for OptionalRange<Date> {
     }
 }
 
+impl Debug for OptionalRange<GradeLetter> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        fmt_eq(self, f)
+    }
+}
+
 fn fmt_float(optional: &OptionalRange<f32>, f: &mut Formatter<'_>) -> FmtResult {
     if optional.min.is_none()
         && optional.max.is_none()
@@ -71,17 +71,12 @@ impl ActiveMessagesBuilder {
                 builder = builder.attachment(name, bytes);
             }
 
-            let response_raw = if start_by_update.unwrap_or(false) {
+            let response = if start_by_update.unwrap_or(false) {
                 orig.create_message(builder).await?
             } else {
                 orig.callback(builder).await?
             };
 
-            let response = response_raw
-                .model()
-                .await
-                .wrap_err("Failed to deserialize response")?;
-
             let msg = response.id;
             let response = ActiveResponse::new(&orig, &response);
             let (activity_tx, activity_rx) = watch::channel(());
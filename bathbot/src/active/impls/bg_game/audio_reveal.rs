@@ -0,0 +1,186 @@
+use std::ops::Range;
+
+use bytes::Bytes;
+use eyre::Result;
+use rand::Rng;
+
+/// Length of the initial snippet in seconds
+const INITIAL_LEN: f32 = 3.0;
+
+/// Added snippet length in seconds whenever the snippet is increased
+const INCREASE_LEN: f32 = 2.0;
+
+pub struct AudioReveal {
+    audio: Bytes,
+    frames: Box<[Range<usize>]>,
+    frame_duration: f32,
+    start: usize,
+    len: usize,
+}
+
+impl AudioReveal {
+    /// Takes the bytes of an mp3 file.
+    pub fn new(audio: Bytes) -> Result<Self> {
+        let (frames, frame_duration) = mp3_frames(&audio);
+
+        if frames.is_empty() {
+            bail!("Audio does not contain any mp3 frames");
+        }
+
+        let len = ((INITIAL_LEN / frame_duration) as usize).clamp(1, frames.len());
+        let start = rand::thread_rng().gen_range(0..=frames.len() - len);
+
+        Ok(Self {
+            audio,
+            frames: frames.into_boxed_slice(),
+            frame_duration,
+            start,
+            len,
+        })
+    }
+
+    pub fn increase_length(&mut self) {
+        let additional = (INCREASE_LEN / self.frame_duration) as usize;
+        self.len = (self.len + additional).min(self.frames.len());
+
+        // Move the start back if the snippet would exceed the audio
+        self.start = self.start.min(self.frames.len() - self.len);
+    }
+
+    pub fn snippet(&self) -> Vec<u8> {
+        let frames = &self.frames[self.start..self.start + self.len];
+        let start = frames.first().map_or(0, |frame| frame.start);
+        let end = frames.last().map_or(0, |frame| frame.end);
+
+        self.audio[start..end].to_vec()
+    }
+}
+
+/// Returns the byte ranges of all MPEG layer III frames and the duration
+/// of a single frame in seconds.
+fn mp3_frames(bytes: &[u8]) -> (Vec<Range<usize>>, f32) {
+    const BITRATES_V1: [u32; 15] = [
+        0, 32, 40, 48, 56, 64, 80, 96, 112, 128, 160, 192, 224, 256, 320,
+    ];
+    const BITRATES_V2: [u32; 15] = [0, 8, 16, 24, 32, 40, 48, 56, 64, 80, 96, 112, 128, 144, 160];
+    const SAMPLE_RATES: [u32; 3] = [44_100, 48_000, 32_000];
+
+    let mut frames = Vec::new();
+    let mut frame_duration = 0.0;
+    let mut idx = id3_len(bytes);
+
+    while let Some(&[a, b, c, d]) = bytes.get(idx..idx + 4) {
+        let header = u32::from_be_bytes([a, b, c, d]);
+
+        let sync = header >> 21;
+        let version = (header >> 19) & 0b11;
+        let layer = (header >> 17) & 0b11;
+        let bitrate_idx = ((header >> 12) & 0b1111) as usize;
+        let sample_rate_idx = ((header >> 10) & 0b11) as usize;
+        let padding = (header >> 9) & 0b1;
+
+        // Version 1 is reserved and layer 1 is layer III
+        let valid = sync == 0x7FF
+            && version != 1
+            && layer == 1
+            && (1..15).contains(&bitrate_idx)
+            && sample_rate_idx < 3;
+
+        if !valid {
+            idx += 1;
+
+            continue;
+        }
+
+        let (bitrate, sample_rate, samples) = match version {
+            // MPEG 1
+            3 => (
+                BITRATES_V1[bitrate_idx],
+                SAMPLE_RATES[sample_rate_idx],
+                1152,
+            ),
+            // MPEG 2
+            2 => (
+                BITRATES_V2[bitrate_idx],
+                SAMPLE_RATES[sample_rate_idx] / 2,
+                576,
+            ),
+            // MPEG 2.5
+            _ => (
+                BITRATES_V2[bitrate_idx],
+                SAMPLE_RATES[sample_rate_idx] / 4,
+                576,
+            ),
+        };
+
+        let frame_len = (samples / 8 * bitrate * 1000 / sample_rate + padding) as usize;
+
+        if idx + frame_len > bytes.len() {
+            break;
+        }
+
+        if frames.is_empty() {
+            frame_duration = samples as f32 / sample_rate as f32;
+        }
+
+        frames.push(idx..idx + frame_len);
+        idx += frame_len;
+    }
+
+    (frames, frame_duration)
+}
+
+/// Length of the ID3v2 tag at the start of the bytes, if any.
+fn id3_len(bytes: &[u8]) -> usize {
+    match bytes {
+        [b'I', b'D', b'3', _, _, flags, size @ ..] if size.len() >= 4 => {
+            // Sizes are stored as syncsafe integers
+            let size = size[..4]
+                .iter()
+                .fold(0, |size, byte| (size << 7) | (*byte & 0x7F) as usize);
+
+            let footer = if flags & 0x10 > 0 { 10 } else { 0 };
+
+            10 + size + footer
+        }
+        _ => 0,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// MPEG 1 layer III frame with 128kbps at 44.1kHz without padding
+    fn frame() -> Vec<u8> {
+        let mut frame = vec![0; 417];
+        frame[..4].copy_from_slice(&[0xFF, 0xFB, 0x90, 0x00]);
+
+        frame
+    }
+
+    #[test]
+    fn parses_frames() {
+        let mut bytes = vec![b'I', b'D', b'3', 4, 0, 0, 0, 0, 0, 5, 1, 2, 3, 4, 5];
+
+        for _ in 0..3 {
+            bytes.extend(frame());
+        }
+
+        let (frames, frame_duration) = mp3_frames(&bytes);
+
+        assert_eq!(frames, vec![15..432, 432..849, 849..1266]);
+        assert!((frame_duration - 1152.0 / 44_100.0).abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn skips_garbage() {
+        let mut bytes = vec![0x12, 0x34, 0xFF];
+        bytes.extend(frame());
+        bytes.extend([0xFF, 0x00]);
+
+        let (frames, _) = mp3_frames(&bytes);
+
+        assert_eq!(frames, vec![3..420]);
+    }
+}
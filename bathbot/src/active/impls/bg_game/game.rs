@@ -1,11 +1,11 @@
 use std::{collections::VecDeque, sync::RwLock};
 
-use bathbot_model::Effects;
+use bathbot_model::{BgGameKind, Effects};
 use bathbot_psql::model::games::MapsetTagsEntries;
 use bathbot_util::{CowUtils, constants::OSU_BASE};
-use eyre::{Result, WrapErr};
+use eyre::{Result, WrapErr, bail};
 use image::{
-    GenericImageView,
+    DynamicImage, GenericImageView,
     imageops::{self, colorops},
 };
 use rosu_v2::model::GameMode;
@@ -17,30 +17,36 @@ use twilight_model::id::{
 };
 use twilight_standby::future::WaitForMessageStream;
 
-use super::{hints::Hints, img_reveal::ImageReveal, mapset::GameMapset, util};
+use super::{
+    audio_reveal::AudioReveal, hints::Hints, img_reveal::ImageReveal, mapset::GameMapset, util,
+};
 use crate::{Context, commands::fun::GameDifficulty, core::BotConfig, util::ChannelExt};
 
 pub struct Game {
     pub mapset: GameMapset,
     difficulty: f32,
     hints: RwLock<Hints>,
-    reveal: RwLock<ImageReveal>,
+    reveal: RwLock<Reveal>,
 }
 
 impl Game {
+    /// How many mapsets are tried before giving up on creating a game
+    const MAX_ATTEMPTS: usize = 10;
+
     pub async fn new(
         entries: &MapsetTagsEntries,
         previous_ids: &mut VecDeque<i32>,
+        kind: BgGameKind,
         effects: Effects,
         difficulty: GameDifficulty,
-    ) -> (Self, Vec<u8>) {
-        loop {
-            match Game::new_(entries, previous_ids, effects, difficulty).await {
+    ) -> Result<(Self, GameAttachment)> {
+        for _ in 0..Self::MAX_ATTEMPTS {
+            match Game::new_(entries, previous_ids, kind, effects, difficulty).await {
                 Ok(game) => {
-                    let sub_image_result = { game.reveal.read().unwrap().sub_image() };
+                    let attachment_result = { game.reveal.read().unwrap().attachment() };
 
-                    match sub_image_result {
-                        Ok(img) => return (game, img),
+                    match attachment_result {
+                        Ok(attachment) => return Ok((game, attachment)),
                         Err(err) => {
                             warn!(
                                 mapset_id = game.mapset.mapset_id,
@@ -55,84 +61,77 @@ impl Game {
                 }
             }
         }
+
+        bail!(
+            "Failed to create bg game after {} attempts",
+            Self::MAX_ATTEMPTS
+        )
     }
 
     async fn new_(
         entries: &MapsetTagsEntries,
         previous_ids: &mut VecDeque<i32>,
+        kind: BgGameKind,
         effects: Effects,
         difficulty: GameDifficulty,
     ) -> Result<Self> {
-        let mut path = BotConfig::get().paths.backgrounds.clone();
-
-        match entries.mode {
-            GameMode::Osu => path.push("osu"),
-            GameMode::Mania => path.push("mania"),
-            _ => bail!("background game not available for {}", entries.mode),
-        }
-
         let mapset = util::get_random_mapset(entries, previous_ids);
-        let mapset_id = mapset.mapset_id;
+        let mapset_id = mapset.mapset_id as u32;
         debug!("Next BG mapset id: {mapset_id}");
-        path.push(&mapset.image_filename);
-
-        let img_fut = async {
-            let bytes = fs::read(path)
-                .await
-                .wrap_err_with(|| format!("failed to read bg image for mapset {mapset_id}"))?;
-
-            let mut img =
-                image::load_from_memory(&bytes).wrap_err("failed to load image from memory")?;
-
-            let (w, h) = img.dimensions();
-
-            // 800*600 (4:3)
-            if w * h > 480_000 {
-                img = img.thumbnail(800, 600);
-            }
-
-            if effects.contains(Effects::Invert) {
-                img.invert();
-            }
-
-            if effects.contains(Effects::Contrast) {
-                colorops::contrast_in_place(&mut img, 18.0);
-            }
 
-            if effects.contains(Effects::FlipHorizontal) {
-                imageops::flip_horizontal_in_place(&mut img);
-            }
-
-            if effects.contains(Effects::FlipVertical) {
-                imageops::flip_vertical_in_place(&mut img);
-            }
+        let reveal_fut = async {
+            match kind {
+                BgGameKind::Background => {
+                    let img = load_background(entries.mode, &mapset.image_filename, effects)
+                        .await
+                        .wrap_err_with(|| format!("failed to load bg for mapset {mapset_id}"))?;
 
-            if effects.contains(Effects::Grayscale) {
-                img = img.grayscale();
-            }
+                    Ok(Reveal::Image(ImageReveal::new(img)))
+                }
+                BgGameKind::Song => {
+                    let audio = Context::client()
+                        .get_mapset_preview(mapset_id)
+                        .await
+                        .wrap_err_with(|| format!("failed to get preview of mapset {mapset_id}"))?;
 
-            if effects.contains(Effects::Blur) {
-                img = img.blur(4.0);
+                    AudioReveal::new(audio).map(Reveal::Audio)
+                }
             }
-
-            Ok(img)
         };
 
-        let (mapset_, img) = tokio::try_join!(GameMapset::new(mapset.mapset_id as u32), img_fut)?;
+        let (mapset_, reveal) = tokio::try_join!(GameMapset::new(mapset_id), reveal_fut)?;
 
         Ok(Self {
             hints: RwLock::new(Hints::new(mapset_.title())),
             difficulty: difficulty.factor(),
             mapset: mapset_,
-            reveal: RwLock::new(ImageReveal::new(img)),
+            reveal: RwLock::new(reveal),
         })
     }
 
-    pub fn sub_image(&self) -> Result<Vec<u8>> {
+    /// Increase the revealed part i.e. enlarge the image or lengthen the
+    /// audio snippet.
+    pub fn increase_reveal(&self) -> Result<GameAttachment> {
         let mut reveal = self.reveal.write().unwrap();
-        reveal.increase_radius();
+        reveal.increase();
 
-        reveal.sub_image()
+        reveal.attachment()
+    }
+
+    /// Links to the mapset and its full background or audio preview.
+    pub fn resolve(&self) -> String {
+        let mapset_id = self.mapset.mapset_id;
+
+        match *self.reveal.read().unwrap() {
+            Reveal::Image(_) => format!(
+                "Mapset: {OSU_BASE}beatmapsets/{mapset_id}\n\
+                Full background: https://catboy.best/preview/background/{mapset_id}/set"
+            ),
+            Reveal::Audio(_) => format!(
+                "Mapset: {OSU_BASE}beatmapsets/{mapset_id}\n\
+                Full preview: https://b.ppy.sh/preview/{mapset_id}.mp3"
+            ),
+        }
     }
 
     pub fn hint(&self) -> String {
@@ -141,10 +140,6 @@ impl Game {
         hints.get(self.mapset.title(), self.mapset.artist())
     }
 
-    pub fn mapset_id(&self) -> u32 {
-        self.mapset.mapset_id
-    }
-
     fn check_msg_content(&self, content: &str) -> ContentResult {
         match self.mapset.matches_title(content, self.difficulty) {
             Some(true) => return ContentResult::Title(true),
@@ -185,15 +180,13 @@ pub async fn game_loop(
             // Title correct?
             ContentResult::Title(exact) => {
                 let content = format!(
-                    "{} \\:)\n\
-                    Mapset: {OSU_BASE}beatmapsets/{mapset_id}\n\
-                    Full background: https://catboy.best/preview/background/{mapset_id}/set",
+                    "{} \\:)\n{}",
                     if exact {
                         format!("Gratz {}, you guessed it", msg.author.name)
                     } else {
                         format!("You were close enough {}, gratz", msg.author.name)
                     },
-                    mapset_id = game.mapset.mapset_id
+                    game.resolve(),
                 );
 
                 // Send message
@@ -235,6 +228,86 @@ pub async fn game_loop(
     LoopResult::Stop
 }
 
+pub struct GameAttachment {
+    pub filename: &'static str,
+    pub bytes: Vec<u8>,
+}
+
+enum Reveal {
+    Image(ImageReveal),
+    Audio(AudioReveal),
+}
+
+impl Reveal {
+    fn increase(&mut self) {
+        match self {
+            Self::Image(reveal) => reveal.increase_radius(),
+            Self::Audio(reveal) => reveal.increase_length(),
+        }
+    }
+
+    fn attachment(&self) -> Result<GameAttachment> {
+        match self {
+            Self::Image(reveal) => reveal.sub_image().map(|bytes| GameAttachment {
+                filename: "bg_img.png",
+                bytes,
+            }),
+            Self::Audio(reveal) => Ok(GameAttachment {
+                filename: "song.mp3",
+                bytes: reveal.snippet(),
+            }),
+        }
+    }
+}
+
+async fn load_background(mode: GameMode, filename: &str, effects: Effects) -> Result<DynamicImage> {
+    let mut path = BotConfig::get().paths.backgrounds.clone();
+
+    match mode {
+        GameMode::Osu => path.push("osu"),
+        GameMode::Mania => path.push("mania"),
+        _ => bail!("background game not available for {mode}"),
+    }
+
+    path.push(filename);
+
+    let bytes = fs::read(path).await.wrap_err("failed to read bg image")?;
+    let mut img = image::load_from_memory(&bytes).wrap_err("failed to load image from memory")?;
+
+    let (w, h) = img.dimensions();
+
+    // 800*600 (4:3)
+    if w * h > 480_000 {
+        img = img.thumbnail(800, 600);
+    }
+
+    if effects.contains(Effects::Invert) {
+        img.invert();
+    }
+
+    if effects.contains(Effects::Contrast) {
+        colorops::contrast_in_place(&mut img, 18.0);
+    }
+
+    if effects.contains(Effects::FlipHorizontal) {
+        imageops::flip_horizontal_in_place(&mut img);
+    }
+
+    if effects.contains(Effects::FlipVertical) {
+        imageops::flip_vertical_in_place(&mut img);
+    }
+
+    if effects.contains(Effects::Grayscale) {
+        img = img.grayscale();
+    }
+
+    if effects.contains(Effects::Blur) {
+        img = img.blur(4.0);
+    }
+
+    Ok(img)
+}
+
 // bool to tell whether its an exact match
 enum ContentResult {
     Title(bool),
@@ -4,9 +4,9 @@ use std::{
     sync::Arc,
 };
 
-use bathbot_model::Effects;
+use bathbot_model::{BgGameKind, Effects};
 use bathbot_psql::model::games::MapsetTagsEntries;
-use bathbot_util::{IntHasher, MessageBuilder, constants::OSU_BASE};
+use bathbot_util::{IntHasher, MessageBuilder};
 use eyre::Result;
 use tokio::{
     sync::{
@@ -20,7 +20,7 @@ use twilight_model::{
     id::{Id, marker::ChannelMarker},
 };
 
-use super::game::{Game, LoopResult, game_loop};
+use super::game::{Game, GameAttachment, LoopResult, game_loop};
 use crate::{Context, commands::fun::GameDifficulty, util::ChannelExt};
 
 const GAME_LEN: Duration = Duration::from_secs(180);
@@ -35,9 +35,10 @@ impl BackgroundGame {
     pub async fn new(
         channel: Id<ChannelMarker>,
         entries: MapsetTagsEntries,
+        kind: BgGameKind,
         effects: Effects,
         difficulty: GameDifficulty,
-    ) -> Self {
+    ) -> Result<Self> {
         let (tx, mut rx) = mpsc::unbounded_channel();
 
         let mut msg_stream = Context::standby()
@@ -47,7 +48,8 @@ impl BackgroundGame {
         let mut scores = HashMap::with_hasher(IntHasher);
 
         // Initialize game
-        let (game, mut img) = Game::new(&entries, &mut previous_ids, effects, difficulty).await;
+        let (game, mut attachment) =
+            Game::new(&entries, &mut previous_ids, kind, effects, difficulty).await?;
         let game = Arc::new(RwLock::new(game));
         let game_clone = Arc::clone(&game);
 
@@ -55,7 +57,7 @@ impl BackgroundGame {
             loop {
                 let builder = MessageBuilder::new()
                     .content("Here's the next one:")
-                    .attachment("bg_img.png", mem::take(&mut img));
+                    .attachment(attachment.filename, mem::take(&mut attachment.bytes));
 
                 if let Err(err) = channel.create_message(builder, None).await {
                     warn!(?err, "Failed to send initial bg game msg");
@@ -73,34 +75,26 @@ impl BackgroundGame {
                 // Process the result
                 match result {
                     LoopResult::Restart => {
-                        let mapset_id = game_clone.read().await.mapset_id();
-
-                        // Send message
-                        let content = format!(
-                            "Mapset: {OSU_BASE}beatmapsets/{mapset_id}\n\
-                            Full background: https://catboy.best/preview/background/{mapset_id}/set"
-                        );
+                        let content = game_clone.read().await.resolve();
 
                         if let Err(err) = channel.plain_message(&content).await {
                             warn!(?err, "Failed to show resolve for bg game restart");
                         }
                     }
                     LoopResult::Stop => {
-                        let mapset_id = game_clone.read().await.mapset_id();
+                        let resolve = game_clone.read().await.resolve();
 
                         // Send message
-                        let content = format!(
-                            "Mapset: {OSU_BASE}beatmapsets/{mapset_id}\n\
-                            Full background: https://catboy.best/preview/background/{mapset_id}/set\n\
-                            End of game, see you next time o/"
-                        );
+                        let content = format!("{resolve}\nEnd of game, see you next time o/");
 
                         if let Err(err) = channel.plain_message(&content).await {
                             warn!(?err, "Failed to show resolve for bg game stop");
                         }
 
                         // Store score for winners
-                        if let Err(err) = Context::games().bggame_increment_scores(&scores).await {
+                        let increment_fut = Context::games().bggame_increment_scores(&scores, kind);
+
+                        if let Err(err) = increment_fut.await {
                             warn!("{err:?}");
                         }
 
@@ -116,16 +110,37 @@ impl BackgroundGame {
                 }
 
                 // Initialize next game
-                let (game, img_) =
-                    Game::new(&entries, &mut previous_ids, effects, difficulty).await;
-                img = img_;
+                let next_fut = Game::new(&entries, &mut previous_ids, kind, effects, difficulty);
+
+                let (game, attachment_) = match next_fut.await {
+                    Ok(tuple) => tuple,
+                    Err(err) => {
+                        warn!(?err, "Failed to create next bg game");
+
+                        let content = "Failed to prepare the next round, end of game";
+
+                        if let Err(err) = channel.error(content).await {
+                            warn!(?err, "Failed to show bg game failure");
+                        }
+
+                        let increment_fut = Context::games().bggame_increment_scores(&scores, kind);
+
+                        if let Err(err) = increment_fut.await {
+                            warn!("{err:?}");
+                        }
+
+                        break;
+                    }
+                };
+
+                attachment = attachment_;
                 *game_clone.write().await = game;
             }
 
             Context::bg_games().write(&channel).await.remove();
         });
 
-        Self { game, tx }
+        Ok(Self { game, tx })
     }
 
     pub fn stop(&self) -> Result<()> {
@@ -140,10 +155,10 @@ impl BackgroundGame {
             .map_err(|_| eyre!("Failed to send restart token"))
     }
 
-    pub async fn sub_image(&self) -> Result<Vec<u8>> {
+    pub async fn increase_reveal(&self) -> Result<GameAttachment> {
         timeout(Duration::from_secs(1), self.game.read())
             .await?
-            .sub_image()
+            .increase_reveal()
     }
 
     pub async fn hint(&self) -> Result<String> {
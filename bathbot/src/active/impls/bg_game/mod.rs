@@ -1,9 +1,9 @@
-use bathbot_model::{Effects, MapsetTags};
+use bathbot_model::{BgGameKind, Effects, MapsetTags};
 use bathbot_psql::model::games::DbMapTagsParams;
 use bathbot_util::{
     Authored, EmbedBuilder, FooterBuilder, MessageBuilder, constants::GENERAL_ISSUE, fields,
 };
-use eyre::{Report, Result};
+use eyre::{Report, Result, WrapErr};
 use rosu_v2::prelude::GameMode;
 use twilight_model::{
     channel::message::{
@@ -24,6 +24,7 @@ use crate::{
     util::{ComponentExt, interaction::InteractionComponent},
 };
 
+mod audio_reveal;
 mod game;
 mod game_wrapper;
 mod hints;
@@ -32,6 +33,7 @@ mod mapset;
 mod util;
 
 pub struct BackgroundGameSetup {
+    kind: BgGameKind,
     difficulty: GameDifficulty,
     effects: Effects,
     excluded: MapsetTags,
@@ -297,12 +299,20 @@ impl IActiveMessage for BackgroundGameSetup {
             ],
         };
 
-        vec![
-            Component::ActionRow(include_row),
-            Component::ActionRow(exclude_row),
-            Component::ActionRow(effects_row),
-            Component::ActionRow(button_row),
-        ]
+        match self.kind {
+            BgGameKind::Background => vec![
+                Component::ActionRow(include_row),
+                Component::ActionRow(exclude_row),
+                Component::ActionRow(effects_row),
+                Component::ActionRow(button_row),
+            ],
+            // Effects only apply to images
+            BgGameKind::Song => vec![
+                Component::ActionRow(include_row),
+                Component::ActionRow(exclude_row),
+                Component::ActionRow(button_row),
+            ],
+        }
     }
 
     async fn handle_component(&mut self, component: &mut InteractionComponent) -> ComponentResult {
@@ -337,8 +347,9 @@ impl IActiveMessage for BackgroundGameSetup {
 }
 
 impl BackgroundGameSetup {
-    pub fn new(difficulty: GameDifficulty, msg_owner: Id<UserMarker>) -> Self {
+    pub fn new(kind: BgGameKind, difficulty: GameDifficulty, msg_owner: Id<UserMarker>) -> Self {
         Self {
+            kind,
             difficulty,
             msg_owner,
             effects: Effects::empty(),
@@ -390,14 +401,21 @@ impl BackgroundGameSetup {
             "None".to_owned()
         };
 
-        let fields = fields![
+        let mut fields = fields![
             "Included", include_value, true;
             "Excluded", excluded_value, true;
-            "Effects", effects_value, true;
         ];
 
+        let title = match self.kind {
+            BgGameKind::Background => {
+                fields![fields { "Effects", effects_value, true }];
+
+                format!("Selected tags ({} backgrounds)", entries.tags.len())
+            }
+            BgGameKind::Song => format!("Selected tags ({} songs)", entries.tags.len()),
+        };
+
         let footer = FooterBuilder::new(format!("Difficulty: {:?}", self.difficulty));
-        let title = format!("Selected tags ({} backgrounds)", entries.tags.len());
 
         let embed = EmbedBuilder::new()
             .fields(fields)
@@ -405,7 +423,7 @@ impl BackgroundGameSetup {
             .title(title);
 
         if entries.tags.is_empty() {
-            let description = "No stored backgrounds match these tags, try different ones";
+            let description = "No stored mapsets match these tags, try different ones";
 
             Ok(BuildPage::new(embed.description(description), false))
         } else {
@@ -415,9 +433,10 @@ impl BackgroundGameSetup {
                 "Starting game"
             );
 
-            let game_fut = BackgroundGame::new(channel, entries, self.effects, self.difficulty);
+            let game_fut =
+                BackgroundGame::new(channel, entries, self.kind, self.effects, self.difficulty);
 
-            let game = game_fut.await;
+            let game = game_fut.await.wrap_err("Failed to start bg game")?;
             Context::bg_games().own(channel).await.insert(game);
 
             Ok(BuildPage::new(embed, false))
@@ -309,6 +309,22 @@ impl BookmarksPagination {
                     None => map.creator_name.as_ref(),
                 }
             );
+
+            let folder = map.folder.as_deref().map(CowUtils::cow_escape_markdown);
+            let note = map.note.as_deref().map(CowUtils::cow_escape_markdown);
+
+            match (folder, note) {
+                (Some(folder), Some(note)) => {
+                    let _ = writeln!(description, ":file_folder: **{folder}** • *{note}*");
+                }
+                (Some(folder), None) => {
+                    let _ = writeln!(description, ":file_folder: **{folder}**");
+                }
+                (None, Some(note)) => {
+                    let _ = writeln!(description, "*{note}*");
+                }
+                (None, None) => {}
+            }
         }
 
         let footer_text = format!(
@@ -402,7 +418,7 @@ impl BookmarksPagination {
 
         pp_values.push_str("\n```");
 
-        let mut fields = Vec::with_capacity(3);
+        let mut fields = Vec::with_capacity(5);
 
         let mut info_value = String::with_capacity(128);
 
@@ -465,6 +481,14 @@ impl BookmarksPagination {
             field_name, pp_values, false;
         }];
 
+        if let Some(ref folder) = map.folder {
+            fields![fields { "Folder", folder.cow_escape_markdown().into_owned(), true }];
+        }
+
+        if let Some(ref note) = map.note {
+            fields![fields { "Note", note.cow_escape_markdown().into_owned(), true }];
+        }
+
         let mut title = String::with_capacity(32);
 
         if map.mode == GameMode::Mania {
@@ -0,0 +1,150 @@
+use bathbot_util::{
+    EmbedBuilder,
+    constants::OSU_BASE,
+    numbers::{WithComma, round},
+};
+use eyre::{Result, WrapErr};
+use rand::Rng;
+use rosu_v2::prelude::GameMode;
+use twilight_model::channel::message::embed::EmbedField;
+
+use crate::{
+    active::impls::higherlower::state::{HigherLowerState, center_images},
+    core::Context,
+};
+
+pub(super) struct GlobalRank {
+    pub rank: u32,
+    pub avatar_url: Box<str>,
+    user_id: u32,
+    player_string: Box<str>,
+    acc: f32,
+    level: f32,
+    playcount: u32,
+    playtime: u32,
+}
+
+impl GlobalRank {
+    pub(super) async fn random(
+        mode: GameMode,
+        prev: Option<&Self>,
+        curr_score: u32,
+    ) -> Result<Self> {
+        let max_rank = 10_000 - (mode != GameMode::Osu) as u32 * 5000;
+
+        let rank = {
+            let mut rng = rand::thread_rng();
+
+            match prev {
+                // The higher the current score, the closer the ranks
+                Some(prev) => {
+                    let spread = (max_rank >> curr_score.min(8)).max(25);
+                    let min = prev.rank.saturating_sub(spread).max(1);
+                    let max = (prev.rank + spread).min(max_rank);
+
+                    rng.gen_range(min..=max)
+                }
+                None => rng.gen_range(1..=max_rank),
+            }
+        };
+
+        let page = ((rank - 1) / 50) + 1;
+        let idx = ((rank - 1) % 50) as usize;
+
+        let ranking = Context::redis()
+            .pp_ranking(mode, page, None)
+            .await
+            .wrap_err("Failed to get cached pp ranking")?;
+
+        let user = &ranking.ranking[idx];
+        let stats = user.statistics.as_ref();
+        let country_code = user.country_code.as_str().to_lowercase();
+        let user_id = user.user_id.to_native();
+
+        Ok(Self {
+            rank: stats
+                .map(|stats| stats.global_rank.to_native())
+                .filter(|&rank| rank > 0)
+                .unwrap_or(rank),
+            avatar_url: user.avatar_url.as_ref().into(),
+            user_id,
+            player_string: format!(
+                ":flag_{country_code}: [{username}]({OSU_BASE}u/{user_id})",
+                username = user.username.as_str(),
+            )
+            .into_boxed_str(),
+            acc: stats.map_or(0.0, |stats| round(stats.accuracy.to_native())),
+            level: stats.map_or(0.0, |stats| stats.level.float()),
+            playcount: stats.map_or(0, |stats| stats.playcount.to_native()),
+            playtime: stats.map_or(0, |stats| stats.playtime.to_native() / 60 / 60),
+        })
+    }
+
+    pub(super) async fn image(pfp1: &str, pfp2: &str) -> Result<String> {
+        let client = Context::client();
+
+        let (pfp_left, pfp_right) =
+            tokio::try_join!(client.get_avatar(pfp1), client.get_avatar(pfp2))
+                .wrap_err("Failed to retrieve some image")?;
+
+        let pfp_left =
+            image::load_from_memory(&pfp_left).wrap_err("Failed to load pfp1 from memory")?;
+
+        let pfp_right =
+            image::load_from_memory(&pfp_right).wrap_err("Failed to load pfp2 from memory")?;
+
+        let blipped = center_images(&pfp_left, &pfp_right);
+        let content = format!("{pfp1} ~ {pfp2}");
+
+        HigherLowerState::upload_image(blipped.as_raw(), content).await
+    }
+
+    pub(super) fn stats_string(&self, rank_visible: bool) -> String {
+        format!(
+            "Accuracy: `{acc}%` • Level: `{level:.2}`\n\
+            Playcount: `{playcount}` (`{playtime} hrs`)\n\
+            **Global rank: #{rank}**",
+            acc = self.acc,
+            level = self.level,
+            playcount = WithComma::new(self.playcount),
+            playtime = self.playtime,
+            rank = if rank_visible {
+                WithComma::new(self.rank).to_string()
+            } else {
+                "???".to_owned()
+            }
+        )
+    }
+
+    pub(super) fn log(game1: &Self, game2: &Self) {
+        debug!("#{} vs #{}", game1.rank, game2.rank);
+    }
+
+    pub(super) fn to_embed(previous: &Self, next: &Self, revealed: bool) -> EmbedBuilder {
+        let fields = vec![
+            EmbedField {
+                inline: false,
+                name: "__Previous:__".to_owned(),
+                value: format!(
+                    "{}\n{}",
+                    previous.player_string,
+                    previous.stats_string(true)
+                ),
+            },
+            EmbedField {
+                inline: false,
+                name: "__Next:__".to_owned(),
+                value: format!("{}\n{}", next.player_string, next.stats_string(revealed)),
+            },
+        ];
+
+        EmbedBuilder::new().fields(fields)
+    }
+}
+
+impl PartialEq for GlobalRank {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.user_id == other.user_id
+    }
+}
@@ -0,0 +1,118 @@
+use bathbot_util::{
+    CowUtils, EmbedBuilder,
+    constants::OSU_BASE,
+    numbers::{WithComma, round},
+};
+use eyre::{ContextCompat, Result, WrapErr};
+use rosu_v2::prelude::{BeatmapsetExtended, GameMode};
+use time::Date;
+use twilight_model::channel::message::embed::EmbedField;
+
+use crate::active::impls::higherlower::state::{pick_random, random_mapsets};
+
+pub(super) struct MapsetPlaycount {
+    pub playcount: u32,
+    pub mapset_id: u32,
+    creator: Box<str>,
+    mapset_string: Box<str>,
+    ranked_date: Option<Date>,
+    map_count: usize,
+    bpm: f32,
+}
+
+impl MapsetPlaycount {
+    pub(super) async fn random(
+        mode: GameMode,
+        prev: Option<&Self>,
+        curr_score: u32,
+    ) -> Result<Self> {
+        let mut mapsets = random_mapsets(mode)
+            .await
+            .wrap_err("Failed to get random mapsets")?;
+
+        if let Some(prev) = prev {
+            mapsets.retain(|mapset| mapset.mapset_id != prev.mapset_id);
+        }
+
+        // Playcounts differ by orders of magnitude so compare them logarithmically
+        let prev_playcount = prev.map_or(0.0, |prev| f64::from(prev.playcount.max(1)).ln());
+
+        let distance = |mapset: &BeatmapsetExtended| {
+            prev.map_or(0.0, |_| {
+                (f64::from(mapset.playcount.max(1)).ln() - prev_playcount).abs()
+            })
+        };
+
+        let mapset = pick_random(mapsets, curr_score, distance).wrap_err("No mapsets found")?;
+
+        Ok(Self::new(mapset))
+    }
+
+    pub(super) fn mapset_string(&self, playcount_visible: bool) -> String {
+        let ranked_date = match self.ranked_date {
+            Some(date) => format!("Ranked: `{date}` • "),
+            None => String::new(),
+        };
+
+        format!(
+            "{mapset}\n\
+            {ranked_date}`{map_count}` difficult{plural} • BPM: `{bpm}`\n\
+            **Playcount: {playcount}**",
+            mapset = self.mapset_string,
+            map_count = self.map_count,
+            plural = if self.map_count == 1 { "y" } else { "ies" },
+            bpm = round(self.bpm),
+            playcount = if playcount_visible {
+                WithComma::new(self.playcount).to_string()
+            } else {
+                "???".to_owned()
+            }
+        )
+    }
+
+    pub(super) fn log(game1: &Self, game2: &Self) {
+        debug!("{} plays vs {} plays", game1.playcount, game2.playcount);
+    }
+
+    pub(super) fn to_embed(previous: &Self, next: &Self, revealed: bool) -> EmbedBuilder {
+        let fields = vec![
+            EmbedField {
+                inline: false,
+                name: format!("__Previous:__ Mapset by {}", previous.creator),
+                value: previous.mapset_string(true),
+            },
+            EmbedField {
+                inline: false,
+                name: format!("__Next:__ Mapset by {}", next.creator),
+                value: next.mapset_string(revealed),
+            },
+        ];
+
+        EmbedBuilder::new().fields(fields)
+    }
+
+    fn new(mapset: BeatmapsetExtended) -> Self {
+        Self {
+            playcount: mapset.playcount,
+            mapset_id: mapset.mapset_id,
+            creator: mapset.creator_name.as_str().into(),
+            mapset_string: format!(
+                "**[{artist} - {title}]({OSU_BASE}s/{mapset_id})**",
+                artist = mapset.artist.cow_escape_markdown(),
+                title = mapset.title.cow_escape_markdown(),
+                mapset_id = mapset.mapset_id,
+            )
+            .into_boxed_str(),
+            ranked_date: mapset.ranked_date.map(|datetime| datetime.date()),
+            map_count: mapset.maps.as_ref().map_or(0, Vec::len),
+            bpm: mapset.bpm,
+        }
+    }
+}
+
+impl PartialEq for MapsetPlaycount {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.mapset_id == other.mapset_id
+    }
+}
@@ -0,0 +1,110 @@
+use bathbot_model::ArchivedOsekaiRarityEntry;
+use bathbot_util::{EmbedBuilder, numbers::WithComma};
+use eyre::{ContextCompat, Result, WrapErr};
+use twilight_model::channel::message::embed::EmbedField;
+
+use crate::{
+    active::impls::higherlower::state::{HigherLowerState, center_images, pick_random},
+    core::Context,
+};
+
+pub(super) struct MedalRarity {
+    pub frequency: f64,
+    pub medal_id: u32,
+    name: Box<str>,
+    description: Box<str>,
+    count_achieved_by: u32,
+}
+
+impl MedalRarity {
+    pub(super) async fn random(prev: Option<&Self>, curr_score: u32) -> Result<Self> {
+        let rarities = Context::redis()
+            .osekai_rarity()
+            .await
+            .wrap_err("Failed to get cached rarity ranking")?;
+
+        let entries: Vec<_> = rarities
+            .iter()
+            .filter(|entry| {
+                prev.is_none_or(|prev| prev.medal_id != u32::from(entry.medal_id.to_native()))
+            })
+            .collect();
+
+        let prev_frequency = prev.map_or(0.0, |prev| prev.frequency);
+
+        let distance = |entry: &&ArchivedOsekaiRarityEntry| {
+            prev.map_or(0.0, |_| {
+                (entry.frequency.to_native() - prev_frequency).abs()
+            })
+        };
+
+        let entry = pick_random(entries, curr_score, distance).wrap_err("No medals found")?;
+
+        Ok(Self {
+            frequency: entry.frequency.to_native(),
+            medal_id: u32::from(entry.medal_id.to_native()),
+            name: entry.medal_name.as_ref().into(),
+            description: entry.description.as_ref().into(),
+            count_achieved_by: entry.count_achieved_by.to_native(),
+        })
+    }
+
+    pub(super) async fn image(medal_id1: u32, medal_id2: u32) -> Result<String> {
+        let icons = Context::redis()
+            .medal_icons(&[medal_id1, medal_id2])
+            .await
+            .wrap_err("Failed to get medal icons")?;
+
+        // The order of the icons is not guaranteed
+        let icon = |medal_id| {
+            let (_, bytes) = icons
+                .iter()
+                .find(|(id, _)| *id == medal_id)
+                .wrap_err("Missing medal icon")?;
+
+            image::load_from_memory(bytes).wrap_err("Failed to load medal icon from memory")
+        };
+
+        let icon_left = icon(medal_id1)?;
+        let icon_right = icon(medal_id2)?;
+
+        let blipped = center_images(&icon_left, &icon_right);
+        let content = format!("{medal_id1} ~ {medal_id2}");
+
+        HigherLowerState::upload_image(blipped.as_raw(), content).await
+    }
+
+    pub(super) fn medal_string(&self, rarity_visible: bool) -> String {
+        if rarity_visible {
+            format!(
+                "{description}\n**Rarity: {frequency:.2}%** (achieved by {count} players)",
+                description = self.description,
+                frequency = self.frequency,
+                count = WithComma::new(self.count_achieved_by),
+            )
+        } else {
+            format!("{}\n**Rarity: ???**", self.description)
+        }
+    }
+
+    pub(super) fn log(game1: &Self, game2: &Self) {
+        debug!("{}% vs {}%", game1.frequency, game2.frequency);
+    }
+
+    pub(super) fn to_embed(previous: &Self, next: &Self, revealed: bool) -> EmbedBuilder {
+        let fields = vec![
+            EmbedField {
+                inline: false,
+                name: format!("__Previous:__ {}", previous.name),
+                value: previous.medal_string(true),
+            },
+            EmbedField {
+                inline: false,
+                name: format!("__Next:__ {}", next.name),
+                value: next.medal_string(revealed),
+            },
+        ];
+
+        EmbedBuilder::new().fields(fields)
+    }
+}
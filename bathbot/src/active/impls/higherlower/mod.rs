@@ -25,7 +25,11 @@ use crate::{
     util::{ComponentExt, Emote, interaction::InteractionComponent},
 };
 
+mod global_rank;
+mod mapset_playcount;
+mod medal_rarity;
 mod score_pp;
+mod star_rating;
 mod state;
 
 pub struct HigherLowerGame {
@@ -189,9 +193,23 @@ impl IActiveMessage for HigherLowerGame {
 }
 
 impl HigherLowerGame {
-    pub async fn new_score_pp(mode: GameMode, msg_owner: Id<UserMarker>) -> Result<Self> {
-        let game_fut = HigherLowerState::start_score_pp(mode);
-        let highscore_fut = Context::games().higherlower_highscore(msg_owner, HlVersion::ScorePp);
+    /// The mode is ignored for [`HlVersion::MedalRarity`].
+    pub async fn new(
+        version: HlVersion,
+        mode: GameMode,
+        msg_owner: Id<UserMarker>,
+    ) -> Result<Self> {
+        let game_fut = async {
+            match version {
+                HlVersion::ScorePp => HigherLowerState::start_score_pp(mode).await,
+                HlVersion::GlobalRank => HigherLowerState::start_global_rank(mode).await,
+                HlVersion::StarRating => HigherLowerState::start_star_rating(mode).await,
+                HlVersion::MapsetPlaycount => HigherLowerState::start_mapset_playcount(mode).await,
+                HlVersion::MedalRarity => HigherLowerState::start_medal_rarity().await,
+            }
+        };
+
+        let highscore_fut = Context::games().higherlower_highscore(msg_owner, version);
 
         let ((state, rx), highscore) = tokio::try_join!(game_fut, highscore_fut)?;
 
@@ -7,20 +7,19 @@ use bathbot_util::{
     numbers::{WithComma, round},
 };
 use eyre::{Result, WrapErr};
-use image::{GenericImageView, ImageBuffer};
 use rand::Rng;
 use rosu_v2::prelude::{CountryCode, GameMode, GameMods, Grade, Score, User as UserRosu, Username};
 use twilight_model::channel::message::embed::EmbedField;
 
 use crate::{
-    active::impls::higherlower::state::{H, HigherLowerState, W, mapset_cover},
+    active::impls::higherlower::state::{
+        HigherLowerState, W, mapset_cover, overlay, stitch_backgrounds,
+    },
     core::Context,
     manager::OsuMapSlim,
     util::{Emote, osu::grade_emote},
 };
 
-const ALPHA_THRESHOLD: u8 = 20;
-
 pub(super) struct ScorePp {
     pub pp: f32,
     pub avatar_url: Box<str>,
@@ -141,30 +140,9 @@ impl ScorePp {
             image::load_from_memory(&bg_right).wrap_err("failed to load right bg from memory")?;
 
         // Combine the images
-        let mut blipped = ImageBuffer::new(W, H);
-
-        let iter = blipped
-            .enumerate_pixels_mut()
-            .zip(bg_left.pixels())
-            .zip(bg_right.pixels());
-
-        for (((x, _, pixel), (.., left)), (.., right)) in iter {
-            *pixel = if x <= W / 2 { left } else { right };
-        }
-
-        for (x, y, pixel) in pfp_left.pixels() {
-            if pixel.0[3] > ALPHA_THRESHOLD {
-                blipped.put_pixel(x, y, pixel);
-            }
-        }
-
-        let pfp_right_width = pfp_right.width();
-
-        for (x, y, pixel) in pfp_right.pixels() {
-            if pixel.0[3] > ALPHA_THRESHOLD {
-                blipped.put_pixel(W - pfp_right_width + x, y, pixel);
-            }
-        }
+        let mut blipped = stitch_backgrounds(bg_left, bg_right);
+        overlay(&mut blipped, &pfp_left, 0, 0);
+        overlay(&mut blipped, &pfp_right, W - pfp_right.width(), 0);
 
         const ID_START_IDX: usize = 17; // "https://a.ppy.sh/{user_id}?{hash}.png"
 
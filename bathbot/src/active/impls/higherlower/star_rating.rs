@@ -0,0 +1,131 @@
+use std::fmt::Display;
+
+use bathbot_util::{
+    CowUtils, EmbedBuilder, constants::OSU_BASE, datetime::SecToMinSec, numbers::round,
+};
+use eyre::{ContextCompat, Result, WrapErr};
+use rosu_v2::prelude::{BeatmapExtended, BeatmapsetExtended, GameMode};
+use twilight_model::channel::message::embed::EmbedField;
+
+use crate::active::impls::higherlower::state::{pick_random, random_mapsets};
+
+pub(super) struct StarRating {
+    pub stars: f32,
+    pub mapset_id: u32,
+    map_id: u32,
+    creator: Box<str>,
+    map_string: Box<str>,
+    seconds_drain: u32,
+    bpm: f32,
+    ar: f32,
+    cs: f32,
+    hp: f32,
+    od: f32,
+}
+
+impl StarRating {
+    pub(super) async fn random(
+        mode: GameMode,
+        prev: Option<&Self>,
+        curr_score: u32,
+    ) -> Result<Self> {
+        let mapsets = random_mapsets(mode)
+            .await
+            .wrap_err("Failed to get random mapsets")?;
+
+        let maps: Vec<_> = mapsets
+            .iter()
+            .flat_map(|mapset| {
+                mapset
+                    .maps
+                    .iter()
+                    .flatten()
+                    .filter(|map| map.mode == mode)
+                    .map(move |map| (mapset, map))
+            })
+            .filter(|(_, map)| prev.is_none_or(|prev| prev.map_id != map.map_id))
+            .collect();
+
+        let prev_stars = prev.map_or(0.0, |prev| prev.stars);
+
+        let distance = |(_, map): &(&BeatmapsetExtended, &BeatmapExtended)| {
+            prev.map_or(0.0, |_| (map.stars - prev_stars).abs() as f64)
+        };
+
+        let (mapset, map) = pick_random(maps, curr_score, distance).wrap_err("No maps found")?;
+
+        Ok(Self::new(mapset, map))
+    }
+
+    pub(super) fn map_string(&self, stars_visible: bool) -> String {
+        format!(
+            "{map}\n\
+            Length: `{len}` • BPM: `{bpm}`\n\
+            `AR: {ar} • OD: {od} • CS: {cs} • HP: {hp}`\n\
+            **Star rating: {stars}★**",
+            map = self.map_string,
+            len = SecToMinSec::new(self.seconds_drain),
+            bpm = round(self.bpm),
+            ar = round(self.ar),
+            od = round(self.od),
+            cs = round(self.cs),
+            hp = round(self.hp),
+            stars = if stars_visible {
+                &self.stars as &dyn Display
+            } else {
+                &"???" as &dyn Display
+            }
+        )
+    }
+
+    pub(super) fn log(game1: &Self, game2: &Self) {
+        debug!("{}★ vs {}★", game1.stars, game2.stars);
+    }
+
+    pub(super) fn to_embed(previous: &Self, next: &Self, revealed: bool) -> EmbedBuilder {
+        let fields = vec![
+            EmbedField {
+                inline: false,
+                name: format!("__Previous:__ Mapset by {}", previous.creator),
+                value: previous.map_string(true),
+            },
+            EmbedField {
+                inline: false,
+                name: format!("__Next:__ Mapset by {}", next.creator),
+                value: next.map_string(revealed),
+            },
+        ];
+
+        EmbedBuilder::new().fields(fields)
+    }
+
+    fn new(mapset: &BeatmapsetExtended, map: &BeatmapExtended) -> Self {
+        Self {
+            stars: round(map.stars),
+            mapset_id: mapset.mapset_id,
+            map_id: map.map_id,
+            creator: mapset.creator_name.as_str().into(),
+            map_string: format!(
+                "**[{artist} - {title} [{version}]]({OSU_BASE}b/{map_id})**",
+                artist = mapset.artist.cow_escape_markdown(),
+                title = mapset.title.cow_escape_markdown(),
+                version = map.version.cow_escape_markdown(),
+                map_id = map.map_id,
+            )
+            .into_boxed_str(),
+            seconds_drain: map.seconds_drain,
+            bpm: map.bpm,
+            ar: map.ar,
+            cs: map.cs,
+            hp: map.hp,
+            od: map.od,
+        }
+    }
+}
+
+impl PartialEq for StarRating {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.map_id == other.map_id
+    }
+}
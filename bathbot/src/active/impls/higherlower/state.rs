@@ -1,18 +1,35 @@
-use std::mem;
+use std::{future::Future, mem};
 
 use bathbot_model::HlVersion;
 use bathbot_util::{EmbedBuilder, MessageBuilder};
-use eyre::{ContextCompat, Result, WrapErr};
-use image::{ColorType, ImageEncoder, codecs::png::PngEncoder};
-use rosu_v2::prelude::GameMode;
+use eyre::{ContextCompat, Result, WrapErr, bail};
+use image::{
+    ColorType, DynamicImage, GenericImageView, ImageBuffer, ImageEncoder, Rgba, RgbaImage,
+    codecs::png::PngEncoder, imageops::FilterType,
+};
+use rand::Rng;
+use rosu_v2::prelude::{BeatmapsetExtended, BeatmapsetSearchSort, GameMode, RankStatus};
+use time::OffsetDateTime;
 use tokio::sync::oneshot::{self, Receiver};
 
-use super::{HlGuess, score_pp::ScorePp};
-use crate::{core::BotConfig, util::ChannelExt};
+use super::{
+    HlGuess, global_rank::GlobalRank, mapset_playcount::MapsetPlaycount, medal_rarity::MedalRarity,
+    score_pp::ScorePp, star_rating::StarRating,
+};
+use crate::{
+    core::{BotConfig, Context},
+    util::ChannelExt,
+};
 
 pub(super) const W: u32 = 900;
 pub(super) const H: u32 = 250;
 
+const ALPHA_THRESHOLD: u8 = 20;
+
+/// How many random months are searched before giving up
+const MAX_MAPSET_SEARCHES: usize = 5;
+const BACKGROUND_COLOR: Rgba<u8> = Rgba([32, 34, 37, 255]);
+
 pub(super) enum ButtonState {
     HigherLower,
     Next {
@@ -33,6 +50,25 @@ pub(super) enum HigherLowerState {
         previous: ScorePp,
         next: ScorePp,
     },
+    GlobalRank {
+        mode: GameMode,
+        previous: GlobalRank,
+        next: GlobalRank,
+    },
+    StarRating {
+        mode: GameMode,
+        previous: StarRating,
+        next: StarRating,
+    },
+    MapsetPlaycount {
+        mode: GameMode,
+        previous: MapsetPlaycount,
+        next: MapsetPlaycount,
+    },
+    MedalRarity {
+        previous: MedalRarity,
+        next: MedalRarity,
+    },
 }
 
 impl HigherLowerState {
@@ -51,26 +87,44 @@ impl HigherLowerState {
 
         ScorePp::log(&previous, &next);
 
-        let (tx, rx) = oneshot::channel();
-
         let pfp1 = previous.avatar_url.as_ref();
         let pfp2 = next.avatar_url.as_ref();
 
         let mapset_id1 = previous.mapset_id;
         let mapset_id2 = next.mapset_id;
 
-        let url = match ScorePp::image(pfp1, pfp2, mapset_id1, mapset_id2).await {
-            Ok(url) => url,
-            Err(err) => {
-                warn!(?err, "Failed to create image");
+        let rx = await_image(ScorePp::image(pfp1, pfp2, mapset_id1, mapset_id2)).await;
 
-                String::new()
-            }
+        let inner = Self::ScorePp {
+            mode,
+            previous,
+            next,
         };
 
-        let _ = tx.send(url);
+        Ok((inner, rx))
+    }
 
-        let inner = Self::ScorePp {
+    pub(super) async fn start_global_rank(mode: GameMode) -> Result<(Self, Receiver<String>)> {
+        let (previous, mut next) = tokio::try_join!(
+            GlobalRank::random(mode, None, 0),
+            GlobalRank::random(mode, None, 0)
+        )
+        .wrap_err("Failed to create global rank entry")?;
+
+        while next == previous {
+            next = GlobalRank::random(mode, None, 0)
+                .await
+                .wrap_err("Failed to create global rank entry")?;
+        }
+
+        GlobalRank::log(&previous, &next);
+
+        let pfp1 = previous.avatar_url.as_ref();
+        let pfp2 = next.avatar_url.as_ref();
+
+        let rx = await_image(GlobalRank::image(pfp1, pfp2)).await;
+
+        let inner = Self::GlobalRank {
             mode,
             previous,
             next,
@@ -79,9 +133,83 @@ impl HigherLowerState {
         Ok((inner, rx))
     }
 
+    pub(super) async fn start_star_rating(mode: GameMode) -> Result<(Self, Receiver<String>)> {
+        let (previous, mut next) = tokio::try_join!(
+            StarRating::random(mode, None, 0),
+            StarRating::random(mode, None, 0)
+        )
+        .wrap_err("Failed to create star rating entry")?;
+
+        while next == previous {
+            next = StarRating::random(mode, None, 0)
+                .await
+                .wrap_err("Failed to create star rating entry")?;
+        }
+
+        StarRating::log(&previous, &next);
+
+        let rx = await_image(covers_image(previous.mapset_id, next.mapset_id)).await;
+
+        let inner = Self::StarRating {
+            mode,
+            previous,
+            next,
+        };
+
+        Ok((inner, rx))
+    }
+
+    pub(super) async fn start_mapset_playcount(mode: GameMode) -> Result<(Self, Receiver<String>)> {
+        let (previous, mut next) = tokio::try_join!(
+            MapsetPlaycount::random(mode, None, 0),
+            MapsetPlaycount::random(mode, None, 0)
+        )
+        .wrap_err("Failed to create mapset playcount entry")?;
+
+        while next == previous {
+            next = MapsetPlaycount::random(mode, None, 0)
+                .await
+                .wrap_err("Failed to create mapset playcount entry")?;
+        }
+
+        MapsetPlaycount::log(&previous, &next);
+
+        let rx = await_image(covers_image(previous.mapset_id, next.mapset_id)).await;
+
+        let inner = Self::MapsetPlaycount {
+            mode,
+            previous,
+            next,
+        };
+
+        Ok((inner, rx))
+    }
+
+    pub(super) async fn start_medal_rarity() -> Result<(Self, Receiver<String>)> {
+        let previous = MedalRarity::random(None, 0)
+            .await
+            .wrap_err("Failed to create medal rarity entry")?;
+
+        let next = MedalRarity::random(Some(&previous), 0)
+            .await
+            .wrap_err("Failed to create medal rarity entry")?;
+
+        MedalRarity::log(&previous, &next);
+
+        let rx = await_image(MedalRarity::image(previous.medal_id, next.medal_id)).await;
+
+        let inner = Self::MedalRarity { previous, next };
+
+        Ok((inner, rx))
+    }
+
     pub(super) async fn restart(&mut self) -> Result<(Self, Receiver<String>)> {
         match self {
             Self::ScorePp { mode, .. } => Self::start_score_pp(*mode).await,
+            Self::GlobalRank { mode, .. } => Self::start_global_rank(*mode).await,
+            Self::StarRating { mode, .. } => Self::start_star_rating(*mode).await,
+            Self::MapsetPlaycount { mode, .. } => Self::start_mapset_playcount(*mode).await,
+            Self::MedalRarity { .. } => Self::start_medal_rarity().await,
         }
     }
 
@@ -115,23 +243,91 @@ impl HigherLowerState {
                 let mapset_id1 = previous.mapset_id;
                 let mapset_id2 = next.mapset_id;
 
-                let (tx, rx) = oneshot::channel();
+                spawn_image(
+                    async move { ScorePp::image(&pfp1, &pfp2, mapset_id1, mapset_id2).await },
+                )
+            }
+            Self::GlobalRank {
+                mode,
+                previous,
+                next,
+            } => {
+                let mode = *mode;
+                mem::swap(previous, next);
 
-                // Create the image in the background so it's available when needed later
-                tokio::spawn(async move {
-                    let url = match ScorePp::image(&pfp1, &pfp2, mapset_id1, mapset_id2).await {
-                        Ok(url) => url,
-                        Err(err) => {
-                            warn!(?err, "Failed to create image");
+                *next = GlobalRank::random(mode, Some(&*previous), curr_score)
+                    .await
+                    .wrap_err("Failed to create global rank entry")?;
 
-                            String::new()
-                        }
-                    };
+                while previous == next {
+                    *next = GlobalRank::random(mode, Some(&*previous), curr_score)
+                        .await
+                        .wrap_err("Failed to create global rank entry")?;
+                }
+
+                GlobalRank::log(&*previous, &*next);
+
+                let pfp1 = mem::take(&mut previous.avatar_url);
+
+                // Clone this since it's needed in the next round
+                let pfp2 = next.avatar_url.clone();
+
+                spawn_image(async move { GlobalRank::image(&pfp1, &pfp2).await })
+            }
+            Self::StarRating {
+                mode,
+                previous,
+                next,
+            } => {
+                let mode = *mode;
+                mem::swap(previous, next);
+
+                *next = StarRating::random(mode, Some(&*previous), curr_score)
+                    .await
+                    .wrap_err("Failed to create star rating entry")?;
+
+                while previous == next {
+                    *next = StarRating::random(mode, Some(&*previous), curr_score)
+                        .await
+                        .wrap_err("Failed to create star rating entry")?;
+                }
 
-                    let _ = tx.send(url);
-                });
+                StarRating::log(&*previous, &*next);
 
-                rx
+                spawn_image(covers_image(previous.mapset_id, next.mapset_id))
+            }
+            Self::MapsetPlaycount {
+                mode,
+                previous,
+                next,
+            } => {
+                let mode = *mode;
+                mem::swap(previous, next);
+
+                *next = MapsetPlaycount::random(mode, Some(&*previous), curr_score)
+                    .await
+                    .wrap_err("Failed to create mapset playcount entry")?;
+
+                while previous == next {
+                    *next = MapsetPlaycount::random(mode, Some(&*previous), curr_score)
+                        .await
+                        .wrap_err("Failed to create mapset playcount entry")?;
+                }
+
+                MapsetPlaycount::log(&*previous, &*next);
+
+                spawn_image(covers_image(previous.mapset_id, next.mapset_id))
+            }
+            Self::MedalRarity { previous, next } => {
+                mem::swap(previous, next);
+
+                *next = MedalRarity::random(Some(&*previous), curr_score)
+                    .await
+                    .wrap_err("Failed to create medal rarity entry")?;
+
+                MedalRarity::log(&*previous, &*next);
+
+                spawn_image(MedalRarity::image(previous.medal_id, next.medal_id))
             }
         };
 
@@ -175,16 +371,45 @@ impl HigherLowerState {
                 next,
             } => {
                 title.push_str("Score PP");
-
-                match mode {
-                    GameMode::Osu => {}
-                    GameMode::Taiko => title.push_str(" (taiko)"),
-                    GameMode::Catch => title.push_str(" (ctb)"),
-                    GameMode::Mania => title.push_str(" (mania)"),
-                }
+                push_mode(&mut title, *mode);
 
                 ScorePp::to_embed(previous, next, revealed)
             }
+            HigherLowerState::GlobalRank {
+                mode,
+                previous,
+                next,
+            } => {
+                title.push_str("Global rank");
+                push_mode(&mut title, *mode);
+
+                GlobalRank::to_embed(previous, next, revealed)
+            }
+            HigherLowerState::StarRating {
+                mode,
+                previous,
+                next,
+            } => {
+                title.push_str("Star rating");
+                push_mode(&mut title, *mode);
+
+                StarRating::to_embed(previous, next, revealed)
+            }
+            HigherLowerState::MapsetPlaycount {
+                mode,
+                previous,
+                next,
+            } => {
+                title.push_str("Mapset playcount");
+                push_mode(&mut title, *mode);
+
+                MapsetPlaycount::to_embed(previous, next, revealed)
+            }
+            HigherLowerState::MedalRarity { previous, next } => {
+                title.push_str("Medal rarity");
+
+                MedalRarity::to_embed(previous, next, revealed)
+            }
         };
 
         builder.title(title)
@@ -196,16 +421,231 @@ impl HigherLowerState {
                 HlGuess::Higher => next.pp >= previous.pp,
                 HlGuess::Lower => next.pp <= previous.pp,
             },
+            // A higher rank means a smaller number
+            Self::GlobalRank { previous, next, .. } => match guess {
+                HlGuess::Higher => next.rank <= previous.rank,
+                HlGuess::Lower => next.rank >= previous.rank,
+            },
+            Self::StarRating { previous, next, .. } => match guess {
+                HlGuess::Higher => next.stars >= previous.stars,
+                HlGuess::Lower => next.stars <= previous.stars,
+            },
+            Self::MapsetPlaycount { previous, next, .. } => match guess {
+                HlGuess::Higher => next.playcount >= previous.playcount,
+                HlGuess::Lower => next.playcount <= previous.playcount,
+            },
+            // A higher rarity means fewer players achieved the medal
+            Self::MedalRarity { previous, next } => match guess {
+                HlGuess::Higher => next.frequency <= previous.frequency,
+                HlGuess::Lower => next.frequency >= previous.frequency,
+            },
         }
     }
 
     pub(super) fn version(&self) -> HlVersion {
         match self {
             Self::ScorePp { .. } => HlVersion::ScorePp,
+            Self::GlobalRank { .. } => HlVersion::GlobalRank,
+            Self::StarRating { .. } => HlVersion::StarRating,
+            Self::MapsetPlaycount { .. } => HlVersion::MapsetPlaycount,
+            Self::MedalRarity { .. } => HlVersion::MedalRarity,
         }
     }
 }
 
+fn push_mode(title: &mut String, mode: GameMode) {
+    match mode {
+        GameMode::Osu => {}
+        GameMode::Taiko => title.push_str(" (taiko)"),
+        GameMode::Catch => title.push_str(" (ctb)"),
+        GameMode::Mania => title.push_str(" (mania)"),
+    }
+}
+
+/// Await the image creation and pass the resulting url into a channel.
+async fn await_image(fut: impl Future<Output = Result<String>>) -> Receiver<String> {
+    let (tx, rx) = oneshot::channel();
+
+    let url = match fut.await {
+        Ok(url) => url,
+        Err(err) => {
+            warn!(?err, "Failed to create image");
+
+            String::new()
+        }
+    };
+
+    let _ = tx.send(url);
+
+    rx
+}
+
+/// Create the image in the background so it's available when needed later.
+fn spawn_image<F>(fut: F) -> Receiver<String>
+where
+    F: Future<Output = Result<String>> + Send + 'static,
+{
+    let (tx, rx) = oneshot::channel();
+
+    tokio::spawn(async move {
+        let url = match fut.await {
+            Ok(url) => url,
+            Err(err) => {
+                warn!(?err, "Failed to create image");
+
+                String::new()
+            }
+        };
+
+        let _ = tx.send(url);
+    });
+
+    rx
+}
+
 pub(super) fn mapset_cover(mapset_id: u32) -> String {
     format!("https://assets.ppy.sh/beatmaps/{mapset_id}/covers/cover.jpg")
 }
+
+/// Combines the left half of the left background with the right half of the
+/// right background. Backgrounds that aren't of size `W`x`H` will be resized.
+pub(super) fn stitch_backgrounds(bg_left: DynamicImage, bg_right: DynamicImage) -> RgbaImage {
+    let resize = |bg: DynamicImage| {
+        if bg.dimensions() == (W, H) {
+            bg
+        } else {
+            bg.resize_to_fill(W, H, FilterType::Triangle)
+        }
+    };
+
+    let bg_left = resize(bg_left);
+    let bg_right = resize(bg_right);
+
+    let mut blipped = ImageBuffer::new(W, H);
+
+    let iter = blipped
+        .enumerate_pixels_mut()
+        .zip(bg_left.pixels())
+        .zip(bg_right.pixels());
+
+    for (((x, _, pixel), (.., left)), (.., right)) in iter {
+        *pixel = if x <= W / 2 { left } else { right };
+    }
+
+    blipped
+}
+
+/// Places the two images centered on their half of a plain background.
+pub(super) fn center_images(left: &DynamicImage, right: &DynamicImage) -> RgbaImage {
+    const SIZE: u32 = H - 40;
+
+    let mut blipped = ImageBuffer::from_pixel(W, H, BACKGROUND_COLOR);
+
+    for (img, offset) in [(left, 0), (right, W / 2)] {
+        let img = img.resize(SIZE, SIZE, FilterType::Triangle);
+        let x = offset + (W / 2 - img.width()) / 2;
+        let y = (H - img.height()) / 2;
+
+        overlay(&mut blipped, &img, x, y);
+    }
+
+    blipped
+}
+
+/// Draws the image onto the canvas while skipping transparent pixels.
+pub(super) fn overlay(canvas: &mut RgbaImage, img: &DynamicImage, x: u32, y: u32) {
+    for (dx, dy, pixel) in img.pixels() {
+        if pixel.0[3] > ALPHA_THRESHOLD {
+            canvas.put_pixel(x + dx, y + dy, pixel);
+        }
+    }
+}
+
+/// Image consisting of the two mapsets' covers.
+pub(super) async fn covers_image(mapset_id1: u32, mapset_id2: u32) -> Result<String> {
+    let cover1 = mapset_cover(mapset_id1);
+    let cover2 = mapset_cover(mapset_id2);
+
+    let client = Context::client();
+
+    let (bg_left, bg_right) = tokio::try_join!(
+        client.get_mapset_cover(&cover1),
+        client.get_mapset_cover(&cover2),
+    )
+    .wrap_err("Failed to retrieve some image")?;
+
+    let bg_left =
+        image::load_from_memory(&bg_left).wrap_err("Failed to load left bg from memory")?;
+
+    let bg_right =
+        image::load_from_memory(&bg_right).wrap_err("Failed to load right bg from memory")?;
+
+    let blipped = stitch_backgrounds(bg_left, bg_right);
+    let content = format!("{mapset_id1} ~ {mapset_id2}");
+
+    HigherLowerState::upload_image(blipped.as_raw(), content).await
+}
+
+/// Ranked mapsets of a random month, sorted by playcount.
+pub(super) async fn random_mapsets(mode: GameMode) -> Result<Vec<BeatmapsetExtended>> {
+    // Years in which the mode already had a decent amount of ranked mapsets
+    let first_year = match mode {
+        GameMode::Osu => 2008,
+        GameMode::Taiko | GameMode::Catch => 2012,
+        GameMode::Mania => 2015,
+    };
+
+    let curr_year = OffsetDateTime::now_utc().year();
+
+    for _ in 0..MAX_MAPSET_SEARCHES {
+        let (year, month) = {
+            let mut rng = rand::thread_rng();
+
+            (rng.gen_range(first_year..curr_year), rng.gen_range(1..=12))
+        };
+
+        let (next_year, next_month) = if month == 12 {
+            (year + 1, 1)
+        } else {
+            (year, month + 1)
+        };
+
+        let query = format!("ranked>={year}-{month:02}-01 ranked<{next_year}-{next_month:02}-01");
+
+        let search_result = Context::osu()
+            .beatmapset_search()
+            .query(query)
+            .mode(mode)
+            .status(Some(RankStatus::Ranked))
+            .sort(BeatmapsetSearchSort::Playcount, true)
+            .await
+            .wrap_err("Failed to search mapsets")?;
+
+        // Make sure there's enough to choose from
+        if search_result.mapsets.len() >= 10 {
+            return Ok(search_result.mapsets);
+        }
+    }
+
+    bail!("Found no month with enough ranked mapsets after {MAX_MAPSET_SEARCHES} searches")
+}
+
+/// Picks a random item. The higher the current score, the more likely it is
+/// that the item is close to the previous one i.e. has a small distance.
+pub(super) fn pick_random<T>(
+    mut items: Vec<T>,
+    curr_score: u32,
+    distance: impl Fn(&T) -> f64,
+) -> Option<T> {
+    if items.is_empty() {
+        return None;
+    }
+
+    items.sort_unstable_by(|a, b| distance(a).total_cmp(&distance(b)));
+
+    let len = items.len();
+    let max = (len >> curr_score.min(16)).max(len.min(3));
+    let idx = rand::thread_rng().gen_range(0..max);
+
+    Some(items.swap_remove(idx))
+}
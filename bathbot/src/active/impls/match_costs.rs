@@ -1,4 +1,10 @@
-use std::{borrow::Cow, cmp, collections::HashMap, fmt::Write, time::Duration};
+use std::{
+    borrow::Cow,
+    cmp,
+    collections::HashMap,
+    fmt::{Display, Formatter, Result as FmtResult, Write},
+    time::Duration,
+};
 
 use bathbot_util::{
     EmbedBuilder, FooterBuilder,
@@ -17,7 +23,9 @@ use crate::{
         BuildPage, ComponentResult, IActiveMessage,
         pagination::{Pages, handle_pagination_component, handle_pagination_modal},
     },
-    commands::osu::{MatchCostDisplay, MatchResult, TeamResult, UserMatchCostEntry},
+    commands::osu::{
+        MatchCostDisplay, MatchCostFormula, MatchResult, TeamResult, UserMatchCostEntry,
+    },
     util::interaction::{InteractionComponent, InteractionModal},
 };
 
@@ -25,6 +33,7 @@ pub struct MatchCostPagination {
     result: MatchResult,
     osu_match: OsuMatch,
     display: MatchCostDisplay,
+    formula: MatchCostFormula,
     content: Box<str>,
     msg_owner: Id<UserMarker>,
     pages: Pages,
@@ -52,7 +61,7 @@ impl IActiveMessage for MatchCostPagination {
 
                 embed = match self.display {
                     MatchCostDisplay::Compact => embed.thumbnail(mvp_avatar_url.as_ref()),
-                    MatchCostDisplay::Full => embed.footer(FooterBuilder::new(FULL_FOOTER)),
+                    MatchCostDisplay::Full => embed.footer(self.full_footer()),
                 };
 
                 embed.description(description)
@@ -65,7 +74,7 @@ impl IActiveMessage for MatchCostPagination {
 
                 embed = match self.display {
                     MatchCostDisplay::Compact => embed.thumbnail(mvp_avatar_url.as_ref()),
-                    MatchCostDisplay::Full => embed.footer(FooterBuilder::new(FULL_FOOTER)),
+                    MatchCostDisplay::Full => embed.footer(self.full_footer()),
                 };
 
                 embed.description(description)
@@ -103,6 +112,7 @@ pub struct MatchCostPaginationBuilder {
     result: Option<MatchResult>,
     osu_match: Option<OsuMatch>,
     display: Option<MatchCostDisplay>,
+    formula: Option<MatchCostFormula>,
     content: Option<Box<str>>,
     msg_owner: Option<Id<UserMarker>>,
 }
@@ -112,6 +122,7 @@ impl MatchCostPaginationBuilder {
         let result = self.result.expect("missing result");
         let osu_match = self.osu_match.expect("missing osu match");
         let display = self.display.expect("missing display");
+        let formula = self.formula.unwrap_or_default();
         let content = self.content.expect("missing content");
         let msg_owner = self.msg_owner.expect("missing msg owner");
 
@@ -125,6 +136,7 @@ impl MatchCostPaginationBuilder {
             result,
             osu_match,
             display,
+            formula,
             content,
             msg_owner,
             pages,
@@ -149,6 +161,12 @@ impl MatchCostPaginationBuilder {
         self
     }
 
+    pub fn formula(mut self, formula: MatchCostFormula) -> Self {
+        self.formula = Some(formula);
+
+        self
+    }
+
     pub fn content(mut self, content: Box<str>) -> Self {
         self.content = Some(content);
 
@@ -167,6 +185,15 @@ impl MatchCostPagination {
         MatchCostPaginationBuilder::default()
     }
 
+    fn full_footer(&self) -> FooterBuilder {
+        let text = match self.formula {
+            MatchCostFormula::Default | MatchCostFormula::Classic => FULL_FOOTER,
+            MatchCostFormula::Average => FULL_FOOTER_AVERAGE,
+        };
+
+        FooterBuilder::new(text)
+    }
+
     fn description_team_vs(&self, blue: &TeamResult, red: &TeamResult) -> String {
         let mut description = String::new();
 
@@ -180,7 +207,7 @@ impl MatchCostPagination {
             :large_blue_diamond: {blue_bold}{blue_score}{blue_bold} \
             - \
             {red_bold}{red_score}{red_bold} :small_red_triangle:\n\n\
-            :large_blue_diamond: **Blue Team**",
+            :large_blue_diamond: **Blue Team**{blue_avg}",
             word = if self.osu_match.end_time.is_some() {
                 "Final"
             } else {
@@ -190,6 +217,7 @@ impl MatchCostPagination {
             red_score = red.win_count,
             blue_bold = bold(blue.win_count, red.win_count),
             red_bold = bold(red.win_count, blue.win_count),
+            blue_avg = TeamAverage(blue),
         );
 
         let lengths = Lengths::default()
@@ -209,7 +237,11 @@ impl MatchCostPagination {
                     1,
                 );
 
-                description.push_str("\n:small_red_triangle: **Red Team**\n");
+                let _ = writeln!(
+                    description,
+                    "\n:small_red_triangle: **Red Team**{}",
+                    TeamAverage(red)
+                );
 
                 fmt_compact(
                     &mut description,
@@ -229,7 +261,11 @@ impl MatchCostPagination {
                     1,
                 );
 
-                description.push_str("\n:small_red_triangle: **Red Team**\n");
+                let _ = writeln!(
+                    description,
+                    "\n:small_red_triangle: **Red Team**{}",
+                    TeamAverage(red)
+                );
 
                 fmt_full(
                     &mut description,
@@ -288,6 +324,21 @@ impl MatchCostPagination {
 const FULL_FOOTER: &str =
     "matchcost = (performance * participation * mods) + tiebreaker | average score";
 
+const FULL_FOOTER_AVERAGE: &str = "matchcost = average normalised score | average score";
+
+/// Displays the average match cost of a team if it has multiple players
+struct TeamAverage<'a>(&'a TeamResult);
+
+impl Display for TeamAverage<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        if self.0.players.len() > 1 {
+            write!(f, " (avg `{:0<4?}`)", round(self.0.avg_match_cost))
+        } else {
+            Ok(())
+        }
+    }
+}
+
 #[derive(Default)]
 struct Lengths {
     index: usize,
@@ -36,6 +36,7 @@ pub use self::{
     snipe::{SnipeCountryListPagination, SnipeDifferencePagination, SnipePlayerListPagination},
     top::TopPagination,
     top_if::TopIfPagination,
+    track_leaderboard_list::TrackLeaderboardListPagination,
     track_list::TrackListPagination,
 };
 
@@ -71,4 +72,5 @@ mod slash_commands;
 mod snipe;
 mod top;
 mod top_if;
+mod track_leaderboard_list;
 mod track_list;
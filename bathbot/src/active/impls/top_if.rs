@@ -60,6 +60,7 @@ impl IActiveMessage for TopIfPagination {
                 stars,
                 max_pp,
                 max_combo,
+                ..
             } = entry;
 
             let _ = writeln!(
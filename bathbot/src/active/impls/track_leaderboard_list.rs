@@ -0,0 +1,86 @@
+use std::fmt::Write;
+
+use bathbot_macros::PaginationBuilder;
+use bathbot_util::{EmbedBuilder, FooterBuilder, constants::OSU_BASE};
+use eyre::Result;
+use twilight_model::{
+    channel::message::Component,
+    id::{Id, marker::UserMarker},
+};
+
+use crate::{
+    active::{
+        BuildPage, ComponentResult, IActiveMessage,
+        pagination::{Pages, handle_pagination_component, handle_pagination_modal},
+    },
+    commands::tracking::TracklistLeaderboardEntry,
+    util::{
+        Emote,
+        interaction::{InteractionComponent, InteractionModal},
+    },
+};
+
+#[derive(PaginationBuilder)]
+pub struct TrackLeaderboardListPagination {
+    #[pagination(per_page = 15)]
+    entries: Box<[TracklistLeaderboardEntry]>,
+    msg_owner: Id<UserMarker>,
+    pages: Pages,
+}
+
+impl IActiveMessage for TrackLeaderboardListPagination {
+    async fn build_page(&mut self) -> Result<BuildPage> {
+        let pages = &self.pages;
+        let end_idx = self.entries.len().min(pages.index() + pages.per_page());
+        let entries = &self.entries[pages.index()..end_idx];
+
+        let mut description = String::with_capacity(entries.len() * 60);
+
+        for entry in entries {
+            let TracklistLeaderboardEntry {
+                name,
+                user_id,
+                mode,
+                params,
+            } = entry;
+
+            let _ = writeln!(
+                description,
+                "[`{name}`]({OSU_BASE}u/{user_id}) {mode}: `Global rank: 1-{max_rank}`",
+                mode = Emote::from(*mode),
+                max_rank = params.max_rank(),
+            );
+        }
+
+        if description.is_empty() {
+            description.push_str("None");
+        }
+
+        let page = pages.curr_page();
+        let pages = pages.last_page();
+
+        let footer_text = format!(
+            "Page {page}/{pages} • Total tracked: {}",
+            self.entries.len()
+        );
+
+        let embed = EmbedBuilder::new()
+            .description(description)
+            .footer(FooterBuilder::new(footer_text))
+            .title("Tracked osu! leaderboards in this channel:");
+
+        Ok(BuildPage::new(embed, false))
+    }
+
+    fn build_components(&self) -> Vec<Component> {
+        self.pages.components()
+    }
+
+    async fn handle_component(&mut self, component: &mut InteractionComponent) -> ComponentResult {
+        handle_pagination_component(component, self.msg_owner, false, &mut self.pages).await
+    }
+
+    async fn handle_modal(&mut self, modal: &mut InteractionModal) -> Result<()> {
+        handle_pagination_modal(modal, self.msg_owner, false, &mut self.pages).await
+    }
+}
@@ -42,9 +42,10 @@ impl IActiveMessage for TrackListPagination {
                 user_id,
                 mode,
                 params,
+                mapper,
             } = entry;
 
-            let _ = writeln!(
+            let _ = write!(
                 description,
                 "[`{name}`]({OSU_BASE}u/{user_id}) {mode}: \
                 `Index: {index}` • `PP: {pp}` • `Combo percent: {combo_percent}%`",
@@ -53,6 +54,9 @@ impl IActiveMessage for TrackListPagination {
                 pp = params.pp(),
                 combo_percent = params.combo_percent(),
             );
+
+            params.write_map_filters(&mut description, " • ", mapper.as_deref());
+            description.push('\n');
         }
 
         if description.is_empty() {
@@ -27,7 +27,7 @@ use self::{
         ScoreEmbedBuilderActive, SettingsImport, SimulateComponents, SingleScorePagination,
         SkinsPagination, SlashCommandsPagination, SnipeCountryListPagination,
         SnipeDifferencePagination, SnipePlayerListPagination, TopIfPagination, TopPagination,
-        TrackListPagination,
+        TrackLeaderboardListPagination, TrackListPagination,
     },
     response::ActiveResponse,
 };
@@ -93,6 +93,7 @@ pub enum ActiveMessage {
     SnipePlayerListPagination,
     TopPagination,
     TopIfPagination,
+    TrackLeaderboardListPagination,
     TrackListPagination,
 }
 
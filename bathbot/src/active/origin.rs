@@ -1,7 +1,7 @@
 use bathbot_util::{MessageBuilder, constants::CANNOT_DM_USER};
-use eyre::Report;
+use eyre::{Report, WrapErr};
 use twilight_http::{
-    Error, Response,
+    Error,
     api_error::{ApiError, GeneralApiError},
     error::ErrorType,
 };
@@ -24,17 +24,9 @@ impl ActiveMessageOrigin<'_> {
     pub(super) async fn create_message(
         &self,
         builder: MessageBuilder<'_>,
-    ) -> Result<Response<Message>, ActiveMessageOriginError> {
+    ) -> Result<Message, ActiveMessageOriginError> {
         match self {
-            Self::Channel(channel) => channel.create_message(builder, None).await.map_err(|err| {
-                if cannot_dm(&err) {
-                    ActiveMessageOriginError::CannotDmUser
-                } else {
-                    let wrap = "Failed to create message as response";
-
-                    Report::new(err).wrap_err(wrap).into()
-                }
-            }),
+            Self::Channel(channel) => create_channel_message(*channel, builder).await,
             Self::Command(orig) => orig
                 .create_message(builder)
                 .await
@@ -45,17 +37,9 @@ impl ActiveMessageOrigin<'_> {
     pub(super) async fn callback(
         &self,
         builder: MessageBuilder<'_>,
-    ) -> Result<Response<Message>, ActiveMessageOriginError> {
+    ) -> Result<Message, ActiveMessageOriginError> {
         match self {
-            Self::Channel(channel) => channel.create_message(builder, None).await.map_err(|err| {
-                if cannot_dm(&err) {
-                    ActiveMessageOriginError::CannotDmUser
-                } else {
-                    let wrap = "Failed to create message as response";
-
-                    Report::new(err).wrap_err(wrap).into()
-                }
-            }),
+            Self::Channel(channel) => create_channel_message(*channel, builder).await,
             Self::Command(orig) => orig
                 .callback_with_response(builder)
                 .await
@@ -88,6 +72,27 @@ impl<'d> From<&'d Message> for ActiveMessageOrigin<'d> {
     }
 }
 
+async fn create_channel_message(
+    channel: Id<ChannelMarker>,
+    builder: MessageBuilder<'_>,
+) -> Result<Message, ActiveMessageOriginError> {
+    let response = channel.create_message(builder, None).await.map_err(|err| {
+        if cannot_dm(&err) {
+            ActiveMessageOriginError::CannotDmUser
+        } else {
+            let wrap = "Failed to create message as response";
+
+            ActiveMessageOriginError::from(Report::new(err).wrap_err(wrap))
+        }
+    })?;
+
+    response
+        .model()
+        .await
+        .wrap_err("Failed to deserialize response")
+        .map_err(ActiveMessageOriginError::Report)
+}
+
 fn cannot_dm(err: &Error) -> bool {
     matches!(
         err.kind(),
@@ -30,15 +30,16 @@ pub async fn bigger(msg: &Message, permissions: Option<Permissions>) -> Result<(
     let _ = Context::http().create_typing_trigger(msg.channel_id).await;
 
     match Context::bg_games().read(&msg.channel_id).await.get() {
-        Some(game) => match game.sub_image().await {
-            Ok(bytes) => {
-                let builder = MessageBuilder::new().attachment("bg_img.png", bytes);
+        Some(game) => match game.increase_reveal().await {
+            Ok(attachment) => {
+                let builder =
+                    MessageBuilder::new().attachment(attachment.filename, attachment.bytes);
                 msg.create_message(builder, permissions).await?;
             }
             Err(err) => {
                 let _ = msg.error(GENERAL_ISSUE).await;
 
-                return Err(err.wrap_err("Failed to get subimage"));
+                return Err(err.wrap_err("Failed to increase reveal"));
             }
         },
         None => {
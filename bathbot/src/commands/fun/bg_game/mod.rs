@@ -1,7 +1,7 @@
 use std::ops::ControlFlow;
 
 use bathbot_macros::{SlashCommand, command};
-use bathbot_model::{Effects, command_fields::ThreadChannel};
+use bathbot_model::{BgGameKind, Effects, command_fields::ThreadChannel};
 use bathbot_psql::model::games::DbMapTagsParams;
 use bathbot_util::{
     Authored, CowUtils, MessageBuilder,
@@ -51,16 +51,19 @@ pub async fn prefix_backgroundgame(
         None | Some("help") => {
             let content = "Use `/bg` to start a new background guessing game.\n\
                 Given part of a map's background, try to guess the **title** of the map's song.\n\
+                With `/bg kind:Song` you'll be given a snippet of the map's audio preview instead.\n\
                 You don't need to guess content in parentheses `(...)` or content after `ft.` or `feat.`.\n\n\
                 Use these prefix commands to initiate with the game:\n\
                 • `<bg s[kip]` / `<bg r[esolve]`: Resolve the current background and \
                 give a new one with the same tag specs.\n\
                 • `<bg h[int]`: Receive a hint (can be used multiple times).\n\
-                • `<bg b[igger]`: Increase the radius of the displayed image (can be used multiple times).\n\
+                • `<bg b[igger]`: Increase the radius of the displayed image or \
+                the length of the audio snippet (can be used multiple times).\n\
                 • `<bg stop`: Resolve the current background and stop the game.
-                • `<bg l[eaderboard] s[erver]`: Check out the global leaderboard for \
-                amount of correct guesses. If `server` or `s` is added at the end, \
-                I will only show members of this server.";
+                • `<bg l[eaderboard] s[erver] song`: Check out the global leaderboard for \
+                amount of correct guesses. If `server` or `s` is added, \
+                I will only show members of this server. If `song` is added, \
+                I will show the leaderboard for song guesses.";
 
             let builder = MessageBuilder::new().embed(content);
             msg.create_message(builder, permissions).await?;
@@ -72,12 +75,18 @@ pub async fn prefix_backgroundgame(
         Some("b" | "bigger" | "enhance") => bigger(msg, permissions).await,
         Some("stop" | "end" | "quit") => stop(msg).await,
         Some("l" | "lb" | "leaderboard") => {
-            let arg = args.next();
-
-            match arg.as_ref().map(|arg| arg.as_ref()) {
-                Some("s" | "server") => leaderboard(msg, false).await,
-                _ => leaderboard(msg, true).await,
+            let mut global = true;
+            let mut kind = BgGameKind::Background;
+
+            for arg in args {
+                match arg.as_ref() {
+                    "s" | "server" => global = false,
+                    "song" | "songs" => kind = BgGameKind::Song,
+                    _ => {}
+                }
             }
+
+            leaderboard(msg, global, kind).await
         }
         _ => {
             let prefix = Context::guild_config().first_prefix(msg.guild_id).await;
@@ -98,21 +107,31 @@ pub async fn prefix_backgroundgame(
     desc = "Start a new background guessing game",
     help = "Start a new background guessing game.\n\
     Given part of a map's background, try to guess the **title** of the map's song.\n\
+    With `kind:Song` you'll be given a snippet of the map's audio preview instead.\n\
     You don't need to guess content in parentheses `(...)` or content after `ft.` or `feat.`.\n\n\
     Use these prefix commands to initiate with the game:\n\
     • `<bg s[kip]` / `<bg r[esolve]`: Resolve the current background and \
     give a new one with the same tag specs.\n\
     • `<bg h[int]`: Receive a hint (can be used multiple times).\n\
-    • `<bg b[igger]`: Increase the radius of the displayed image (can be used multiple times).\n\
+    • `<bg b[igger]`: Increase the radius of the displayed image or \
+    the length of the audio snippet (can be used multiple times).\n\
     • `<bg stop`: Resolve the current background and stop the game.
-    • `<bg l[eaderboard] s[erver]`: Check out the global leaderboard for \
-    amount of correct guesses. If `server` or `s` is added at the end, \
-    I will only show members of this server."
+    • `<bg l[eaderboard] s[erver] song`: Check out the global leaderboard for \
+    amount of correct guesses. If `server` or `s` is added, \
+    I will only show members of this server. If `song` is added, \
+    I will show the leaderboard for song guesses."
 )]
 #[flags(SKIP_DEFER)]
 pub struct Bg {
     #[command(desc = "Specify a gamemode")]
     mode: Option<BgGameMode>,
+    #[command(
+        desc = "Guess backgrounds or audio snippets, defaults to backgrounds",
+        help = "Choose whether you want to guess maps by their background or by \
+        a snippet of their audio preview.\n\
+        Both kinds have their own leaderboard."
+    )]
+    kind: Option<BgGameKind>,
     #[command(
         desc = "Increase difficulty by requiring better guessing",
         help = "Increase the difficulty.\n\
@@ -250,9 +269,12 @@ async fn slash_bg(mut command: InteractionCommand) -> Result<()> {
     let Bg {
         difficulty,
         mode,
+        kind,
         thread,
     } = Bg::from_interaction(command.input_data())?;
 
+    let kind = kind.unwrap_or_default();
+
     let can_view_channel = command
         .permissions
         .is_none_or(|permissions| permissions.contains(Permissions::VIEW_CHANNEL));
@@ -301,12 +323,15 @@ async fn slash_bg(mut command: InteractionCommand) -> Result<()> {
             return Ok(());
         }
 
-        let kind = ChannelType::PublicThread;
+        let channel_kind = ChannelType::PublicThread;
         let archive_dur = AutoArchiveDuration::Day;
-        let thread_name = format!("Background guessing game of {}", author_user.name);
+        let thread_name = match kind {
+            BgGameKind::Background => format!("Background guessing game of {}", author_user.name),
+            BgGameKind::Song => format!("Song guessing game of {}", author_user.name),
+        };
 
         let create_fut = Context::http()
-            .create_thread(channel, &thread_name, kind)
+            .create_thread(channel, &thread_name, channel_kind)
             .auto_archive_duration(archive_dur);
 
         match create_fut.await {
@@ -360,7 +385,7 @@ async fn slash_bg(mut command: InteractionCommand) -> Result<()> {
 
     match mode {
         Some(BgGameMode::Osu) | None => {
-            let setup = BackgroundGameSetup::new(difficulty, author);
+            let setup = BackgroundGameSetup::new(kind, difficulty, author);
 
             if matches!(thread, Some(ThreadChannel::Thread)) {
                 let res_builder = MessageBuilder::new().embed("Starting new thread...");
@@ -383,10 +408,16 @@ async fn slash_bg(mut command: InteractionCommand) -> Result<()> {
                 }
             };
 
-            let content = format!(
-                "Starting mania background guessing game with {} different backgrounds",
-                entries.tags.len()
-            );
+            let content = match kind {
+                BgGameKind::Background => format!(
+                    "Starting mania background guessing game with {} different backgrounds",
+                    entries.tags.len()
+                ),
+                BgGameKind::Song => format!(
+                    "Starting mania song guessing game with {} different songs",
+                    entries.tags.len()
+                ),
+            };
 
             let builder = MessageBuilder::new().embed(content);
 
@@ -399,12 +430,19 @@ async fn slash_bg(mut command: InteractionCommand) -> Result<()> {
                 command.callback(builder, false).await?;
             }
 
-            let game_fut = BackgroundGame::new(channel, entries, Effects::empty(), difficulty);
+            let game_fut =
+                BackgroundGame::new(channel, entries, kind, Effects::empty(), difficulty);
+
+            let game = match game_fut.await {
+                Ok(game) => game,
+                Err(err) => {
+                    let _ = channel.error(GENERAL_ISSUE).await;
+
+                    return Err(err.wrap_err("Failed to start bg game"));
+                }
+            };
 
-            Context::bg_games()
-                .own(channel)
-                .await
-                .insert(game_fut.await);
+            Context::bg_games().own(channel).await.insert(game);
 
             Ok(())
         }
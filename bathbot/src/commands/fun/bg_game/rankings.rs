@@ -3,7 +3,7 @@ use std::{
     collections::{BTreeMap, HashSet},
 };
 
-use bathbot_model::{RankingEntries, RankingEntry, RankingKind};
+use bathbot_model::{BgGameKind, RankingEntries, RankingEntry, RankingKind};
 use bathbot_util::{IntHasher, constants::GENERAL_ISSUE};
 use eyre::Result;
 use twilight_model::{channel::Message, id::Id};
@@ -14,10 +14,10 @@ use crate::{
     util::ChannelExt,
 };
 
-pub async fn leaderboard(msg: &Message, global: bool) -> Result<()> {
+pub async fn leaderboard(msg: &Message, global: bool, kind: BgGameKind) -> Result<()> {
     let cache = Context::cache();
 
-    let mut scores = match Context::games().bggame_leaderboard().await {
+    let mut scores = match Context::games().bggame_leaderboard(kind).await {
         Ok(scores) => scores,
         Err(err) => {
             let _ = msg.error(GENERAL_ISSUE).await;
@@ -84,7 +84,11 @@ pub async fn leaderboard(msg: &Message, global: bool) -> Result<()> {
     // Prepare initial page
     let total = scores.len();
     let global = guild.is_none() || global;
-    let data = RankingKind::BgScores { global, scores };
+    let data = RankingKind::BgScores {
+        global,
+        kind,
+        scores,
+    };
 
     let pagination = RankingPagination::builder()
         .entries(entries)
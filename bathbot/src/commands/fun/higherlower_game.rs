@@ -27,6 +27,14 @@ use crate::{
 pub enum HigherLower {
     #[command(name = "pp")]
     ScorePp(HigherLowerScorePp),
+    #[command(name = "rank")]
+    GlobalRank(HigherLowerGlobalRank),
+    #[command(name = "stars")]
+    StarRating(HigherLowerStarRating),
+    #[command(name = "playcount")]
+    MapsetPlaycount(HigherLowerMapsetPlaycount),
+    #[command(name = "medals")]
+    MedalRarity(HigherLowerMedalRarity),
     #[command(name = "leaderboard")]
     Leaderboard(HigherLowerLeaderboard),
 }
@@ -45,34 +53,98 @@ pub struct HigherLowerScorePp {
     mode: Option<GameModeOption>,
 }
 
+#[derive(CommandModel, CreateCommand)]
+#[command(
+    name = "rank",
+    desc = "Is the player's global rank higher or lower?",
+    help = "Is the player's global rank higher or lower?\n\
+    A higher rank means a rank closer to #1.\n\
+    The players are chosen randomly from the top 10,000 (top 5,000 for non-osu!std) \
+    but the higher the current score is, the closer the next player's rank will \
+    be to the previous rank."
+)]
+pub struct HigherLowerGlobalRank {
+    #[command(desc = "Specify a gamemode")]
+    mode: Option<GameModeOption>,
+}
+
+#[derive(CommandModel, CreateCommand)]
+#[command(
+    name = "stars",
+    desc = "Is the map's star rating higher or lower?",
+    help = "Is the map's star rating higher or lower?\n\
+    The maps are chosen randomly from ranked mapsets of a random month \
+    but the higher the current score is, the more likely it is that the \
+    next star rating is close to the previous one."
+)]
+pub struct HigherLowerStarRating {
+    #[command(desc = "Specify a gamemode")]
+    mode: Option<GameModeOption>,
+}
+
+#[derive(CommandModel, CreateCommand)]
+#[command(
+    name = "playcount",
+    desc = "Is the mapset's playcount higher or lower?",
+    help = "Is the mapset's playcount higher or lower?\n\
+    The mapsets are chosen randomly from ranked mapsets of a random month \
+    but the higher the current score is, the more likely it is that the \
+    next playcount is close to the previous one."
+)]
+pub struct HigherLowerMapsetPlaycount {
+    #[command(desc = "Specify a gamemode")]
+    mode: Option<GameModeOption>,
+}
+
+#[derive(CommandModel, CreateCommand)]
+#[command(
+    name = "medals",
+    desc = "Is the medal's rarity higher or lower?",
+    help = "Is the medal's rarity higher or lower?\n\
+    A higher rarity means that fewer players achieved the medal.\n\
+    Rarities are provided by [osekai](https://osekai.net/) and the higher \
+    the current score is, the more likely it is that the next rarity is close \
+    to the previous one."
+)]
+pub struct HigherLowerMedalRarity;
+
 #[derive(CommandModel, CreateCommand)]
 #[command(
     name = "leaderboard",
     desc = "Get the server leaderboard for higherlower highscores"
 )]
-pub struct HigherLowerLeaderboard;
+pub struct HigherLowerLeaderboard {
+    #[command(desc = "Specify the game version, defaults to score pp")]
+    version: Option<HlVersion>,
+}
 
 async fn slash_higherlower(mut command: InteractionCommand) -> Result<()> {
     let args = HigherLower::from_interaction(command.input_data())?;
     let user = command.user_id()?;
 
-    let game_res = match args {
-        HigherLower::ScorePp(args) => {
-            let mode = match args.mode.map(GameMode::from) {
-                Some(mode) => mode,
-                None => Context::user_config()
-                    .mode(user)
-                    .await?
-                    .unwrap_or(GameMode::Osu),
-            };
-
-            HigherLowerGame::new_score_pp(mode, user).await
-        }
-        HigherLower::Leaderboard(_) => {
-            return higherlower_leaderboard(command, HlVersion::ScorePp).await;
+    let (version, mode) = match args {
+        HigherLower::ScorePp(args) => (HlVersion::ScorePp, args.mode),
+        HigherLower::GlobalRank(args) => (HlVersion::GlobalRank, args.mode),
+        HigherLower::StarRating(args) => (HlVersion::StarRating, args.mode),
+        HigherLower::MapsetPlaycount(args) => (HlVersion::MapsetPlaycount, args.mode),
+        HigherLower::MedalRarity(_) => (HlVersion::MedalRarity, Some(GameModeOption::Osu)),
+        HigherLower::Leaderboard(args) => {
+            let version = args.version.unwrap_or(HlVersion::ScorePp);
+
+            return higherlower_leaderboard(command, version).await;
         }
     };
 
+    let mode = match mode.map(GameMode::from) {
+        Some(mode) => mode,
+        None => Context::user_config()
+            .mode(user)
+            .await?
+            .unwrap_or(GameMode::Osu),
+    };
+
+    let game_res = HigherLowerGame::new(version, mode, user).await;
+
     match game_res {
         Ok(game) => {
             ActiveMessages::builder(game)
@@ -1,15 +1,18 @@
-use std::{borrow::Cow, fmt::Write};
+use std::{borrow::Cow, collections::BTreeMap, fmt::Write};
 
 use bathbot_macros::{SlashCommand, command};
 use bathbot_model::command_fields::GameModeOption;
 use bathbot_psql::model::{configs::ListSize, osu::MapBookmark};
 use bathbot_util::{
-    CowUtils, MessageOrigin,
+    CowUtils, MessageBuilder, MessageOrigin,
+    collection_db::{Collection, write_collection_db},
     constants::GENERAL_ISSUE,
-    query::{BookmarkCriteria, FilterCriteria, IFilterCriteria},
+    matcher,
+    query::{BookmarkCriteria, FilterExpr, IFilterCriteria},
 };
-use eyre::Result;
+use eyre::{Report, Result};
 use rosu_v2::prelude::GameMode;
+use serde::Serialize;
 use twilight_interactions::command::{CommandModel, CommandOption, CreateCommand, CreateOption};
 use twilight_model::guild::Permissions;
 
@@ -22,6 +25,12 @@ use crate::{
     util::{InteractionCommandExt, interaction::InteractionCommand},
 };
 
+/// Collection name for bookmarks that are not in a folder
+const DEFAULT_COLLECTION: &str = "Bookmarks";
+
+const ERR_PARSE_MAP: &str =
+    "Failed to parse map url. Be sure you specify a valid map id or url to a map.";
+
 const BOOKMARKS_DESC: &str = "List all your bookmarked maps";
 const BOOKMARKS_HELP: &str = "List all your bookmarked maps. You can bookmark maps by:\n\
 1. Rightclicking a bot message that contains a single map\n\
@@ -29,17 +38,32 @@ const BOOKMARKS_HELP: &str = "List all your bookmarked maps. You can bookmark ma
 3. Click on `Bookmark map`.";
 
 #[derive(CreateCommand, CommandModel, SlashCommand)]
-#[command(name = "bookmarks", desc = BOOKMARKS_DESC, help = BOOKMARKS_HELP)]
+#[command(name = "bookmarks", desc = "Manage your bookmarked maps", help = BOOKMARKS_HELP)]
 #[flags(EPHEMERAL)]
-pub struct Bookmarks<'a> {
+pub enum Bookmarks<'a> {
+    #[command(name = "list")]
+    List(BookmarksList<'a>),
+    #[command(name = "folder")]
+    Folder(BookmarksFolder<'a>),
+    #[command(name = "note")]
+    Note(BookmarksNote<'a>),
+    #[command(name = "export")]
+    Export(BookmarksExport<'a>),
+}
+
+#[derive(CreateCommand, CommandModel)]
+#[command(name = "list", desc = BOOKMARKS_DESC, help = BOOKMARKS_HELP)]
+pub struct BookmarksList<'a> {
     #[command(desc = "Choose how the maps should be ordered")]
     sort: Option<BookmarksSort>,
     #[command(
         desc = "Specify a search query containing artist, AR, BPM, language, ...",
         help = "Filter out maps similarly as you filter maps in osu! itself.\n\
-        You can specify the artist, difficulty, title, language, genre or limit values for \
+        You can specify the artist, difficulty, title, language, genre, folder or limit values for \
         ar, cs, hp, od, bpm, length, bookmarked, or rankeddate.\n\
-        Example: `od>=9 od<9.5 len>180 difficulty=insane bookmarked<2020-12-31 genre=electronic`"
+        Example: `od>=9 od<9.5 len>180 difficulty=insane bookmarked<2020-12-31 genre=electronic`\n\
+        You can also filter by `status=loved` and combine terms with `OR`, negate them with \
+        `NOT` or `-`, and group them with parentheses e.g. `(genre=anime OR language=japanese) -camellia`"
     )]
     query: Option<Cow<'a, str>>,
     #[command(desc = "Filter out maps that don't belong to a gamemode")]
@@ -53,7 +77,58 @@ pub struct Bookmarks<'a> {
     size: Option<ListSize>,
 }
 
-impl<'a> Bookmarks<'a> {
+#[derive(CreateCommand, CommandModel)]
+#[command(name = "folder", desc = "Move a bookmarked map into a folder")]
+pub struct BookmarksFolder<'a> {
+    #[command(desc = "Specify a map url or map id")]
+    map: Cow<'a, str>,
+    #[command(
+        desc = "Name of the folder, leave empty to remove the map from its folder",
+        max_length = 32
+    )]
+    name: Option<Cow<'a, str>>,
+}
+
+#[derive(CreateCommand, CommandModel)]
+#[command(name = "note", desc = "Add a note to a bookmarked map")]
+pub struct BookmarksNote<'a> {
+    #[command(desc = "Specify a map url or map id")]
+    map: Cow<'a, str>,
+    #[command(
+        desc = "The note, leave empty to remove the current note",
+        max_length = 200
+    )]
+    note: Option<Cow<'a, str>>,
+}
+
+#[derive(CreateCommand, CommandModel)]
+#[command(
+    name = "export",
+    desc = "Export your bookmarks as osu! collections",
+    help = "Export your bookmarks as osu! collections.\n\
+    Each folder becomes its own collection and maps without folder are put into a `Bookmarks` collection.\n\
+    For osu!stable, replace the `collection.db` file in your osu! folder with the exported one; \
+    be sure to make a backup first since existing collections will be overwritten.\n\
+    For osu!lazer, the exported `collections.json` contains collection names and map hashes \
+    that can be imported with external tools."
+)]
+pub struct BookmarksExport<'a> {
+    #[command(desc = "Specify for which osu! client the collections should be exported")]
+    format: Option<BookmarksExportFormat>,
+    #[command(desc = "Only export the maps of this folder", max_length = 32)]
+    folder: Option<Cow<'a, str>>,
+}
+
+#[derive(Copy, Clone, CommandOption, CreateOption, Default)]
+pub enum BookmarksExportFormat {
+    #[option(name = "osu!stable (collection.db)", value = "stable")]
+    #[default]
+    Stable,
+    #[option(name = "osu!lazer (collections.json)", value = "lazer")]
+    Lazer,
+}
+
+impl<'a> BookmarksList<'a> {
     fn args(args: Args<'a>) -> Self {
         let query = args.rest();
         let query = (!query.is_empty()).then_some(Cow::Borrowed(query));
@@ -100,18 +175,24 @@ pub enum BookmarksSort {
 #[group(AllModes)]
 async fn prefix_bookmarks(msg: &Message, args: Args<'_>, perms: Option<Permissions>) -> Result<()> {
     let orig = CommandOrigin::from_msg(msg, perms);
-    let args = Bookmarks::args(args);
+    let args = BookmarksList::args(args);
 
-    bookmarks(orig, args).await
+    bookmarks_list(orig, args).await
 }
 
 pub async fn slash_bookmarks(mut command: InteractionCommand) -> Result<()> {
     let args = Bookmarks::from_interaction(command.input_data())?;
+    let orig = CommandOrigin::from(&mut command);
 
-    bookmarks((&mut command).into(), args).await
+    match args {
+        Bookmarks::List(args) => bookmarks_list(orig, args).await,
+        Bookmarks::Folder(args) => bookmarks_folder(orig, args).await,
+        Bookmarks::Note(args) => bookmarks_note(orig, args).await,
+        Bookmarks::Export(args) => bookmarks_export(orig, args).await,
+    }
 }
 
-async fn bookmarks(orig: CommandOrigin<'_>, args: Bookmarks<'_>) -> Result<()> {
+async fn bookmarks_list(orig: CommandOrigin<'_>, args: BookmarksList<'_>) -> Result<()> {
     let owner = orig.user_id()?;
 
     let config_fut = Context::user_config().with_osu_id(owner);
@@ -172,10 +253,204 @@ async fn bookmarks(orig: CommandOrigin<'_>, args: Bookmarks<'_>) -> Result<()> {
         .await
 }
 
+async fn bookmarks_folder(orig: CommandOrigin<'_>, args: BookmarksFolder<'_>) -> Result<()> {
+    let owner = orig.user_id()?;
+
+    let Some(map_id) = matcher::get_osu_map_id(&args.map) else {
+        return orig.error(ERR_PARSE_MAP).await;
+    };
+
+    let folder = args
+        .name
+        .as_deref()
+        .map(str::trim)
+        .filter(|name| !name.is_empty());
+
+    if folder.is_some_and(|folder| folder.eq_ignore_ascii_case(DEFAULT_COLLECTION)) {
+        let content = format!("`{DEFAULT_COLLECTION}` is reserved for maps without folder");
+
+        return orig.error(content).await;
+    }
+
+    let manager = Context::bookmarks();
+
+    // Reuse the name of an existing folder that only differs in casing
+    let folder = match folder {
+        Some(folder) => match manager.get(owner).await {
+            Ok(bookmarks) => Some(
+                bookmarks
+                    .iter()
+                    .filter_map(|bookmark| bookmark.folder.as_deref())
+                    .find(|existing| existing.eq_ignore_ascii_case(folder))
+                    .unwrap_or(folder)
+                    .to_owned(),
+            ),
+            Err(err) => {
+                let _ = orig.error(GENERAL_ISSUE).await;
+
+                return Err(err);
+            }
+        },
+        None => None,
+    };
+
+    let content = match manager.set_folder(owner, map_id, folder.as_deref()).await {
+        Ok(true) => match folder {
+            Some(folder) => format!(
+                "Moved map {map_id} into folder **{}**",
+                folder.cow_escape_markdown()
+            ),
+            None => format!("Removed map {map_id} from its folder"),
+        },
+        Ok(false) => return orig.error(not_bookmarked(map_id)).await,
+        Err(err) => {
+            let _ = orig.error(GENERAL_ISSUE).await;
+
+            return Err(err);
+        }
+    };
+
+    let builder = MessageBuilder::new().embed(content);
+    orig.create_message(builder).await?;
+
+    Ok(())
+}
+
+async fn bookmarks_note(orig: CommandOrigin<'_>, args: BookmarksNote<'_>) -> Result<()> {
+    let owner = orig.user_id()?;
+
+    let Some(map_id) = matcher::get_osu_map_id(&args.map) else {
+        return orig.error(ERR_PARSE_MAP).await;
+    };
+
+    let note = args
+        .note
+        .as_deref()
+        .map(str::trim)
+        .filter(|note| !note.is_empty());
+
+    let content = match Context::bookmarks().set_note(owner, map_id, note).await {
+        Ok(true) if note.is_some() => format!("Updated the note of map {map_id}"),
+        Ok(true) => format!("Removed the note of map {map_id}"),
+        Ok(false) => return orig.error(not_bookmarked(map_id)).await,
+        Err(err) => {
+            let _ = orig.error(GENERAL_ISSUE).await;
+
+            return Err(err);
+        }
+    };
+
+    let builder = MessageBuilder::new().embed(content);
+    orig.create_message(builder).await?;
+
+    Ok(())
+}
+
+async fn bookmarks_export(orig: CommandOrigin<'_>, args: BookmarksExport<'_>) -> Result<()> {
+    let owner = orig.user_id()?;
+
+    let bookmarks = match Context::bookmarks().get(owner).await {
+        Ok(bookmarks) => bookmarks,
+        Err(err) => {
+            let _ = orig.error(GENERAL_ISSUE).await;
+
+            return Err(err);
+        }
+    };
+
+    let folder = args
+        .folder
+        .as_deref()
+        .map(str::trim)
+        .filter(|name| !name.is_empty());
+
+    // Sorted by name so the export is deterministic
+    let mut collections = BTreeMap::<_, Vec<_>>::new();
+
+    for bookmark in bookmarks.iter() {
+        let name = bookmark.folder.as_deref().unwrap_or(DEFAULT_COLLECTION);
+
+        if folder.is_some_and(|folder| !folder.eq_ignore_ascii_case(name)) {
+            continue;
+        }
+
+        collections
+            .entry(name)
+            .or_default()
+            .push(bookmark.checksum.as_ref());
+    }
+
+    if collections.is_empty() {
+        let content = match folder {
+            Some(folder) => format!(
+                "You have no bookmarks in folder **{}**",
+                folder.cow_escape_markdown()
+            ),
+            None => "You have no bookmarks yet".to_owned(),
+        };
+
+        return orig.error(content).await;
+    }
+
+    let map_count: usize = collections.values().map(Vec::len).sum();
+
+    let (filename, bytes) = match args.format.unwrap_or_default() {
+        BookmarksExportFormat::Stable => {
+            let collections: Vec<_> = collections
+                .into_iter()
+                .map(|(name, checksums)| Collection { name, checksums })
+                .collect();
+
+            ("collection.db", write_collection_db(&collections))
+        }
+        BookmarksExportFormat::Lazer => {
+            let collections: Vec<_> = collections
+                .into_iter()
+                .map(|(name, checksums)| LazerCollection { name, checksums })
+                .collect();
+
+            match serde_json::to_vec_pretty(&collections) {
+                Ok(bytes) => ("collections.json", bytes),
+                Err(err) => {
+                    let _ = orig.error(GENERAL_ISSUE).await;
+
+                    return Err(Report::new(err).wrap_err("Failed to serialize collections"));
+                }
+            }
+        }
+    };
+
+    let content = format!(
+        "Exported {map_count} bookmark{}",
+        if map_count == 1 { "" } else { "s" }
+    );
+
+    let builder = MessageBuilder::new()
+        .embed(content)
+        .attachment(filename, bytes);
+
+    orig.create_message(builder).await?;
+
+    Ok(())
+}
+
+/// Collection entry as expected by osu!lazer import tools.
+#[derive(Serialize)]
+struct LazerCollection<'a> {
+    #[serde(rename = "Name")]
+    name: &'a str,
+    #[serde(rename = "BeatmapMD5Hashes")]
+    checksums: Vec<&'a str>,
+}
+
+fn not_bookmarked(map_id: u32) -> String {
+    format!("You did not bookmark map {map_id}")
+}
+
 fn process_bookmarks(
     bookmarks: &mut Vec<MapBookmark>,
-    args: &Bookmarks,
-    criteria: Option<&FilterCriteria<BookmarkCriteria<'_>>>,
+    args: &BookmarksList,
+    criteria: Option<&FilterExpr<BookmarkCriteria<'_>>>,
 ) {
     if let Some(mode) = args.mode.map(GameMode::from) {
         bookmarks.retain(|bookmark| bookmark.mode == mode);
@@ -183,50 +458,61 @@ fn process_bookmarks(
 
     if let Some(criteria) = criteria {
         bookmarks.retain(|bookmark| {
-            let mut matches = true;
-
-            matches &= criteria.ar.contains(bookmark.ar);
-            matches &= criteria.cs.contains(bookmark.cs);
-            matches &= criteria.hp.contains(bookmark.hp);
-            matches &= criteria.od.contains(bookmark.od);
-            matches &= criteria.length.contains(bookmark.seconds_drain as f32);
-            matches &= criteria.bpm.contains(bookmark.bpm);
-
-            matches &= criteria.insert_date.contains(bookmark.insert_date.date());
-            matches &= bookmark
-                .ranked_date
-                .is_some_and(|datetime| criteria.ranked_date.contains(datetime.date()));
-
-            let version = bookmark.version.cow_to_ascii_lowercase();
-            matches &= criteria.version.matches(&version);
-
-            let artist = bookmark.artist.cow_to_ascii_lowercase();
-            matches &= criteria.artist.matches(&artist);
-
-            let title = bookmark.title.cow_to_ascii_lowercase();
-            matches &= criteria.title.matches(&title);
-
-            let language = format!("{:?}", bookmark.language).to_lowercase();
-            matches &= criteria.language.matches(&language);
-
-            let genre = format!("{:?}", bookmark.genre).to_lowercase();
-            matches &= criteria.genre.matches(&genre);
-
-            if matches && criteria.has_search_terms() {
-                let terms = [
-                    artist.as_ref(),
-                    title.as_ref(),
-                    version.as_ref(),
-                    language.as_str(),
-                    genre.as_str(),
-                ];
-
-                matches &= criteria
-                    .search_terms()
-                    .all(|term| terms.iter().any(|searchable| searchable.contains(term)))
-            }
-
-            matches
+            criteria.evaluate(|criteria| {
+                let mut matches = true;
+
+                matches &= criteria.ar.contains(bookmark.ar);
+                matches &= criteria.cs.contains(bookmark.cs);
+                matches &= criteria.hp.contains(bookmark.hp);
+                matches &= criteria.od.contains(bookmark.od);
+                matches &= criteria.length.contains(bookmark.seconds_drain as f32);
+                matches &= criteria.bpm.contains(bookmark.bpm);
+
+                matches &= criteria.insert_date.contains(bookmark.insert_date.date());
+                matches &= criteria.ranked_date.is_empty()
+                    || bookmark
+                        .ranked_date
+                        .is_some_and(|datetime| criteria.ranked_date.contains(datetime.date()));
+                matches &= criteria.status.matches(bookmark.status);
+
+                let version = bookmark.version.cow_to_ascii_lowercase();
+                matches &= criteria.version.matches(&version);
+
+                let artist = bookmark.artist.cow_to_ascii_lowercase();
+                matches &= criteria.artist.matches(&artist);
+
+                let title = bookmark.title.cow_to_ascii_lowercase();
+                matches &= criteria.title.matches(&title);
+
+                let language = format!("{:?}", bookmark.language).to_lowercase();
+                matches &= criteria.language.matches(&language);
+
+                let genre = format!("{:?}", bookmark.genre).to_lowercase();
+                matches &= criteria.genre.matches(&genre);
+
+                let folder = bookmark
+                    .folder
+                    .as_deref()
+                    .unwrap_or_default()
+                    .cow_to_ascii_lowercase();
+                matches &= criteria.folder.matches(&folder);
+
+                if matches && criteria.has_search_terms() {
+                    let terms = [
+                        artist.as_ref(),
+                        title.as_ref(),
+                        version.as_ref(),
+                        language.as_str(),
+                        genre.as_str(),
+                    ];
+
+                    matches &= criteria
+                        .search_terms()
+                        .all(|term| terms.iter().any(|searchable| searchable.contains(term)))
+                }
+
+                matches
+            })
         });
     }
 
@@ -271,8 +557,8 @@ fn process_bookmarks(
 }
 
 fn msg_content(
-    args: &Bookmarks,
-    criteria: Option<&FilterCriteria<BookmarkCriteria<'_>>>,
+    args: &BookmarksList,
+    criteria: Option<&FilterExpr<BookmarkCriteria<'_>>>,
 ) -> String {
     let mut content = String::new();
 
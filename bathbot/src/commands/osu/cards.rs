@@ -1,6 +1,6 @@
 use std::{borrow::Cow, collections::HashMap};
 
-use bathbot_cards::{BathbotCard, RequiredAttributes};
+use bathbot_cards::{BathbotCard, CardLayout, CardTheme, ManiaPatterns, RequiredAttributes};
 use bathbot_macros::{HasName, SlashCommand, command};
 use bathbot_model::command_fields::GameModeOption;
 use bathbot_psql::model::configs::ScoreData;
@@ -13,7 +13,11 @@ use bathbot_util::{
 };
 use eyre::{Report, Result, WrapErr};
 use futures::{TryStreamExt, stream::FuturesUnordered};
-use rosu_v2::{model::GameMode, prelude::OsuError, request::UserId};
+use rosu_v2::{
+    model::GameMode,
+    prelude::{OsuError, Score, Username},
+    request::UserId,
+};
 use time::OffsetDateTime;
 use twilight_interactions::command::{CommandModel, CreateCommand};
 use twilight_model::id::{Id, marker::UserMarker};
@@ -34,6 +38,9 @@ Most skill values are based on the strain value of the official pp calculation.
 Only the accuracy values for [catch](https://www.desmos.com/calculator/cg59pywpry) \
 and [mania](https://www.desmos.com/calculator/b30p1awwft) come from custom formulas \
 that are based on score accuracy, map OD, object count, and star rating.\n\
+The strain value is split into rhythm, colour, and stamina for taiko based on the \
+map's difficulty attributes, and into chord, jack, and stream for mania based on \
+the map's note patterns. Catch precision depends on star rating, AR, and tiny droplets.\n\
 Note that only the user's top100 is considered while calculating card values.\n\
 Titles consist of three parts: **prefix**, **descriptions**, and **suffix**.\n\n\
 - The **prefix** is determined by checking the highest skill value \
@@ -60,7 +67,11 @@ for thresholds:\n\
 - none of above: `Versatile`\n  \
 - `<50 CL`: `New-Skool`\n  \
 - `>70 Key[X]`: `[X]K`\n  \
-- otherwise: `Multi-Key`\n\
+- otherwise: `Multi-Key`\n  \
+- high skills besides accuracy and the highest skill:\n    \
+- taiko: `Syncopated` / `Colour-Reading` / `Iron-Wristed`\n    \
+- catch: `Hyperdashing` / `Pixel-Perfect`\n    \
+- mania: `Chord-Smashing` / `Jack-Hammering` / `Stream-Rolling`\n\
 - The **suffix** is determined by checking proximity of skill \
 values to each other:\n  \
 - osu!:\n    \
@@ -73,8 +84,11 @@ values to each other:\n  \
 - Only high speed: `Masher`\n  \
 - taiko, catch, and mania:\n    \
 - All skills are roughly the same: `Gamer`\n    \
-- High accuracy but low strain: `Rhythm Enjoyer`\n    \
-- High strain but low accuracy: `Masher` / `Droplet Dodger`";
+- Only high accuracy: `Rhythm Enjoyer`\n    \
+- Otherwise the highest other skill:\n      \
+- taiko: `Metronome` / `Pattern Reader` / `Masher`\n      \
+- catch: `Droplet Dodger` / `Pixel Hunter`\n      \
+- mania: `Chordsmith` / `Jackhammer` / `Streamer`";
 
 #[derive(CommandModel, CreateCommand, SlashCommand, HasName)]
 #[command(name = "card", desc = "Create a user card", help = CARD_HELP)]
@@ -85,6 +99,18 @@ pub struct Card<'a> {
     name: Option<Cow<'a, str>>,
     #[command(desc = DISCORD_OPTION_DESC, help = DISCORD_OPTION_HELP)]
     discord: Option<Id<UserMarker>>,
+    #[command(
+        desc = "Specify a color theme",
+        help = "Specify a color theme.\n\
+        To change the default theme, use the `card_theme` option of `/config`."
+    )]
+    theme: Option<CardTheme>,
+    #[command(
+        desc = "Specify a layout",
+        help = "Specify a layout.\n\
+        To change the default layout, use the `card_layout` option of `/config`."
+    )]
+    layout: Option<CardLayout>,
 }
 
 impl<'m> Card<'m> {
@@ -104,6 +130,8 @@ impl<'m> Card<'m> {
             mode,
             name,
             discord,
+            theme: None,
+            layout: None,
         }
     }
 }
@@ -249,9 +277,16 @@ async fn card(orig: CommandOrigin<'_>, args: Card<'_>) -> Result<()> {
                 })
                 .to_owned();
 
+            let patterns = if mode == GameMode::Mania {
+                ManiaPatterns::new(&map)
+            } else {
+                None
+            };
+
             let attrs = RequiredAttributes {
                 difficulty,
                 od: map.od,
+                patterns,
             };
 
             Ok::<_, Report>((score.map_id, attrs))
@@ -288,7 +323,20 @@ async fn card(orig: CommandOrigin<'_>, args: Card<'_>) -> Result<()> {
         .format(DATE_FORMAT)
         .unwrap();
 
-    let card_res = BathbotCard::new(mode, &scores, maps, legacy_scores)
+    let theme = args.theme.or(config.card_theme).unwrap_or_default();
+    let layout = args.layout.or(config.card_layout).unwrap_or_default();
+
+    let show_stats = config.card_stats.unwrap_or(false);
+
+    let top_mapper = if show_stats {
+        top_mapper(&scores).await
+    } else {
+        None
+    };
+
+    let mut card = BathbotCard::new(mode, &scores, maps, legacy_scores);
+
+    let card = card
         .user(user.username.as_str(), stats.level.float())
         .ranks(
             stats.global_rank.to_native(),
@@ -298,7 +346,26 @@ async fn card(orig: CommandOrigin<'_>, args: Card<'_>) -> Result<()> {
         .bytes(&pfp, &flag)
         .date(&today)
         .assets(BotConfig::get().paths.assets.clone())
-        .draw();
+        .theme(theme)
+        .layout(layout);
+
+    if show_stats {
+        card.playcount(stats.playcount.to_native());
+
+        if let Some(pp) = scores
+            .iter()
+            .filter_map(|score| score.pp)
+            .max_by(f32::total_cmp)
+        {
+            card.top_pp(pp);
+        }
+
+        if let Some(ref mapper) = top_mapper {
+            card.top_mapper(mapper.as_str());
+        }
+    }
+
+    let card_res = card.draw();
 
     let bytes = match card_res {
         Ok(bytes) => bytes,
@@ -321,3 +388,35 @@ async fn card(orig: CommandOrigin<'_>, args: Card<'_>) -> Result<()> {
 
     Ok(())
 }
+
+/// Name of the mapper whose maps occur most often in the scores.
+async fn top_mapper(scores: &[Score]) -> Option<Username> {
+    let mut counts = HashMap::with_capacity_and_hasher(32, IntHasher);
+
+    for score in scores {
+        if let Some(ref map) = score.map {
+            *counts.entry(map.creator_id).or_insert(0_u8) += 1;
+        }
+    }
+
+    let (mapper_id, _) = counts.into_iter().max_by_key(|(_, count)| *count)?;
+
+    // Mapset creators come with their name already
+    let mapset_creator = scores
+        .iter()
+        .filter_map(|score| score.mapset.as_ref())
+        .find(|mapset| mapset.creator_id == mapper_id);
+
+    if let Some(mapset) = mapset_creator {
+        return Some(mapset.creator_name.clone());
+    }
+
+    match Context::osu_user().names(&[mapper_id as i32]).await {
+        Ok(mut names) => names.remove(&mapper_id),
+        Err(err) => {
+            warn!(?err, "Failed to get top mapper name");
+
+            None
+        }
+    }
+}
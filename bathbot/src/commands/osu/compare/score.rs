@@ -3,7 +3,7 @@ use std::{
     cmp::{Ordering, Reverse},
 };
 
-use bathbot_macros::{HasMods, HasName, SlashCommand, command};
+use bathbot_macros::{HasMods, HasName, SlashCommand, command, msg_command};
 use bathbot_model::{
     PersonalBestIndex, ScoreSlim,
     command_fields::{GameModeOption, GradeOption},
@@ -41,7 +41,10 @@ use crate::{
     },
     commands::{
         DISCORD_OPTION_DESC, DISCORD_OPTION_HELP,
-        osu::{HasMods, ModsResult, map_strains_graph, require_link, user_not_found},
+        osu::{
+            HasMods, ModsResult, map_strains_graph, require_link, target_map_or_score,
+            user_not_found,
+        },
         utility::{SCORE_DATA_DESC, SCORE_DATA_HELP, ScoreEmbedData, ScoreEmbedDataPersonalBest},
     },
     core::commands::{
@@ -428,6 +431,28 @@ pub async fn slash_compare(
     }
 }
 
+#[msg_command(name = "Compare score on map", bucket(All))]
+async fn msg_compare(mut command: InteractionCommand) -> Result<()> {
+    let Some(map) = target_map_or_score(&command).await? else {
+        return Ok(());
+    };
+
+    let args = CompareScoreArgs {
+        name: None,
+        map: Some(map),
+        difficulty: None,
+        mode: None,
+        sort: None,
+        mods: None,
+        discord: None,
+        index: None,
+        grade: None,
+        score_data: None,
+    };
+
+    score((&mut command).into(), args).await
+}
+
 pub(super) async fn score(orig: CommandOrigin<'_>, args: CompareScoreArgs<'_>) -> Result<()> {
     let owner = orig.user_id()?;
     let config = Context::user_config().with_osu_id(owner).await?;
@@ -0,0 +1,153 @@
+use std::fmt::Write;
+
+use bathbot_psql::model::osu::DbDailyChallengeResult;
+use bathbot_util::{
+    EmbedBuilder, FooterBuilder, MessageBuilder, constants::GENERAL_ISSUE, fields,
+    numbers::WithComma,
+};
+use eyre::{Report, Result};
+use rosu_v2::{error::OsuError, model::GameMode, request::UserId};
+
+use super::DailyChallengeHistory;
+use crate::{
+    commands::osu::{require_link, user_not_found},
+    core::{Context, commands::CommandOrigin},
+    manager::redis::osu::{UserArgs, UserArgsError},
+    util::CachedUserExt,
+};
+
+/// Amount of most recent results that are listed
+const RECENT_COUNT: usize = 10;
+
+pub(super) async fn history(
+    orig: CommandOrigin<'_>,
+    args: DailyChallengeHistory<'_>,
+) -> Result<()> {
+    let owner = orig.user_id()?;
+
+    let user_id = match user_id!(orig, args) {
+        Some(user_id) => user_id,
+        None => match Context::user_config().osu_id(owner).await {
+            Ok(Some(user_id)) => UserId::Id(user_id),
+            Ok(None) => return require_link(&orig).await,
+            Err(err) => {
+                let _ = orig.error(GENERAL_ISSUE).await;
+
+                return Err(err);
+            }
+        },
+    };
+
+    let user_args = UserArgs::rosu_id(&user_id, GameMode::Osu).await;
+
+    let user = match Context::redis().osu_user(user_args).await {
+        Ok(user) => user,
+        Err(UserArgsError::Osu(OsuError::NotFound)) => {
+            let content = user_not_found(user_id).await;
+
+            return orig.error(content).await;
+        }
+        Err(err) => {
+            let _ = orig.error(GENERAL_ISSUE).await;
+            let err = Report::new(err).wrap_err("Failed to get user");
+
+            return Err(err);
+        }
+    };
+
+    let results = match Context::psql()
+        .select_daily_challenge_history(user.user_id.to_native())
+        .await
+    {
+        Ok(results) => results,
+        Err(err) => {
+            let _ = orig.error(GENERAL_ISSUE).await;
+
+            return Err(err.wrap_err("Failed to get daily challenge history"));
+        }
+    };
+
+    if results.is_empty() {
+        let content = format!(
+            "No daily challenge results are stored for `{}`.\n\
+            Results are recorded for linked users that place on the daily \
+            challenge leaderboard or that are in a server with a daily \
+            challenge channel.",
+            user.username.as_str()
+        );
+
+        return orig.error(content).await;
+    }
+
+    let best_pos = results.iter().filter_map(|result| result.pos).min();
+
+    let (acc_sum, acc_count) = results
+        .iter()
+        .filter_map(|result| result.accuracy)
+        .fold((0.0, 0), |(sum, count), acc| (sum + acc, count + 1));
+
+    let mut description = String::with_capacity(RECENT_COUNT * 64);
+
+    for result in results.iter().rev().take(RECENT_COUNT) {
+        let DbDailyChallengeResult {
+            date,
+            total_score: Some(score),
+            accuracy: Some(acc),
+            attempts: Some(attempts),
+            pos: Some(pos),
+            ..
+        } = result
+        else {
+            let _ = writeln!(
+                description,
+                "`{}` Played, not on the leaderboard",
+                result.date
+            );
+
+            continue;
+        };
+
+        let _ = writeln!(
+            description,
+            "`{date}` **#{pos}** • {score} • {acc:.2}% • {attempts} attempt{plural}",
+            score = WithComma::new(*score),
+            plural = if *attempts != 1 { "s" } else { "" },
+        );
+    }
+
+    let daily = &user.daily_challenge;
+
+    let best_pos = match best_pos {
+        Some(pos) => format!("#{pos}"),
+        None => "-".to_owned(),
+    };
+
+    let avg_acc = if acc_count > 0 {
+        format!("{:.2}%", acc_sum / acc_count as f32)
+    } else {
+        "-".to_owned()
+    };
+
+    let fields = fields![
+        "Current streak", daily.daily_streak_current.to_string(), true;
+        "Best streak", daily.daily_streak_best.to_string(), true;
+        "Stored days", results.len().to_string(), true;
+        "Best position", best_pos, true;
+        "Average accuracy", avg_acc, true;
+    ];
+
+    let footer = FooterBuilder::new("Scores are only known for leaderboard placements");
+
+    let embed = EmbedBuilder::new()
+        .author(user.author_builder(false))
+        .description(description)
+        .fields(fields)
+        .footer(footer)
+        .thumbnail(user.avatar_url.as_ref())
+        .title("Daily challenge history");
+
+    let builder = MessageBuilder::new().embed(embed);
+    orig.create_message(builder).await?;
+
+    Ok(())
+}
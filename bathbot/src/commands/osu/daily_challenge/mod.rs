@@ -2,7 +2,7 @@ use std::borrow::Cow;
 
 use bathbot_macros::{HasName, SlashCommand};
 use eyre::Result;
-use twilight_interactions::command::{CommandModel, CreateCommand};
+use twilight_interactions::command::{CommandModel, CommandOption, CreateCommand, CreateOption};
 use twilight_model::id::{Id, marker::UserMarker};
 
 pub use self::today::DailyChallengeDay;
@@ -11,6 +11,8 @@ use crate::{
     util::{InteractionCommandExt, interaction::InteractionCommand},
 };
 
+mod history;
+mod server;
 mod today;
 mod user;
 
@@ -21,6 +23,10 @@ pub enum DailyChallenge<'a> {
     User(DailyChallengeUser<'a>),
     #[command(name = "today")]
     Today(DailyChallengeToday),
+    #[command(name = "history")]
+    History(DailyChallengeHistory<'a>),
+    #[command(name = "server")]
+    Server(DailyChallengeServer),
 }
 
 const DC_USER_DESC: &str = "Daily challenge statistics of a user";
@@ -40,9 +46,50 @@ const DC_TODAY_DESC: &str = "Check the map and leaderboard of today's daily chal
 #[command(name = "today", desc = DC_TODAY_DESC)]
 pub struct DailyChallengeToday;
 
+#[derive(CommandModel, CreateCommand, HasName)]
+#[command(
+    name = "history",
+    desc = "Stored daily challenge results and streaks of a user",
+    help = "Show the stored daily challenge results and streaks of a user.\n\
+    Results are recorded for linked users that place on the daily challenge leaderboard \
+    or that are in a server with a configured daily challenge channel. \
+    Scores are only known for leaderboard placements."
+)]
+pub struct DailyChallengeHistory<'a> {
+    #[command(desc = "Specify a username")]
+    name: Option<Cow<'a, str>>,
+    #[command(desc = DISCORD_OPTION_DESC, help = DISCORD_OPTION_HELP)]
+    discord: Option<Id<UserMarker>>,
+}
+
+#[derive(CommandModel, CreateCommand)]
+#[command(
+    name = "server",
+    desc = "Daily challenge leaderboard of the server's linked members",
+    help = "Daily challenge leaderboard of the server's linked members.\n\
+    Results are recorded for linked users that place on the daily challenge leaderboard. \
+    If the server configured a daily challenge channel, other members' participation \
+    is recorded too but their scores remain unknown."
+)]
+pub struct DailyChallengeServer {
+    #[command(desc = "Specify whether to show today's or the all-time leaderboard")]
+    period: Option<DailyChallengeServerPeriod>,
+}
+
+#[derive(Copy, Clone, Default, CommandOption, CreateOption)]
+pub enum DailyChallengeServerPeriod {
+    #[default]
+    #[option(name = "Today", value = "today")]
+    Today,
+    #[option(name = "All-time", value = "all_time")]
+    AllTime,
+}
+
 async fn slash_dailychallenge(mut command: InteractionCommand) -> Result<()> {
     match DailyChallenge::from_interaction(command.input_data())? {
         DailyChallenge::User(user) => user::user((&mut command).into(), user).await,
         DailyChallenge::Today(_) => today::today((&mut command).into()).await,
+        DailyChallenge::History(args) => history::history((&mut command).into(), args).await,
+        DailyChallenge::Server(args) => server::server((&mut command).into(), args).await,
     }
 }
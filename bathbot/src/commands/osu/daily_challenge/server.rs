@@ -0,0 +1,142 @@
+use std::fmt::Write;
+
+use bathbot_psql::model::osu::DbDailyChallengeResult;
+use bathbot_util::{
+    AuthorBuilder, EmbedBuilder, FooterBuilder, MessageBuilder,
+    constants::{GENERAL_ISSUE, OSU_BASE},
+    numbers::WithComma,
+};
+use eyre::Result;
+use time::UtcDateTime;
+
+use super::{DailyChallengeServer, DailyChallengeServerPeriod};
+use crate::core::{Context, commands::CommandOrigin};
+
+/// Maximum amount of listed members
+const MAX_ENTRIES: usize = 20;
+
+pub(super) async fn server(orig: CommandOrigin<'_>, args: DailyChallengeServer) -> Result<()> {
+    let Some(guild_id) = orig.guild_id() else {
+        return orig.error("This command only works in servers").await;
+    };
+
+    let members: Vec<_> = match Context::cache().members(guild_id).await {
+        Ok(members) => members.into_iter().map(|id| id as i64).collect(),
+        Err(err) => {
+            let _ = orig.error(GENERAL_ISSUE).await;
+
+            return Err(err.wrap_err("Failed to get members"));
+        }
+    };
+
+    let psql = Context::psql();
+    let mut description = String::with_capacity(MAX_ENTRIES * 96);
+
+    let (title, len) = match args.period.unwrap_or_default() {
+        DailyChallengeServerPeriod::Today => {
+            let today = UtcDateTime::now().date();
+
+            let results = match psql
+                .select_guild_daily_challenge_results(&members, today)
+                .await
+            {
+                Ok(results) => results,
+                Err(err) => {
+                    let _ = orig.error(GENERAL_ISSUE).await;
+
+                    return Err(err.wrap_err("Failed to get guild daily challenge results"));
+                }
+            };
+
+            for (result, i) in results.iter().take(MAX_ENTRIES).zip(1..) {
+                let _ = write!(
+                    description,
+                    "**#{i}** **[{name}]({OSU_BASE}users/{user_id})**",
+                    name = result.username,
+                    user_id = result.user_id,
+                );
+
+                let DbDailyChallengeResult {
+                    total_score: Some(score),
+                    accuracy: Some(acc),
+                    attempts: Some(attempts),
+                    pos: Some(pos),
+                    ..
+                } = result
+                else {
+                    description.push_str(": Played, not on the leaderboard\n");
+
+                    continue;
+                };
+
+                let _ = writeln!(
+                    description,
+                    ": {score}\n{acc:.2}% • {attempts} attempt{plural} • Global #{pos}",
+                    score = WithComma::new(*score),
+                    plural = if *attempts != 1 { "s" } else { "" },
+                );
+            }
+
+            ("Today's daily challenge", results.len())
+        }
+        DailyChallengeServerPeriod::AllTime => {
+            let totals = match psql.select_guild_daily_challenge_totals(&members).await {
+                Ok(totals) => totals,
+                Err(err) => {
+                    let _ = orig.error(GENERAL_ISSUE).await;
+
+                    return Err(err.wrap_err("Failed to get guild daily challenge totals"));
+                }
+            };
+
+            for (total, i) in totals.iter().take(MAX_ENTRIES).zip(1..) {
+                let _ = write!(
+                    description,
+                    "**#{i}** **[{name}]({OSU_BASE}users/{user_id})**: {score}\n\
+                    {days} day{plural}",
+                    name = total.username,
+                    user_id = total.user_id,
+                    score = WithComma::new(total.total_score),
+                    days = total.days,
+                    plural = if total.days != 1 { "s" } else { "" },
+                );
+
+                if let Some(acc) = total.accuracy {
+                    let _ = write!(description, " • {acc:.2}%");
+                }
+
+                if let Some(pos) = total.best_pos {
+                    let _ = write!(description, " • Best global #{pos}");
+                }
+
+                description.push('\n');
+            }
+
+            ("All-time daily challenge", totals.len())
+        }
+    };
+
+    if len == 0 {
+        description.push_str("No results of linked members are stored");
+    }
+
+    let author = match Context::cache().guild(guild_id).await {
+        Ok(Some(guild)) => AuthorBuilder::new(format!("{title} in {}", guild.name.as_ref())),
+        _ => AuthorBuilder::new(title),
+    };
+
+    let footer = FooterBuilder::new(format!(
+        "{len} linked member{} • Scores are only known for leaderboard placements",
+        if len != 1 { "s" } else { "" }
+    ));
+
+    let embed = EmbedBuilder::new()
+        .author(author)
+        .description(description)
+        .footer(footer);
+
+    let builder = MessageBuilder::new().embed(embed);
+    orig.create_message(builder).await?;
+
+    Ok(())
+}
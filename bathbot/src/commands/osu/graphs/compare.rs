@@ -0,0 +1,475 @@
+use std::iter;
+
+use bathbot_model::{RespektiveUser, rosu_v2::user::MedalCompactRkyv};
+use bathbot_psql::model::configs::ScoreData;
+use bathbot_util::{constants::GENERAL_ISSUE, numbers::WithComma};
+use eyre::{Report, Result, WrapErr};
+use futures::{StreamExt, stream::FuturesOrdered};
+use plotters::{
+    chart::{ChartBuilder, SeriesLabelPosition},
+    prelude::{IntoDrawingArea, PathElement},
+    series::LineSeries,
+    style::{Color, RGBColor, TextStyle, WHITE},
+};
+use plotters_backend::FontStyle;
+use plotters_skia::SkiaBackend;
+use rkyv::{
+    rancor::{Panic, ResultExt},
+    with::{Map, With},
+};
+use rosu_v2::{
+    prelude::{GameMode, OsuError, Score},
+    request::UserId,
+};
+use skia_safe::{EncodedImageFormat, surfaces};
+use time::{Duration, OffsetDateTime};
+
+use super::{GraphCompare, GraphCompareKind, H, LegendDraw, W};
+use crate::{
+    commands::osu::{require_link, user_not_found},
+    core::{Context, commands::CommandOrigin},
+    manager::redis::osu::{CachedUser, UserArgs, UserArgsError, UserArgsSlim},
+    util::Monthly,
+};
+
+/// Amount of users that can be compared at once
+const MAX_USERS: usize = 5;
+
+/// Distinct colors for each user's line
+const COLORS: [RGBColor; MAX_USERS] = [
+    RGBColor(0, 246, 193),
+    RGBColor(255, 102, 170),
+    RGBColor(255, 204, 34),
+    RGBColor(102, 153, 255),
+    RGBColor(255, 128, 64),
+];
+
+pub struct CompareGraph {
+    pub title: String,
+    pub footer: Option<String>,
+    pub bytes: Vec<u8>,
+}
+
+struct UserSeries {
+    username: Box<str>,
+    /// Sorted by date
+    points: Vec<(OffsetDateTime, f64)>,
+}
+
+enum SeriesError {
+    NotFound(UserId),
+    Report(Report),
+}
+
+pub async fn compare_graph(
+    orig: &CommandOrigin<'_>,
+    args: GraphCompare,
+) -> Result<Option<CompareGraph>> {
+    let GraphCompare {
+        kind,
+        name1,
+        name2,
+        name3,
+        name4,
+        name5,
+        mode,
+    } = args;
+
+    let mut names: Vec<String> = Vec::with_capacity(MAX_USERS);
+
+    for name in [Some(name1), name2, name3, name4, name5]
+        .into_iter()
+        .flatten()
+    {
+        if !names.iter().any(|prev| prev.eq_ignore_ascii_case(&name)) {
+            names.push(name);
+        }
+    }
+
+    let config = match Context::user_config().with_osu_id(orig.user_id()?).await {
+        Ok(config) => config,
+        Err(err) => {
+            let _ = orig.error(GENERAL_ISSUE).await;
+
+            return Err(err.wrap_err("Failed to get user config"));
+        }
+    };
+
+    let mut user_ids: Vec<_> = names
+        .into_iter()
+        .map(|name| UserId::Name(name.into()))
+        .collect();
+
+    // Comparing a single user with nobody else is pointless so the author is
+    // used as second user
+    if user_ids.len() < 2 {
+        match config.osu {
+            Some(user_id) => user_ids.insert(0, UserId::Id(user_id)),
+            None => return require_link(orig).await.map(|_| None),
+        }
+    }
+
+    let mode = match kind {
+        // Medals are not mode specific
+        GraphCompareKind::Medals => GameMode::Osu,
+        _ => mode
+            .map(GameMode::from)
+            .or(config.mode)
+            .unwrap_or(GameMode::Osu),
+    };
+
+    let legacy_scores = match kind {
+        GraphCompareKind::TopPlays => match config.score_data {
+            Some(score_data) => score_data.is_legacy(),
+            None => match orig.guild_id() {
+                Some(guild_id) => Context::guild_config()
+                    .peek(guild_id, |config| config.score_data)
+                    .await
+                    .is_some_and(ScoreData::is_legacy),
+                None => false,
+            },
+        },
+        _ => false,
+    };
+
+    let mut series_futs: FuturesOrdered<_> = user_ids
+        .into_iter()
+        .map(|user_id| user_series(user_id, mode, kind, legacy_scores))
+        .collect();
+
+    let mut series = Vec::with_capacity(series_futs.len());
+    let mut missing = Vec::new();
+
+    while let Some(res) = series_futs.next().await {
+        match res {
+            Ok(user) if user.points.is_empty() => missing.push(user.username),
+            Ok(user) => series.push(user),
+            Err(SeriesError::NotFound(user_id)) => {
+                let content = user_not_found(user_id).await;
+                orig.error(content).await?;
+
+                return Ok(None);
+            }
+            Err(SeriesError::Report(err)) => {
+                let _ = orig.error(GENERAL_ISSUE).await;
+
+                return Err(err);
+            }
+        }
+    }
+
+    if series.is_empty() {
+        let content = format!("None of the users have any {} data", kind.data_name());
+        orig.error(content).await?;
+
+        return Ok(None);
+    }
+
+    let bytes = match draw_graph(kind, &series) {
+        Ok(bytes) => bytes,
+        Err(err) => {
+            let _ = orig.error(GENERAL_ISSUE).await;
+            warn!(?err, "Failed to draw comparison graph");
+
+            return Ok(None);
+        }
+    };
+
+    let mut title = format!("{} comparison: ", kind.title());
+
+    for (user, i) in series.iter().zip(0..) {
+        if i > 0 {
+            title.push_str(", ");
+        }
+
+        title.push_str(&user.username);
+    }
+
+    let footer = (!missing.is_empty()).then(|| {
+        let mut footer = format!("No {} data for ", kind.data_name());
+
+        for (name, i) in missing.iter().zip(0..) {
+            if i > 0 {
+                footer.push_str(", ");
+            }
+
+            footer.push_str(name);
+        }
+
+        footer
+    });
+
+    Ok(Some(CompareGraph {
+        title,
+        footer,
+        bytes,
+    }))
+}
+
+async fn user_series(
+    user_id: UserId,
+    mode: GameMode,
+    kind: GraphCompareKind,
+    legacy_scores: bool,
+) -> Result<UserSeries, SeriesError> {
+    let user_args = UserArgs::rosu_id(&user_id, mode).await;
+
+    let user = match Context::redis().osu_user(user_args).await {
+        Ok(user) => user,
+        Err(UserArgsError::Osu(OsuError::NotFound)) => return Err(SeriesError::NotFound(user_id)),
+        Err(err) => {
+            let err = Report::new(err).wrap_err("Failed to get user");
+
+            return Err(SeriesError::Report(err));
+        }
+    };
+
+    let points = match kind {
+        GraphCompareKind::Rank => rank_points(&user),
+        GraphCompareKind::Pp | GraphCompareKind::Playcount => {
+            let user_id = user.user_id.to_native();
+
+            let history = match Context::redis().osutrack_history(user_id, mode).await {
+                Ok(history) => history,
+                Err(err) => {
+                    let err = Report::new(err).wrap_err("Failed to get osutrack history");
+
+                    return Err(SeriesError::Report(err));
+                }
+            };
+
+            history
+                .iter()
+                .map(|entry| {
+                    let value = match kind {
+                        GraphCompareKind::Pp => entry.pp.to_native() as f64,
+                        _ => entry.playcount.to_native() as f64,
+                    };
+
+                    (entry.timestamp(), value)
+                })
+                .collect()
+        }
+        GraphCompareKind::Medals => medal_points(&user),
+        GraphCompareKind::ScoreRank => {
+            let user_id = user.user_id.to_native();
+            let users_fut = Context::client().get_respektive_users(iter::once(user_id), mode);
+
+            match users_fut.await {
+                Ok(mut users) => score_rank_points(users.next().flatten()),
+                Err(err) => {
+                    let err = err.wrap_err("Failed to get respektive user");
+
+                    return Err(SeriesError::Report(err));
+                }
+            }
+        }
+        GraphCompareKind::TopPlays => {
+            let user_args = UserArgsSlim::user_id(user.user_id.to_native()).mode(mode);
+            let scores_fut = Context::osu_scores()
+                .top(100, legacy_scores)
+                .archived(true)
+                .exec(user_args);
+
+            match scores_fut.await {
+                Ok(scores) => top_play_points(scores),
+                Err(err) => {
+                    let err = Report::new(err).wrap_err("Failed to get top scores");
+
+                    return Err(SeriesError::Report(err));
+                }
+            }
+        }
+    };
+
+    Ok(UserSeries {
+        username: user.username.as_str().into(),
+        points,
+    })
+}
+
+/// Negated ranks so that better ranks are further up in the graph
+fn rank_points(user: &CachedUser) -> Vec<(OffsetDateTime, f64)> {
+    let now = OffsetDateTime::now_utc();
+    let len = user.rank_history.len();
+
+    user.rank_history
+        .iter()
+        .zip(0..)
+        .filter(|(rank, _)| rank.to_native() != 0)
+        .map(|(rank, i)| {
+            let days_ago = (len - 1 - i) as i64;
+
+            (now - Duration::days(days_ago), -(rank.to_native() as f64))
+        })
+        .collect()
+}
+
+/// Negated ranks so that better ranks are further up in the graph
+fn score_rank_points(user: Option<RespektiveUser>) -> Vec<(OffsetDateTime, f64)> {
+    let Some(rank_history) = user.and_then(|user| user.rank_history) else {
+        return Vec::new();
+    };
+
+    let mut points: Vec<_> = rank_history
+        .into_iter()
+        .filter_map(|entry| match entry.rank {
+            Some(rank) if rank != 0 => Some((entry.date, -(rank as f64))),
+            _ => None,
+        })
+        .collect();
+
+    points.sort_unstable_by_key(|(date, _)| *date);
+
+    points
+}
+
+/// Amount of top plays that were set up to each point in time
+fn top_play_points(mut scores: Vec<Score>) -> Vec<(OffsetDateTime, f64)> {
+    scores.sort_unstable_by_key(|score| score.ended_at);
+
+    scores
+        .iter()
+        .zip(1..)
+        .map(|(score, count)| (score.ended_at, count as f64))
+        .collect()
+}
+
+fn medal_points(user: &CachedUser) -> Vec<(OffsetDateTime, f64)> {
+    let mut medals = rkyv::api::deserialize_using::<_, _, Panic>(
+        With::<_, Map<MedalCompactRkyv>>::cast(&user.medals),
+        &mut (),
+    )
+    .always_ok();
+
+    medals.sort_unstable_by_key(|medal| medal.achieved_at);
+
+    medals
+        .iter()
+        .zip(1..)
+        .map(|(medal, count)| (medal.achieved_at, count as f64))
+        .collect()
+}
+
+fn draw_graph(kind: GraphCompareKind, series: &[UserSeries]) -> Result<Vec<u8>> {
+    let mut start = OffsetDateTime::now_utc();
+    let mut end = OffsetDateTime::UNIX_EPOCH;
+    let mut min = f64::MAX;
+    let mut max = f64::MIN;
+
+    for (datetime, value) in series.iter().flat_map(|user| user.points.iter()) {
+        start = start.min(*datetime);
+        end = end.max(*datetime);
+        min = min.min(*value);
+        max = max.max(*value);
+    }
+
+    // Prevent empty ranges if there is only a single data point
+    if start >= end {
+        start -= Duration::days(1);
+        end += Duration::days(1);
+    }
+
+    let padding = ((max - min) * 0.05).max(1.0);
+    let (min, max) = (min - padding, max + padding);
+
+    let mut surface =
+        surfaces::raster_n32_premul((W as i32, H as i32)).wrap_err("Failed to create surface")?;
+
+    {
+        let mut root = SkiaBackend::new(surface.canvas(), W, H).into_drawing_area();
+
+        let background = RGBColor(19, 43, 33);
+        root.fill(&background)
+            .wrap_err("Failed to fill background")?;
+
+        let title_style = TextStyle::from(("sans-serif", 25_i32, FontStyle::Bold)).color(&WHITE);
+        root = root
+            .titled(kind.title(), title_style)
+            .wrap_err("Failed to draw title")?;
+
+        let mut chart = ChartBuilder::on(&root)
+            .x_label_area_size(20)
+            .y_label_area_size(90)
+            .margin(9)
+            .margin_right(20)
+            .build_cartesian_2d(Monthly(start..end), min..max)
+            .wrap_err("Failed to build chart")?;
+
+        chart
+            .configure_mesh()
+            .disable_x_mesh()
+            .bold_line_style(WHITE.mix(0.3))
+            .light_line_style(WHITE.mix(0.0)) // hide
+            .y_desc(kind.title())
+            .y_label_formatter(&|y| kind.format_value(*y))
+            .label_style(("sans-serif", 20_i32, &WHITE))
+            .axis_style(RGBColor(7, 18, 14))
+            .axis_desc_style(("sans-serif", 20_i32, FontStyle::Bold, &WHITE))
+            .draw()
+            .wrap_err("Failed to draw mesh")?;
+
+        for (user, color) in series.iter().zip(COLORS) {
+            let style = color.stroke_width(2);
+            let line = LineSeries::new(user.points.iter().copied(), style);
+
+            // The caller already filtered out users without points
+            let (_, last) = user.points[user.points.len() - 1];
+            let label = format!("{}: {}", user.username, kind.format_value(last));
+
+            chart
+                .draw_series(line)
+                .wrap_err("Failed to draw series")?
+                .label(label)
+                .legend(move |(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], style));
+        }
+
+        LegendDraw::new(&mut chart)
+            .position(SeriesLabelPosition::UpperLeft)
+            .legend_area_size(25)
+            .draw()?;
+    }
+
+    let png_bytes = surface
+        .image_snapshot()
+        .encode(None, EncodedImageFormat::PNG, None)
+        .wrap_err("Failed to encode image")?
+        .to_vec();
+
+    Ok(png_bytes)
+}
+
+impl GraphCompareKind {
+    fn title(self) -> &'static str {
+        match self {
+            Self::Rank => "Rank",
+            Self::Pp => "PP",
+            Self::Playcount => "Playcount",
+            Self::Medals => "Medals",
+            Self::ScoreRank => "Score rank",
+            Self::TopPlays => "Top plays",
+        }
+    }
+
+    fn data_name(self) -> &'static str {
+        match self {
+            Self::Rank => "rank",
+            Self::Pp | Self::Playcount => "osutrack",
+            Self::Medals => "medal",
+            Self::ScoreRank => "score rank",
+            Self::TopPlays => "top play",
+        }
+    }
+
+    fn format_value(self, value: f64) -> String {
+        match self {
+            Self::Rank | Self::ScoreRank => {
+                format!("#{}", WithComma::new((-value).round().max(1.0) as u32))
+            }
+            Self::Pp => format!("{}pp", WithComma::new(value.max(0.0).round() as u32)),
+            Self::Playcount | Self::Medals | Self::TopPlays => {
+                WithComma::new(value.max(0.0).round() as u32).to_string()
+            }
+        }
+    }
+}
@@ -36,6 +36,7 @@ use twilight_model::id::{
 pub use self::map_strains::map_strains_graph;
 use self::{
     bpm::map_bpm_graph,
+    compare::compare_graph,
     medals::medals_graph,
     osutrack::osutrack_graph,
     playcount_replays::{ProfileGraphFlags, playcount_replays_graph},
@@ -62,6 +63,7 @@ use crate::{
 };
 
 mod bpm;
+mod compare;
 mod map_strains;
 mod medals;
 mod osutrack;
@@ -79,6 +81,8 @@ mod top_time;
 pub enum Graph<'a> {
     #[command(name = "bpm")]
     MapBpm(GraphMapBpm<'a>),
+    #[command(name = "compare")]
+    Compare(GraphCompare),
     #[command(name = "strains")]
     MapStrains(GraphMapStrains<'a>),
     #[command(name = "medals")]
@@ -118,6 +122,50 @@ pub struct GraphMapBpm<'a> {
     mods: Option<Cow<'a, str>>,
 }
 
+const GRAPH_COMPARE_DESC: &str = "Compare the progression of up to five users";
+
+#[derive(CommandModel, CreateCommand)]
+#[command(
+    name = "compare",
+    desc = GRAPH_COMPARE_DESC,
+    help = "Compare the progression of up to five users in a single graph.\n\
+    If only one username is specified, it will be compared with your linked osu! profile.\n\
+    Rank and score rank data only cover the last 90 days while pp and playcount are based on osutrack data.\n\
+    Top plays count how many of the top scores were set up to each point in time."
+)]
+pub struct GraphCompare {
+    #[command(desc = "Choose which data to compare")]
+    kind: GraphCompareKind,
+    #[command(desc = "Specify a username")]
+    name1: String,
+    #[command(desc = "Specify a username")]
+    name2: Option<String>,
+    #[command(desc = "Specify a username")]
+    name3: Option<String>,
+    #[command(desc = "Specify a username")]
+    name4: Option<String>,
+    #[command(desc = "Specify a username")]
+    name5: Option<String>,
+    #[command(desc = "Specify a gamemode")]
+    mode: Option<GameModeOption>,
+}
+
+#[derive(Copy, Clone, CommandOption, CreateOption)]
+pub enum GraphCompareKind {
+    #[option(name = "Rank", value = "rank")]
+    Rank,
+    #[option(name = "PP", value = "pp")]
+    Pp,
+    #[option(name = "Playcount", value = "playcount")]
+    Playcount,
+    #[option(name = "Medals", value = "medals")]
+    Medals,
+    #[option(name = "Score rank", value = "score_rank")]
+    ScoreRank,
+    #[option(name = "Top plays", value = "top_plays")]
+    TopPlays,
+}
+
 #[derive(CommandModel, CreateCommand, HasMods)]
 #[command(name = "strains", desc = "Display a map's strains over time")]
 pub struct GraphMapStrains<'a> {
@@ -375,6 +423,31 @@ async fn graph(orig: CommandOrigin<'_>, args: Graph<'_>) -> Result<()> {
                 Err(err) => Err(err.wrap_err("Failed to create map bpm graph")),
             };
         }
+        Graph::Compare(args) => {
+            let graph_option = compare_graph(&orig, args)
+                .await
+                .wrap_err("Failed to create comparison graph")?;
+
+            let Some(graph) = graph_option else {
+                return Ok(());
+            };
+
+            let mut embed = EmbedBuilder::new()
+                .title(graph.title)
+                .image(attachment("graph.png"));
+
+            if let Some(footer) = graph.footer {
+                embed = embed.footer(FooterBuilder::new(footer));
+            }
+
+            let builder = MessageBuilder::new()
+                .embed(embed)
+                .attachment("graph.png", graph.bytes);
+
+            orig.create_message(builder).await?;
+
+            return Ok(());
+        }
         Graph::MapStrains(args) => {
             return match map_strains(&orig, args).await {
                 Ok(ControlFlow::Continue(map)) => {
@@ -841,6 +914,7 @@ async fn top_graph(
 ) -> Result<Option<(CachedUser, Vec<u8>)>> {
     let scores_fut = Context::osu_scores()
         .top(200, legacy_scores)
+        .archived(true)
         .exec_with_user(user_args);
 
     let (user, mut scores) = match scores_fut.await {
@@ -1,6 +1,6 @@
 use std::{borrow::Cow, cmp::Reverse, collections::HashMap};
 
-use bathbot_macros::{HasMods, SlashCommand, command};
+use bathbot_macros::{HasMods, SlashCommand, command, msg_command};
 use bathbot_model::command_fields::GameModeOption;
 use bathbot_psql::model::configs::ScoreData;
 use bathbot_util::{
@@ -22,7 +22,7 @@ use twilight_model::{
     id::{Id, marker::UserMarker},
 };
 
-use super::{HasMods, ModsResult};
+use super::{HasMods, ModsResult, target_map_or_score};
 use crate::{
     Context,
     active::{ActiveMessages, impls::LeaderboardPagination},
@@ -332,6 +332,30 @@ async fn slash_leaderboard(mut command: InteractionCommand) -> Result<()> {
     }
 }
 
+#[msg_command(name = "Leaderboard for map", bucket(All))]
+async fn msg_leaderboard(mut command: InteractionCommand) -> Result<()> {
+    let map = match target_map_or_score(&command).await? {
+        Some(MapOrScore::Map(map)) => map,
+        Some(MapOrScore::Score { .. }) => {
+            let content = "I found a score id in that message but I need a map id";
+            command.error(content).await?;
+
+            return Ok(());
+        }
+        None => return Ok(()),
+    };
+
+    let args = LeaderboardArgs {
+        map: Some(map),
+        mods: None,
+        mode: None,
+        sort: LeaderboardSort::default(),
+        score_data: None,
+    };
+
+    leaderboard((&mut command).into(), args).await
+}
+
 async fn leaderboard(orig: CommandOrigin<'_>, args: LeaderboardArgs<'_>) -> Result<()> {
     let mods = match args.mods() {
         ModsResult::Mods(mods) => Some(mods),
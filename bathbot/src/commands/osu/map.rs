@@ -8,7 +8,7 @@ use bathbot_util::{
     osu::{MapIdType, ModSelection},
 };
 use eyre::{Report, Result};
-use rosu_v2::prelude::{GameMode, GameModsIntermode, OsuError};
+use rosu_v2::prelude::{BeatmapExtended, GameMode, GameModsIntermode, OsuError};
 use twilight_interactions::command::{CommandModel, CreateCommand};
 use twilight_model::{channel::Message, guild::Permissions};
 
@@ -328,18 +328,7 @@ async fn map(orig: CommandOrigin<'_>, args: MapArgs<'_>) -> Result<()> {
         return orig.error("The mapset has no maps").await;
     };
 
-    maps.sort_unstable_by(|m1, m2| {
-        m1.mode.cmp(&m2.mode).then_with(|| match m1.mode {
-            // For mania sort first by mania key, then star rating
-            GameMode::Mania => m1
-                .cs
-                .partial_cmp(&m2.cs)
-                .unwrap_or(Ordering::Equal)
-                .then(m1.stars.partial_cmp(&m2.stars).unwrap_or(Ordering::Equal)),
-            // For other mods just sort by star rating
-            _ => m1.stars.partial_cmp(&m2.stars).unwrap_or(Ordering::Equal),
-        })
-    });
+    sort_mapset_maps(&mut maps);
 
     let map_idx = match map_id {
         MapIdType::Map(map_id) => maps
@@ -413,3 +402,19 @@ async fn map(orig: CommandOrigin<'_>, args: MapArgs<'_>) -> Result<()> {
         .begin(orig)
         .await
 }
+
+/// Sort maps by mode and star rating. Mania maps are sorted by key count first.
+pub fn sort_mapset_maps(maps: &mut [BeatmapExtended]) {
+    maps.sort_unstable_by(|m1, m2| {
+        m1.mode.cmp(&m2.mode).then_with(|| match m1.mode {
+            // For mania sort first by mania key, then star rating
+            GameMode::Mania => m1
+                .cs
+                .partial_cmp(&m2.cs)
+                .unwrap_or(Ordering::Equal)
+                .then(m1.stars.partial_cmp(&m2.stars).unwrap_or(Ordering::Equal)),
+            // For other mods just sort by star rating
+            _ => m1.stars.partial_cmp(&m2.stars).unwrap_or(Ordering::Equal),
+        })
+    });
+}
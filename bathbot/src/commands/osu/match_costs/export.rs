@@ -0,0 +1,140 @@
+use std::{
+    collections::HashMap,
+    fmt::{Display, Formatter, Result as FmtResult, Write},
+};
+
+use eyre::{Result, WrapErr};
+use rosu_v2::prelude::{MatchGame, MatchTeam, User};
+use serde::Serialize;
+use twilight_interactions::command::{CommandOption, CreateOption};
+
+/// File format of the per-map breakdown of a match
+#[derive(Copy, Clone, CommandOption, CreateOption)]
+pub enum MatchCostExport {
+    #[option(name = "CSV", value = "csv")]
+    Csv,
+    #[option(name = "JSON", value = "json")]
+    Json,
+}
+
+impl MatchCostExport {
+    /// Create the file name and content containing each player's normalised
+    /// score on each game.
+    pub(super) fn attachment(
+        self,
+        match_id: u32,
+        games: &[MatchGame],
+        users: &HashMap<u32, User>,
+    ) -> Result<(String, Vec<u8>)> {
+        let maps = ExportMap::collect(games, users);
+
+        match self {
+            Self::Csv => {
+                let name = format!("matchcost_{match_id}.csv");
+
+                Ok((name, csv(&maps).into_bytes()))
+            }
+            Self::Json => {
+                let name = format!("matchcost_{match_id}.json");
+                let bytes = serde_json::to_vec_pretty(&maps).wrap_err("Failed to serialize")?;
+
+                Ok((name, bytes))
+            }
+        }
+    }
+}
+
+fn csv(maps: &[ExportMap<'_>]) -> String {
+    let mut csv = String::from("map_index,map_id,user_id,username,team,score,normalised_score\n");
+
+    for map in maps {
+        for score in map.scores.iter() {
+            let _ = writeln!(
+                csv,
+                "{index},{map_id},{user_id},{username},{team},{score},{normalised:.4}",
+                index = map.map_index,
+                map_id = map.map_id,
+                user_id = score.user_id,
+                username = CsvField(score.username),
+                team = score.team,
+                score = score.score,
+                normalised = score.normalised_score,
+            );
+        }
+    }
+
+    csv
+}
+
+/// Quotes the value if it contains characters that would break the CSV.
+struct CsvField<'a>(&'a str);
+
+impl Display for CsvField<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        if self.0.contains([',', '"', '\n']) {
+            write!(f, "\"{}\"", self.0.replace('"', "\"\""))
+        } else {
+            f.write_str(self.0)
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct ExportMap<'u> {
+    map_index: usize,
+    map_id: u32,
+    scores: Vec<ExportScore<'u>>,
+}
+
+#[derive(Serialize)]
+struct ExportScore<'u> {
+    user_id: u32,
+    username: &'u str,
+    team: &'static str,
+    score: u32,
+    /// The score divided by the average score on the map
+    normalised_score: f32,
+}
+
+impl<'u> ExportMap<'u> {
+    fn collect(games: &[MatchGame], users: &'u HashMap<u32, User>) -> Vec<Self> {
+        games
+            .iter()
+            .zip(1..)
+            .filter_map(|(game, map_index)| {
+                let score_sum: u32 = game.scores.iter().map(|score| score.score).sum();
+
+                // Games without any score can't be normalised
+                if score_sum == 0 {
+                    return None;
+                }
+
+                let score_avg = score_sum as f32 / game.scores.len() as f32;
+
+                let scores = game
+                    .scores
+                    .iter()
+                    .map(|score| ExportScore {
+                        user_id: score.user_id,
+                        username: users
+                            .get(&score.user_id)
+                            .map_or("", |user| user.username.as_str()),
+                        team: match score.info.team {
+                            MatchTeam::None => "none",
+                            MatchTeam::Blue => "blue",
+                            MatchTeam::Red => "red",
+                        },
+                        score: score.score,
+                        normalised_score: score.score as f32 / score_avg,
+                    })
+                    .collect();
+
+                Some(Self {
+                    map_index,
+                    map_id: game.map_id,
+                    scores,
+                })
+            })
+            .collect()
+    }
+}
@@ -6,7 +6,11 @@ use std::{
 };
 
 use bathbot_macros::{SlashCommand, command};
-use bathbot_util::{IntHasher, constants::OSU_API_ISSUE, matcher};
+use bathbot_util::{
+    IntHasher,
+    constants::{GENERAL_ISSUE, OSU_API_ISSUE},
+    matcher,
+};
 use eyre::{Report, Result};
 use rosu_v2::prelude::{
     GameMod, GameModIntermode, GameMods, GameModsIntermode, MatchGame, MatchTeam, Osu, OsuError,
@@ -14,6 +18,7 @@ use rosu_v2::prelude::{
 };
 use twilight_interactions::command::{CommandModel, CommandOption, CreateCommand, CreateOption};
 
+pub use self::export::MatchCostExport;
 use crate::{
     Context,
     active::{ActiveMessages, impls::MatchCostPagination},
@@ -24,12 +29,14 @@ use crate::{
     util::{ChannelExt, InteractionCommandExt, interaction::InteractionCommand},
 };
 
+mod export;
+
 #[derive(CommandModel, CreateCommand, SlashCommand)]
 #[command(
     name = "matchcost",
     desc = "Display performance ratings for a multiplayer match",
     help = "Calculate a performance rating for each player in the given multiplayer match.\n\
-    Current formula: <https://i.imgur.com/zuii7Oj.png> ([desmos](https://www.desmos.com/calculator/mm4tins990))"
+    Default formula: <https://i.imgur.com/zuii7Oj.png> ([desmos](https://www.desmos.com/calculator/mm4tins990))"
 )]
 pub struct MatchCost<'a> {
     #[command(desc = "Specify a match url or match id")]
@@ -61,6 +68,30 @@ pub struct MatchCost<'a> {
     skip_last: Option<usize>,
     #[command(desc = "How the data should be displayed")]
     display: Option<MatchCostDisplay>,
+    #[command(
+        desc = "Specify the formula for the ratings",
+        help = "Specify the formula for the ratings.\n\
+        - `Default`: <https://i.imgur.com/zuii7Oj.png>\n\
+        - `Bathbot classic`: The average normalised score with a milder \
+        participation bonus and without a flat bonus\n\
+        - `Average normalised score`: The average of the player's score divided by \
+        the average score on each map, without any bonuses"
+    )]
+    formula: Option<MatchCostFormula>,
+    #[command(
+        desc = "Specify how the last map should be handled as tiebreaker",
+        help = "By default, the last map counts as tiebreaker if the match is over, \
+        at least five maps were played, and the teams were within one win of each other.\n\
+        Use this option to always or never consider the last map a tiebreaker."
+    )]
+    tiebreaker: Option<MatchCostTiebreaker>,
+    #[command(
+        desc = "Attach the normalised score of each player on each map",
+        help = "Attach a file containing each player's score on each map, \
+        normalised by the map's average score.\n\
+        Useful to paste the results into a spreadsheet."
+    )]
+    export: Option<MatchCostExport>,
 }
 
 #[derive(Copy, Clone, CommandOption, CreateOption, Default)]
@@ -72,6 +103,35 @@ pub enum MatchCostDisplay {
     Full,
 }
 
+#[derive(Copy, Clone, CommandOption, CreateOption, Default)]
+pub enum MatchCostFormula {
+    #[default]
+    #[option(name = "Default", value = "default")]
+    Default,
+    #[option(name = "Bathbot classic", value = "classic")]
+    Classic,
+    #[option(name = "Average normalised score", value = "average")]
+    Average,
+}
+
+impl MatchCostFormula {
+    /// Whether the formula rewards mods and tiebreakers
+    fn has_bonuses(self) -> bool {
+        !matches!(self, Self::Average)
+    }
+}
+
+#[derive(Copy, Clone, CommandOption, CreateOption, Default)]
+pub enum MatchCostTiebreaker {
+    #[default]
+    #[option(name = "Detect automatically", value = "auto")]
+    Auto,
+    #[option(name = "Last map is a tiebreaker", value = "always")]
+    Always,
+    #[option(name = "No tiebreaker", value = "never")]
+    Never,
+}
+
 impl<'m> MatchCost<'m> {
     fn args(mut args: Args<'m>) -> Result<Self, &'static str> {
         let match_url = match args.next() {
@@ -95,6 +155,9 @@ impl<'m> MatchCost<'m> {
             skip_last: None,
             ez_mult: None,
             display: None,
+            formula: None,
+            tiebreaker: None,
+            export: None,
         })
     }
 }
@@ -137,6 +200,9 @@ async fn matchcosts(orig: CommandOrigin<'_>, args: MatchCost<'_>) -> Result<()>
         skip_last,
         ez_mult,
         display,
+        formula,
+        tiebreaker,
+        export,
     } = args;
 
     let Some(match_id) = matcher::get_osu_match_id(&match_url) else {
@@ -149,6 +215,7 @@ async fn matchcosts(orig: CommandOrigin<'_>, args: MatchCost<'_>) -> Result<()>
     let warmups = warmups.unwrap_or(0);
     let ez_mult = ez_mult.unwrap_or(1.0);
     let skip_last = skip_last.unwrap_or(0);
+    let formula = formula.unwrap_or_default();
     let osu = Context::osu();
 
     // Retrieve the match
@@ -215,7 +282,24 @@ async fn matchcosts(orig: CommandOrigin<'_>, args: MatchCost<'_>) -> Result<()>
 
         MatchResult::NoGames { description }
     } else {
-        process_match(&games, osu_match.end_time.is_some(), &osu_match.users)
+        let finished = osu_match.end_time.is_some();
+        let tiebreaker = tiebreaker.unwrap_or_default();
+
+        process_match(&games, finished, &osu_match.users, formula, tiebreaker)
+    };
+
+    let attachment = match export {
+        Some(export) if !games.is_empty() => {
+            match export.attachment(osu_match.match_id, &games, &osu_match.users) {
+                Ok(attachment) => Some(attachment),
+                Err(err) => {
+                    let _ = orig.error(GENERAL_ISSUE).await;
+
+                    return Err(err.wrap_err("Failed to export match costs"));
+                }
+            }
+        }
+        _ => None,
     };
 
     let mut content = String::new();
@@ -246,11 +330,13 @@ async fn matchcosts(orig: CommandOrigin<'_>, args: MatchCost<'_>) -> Result<()>
         .osu_match(osu_match)
         .content(content.into_boxed_str())
         .display(display.unwrap_or_default())
+        .formula(formula)
         .msg_owner(owner)
         .result(match_result)
         .build();
 
     ActiveMessages::builder(pagination)
+        .attachment(attachment)
         .start_by_update(true)
         .begin(orig)
         .await
@@ -306,10 +392,18 @@ const TIEBREAKER_FACTOR: f32 = 0.25;
 // any tiebreaker performance cost >=2 gets the same bonus
 const MAX_TIEBREAKER_BONUS: f32 = 0.5;
 
+// classic formula: maximum participation bonus for playing each game
+const CLASSIC_BASE_PARTICIPATION_BONUS: f32 = 1.2;
+
+// classic formula: curve to reach the maximum participation bonus
+const CLASSIC_EXP_PARTICIPATION_BONUS: f32 = 0.4;
+
 pub fn process_match(
     games: &[MatchGame],
     finished: bool,
     users: &HashMap<u32, User>,
+    formula: MatchCostFormula,
+    tiebreaker: MatchCostTiebreaker,
 ) -> MatchResult {
     let mut users_mods = UsersMods::default();
     let mut users_performance_costs = UsersPerformanceCosts::default();
@@ -333,12 +427,16 @@ pub fn process_match(
         teams_win_count.add_win(teams_score.winner());
     }
 
-    let tiebreaker_game = games
-        .last()
-        .filter(|_| finished && games.len() > 4 && teams_win_count.diff() == 1);
+    let tiebreaker_game = match tiebreaker {
+        MatchCostTiebreaker::Auto => games
+            .last()
+            .filter(|_| finished && games.len() > 4 && teams_win_count.diff() == 1),
+        MatchCostTiebreaker::Always => games.last().filter(|_| games.len() > 1),
+        MatchCostTiebreaker::Never => None,
+    };
 
     let match_costs =
-        users_performance_costs.match_costs(games.len(), &users_mods, tiebreaker_game);
+        users_performance_costs.match_costs(games.len(), &users_mods, tiebreaker_game, formula);
 
     let mvp_avatar_url = match_costs
         .iter()
@@ -380,6 +478,8 @@ pub fn process_match(
 
         UserMatchCostEntry::sort(&mut blue.players);
         UserMatchCostEntry::sort(&mut red.players);
+        blue.calculate_avg();
+        red.calculate_avg();
 
         MatchResult::TeamVS {
             blue,
@@ -423,15 +523,17 @@ pub fn process_match(
 
             let red_players = players.split_off(1);
 
+            let mut blue = TeamResult::new(teams_win_count.get(MatchTeam::Blue));
+            blue.players = players;
+            blue.calculate_avg();
+
+            let mut red = TeamResult::new(teams_win_count.get(MatchTeam::Red));
+            red.players = red_players;
+            red.calculate_avg();
+
             MatchResult::TeamVS {
-                blue: TeamResult {
-                    players,
-                    win_count: teams_win_count.get(MatchTeam::Blue),
-                },
-                red: TeamResult {
-                    players: red_players,
-                    win_count: teams_win_count.get(MatchTeam::Red),
-                },
+                blue,
+                red,
                 mvp_avatar_url,
             }
         } else {
@@ -490,6 +592,7 @@ impl UsersPerformanceCosts {
         games_count: usize,
         users_mods: &UsersMods,
         tiebreaker_game: Option<&MatchGame>,
+        formula: MatchCostFormula,
     ) -> HashMap<u32, MatchCostEntry, IntHasher> {
         let mut match_costs = HashMap::with_capacity_and_hasher(self.entries.len(), IntHasher);
 
@@ -506,11 +609,12 @@ impl UsersPerformanceCosts {
 
             let scores_len = entries.len() as f32;
             let avg_score = (score_sum as f32 / scores_len) as u32;
-            let performance_cost = performance_cost_sum / scores_len + FLAT_BONUS;
+            let avg_performance_cost = performance_cost_sum / scores_len;
 
             let mut tiebreaker_bonus = 0.0;
 
-            if let Some(game) = tiebreaker_game
+            if formula.has_bonuses()
+                && let Some(game) = tiebreaker_game
                 && game.scores.iter().any(|score| score.user_id == *user_id)
                 && let Some(entry) = entries.last()
             {
@@ -518,20 +622,38 @@ impl UsersPerformanceCosts {
                     MAX_TIEBREAKER_BONUS.min(TIEBREAKER_FACTOR * entry.performance_cost);
             }
 
-            let exp = if games_count <= 1 {
-                0.0
-            } else {
-                (scores_len - 1.0) / (games_count - 1) as f32
-            };
+            let (performance_cost, participation_bonus_factor) = match formula {
+                MatchCostFormula::Default => {
+                    let exp = if games_count <= 1 {
+                        0.0
+                    } else {
+                        (scores_len - 1.0) / (games_count - 1) as f32
+                    };
+
+                    let participation_bonus_factor =
+                        BASE_PARTICIPATION_BONUS.powf(exp.powf(EXP_PARTICIPATION_BONUS));
+
+                    (
+                        avg_performance_cost + FLAT_BONUS,
+                        participation_bonus_factor,
+                    )
+                }
+                MatchCostFormula::Classic => {
+                    let exp = scores_len / games_count as f32;
 
-            let participation_bonus_factor =
-                BASE_PARTICIPATION_BONUS.powf(exp.powf(EXP_PARTICIPATION_BONUS));
+                    let participation_bonus_factor = CLASSIC_BASE_PARTICIPATION_BONUS
+                        .powf(exp.powf(CLASSIC_EXP_PARTICIPATION_BONUS));
+
+                    (avg_performance_cost, participation_bonus_factor)
+                }
+                MatchCostFormula::Average => (avg_performance_cost, 1.0),
+            };
 
             let mods_used = users_mods.get_count(*user_id).unwrap_or(0) as u32;
 
             let mut mods_bonus_factor = 1.0;
 
-            if mods_used > 2 {
+            if formula.has_bonuses() && mods_used > 2 {
                 mods_bonus_factor += MOD_BONUS * (mods_used - 2) as f32;
             }
 
@@ -653,6 +775,8 @@ impl UserMatchCostEntry {
 pub struct TeamResult {
     pub players: Vec<UserMatchCostEntry>,
     pub win_count: u32,
+    /// Average match cost of the team's players
+    pub avg_match_cost: f32,
 }
 
 impl TeamResult {
@@ -660,8 +784,18 @@ impl TeamResult {
         Self {
             players: Vec::new(),
             win_count,
+            avg_match_cost: 0.0,
         }
     }
+
+    fn calculate_avg(&mut self) {
+        if self.players.is_empty() {
+            return;
+        }
+
+        let sum: f32 = self.players.iter().map(|entry| entry.match_cost).sum();
+        self.avg_match_cost = sum / self.players.len() as f32;
+    }
 }
 
 pub enum MatchResult {
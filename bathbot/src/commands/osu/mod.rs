@@ -63,7 +63,7 @@ macro_rules! user_id_mode {
 
 use std::{future::Future, pin::Pin};
 
-use bathbot_util::osu::ModsResult;
+use bathbot_util::{constants::GENERAL_ISSUE, osu::ModsResult};
 use eyre::{Report, Result, WrapErr};
 use rosu_v2::request::UserId;
 use twilight_interactions::command::{CommandOption, CreateOption};
@@ -77,6 +77,7 @@ pub use self::{
 use crate::{
     Context,
     core::commands::{CommandOrigin, interaction::InteractionCommands},
+    util::{InteractionCommandExt, interaction::InteractionCommand, osu::MapOrScore},
 };
 
 mod attributes;
@@ -112,6 +113,7 @@ mod ratios;
 mod recent;
 pub(crate) mod relax;
 mod render;
+mod replay;
 mod serverleaderboard;
 mod simulate;
 mod snipe;
@@ -177,6 +179,31 @@ pub async fn require_link(orig: &CommandOrigin<'_>) -> Result<()> {
         .wrap_err("Failed to send require-link message")
 }
 
+/// Find a map or score in the message on which a message command was used.
+///
+/// If none is found, an error response is sent and `None` is returned.
+pub async fn target_map_or_score(command: &InteractionCommand) -> Result<Option<MapOrScore>> {
+    let Some(msg) = command.target_message() else {
+        let _ = command.error(GENERAL_ISSUE).await;
+
+        bail!("Missing resolved message");
+    };
+
+    if let map_or_score @ Some(_) = MapOrScore::find_in_msg(msg).await {
+        return Ok(map_or_score);
+    }
+
+    let content = "Could not find map in that message.\n\
+        Be sure either:\n\
+        - the message content is a map url\n\
+        - the embed author url is a map url\n\
+        - the embed url is a map url";
+
+    command.error(content).await?;
+
+    Ok(None)
+}
+
 pub async fn user_not_found(user_id: UserId) -> String {
     let user_id = match user_id {
         user_id @ UserId::Name(_) => user_id,
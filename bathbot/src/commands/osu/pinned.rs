@@ -14,7 +14,7 @@ use bathbot_util::{
     constants::{GENERAL_ISSUE, OSU_API_ISSUE},
     matcher,
     osu::ModSelection,
-    query::{IFilterCriteria, Searchable, TopCriteria},
+    query::{IFilterCriteria, TopCriteria},
 };
 use eyre::{Report, Result};
 use rand::{Rng, thread_rng};
@@ -61,7 +61,9 @@ pub struct Pinned<'a> {
         help = "Filter out scores similarly as you filter maps in osu! itself.\n\
         You can specify the artist, creator, difficulty, title, or limit values such as \
         ar, cs, hp, od, bpm, length, or stars like for example `fdfd ar>10 od>=9`.\n\
-        While ar & co will be adjusted to mods, stars will not."
+        While ar & co will be adjusted to mods, stars will not.\n\
+        Terms can be combined with `OR`, negated with `NOT` or `-`, and grouped with \
+        parentheses e.g. `(ar>10 OR od>10) -fdfd`."
     )]
     query: Option<Cow<'a, str>>,
     #[command(desc = "Reverse the resulting score list")]
@@ -513,7 +515,9 @@ async fn process_scores(
         half.original_idx = Some(i);
 
         if let Some(ref criteria) = filter_criteria {
-            if half.matches(criteria) {
+            half.prepare_criteria(criteria).await;
+
+            if criteria.matches(&half) {
                 entries.push(half.into());
             }
         } else {
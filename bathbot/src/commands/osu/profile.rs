@@ -1,6 +1,6 @@
 use std::borrow::Cow;
 
-use bathbot_macros::{HasName, SlashCommand, command};
+use bathbot_macros::{HasName, SlashCommand, command, user_command};
 use bathbot_model::command_fields::GameModeOption;
 use bathbot_psql::model::configs::ScoreData;
 use bathbot_util::{CowUtils, MessageOrigin, constants::GENERAL_ISSUE, matcher};
@@ -163,6 +163,18 @@ async fn slash_profile(mut command: InteractionCommand) -> Result<()> {
     profile((&mut command).into(), args).await
 }
 
+#[user_command(name = "osu! profile", bucket(All))]
+async fn user_profile(mut command: InteractionCommand) -> Result<()> {
+    let args = Profile {
+        mode: None,
+        name: None,
+        embed: None,
+        discord: command.target_user(),
+    };
+
+    profile((&mut command).into(), args).await
+}
+
 async fn profile(orig: CommandOrigin<'_>, args: Profile<'_>) -> Result<()> {
     let owner = orig.user_id()?;
 
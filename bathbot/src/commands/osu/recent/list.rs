@@ -12,11 +12,8 @@ use bathbot_model::{
 };
 use bathbot_psql::model::configs::ScoreData;
 use bathbot_util::{
-    CowUtils, IntHasher,
-    constants::GENERAL_ISSUE,
-    matcher,
-    osu::ModSelection,
-    query::{IFilterCriteria, Searchable},
+    CowUtils, IntHasher, constants::GENERAL_ISSUE, matcher, osu::ModSelection,
+    query::IFilterCriteria,
 };
 use eyre::{Report, Result};
 use rosu_pp::{Beatmap, Difficulty, any::DifficultyAttributes};
@@ -375,6 +372,7 @@ pub(super) async fn list(orig: CommandOrigin<'_>, args: RecentList<'_>) -> Resul
         .recent(legacy_scores)
         .limit(100)
         .include_fails(include_fails)
+        .archived(true)
         .exec_with_user(user_args);
 
     let (user, scores) = match scores_fut.await {
@@ -504,7 +502,7 @@ async fn process_scores(
     let mut entries = Vec::new();
 
     let score_filter = |score: &Score| {
-        if filter_criteria.as_ref().is_some_and(|c| !score.matches(c)) {
+        if filter_criteria.as_ref().is_some_and(|c| !c.matches(score)) {
             return false;
         }
 
@@ -534,8 +532,12 @@ async fn process_scores(
     let maps_id_checksum = scores
         .iter()
         .filter(|&score| score_filter(score))
-        .filter_map(|score| score.map.as_ref())
-        .map(|map| (map.map_id as i32, map.checksum.as_deref()))
+        .map(|score| {
+            (
+                score.map_id as i32,
+                score.map.as_ref().and_then(|map| map.checksum.as_deref()),
+            )
+        })
         .collect();
 
     let mut maps = Context::osu_map().maps(&maps_id_checksum).await?;
@@ -115,7 +115,9 @@ pub struct RecentBest {
         help = "Filter out scores similarly as you filter maps in osu! itself.\n\
         You can specify the artist, creator, difficulty, title, or limit values such as \
         ar, cs, hp, od, bpm, length, or stars like for example `fdfd ar>10 od>=9`.\n\
-        While ar & co will be adjusted to mods, stars will not."
+        While ar & co will be adjusted to mods, stars will not.\n\
+        Terms can be combined with `OR`, negated with `NOT` or `-`, and grouped with \
+        parentheses e.g. `(ar>10 OR od>10) -fdfd`."
     )]
     query: Option<String>,
     #[command(desc = "Consider only scores with this grade")]
@@ -214,7 +216,9 @@ pub struct RecentList<'a> {
         help = "Filter out scores similarly as you filter maps in osu! itself.\n\
         You can specify the artist, creator, difficulty, title, or limit values such as \
         ar, cs, hp, od, bpm, length, or stars like for example `fdfd ar>10 od>=9`.\n\
-        While ar & co will be adjusted to mods, stars will not."
+        While ar & co will be adjusted to mods, stars will not.\n\
+        Terms can be combined with `OR`, negated with `NOT` or `-`, and grouped with \
+        parentheses e.g. `(ar>10 OR od>10) -fdfd`."
     )]
     query: Option<String>,
     #[command(desc = "Consider only scores with this grade")]
@@ -336,7 +340,9 @@ pub struct Rb {
         help = "Filter out scores similarly as you filter maps in osu! itself.\n\
         You can specify the artist, creator, difficulty, title, or limit values such as \
         ar, cs, hp, od, bpm, length, or stars like for example `fdfd ar>10 od>=9`.\n\
-        While ar & co will be adjusted to mods, stars will not."
+        While ar & co will be adjusted to mods, stars will not.\n\
+        Terms can be combined with `OR`, negated with `NOT` or `-`, and grouped with \
+        parentheses e.g. `(ar>10 OR od>10) -fdfd`."
     )]
     query: Option<String>,
     #[command(desc = "Consider only scores with this grade")]
@@ -1,6 +1,6 @@
 use std::{borrow::Cow, sync::Arc};
 
-use bathbot_macros::{HasName, SlashCommand, command};
+use bathbot_macros::{HasName, SlashCommand, command, user_command};
 use bathbot_model::{
     command_fields::{GameModeOption, GradeOption},
     embed_builder::SettingsImage,
@@ -851,6 +851,21 @@ async fn slash_rs(mut command: InteractionCommand) -> Result<()> {
     score((&mut command).into(), args.into()).await
 }
 
+#[user_command(name = "Recent score", bucket(All))]
+async fn user_recent_score(mut command: InteractionCommand) -> Result<()> {
+    let args = RecentScore {
+        mode: None,
+        name: None,
+        index: None,
+        grade: None,
+        passes: None,
+        discord: command.target_user(),
+        score_data: None,
+    };
+
+    score((&mut command).into(), args).await
+}
+
 #[derive(Default)]
 struct GuildValues {
     retries: Option<Retries>,
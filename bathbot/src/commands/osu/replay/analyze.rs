@@ -0,0 +1,257 @@
+use bathbot_util::osr::{Replay, ReplayFrame, ReplayKeys};
+use rosu_pp::Beatmap;
+use rosu_v2::prelude::GameModIntermode;
+
+/// Height of the osu!standard playfield in osu!pixels
+const PLAYFIELD_HEIGHT: f32 = 384.0;
+
+/// Statistics of an osu!standard replay.
+///
+/// Hits are aligned by matching each hit object with the first unused key
+/// press within its 50 hit window that lands inside the circle. This ignores
+/// notelock, stacking, and slider specifics so values are approximate.
+pub struct ReplayAnalysis {
+    /// Objects that were matched with a key press
+    pub hits: usize,
+    /// Objects that can be hit by a key press i.e. no spinners
+    pub hittable: usize,
+    pub unstable_rate: Option<f64>,
+    /// Negative values mean early hits
+    pub hit_error_mean: Option<f64>,
+    pub key_presses: KeyPresses,
+    /// Total distance of the cursor in osu!pixels
+    pub cursor_travel: f64,
+    pub frame_time: Option<FrameTimeStats>,
+}
+
+#[derive(Default)]
+pub struct KeyPresses {
+    pub k1: usize,
+    pub k2: usize,
+    pub m1: usize,
+    pub m2: usize,
+}
+
+impl KeyPresses {
+    pub fn total(&self) -> usize {
+        self.k1 + self.k2 + self.m1 + self.m2
+    }
+}
+
+/// Milliseconds between frames in real time
+pub struct FrameTimeStats {
+    pub mean: f64,
+    pub median: f64,
+    pub std_dev: f64,
+}
+
+struct Press {
+    time: f64,
+    x: f32,
+    y: f32,
+    used: bool,
+}
+
+impl ReplayAnalysis {
+    pub fn new(replay: &Replay, map: &Beatmap) -> Self {
+        let hard_rock = replay.mods.contains(GameModIntermode::HardRock);
+        let easy = replay.mods.contains(GameModIntermode::Easy);
+
+        let clock_rate = if replay.mods.contains(GameModIntermode::DoubleTime)
+            || replay.mods.contains(GameModIntermode::Nightcore)
+        {
+            1.5
+        } else if replay.mods.contains(GameModIntermode::HalfTime)
+            || replay.mods.contains(GameModIntermode::Daycore)
+        {
+            0.75
+        } else {
+            1.0
+        };
+
+        let adjust = |value: f32| {
+            if hard_rock {
+                (value * 1.4).min(10.0)
+            } else if easy {
+                value * 0.5
+            } else {
+                value
+            }
+        };
+
+        let od = adjust(map.od);
+
+        let cs = if hard_rock {
+            (map.cs * 1.3).min(10.0)
+        } else {
+            adjust(map.cs)
+        };
+
+        let hit_window_50 = 200.0 - 10.0 * od as f64;
+        let radius = 54.4 - 4.48 * cs;
+
+        let (key_presses, mut presses) = collect_presses(&replay.frames, hard_rock);
+
+        let mut hit_errors = Vec::with_capacity(map.hit_objects.len());
+        let mut hittable = 0;
+        let mut press_idx = 0;
+
+        for h in map.hit_objects.iter().filter(|h| !h.is_spinner()) {
+            hittable += 1;
+
+            let window_start = h.start_time - hit_window_50;
+            let window_end = h.start_time + hit_window_50;
+
+            // Presses before the window of this object are too early
+            // for all subsequent objects as well
+            while presses
+                .get(press_idx)
+                .is_some_and(|press| press.time < window_start)
+            {
+                press_idx += 1;
+            }
+
+            let hit = presses[press_idx..]
+                .iter_mut()
+                .take_while(|press| press.time <= window_end)
+                .find(|press| {
+                    let dx = press.x - h.pos.x;
+                    let dy = press.y - h.pos.y;
+
+                    !press.used && dx * dx + dy * dy <= radius * radius
+                });
+
+            if let Some(press) = hit {
+                press.used = true;
+                hit_errors.push(press.time - h.start_time);
+            }
+        }
+
+        let hits = hit_errors.len();
+
+        let (hit_error_mean, unstable_rate) = if hit_errors.is_empty() {
+            (None, None)
+        } else {
+            let (mean, std_dev) = mean_std_dev(&hit_errors);
+
+            // Both values are shown in real time rather than map time
+            (Some(mean / clock_rate), Some(std_dev * 10.0 / clock_rate))
+        };
+
+        let cursor_travel = replay
+            .frames
+            .windows(2)
+            .map(|window| {
+                let dx = window[1].x - window[0].x;
+                let dy = window[1].y - window[0].y;
+
+                (dx * dx + dy * dy).sqrt() as f64
+            })
+            .sum();
+
+        Self {
+            hits,
+            hittable,
+            unstable_rate,
+            hit_error_mean,
+            key_presses,
+            cursor_travel,
+            frame_time: FrameTimeStats::new(&replay.frames, clock_rate),
+        }
+    }
+}
+
+impl FrameTimeStats {
+    fn new(frames: &[ReplayFrame], clock_rate: f64) -> Option<Self> {
+        // The first frames of a replay only carry metadata
+        let mut deltas: Vec<_> = frames
+            .iter()
+            .filter(|frame| frame.time > 0 && frame.delta > 0)
+            .map(|frame| frame.delta as f64 / clock_rate)
+            .collect();
+
+        if deltas.is_empty() {
+            return None;
+        }
+
+        deltas.sort_unstable_by(f64::total_cmp);
+
+        let median = if deltas.len() % 2 == 0 {
+            (deltas[deltas.len() / 2 - 1] + deltas[deltas.len() / 2]) / 2.0
+        } else {
+            deltas[deltas.len() / 2]
+        };
+
+        let (mean, std_dev) = mean_std_dev(&deltas);
+
+        Some(Self {
+            mean,
+            median,
+            std_dev,
+        })
+    }
+}
+
+/// Collects the amount of presses per key and the position of all presses.
+fn collect_presses(frames: &[ReplayFrame], flip_y: bool) -> (KeyPresses, Vec<Press>) {
+    let mut key_presses = KeyPresses::default();
+    let mut presses = Vec::new();
+    let mut prev = [false; 4];
+
+    for frame in frames {
+        let keys = frame.keys;
+
+        // Keyboard presses also set the bit of their mouse button
+        let k1 = keys.contains(ReplayKeys::K1);
+        let k2 = keys.contains(ReplayKeys::K2);
+        let m1 = keys.contains(ReplayKeys::M1) && !k1;
+        let m2 = keys.contains(ReplayKeys::M2) && !k2;
+        let curr = [k1, k2, m1, m2];
+
+        let counters = [
+            &mut key_presses.k1,
+            &mut key_presses.k2,
+            &mut key_presses.m1,
+            &mut key_presses.m2,
+        ];
+
+        let mut pressed = false;
+
+        for ((counter, curr), prev) in counters.into_iter().zip(curr).zip(prev) {
+            if curr && !prev {
+                *counter += 1;
+                pressed = true;
+            }
+        }
+
+        if pressed {
+            presses.push(Press {
+                time: frame.time as f64,
+                x: frame.x,
+                y: if flip_y {
+                    PLAYFIELD_HEIGHT - frame.y
+                } else {
+                    frame.y
+                },
+                used: false,
+            });
+        }
+
+        prev = curr;
+    }
+
+    (key_presses, presses)
+}
+
+fn mean_std_dev(values: &[f64]) -> (f64, f64) {
+    let len = values.len() as f64;
+    let mean = values.iter().sum::<f64>() / len;
+
+    let variance = values
+        .iter()
+        .map(|value| (value - mean) * (value - mean))
+        .sum::<f64>()
+        / len;
+
+    (mean, variance.sqrt())
+}
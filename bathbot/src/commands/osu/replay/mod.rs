@@ -0,0 +1,294 @@
+use std::fmt::Write;
+
+use bathbot_macros::SlashCommand;
+use bathbot_util::{
+    CowUtils, EmbedBuilder, FooterBuilder, MessageBuilder,
+    constants::{GENERAL_ISSUE, OSU_API_ISSUE, OSU_BASE},
+    matcher,
+    numbers::{WithComma, round},
+    osr,
+};
+use eyre::{Report, Result};
+use rosu_v2::prelude::{GameMode, OsuError};
+use twilight_interactions::command::{CommandModel, CreateCommand};
+use twilight_model::channel::{Attachment, message::embed::EmbedField};
+
+use self::analyze::ReplayAnalysis;
+use crate::{
+    core::Context,
+    manager::ReplayError,
+    util::{InteractionCommandExt, interaction::InteractionCommand},
+};
+
+mod analyze;
+
+#[derive(CommandModel, CreateCommand, SlashCommand)]
+#[command(
+    name = "replay",
+    desc = "Inspect osu!standard replays",
+    help = "Inspect osu!standard replays.\n\
+    Replays can either be attached as .osr file or specified through a score id or url \
+    in which case the replay will be downloaded from osu!."
+)]
+pub enum Replay {
+    #[command(name = "analyze")]
+    Analyze(ReplayAnalyze),
+}
+
+#[derive(CommandModel, CreateCommand)]
+#[command(
+    name = "analyze",
+    desc = "Analyze the hits, keys, and cursor of a replay",
+    help = "Analyze the hits, keys, and cursor of a replay.\n\
+    Hits are aligned with the map by matching each object with the first key press \
+    inside the circle within the object's 50 hit window. \
+    Notelock, stacking, and sliders are not taken into account \
+    so the unstable rate and hit error are approximations."
+)]
+pub struct ReplayAnalyze {
+    #[command(desc = "Specify the replay through a .osr file")]
+    replay: Option<Attachment>,
+    #[command(desc = "Specify the replay through a score id or url")]
+    score: Option<String>,
+}
+
+pub async fn slash_replay(mut command: InteractionCommand) -> Result<()> {
+    match Replay::from_interaction(command.input_data())? {
+        Replay::Analyze(args) => analyze(&mut command, args).await,
+    }
+}
+
+async fn analyze(command: &mut InteractionCommand, args: ReplayAnalyze) -> Result<()> {
+    let parse_res = match args {
+        ReplayAnalyze {
+            replay: Some(attachment),
+            ..
+        } => {
+            if !attachment.filename.ends_with(".osr") {
+                let content = "The attached replay must be a .osr file";
+                command.error(content).await?;
+
+                return Ok(());
+            }
+
+            match Context::client().get_discord_attachment(&attachment).await {
+                Ok(bytes) => osr::Replay::parse(&bytes),
+                Err(err) => {
+                    let _ = command.error(GENERAL_ISSUE).await;
+
+                    return Err(err.wrap_err("Failed to download attachment"));
+                }
+            }
+        }
+        ReplayAnalyze {
+            score: Some(score), ..
+        } => {
+            let score_id = match score.parse() {
+                Ok(score_id) => score_id,
+                Err(_) => match matcher::get_osu_score_id(&score) {
+                    Some((score_id, _)) => score_id,
+                    None => {
+                        let content = "Must give either a score id or url";
+                        command.error(content).await?;
+
+                        return Ok(());
+                    }
+                },
+            };
+
+            match Context::replay().get_replay(score_id).await {
+                Ok(Some(bytes)) => osr::Replay::parse(&bytes),
+                Ok(None) => {
+                    let content = "Looks like the replay for that score is not available";
+                    command.error(content).await?;
+
+                    return Ok(());
+                }
+                Err(ReplayError::Osu(OsuError::NotFound)) => {
+                    command.error("Found no score with that id").await?;
+
+                    return Ok(());
+                }
+                Err(ReplayError::Osu(err)) => {
+                    let _ = command.error(OSU_API_ISSUE).await;
+
+                    return Err(Report::new(err).wrap_err("Failed to get replay"));
+                }
+                Err(ReplayError::AlreadyRequestedCheck(err)) => {
+                    let _ = command.error(GENERAL_ISSUE).await;
+
+                    return Err(err.wrap_err(ReplayError::ALREADY_REQUESTED_TEXT));
+                }
+            }
+        }
+        ReplayAnalyze {
+            replay: None,
+            score: None,
+        } => {
+            let content = "You must specify either a replay file or a score";
+            command.error(content).await?;
+
+            return Ok(());
+        }
+    };
+
+    let replay = match parse_res {
+        Ok(replay) => replay,
+        Err(err) => {
+            debug!(?err, "Failed to parse replay");
+
+            let content = "Failed to parse replay. Be sure you provide a valid .osr file.";
+            command.error(content).await?;
+
+            return Ok(());
+        }
+    };
+
+    if replay.mode != GameMode::Osu {
+        let content = "Only osu!standard replays can be analyzed";
+        command.error(content).await?;
+
+        return Ok(());
+    }
+
+    let map = match Context::osu()
+        .beatmap()
+        .checksum(replay.map_md5.as_ref())
+        .await
+    {
+        Ok(map) => map,
+        Err(OsuError::NotFound) => {
+            let content = "The map of the replay is not available on osu!";
+            command.error(content).await?;
+
+            return Ok(());
+        }
+        Err(err) => {
+            let _ = command.error(OSU_API_ISSUE).await;
+
+            return Err(Report::new(err).wrap_err("Failed to get map of replay"));
+        }
+    };
+
+    let pp_map = match Context::osu_map().pp_map(map.map_id).await {
+        Ok(pp_map) => pp_map,
+        Err(err) => {
+            let _ = command.error(GENERAL_ISSUE).await;
+
+            return Err(err.wrap_err("Failed to get pp map"));
+        }
+    };
+
+    let analysis = ReplayAnalysis::new(&replay, &pp_map);
+
+    let mut description = format!(
+        "Replay of **{name}** on [{map_name}]({OSU_BASE}b/{map_id})",
+        name = replay.username.cow_escape_markdown(),
+        map_name = match map.mapset {
+            Some(ref mapset) => format!(
+                "{} - {} [{}]",
+                mapset.artist.cow_escape_markdown(),
+                mapset.title.cow_escape_markdown(),
+                map.version.cow_escape_markdown()
+            ),
+            None => format!("[{}]", map.version.cow_escape_markdown()),
+        },
+        map_id = map.map_id,
+    );
+
+    if !replay.mods.is_empty() {
+        let _ = write!(description, " +{}", replay.mods);
+    }
+
+    let _ = write!(
+        description,
+        "\n{score} • x{combo} • {n300}/{n100}/{n50}/{miss}",
+        score = WithComma::new(replay.score),
+        combo = replay.max_combo,
+        n300 = replay.count_300,
+        n100 = replay.count_100,
+        n50 = replay.count_50,
+        miss = replay.count_miss,
+    );
+
+    let fields = vec![
+        field("Unstable rate", unstable_rate(&analysis)),
+        field("Hit error", hit_error(&analysis)),
+        field("Keys", keys(&analysis)),
+        field(
+            "Cursor travel",
+            format!(
+                "{}px",
+                WithComma::new(analysis.cursor_travel.round() as u64)
+            ),
+        ),
+        field("Frame time", frame_time(&analysis)),
+    ];
+
+    let embed = EmbedBuilder::new()
+        .title("Replay analysis")
+        .description(description)
+        .fields(fields)
+        .footer(FooterBuilder::new("Hit alignment is approximate"))
+        .timestamp(replay.timestamp);
+
+    let builder = MessageBuilder::new().embed(embed);
+    command.update(builder).await?;
+
+    Ok(())
+}
+
+fn field(name: &str, value: String) -> EmbedField {
+    EmbedField {
+        inline: true,
+        name: name.to_owned(),
+        value,
+    }
+}
+
+fn unstable_rate(analysis: &ReplayAnalysis) -> String {
+    match analysis.unstable_rate {
+        Some(ur) => format!(
+            "`{}` ({}/{} objects aligned)",
+            round(ur as f32),
+            analysis.hits,
+            analysis.hittable
+        ),
+        None => "No hits could be aligned".to_owned(),
+    }
+}
+
+fn hit_error(analysis: &ReplayAnalysis) -> String {
+    match analysis.hit_error_mean {
+        Some(mean) if mean < 0.0 => format!("`{}ms` early", round(-mean as f32)),
+        Some(mean) => format!("`{}ms` late", round(mean as f32)),
+        None => "-".to_owned(),
+    }
+}
+
+fn keys(analysis: &ReplayAnalysis) -> String {
+    let presses = &analysis.key_presses;
+    let total = presses.total().max(1) as f32;
+
+    let percent = |count: usize| round(100.0 * count as f32 / total);
+
+    format!(
+        "K1 `{}%` • K2 `{}%`\nM1 `{}%` • M2 `{}%`",
+        percent(presses.k1),
+        percent(presses.k2),
+        percent(presses.m1),
+        percent(presses.m2),
+    )
+}
+
+fn frame_time(analysis: &ReplayAnalysis) -> String {
+    match analysis.frame_time {
+        Some(ref stats) => format!(
+            "Mean `{}ms` • Median `{}ms`\nStd dev `{}ms`",
+            round(stats.mean as f32),
+            round(stats.median as f32),
+            round(stats.std_dev as f32),
+        ),
+        None => "-".to_owned(),
+    }
+}
@@ -3,7 +3,7 @@ pub mod parsed_map;
 
 use std::borrow::Cow;
 
-use bathbot_macros::{HasMods, SlashCommand, command};
+use bathbot_macros::{HasMods, SlashCommand, command, msg_command};
 use bathbot_model::command_fields::GameModeOption;
 use bathbot_psql::model::configs::ScoreData;
 use bathbot_util::{
@@ -22,7 +22,7 @@ use twilight_model::{
 use self::args::{ParseError, SimulateArg};
 use super::{
     HasMods, ModsResult, TopOldCatchVersion, TopOldManiaVersion, TopOldOsuVersion,
-    TopOldTaikoVersion,
+    TopOldTaikoVersion, target_map_or_score,
 };
 use crate::{
     active::{
@@ -102,6 +102,27 @@ pub async fn slash_simulate(mut command: InteractionCommand) -> Result<()> {
     }
 }
 
+#[msg_command(name = "Simulate map", bucket(All))]
+async fn msg_simulate(mut command: InteractionCommand) -> Result<()> {
+    let map = match target_map_or_score(&command).await? {
+        Some(MapOrScore::Map(map)) => map,
+        Some(MapOrScore::Score { .. }) => {
+            let content = "I found a score id in that message but I need a map id";
+            command.error(content).await?;
+
+            return Ok(());
+        }
+        None => return Ok(()),
+    };
+
+    let args = SimulateArgs {
+        map: Some(SimulateMapArg::Id(map)),
+        ..Default::default()
+    };
+
+    simulate((&mut command).into(), args).await
+}
+
 async fn simulate(orig: CommandOrigin<'_>, mut args: SimulateArgs) -> Result<()> {
     let owner = orig.user_id()?;
     let config = Context::user_config().with_osu_id(owner).await?;
@@ -9,7 +9,7 @@ use bathbot_util::{
     matcher,
     numbers::round,
     osu::ModSelection,
-    query::{FilterCriteria, IFilterCriteria, Searchable, TopCriteria},
+    query::{FilterCriteria, FilterExpr, IFilterCriteria, Searchable, TopCriteria},
 };
 use eyre::{Report, Result};
 use rosu_v2::{
@@ -31,7 +31,7 @@ use crate::{
         OsuMap,
         redis::osu::{UserArgs, UserArgsError},
     },
-    util::{ChannelExt, InteractionCommandExt, interaction::InteractionCommand},
+    util::{ChannelExt, InteractionCommandExt, interaction::InteractionCommand, osu::IfFc},
 };
 
 #[derive(CommandModel, CreateCommand, HasName, SlashCommand)]
@@ -60,8 +60,11 @@ pub struct TopIf<'a> {
         desc = "Specify a search query containing artist, difficulty, AR, BPM, ...",
         help = "Filter out scores similarly as you filter maps in osu! itself.\n\
         You can specify the artist, creator, difficulty, title, or limit values such as \
-        ar, cs, hp, od, bpm, length, stars, pp, acc, score, misses, date or ranked_date \
-        e.g. `ar>10 od>=9 ranked<2017-01-01 creator=monstrata acc>99 acc<=99.5`."
+        ar, cs, hp, od, bpm, length, stars, pp, pp_fc, acc, score, misses, date or ranked_date \
+        e.g. `ar>10 od>=9 ranked<2017-01-01 creator=monstrata acc>99 acc<=99.5`.\n\
+        You can also filter by `mods=+hd`, `grade>=s`, `status=loved`, or `lazer=false`.\n\
+        Terms can be combined with `OR`, negated with `NOT` or `-`, and grouped with \
+        parentheses e.g. `(mods=+hr OR ar>10) artist!=camellia`."
     )]
     query: Option<String>,
     #[command(
@@ -307,7 +310,8 @@ async fn topif(orig: CommandOrigin<'_>, args: TopIf<'_>) -> Result<()> {
     // Process query afterwards so that total pp is calculated with *all* scores
     if let Some(query) = args.query.as_deref() {
         let criteria = TopCriteria::create(query);
-        entries.retain(|entry| entry.matches(&criteria));
+        TopIfEntry::prepare_criteria(&mut entries, &criteria).await;
+        entries.retain(|entry| criteria.matches(entry));
     }
 
     let final_pp = round(bonus_pp + adjusted_pp);
@@ -354,9 +358,25 @@ pub struct TopIfEntry {
     pub stars: f32,
     pub max_pp: f32,
     pub max_combo: u32,
+    /// Only calculated if the filter query requires it
+    pub if_fc_pp: Option<f32>,
 }
 
 impl TopIfEntry {
+    /// Calculate values that the filter criteria require but that are too
+    /// expensive to calculate for all entries by default.
+    pub async fn prepare_criteria(entries: &mut [Self], criteria: &FilterExpr<TopCriteria<'_>>) {
+        if !criteria.any_criteria(|criteria| !criteria.pp_fc.is_empty()) {
+            return;
+        }
+
+        for entry in entries.iter_mut() {
+            entry.if_fc_pp = IfFc::new(&entry.score, &entry.map)
+                .await
+                .map(|if_fc| if_fc.pp);
+        }
+    }
+
     pub fn pp_diff(&self) -> f32 {
         self.score.pp - self.old_pp
     }
@@ -376,7 +396,14 @@ impl<'q> Searchable<TopCriteria<'q>> for TopIfEntry {
         matches &= criteria.date.contains(self.score.ended_at.date());
         matches &= criteria.stars.contains(self.stars);
         matches &= criteria.pp.contains(self.score.pp);
+        matches &= criteria
+            .pp_fc
+            .contains(self.if_fc_pp.unwrap_or(self.score.pp));
         matches &= criteria.acc.contains(self.score.accuracy);
+        matches &= criteria.grade.contains_grade(self.score.grade);
+        matches &= criteria.mods.matches(&self.score.mods);
+        matches &= criteria.status.matches(self.map.status());
+        matches &= criteria.lazer.matches(self.score.set_on_lazer);
 
         if !criteria.ranked_date.is_empty() {
             let Some(datetime) = self.map.ranked_date() else {
@@ -593,6 +620,7 @@ async fn process_scores(
             stars,
             max_pp,
             max_combo,
+            if_fc_pp: None,
         };
 
         entries.push(entry);
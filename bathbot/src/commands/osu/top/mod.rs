@@ -12,7 +12,7 @@ use bathbot_util::{
     matcher,
     numbers::round,
     osu::ModSelection,
-    query::{IFilterCriteria, Searchable, TopCriteria},
+    query::{IFilterCriteria, TopCriteria},
 };
 use eyre::{Report, Result};
 use rand::{Rng, thread_rng};
@@ -83,8 +83,11 @@ pub struct Top {
         desc = "Specify a search query containing artist, difficulty, AR, BPM, ...",
         help = "Filter out scores similarly as you filter maps in osu! itself.\n\
         You can specify the artist, creator, difficulty, title, or limit values such as \
-        ar, cs, hp, od, bpm, length, stars, pp, acc, score, misses, date or ranked_date \
-        e.g. `ar>10 od>=9 ranked<2017-01-01 creator=monstrata acc>99 acc<=99.5`."
+        ar, cs, hp, od, bpm, length, stars, pp, pp_fc, acc, score, misses, date or ranked_date \
+        e.g. `ar>10 od>=9 ranked<2017-01-01 creator=monstrata acc>99 acc<=99.5`.\n\
+        You can also filter by `mods=+hd`, `grade>=s`, `status=loved`, or `lazer=false`.\n\
+        Terms can be combined with `OR`, negated with `NOT` or `-`, and grouped with \
+        parentheses e.g. `(mods=+hr OR ar>10) artist!=camellia`."
     )]
     query: Option<String>,
     #[command(desc = "Consider only scores with this grade")]
@@ -784,6 +787,7 @@ pub(super) async fn top(orig: CommandOrigin<'_>, args: TopArgs<'_>) -> Result<()
     let user_args = UserArgs::rosu_id(&user_id, mode).await;
     let scores_fut = Context::osu_scores()
         .top(200, legacy_scores)
+        .archived(true)
         .exec_with_user(user_args);
 
     let (user, scores) = match scores_fut.await {
@@ -993,7 +997,7 @@ async fn process_scores(
 
         let pb_idx = Some(ScoreEmbedDataPersonalBest::from_index(i));
 
-        let half = ScoreEmbedDataHalf::new(
+        let mut half = ScoreEmbedDataHalf::new(
             score,
             map,
             pb_idx,
@@ -1004,7 +1008,9 @@ async fn process_scores(
         .await;
 
         if let Some(ref criteria) = filter_criteria {
-            if half.matches(criteria) {
+            half.prepare_criteria(criteria).await;
+
+            if criteria.matches(&half) {
                 entries.push(half.into());
             }
         } else {
@@ -11,7 +11,7 @@ use bathbot_util::{
     matcher,
     numbers::round,
     osu::ModSelection,
-    query::{FilterCriteria, IFilterCriteria, Searchable, TopCriteria},
+    query::{FilterExpr, IFilterCriteria, TopCriteria},
 };
 use eyre::{Report, Result};
 use rosu_pp::any::DifficultyAttributes;
@@ -88,8 +88,11 @@ pub struct TopOldOsu<'a> {
         desc = "Specify a search query containing artist, difficulty, AR, BPM, ...",
         help = "Filter out scores similarly as you filter maps in osu! itself.\n\
         You can specify the artist, creator, difficulty, title, or limit values such as \
-        ar, cs, hp, od, bpm, length, stars, pp, acc, score, misses, date or ranked_date \
-        e.g. `ar>10 od>=9 ranked<2017-01-01 creator=monstrata acc>99 acc<=99.5`."
+        ar, cs, hp, od, bpm, length, stars, pp, pp_fc, acc, score, misses, date or ranked_date \
+        e.g. `ar>10 od>=9 ranked<2017-01-01 creator=monstrata acc>99 acc<=99.5`.\n\
+        You can also filter by `mods=+hd`, `grade>=s`, `status=loved`, or `lazer=false`.\n\
+        Terms can be combined with `OR`, negated with `NOT` or `-`, and grouped with \
+        parentheses e.g. `(mods=+hr OR ar>10) artist!=camellia`."
     )]
     query: Option<String>,
     #[command(
@@ -204,8 +207,11 @@ pub struct TopOldTaiko<'a> {
         desc = "Specify a search query containing artist, difficulty, AR, BPM, ...",
         help = "Filter out scores similarly as you filter maps in osu! itself.\n\
         You can specify the artist, creator, difficulty, title, or limit values such as \
-        ar, cs, hp, od, bpm, length, stars, pp, acc, score, misses, date or ranked_date \
-        e.g. `ar>10 od>=9 ranked<2017-01-01 creator=monstrata acc>99 acc<=99.5`."
+        ar, cs, hp, od, bpm, length, stars, pp, pp_fc, acc, score, misses, date or ranked_date \
+        e.g. `ar>10 od>=9 ranked<2017-01-01 creator=monstrata acc>99 acc<=99.5`.\n\
+        You can also filter by `mods=+hd`, `grade>=s`, `status=loved`, or `lazer=false`.\n\
+        Terms can be combined with `OR`, negated with `NOT` or `-`, and grouped with \
+        parentheses e.g. `(mods=+hr OR ar>10) artist!=camellia`."
     )]
     query: Option<String>,
     #[command(
@@ -289,8 +295,11 @@ pub struct TopOldCatch<'a> {
         desc = "Specify a search query containing artist, difficulty, AR, BPM, ...",
         help = "Filter out scores similarly as you filter maps in osu! itself.\n\
         You can specify the artist, creator, difficulty, title, or limit values such as \
-        ar, cs, hp, od, bpm, length, stars, pp, acc, score, misses, date or ranked_date \
-        e.g. `ar>10 od>=9 ranked<2017-01-01 creator=monstrata acc>99 acc<=99.5`."
+        ar, cs, hp, od, bpm, length, stars, pp, pp_fc, acc, score, misses, date or ranked_date \
+        e.g. `ar>10 od>=9 ranked<2017-01-01 creator=monstrata acc>99 acc<=99.5`.\n\
+        You can also filter by `mods=+hd`, `grade>=s`, `status=loved`, or `lazer=false`.\n\
+        Terms can be combined with `OR`, negated with `NOT` or `-`, and grouped with \
+        parentheses e.g. `(mods=+hr OR ar>10) artist!=camellia`."
     )]
     query: Option<String>,
     #[command(
@@ -362,8 +371,11 @@ pub struct TopOldMania<'a> {
         desc = "Specify a search query containing artist, difficulty, AR, BPM, ...",
         help = "Filter out scores similarly as you filter maps in osu! itself.\n\
         You can specify the artist, creator, difficulty, title, or limit values such as \
-        ar, cs, hp, od, bpm, length, stars, pp, acc, score, misses, date or ranked_date \
-        e.g. `ar>10 od>=9 ranked<2017-01-01 creator=monstrata acc>99 acc<=99.5`."
+        ar, cs, hp, od, bpm, length, stars, pp, pp_fc, acc, score, misses, date or ranked_date \
+        e.g. `ar>10 od>=9 ranked<2017-01-01 creator=monstrata acc>99 acc<=99.5`.\n\
+        You can also filter by `mods=+hd`, `grade>=s`, `status=loved`, or `lazer=false`.\n\
+        Terms can be combined with `OR`, negated with `NOT` or `-`, and grouped with \
+        parentheses e.g. `(mods=+hr OR ar>10) artist!=camellia`."
     )]
     query: Option<String>,
     #[command(
@@ -1074,6 +1086,10 @@ async fn topold(orig: CommandOrigin<'_>, args: TopOld<'_>) -> Result<()> {
     let username = user.username.as_str();
 
     // Process filter/sorting afterwards
+    if let Some(ref criteria) = common.query {
+        TopIfEntry::prepare_criteria(&mut entries, criteria).await;
+    }
+
     common.process(&mut entries);
 
     // Accumulate all necessary data
@@ -1299,6 +1315,7 @@ async fn process_scores(scores: Vec<Score>, args: &TopOld<'_>) -> Result<Vec<Top
             stars,
             max_pp,
             max_combo,
+            if_fc_pp: None,
         };
 
         entries.push(entry);
@@ -1327,14 +1344,14 @@ struct CommonArgs<'a> {
     mode: GameMode,
     sort: Option<TopIfScoreOrder>,
     mods: Option<ModSelection>,
-    query: Option<FilterCriteria<TopCriteria<'a>>>,
+    query: Option<FilterExpr<TopCriteria<'a>>>,
     reverse: Option<bool>,
 }
 
 impl CommonArgs<'_> {
     fn process(&self, entries: &mut Vec<TopIfEntry>) {
         if let Some(ref criteria) = self.query {
-            entries.retain(|entry| entry.matches(criteria));
+            entries.retain(|entry| criteria.matches(entry));
         }
 
         if let Some(ref selection) = self.mods {
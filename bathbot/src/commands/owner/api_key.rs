@@ -0,0 +1,68 @@
+use bathbot_util::{MessageBuilder, constants::GENERAL_ISSUE};
+use eyre::Result;
+use rand::{Rng, distributions::Alphanumeric};
+
+use super::OwnerApiKey;
+use crate::{
+    core::Context,
+    util::{InteractionCommandExt, interaction::InteractionCommand},
+};
+
+const KEY_LEN: usize = 32;
+
+pub async fn api_key(command: InteractionCommand, args: OwnerApiKey) -> Result<()> {
+    match args {
+        OwnerApiKey::Create(args) => {
+            let key: String = rand::thread_rng()
+                .sample_iter(&Alphanumeric)
+                .take(KEY_LEN)
+                .map(char::from)
+                .collect();
+
+            let rate_limit = args.rate_limit as u32;
+
+            let insert_fut = Context::psql().insert_api_key(&args.name, &key, rate_limit);
+
+            match insert_fut.await {
+                Ok(true) => {
+                    let content = format!(
+                        "Created API key `{name}` with {rate_limit} requests per minute:\n\
+                        ||`{key}`||\n\
+                        Only a hash of the key is stored so it won't be shown again.",
+                        name = args.name,
+                    );
+
+                    let builder = MessageBuilder::new().embed(content);
+                    command.callback(builder, true).await?;
+                }
+                Ok(false) => {
+                    let content = format!("There already is an API key named `{}`", args.name);
+                    command.error_callback(content).await?;
+                }
+                Err(err) => {
+                    let _ = command.error_callback(GENERAL_ISSUE).await;
+
+                    return Err(err.wrap_err("Failed to insert api key"));
+                }
+            }
+        }
+        OwnerApiKey::Revoke(args) => match Context::psql().delete_api_key(&args.name).await {
+            Ok(true) => {
+                let content = format!("Revoked API key `{}`", args.name);
+                let builder = MessageBuilder::new().embed(content);
+                command.callback(builder, true).await?;
+            }
+            Ok(false) => {
+                let content = format!("There is no API key named `{}`", args.name);
+                command.error_callback(content).await?;
+            }
+            Err(err) => {
+                let _ = command.error_callback(GENERAL_ISSUE).await;
+
+                return Err(err.wrap_err("Failed to delete api key"));
+            }
+        },
+    }
+
+    Ok(())
+}
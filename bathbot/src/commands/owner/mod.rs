@@ -5,13 +5,14 @@ use twilight_interactions::command::{CommandModel, CreateCommand};
 use twilight_model::channel::Attachment;
 
 pub use self::reshard::RESHARD_TX;
-use self::{add_bg::*, cache::*, request_members::*};
+use self::{add_bg::*, api_key::*, cache::*, request_members::*};
 use crate::{
     commands::owner::reshard::reshard,
     util::{InteractionCommandExt, interaction::InteractionCommand},
 };
 
 mod add_bg;
+mod api_key;
 mod cache;
 mod request_members;
 mod reshard;
@@ -24,6 +25,8 @@ mod tracking_stats;
 pub enum Owner {
     #[command(name = "add_bg")]
     AddBg(OwnerAddBg),
+    #[command(name = "apikey")]
+    ApiKey(OwnerApiKey),
     #[command(name = "cache")]
     Cache(OwnerCache),
     #[command(name = "requestmembers")]
@@ -43,6 +46,31 @@ pub struct OwnerAddBg {
     mode: Option<GameModeOption>,
 }
 
+#[derive(CommandModel, CreateCommand)]
+#[command(name = "apikey", desc = "Manage keys for the REST API")]
+pub enum OwnerApiKey {
+    #[command(name = "create")]
+    Create(OwnerApiKeyCreate),
+    #[command(name = "revoke")]
+    Revoke(OwnerApiKeyRevoke),
+}
+
+#[derive(CommandModel, CreateCommand)]
+#[command(name = "create", desc = "Create a new API key")]
+pub struct OwnerApiKeyCreate {
+    #[command(desc = "Name to identify the key", max_length = 64)]
+    name: String,
+    #[command(desc = "Allowed requests per minute", min_value = 1, max_value = 10000)]
+    rate_limit: i64,
+}
+
+#[derive(CommandModel, CreateCommand)]
+#[command(name = "revoke", desc = "Revoke an API key")]
+pub struct OwnerApiKeyRevoke {
+    #[command(desc = "Name of the key")]
+    name: String,
+}
+
 #[derive(CommandModel, CreateCommand)]
 #[command(name = "cache", desc = "Display stats about the internal cache")]
 pub struct OwnerCache;
@@ -75,6 +103,7 @@ pub struct OwnerTrackingStats;
 async fn slash_owner(mut command: InteractionCommand) -> Result<()> {
     match Owner::from_interaction(command.input_data())? {
         Owner::AddBg(bg) => addbg(command, bg).await,
+        Owner::ApiKey(args) => api_key(command, args).await,
         Owner::Cache(_) => cache(command).await,
         Owner::RequestMembers(args) => request_members(command, &args.guild_id).await,
         Owner::Reshard(_) => reshard(command).await,
@@ -78,7 +78,7 @@ async fn song(lyrics: &[&str], delay: u64, orig: CommandOrigin<'_>) -> Result<()
 
         interval.tick().await;
 
-        let mut response = orig.callback_with_response(builder).await?.model().await?;
+        let mut response = orig.callback_with_response(builder).await?;
 
         for line in &lyrics[1..] {
             interval.tick().await;
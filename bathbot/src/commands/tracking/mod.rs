@@ -2,12 +2,19 @@ use std::{borrow::Cow, collections::HashMap};
 
 use bathbot_macros::SlashCommand;
 use bathbot_model::command_fields::GameModeOption;
-use bathbot_util::CowUtils;
+use bathbot_util::{
+    CowUtils, matcher,
+    osu::{ModSelection, ModsResult},
+};
 use eyre::Result;
-use rosu_v2::prelude::{GameMode, Username};
-use twilight_interactions::command::{CommandModel, CreateCommand};
+use rosu_v2::prelude::{GameMode, RankStatus, Username};
+use twilight_interactions::command::{CommandModel, CommandOption, CreateCommand, CreateOption};
 
-pub use self::{track::*, track_list::*, untrack::*, untrack_all::*};
+pub use self::{track::*, track_leaderboard::*, track_list::*, untrack::*, untrack_all::*};
+use self::{
+    track_map::{track_map, track_map_diff, tracklist_map, untrack_map},
+    track_mapper::{track_mapper, tracklist_mapper, untrack_mapper},
+};
 use crate::{
     Context,
     core::commands::prefix::{Args, ArgsNum},
@@ -16,7 +23,10 @@ use crate::{
 };
 
 mod track;
+mod track_leaderboard;
 mod track_list;
+mod track_map;
+mod track_mapper;
 mod untrack;
 mod untrack_all;
 
@@ -30,6 +40,12 @@ pub enum Track {
     Remove(TrackRemove),
     #[command(name = "list")]
     List(TrackList),
+    #[command(name = "leaderboard")]
+    Leaderboard(TrackLeaderboard),
+    #[command(name = "map")]
+    Map(TrackMap),
+    #[command(name = "mapper")]
+    Mapper(TrackMapper),
 }
 
 #[derive(CommandModel, CreateCommand)]
@@ -72,6 +88,32 @@ pub struct TrackAdd {
         desc = "Scores must have at most X max combo percent (0-100; default 100)"
     )]
     max_combo_percent: Option<f32>,
+    #[command(
+        desc = "Filter mods (`+mods` for included, `+mods!` for exact, `-mods!` for excluded)",
+        help = "Only notify about scores that match the specified mods.\n\
+        Mods must be given as `+mods` for included mods, `+mods!` for exact mods, \
+        or `-mods!` for excluded mods.\n\
+        Examples:\n\
+        - `+hd`: Scores must have at least `HD` but can also have more other mods\n\
+        - `+hdhr!`: Scores must have exactly `HDHR`\n\
+        - `-ezhd!`: Scores must have neither `EZ` nor `HD` e.g. `HDDT` would get filtered out"
+    )]
+    mods: Option<String>,
+    #[command(
+        min_value = 0.0,
+        desc = "Maps must have at least X stars (default 0.0)"
+    )]
+    min_stars: Option<f32>,
+    #[command(min_value = 0.0, desc = "Maps must have at most X stars")]
+    max_stars: Option<f32>,
+    #[command(
+        desc = "Maps must be mapped by this user",
+        help = "Only notify about scores on maps that were mapped by this user.\n\
+        Both the mapset creator and the guest difficulty mapper are considered."
+    )]
+    mapper: Option<String>,
+    #[command(desc = "Maps must have this status")]
+    status: Option<TrackMapStatus>,
     #[command(desc = "Specify a second username")]
     name2: Option<String>,
     #[command(desc = "Specify a third username")]
@@ -82,6 +124,27 @@ pub struct TrackAdd {
     name5: Option<String>,
 }
 
+#[derive(Copy, Clone, CommandOption, CreateOption)]
+pub enum TrackMapStatus {
+    #[option(name = "Ranked", value = "ranked")]
+    Ranked,
+    #[option(name = "Approved", value = "approved")]
+    Approved,
+    #[option(name = "Loved", value = "loved")]
+    Loved,
+}
+
+impl From<TrackMapStatus> for RankStatus {
+    #[inline]
+    fn from(status: TrackMapStatus) -> Self {
+        match status {
+            TrackMapStatus::Ranked => Self::Ranked,
+            TrackMapStatus::Approved => Self::Approved,
+            TrackMapStatus::Loved => Self::Loved,
+        }
+    }
+}
+
 #[derive(CommandModel, CreateCommand)]
 #[command(
     name = "remove",
@@ -118,14 +181,229 @@ pub struct TrackRemoveAll {
 )]
 pub struct TrackList;
 
+#[derive(CommandModel, CreateCommand)]
+#[command(
+    name = "leaderboard",
+    desc = "Track global leaderboard scores of players",
+    help = "Track global leaderboard scores of players i.e. notify a channel when \
+    a player sets a score within the top X of a ranked map's global leaderboard, \
+    regardless of whether the score is in their top100."
+)]
+pub enum TrackLeaderboard {
+    #[command(name = "add")]
+    Add(TrackLeaderboardAdd),
+    #[command(name = "remove")]
+    Remove(TrackLeaderboardRemove),
+    #[command(name = "list")]
+    List(TrackLeaderboardList),
+}
+
+#[derive(CommandModel, CreateCommand)]
+#[command(
+    name = "add",
+    desc = "Track global leaderboard scores of a player",
+    help = "Add users to the leaderboard tracking list for this channel.\n\
+    If a tracked user sets a score on a ranked map that lands within the top X \
+    of the map's global leaderboard, this channel will be notified about it."
+)]
+pub struct TrackLeaderboardAdd {
+    #[command(desc = "Choose a username to be tracked")]
+    name: String,
+    #[command(desc = "Specify a mode for the tracked users")]
+    mode: GameModeOption,
+    #[command(
+        min_value = 1,
+        max_value = 100,
+        desc = "Scores must be at least #X on the global leaderboard (1-100; default 1)"
+    )]
+    max_rank: Option<u8>,
+    #[command(desc = "Specify a second username")]
+    name2: Option<String>,
+    #[command(desc = "Specify a third username")]
+    name3: Option<String>,
+    #[command(desc = "Specify a fourth username")]
+    name4: Option<String>,
+    #[command(desc = "Specify a fifth username")]
+    name5: Option<String>,
+}
+
+#[derive(CommandModel, CreateCommand)]
+#[command(
+    name = "remove",
+    desc = "Untrack global leaderboard scores of a player"
+)]
+pub struct TrackLeaderboardRemove {
+    #[command(desc = "Choose a username to be untracked")]
+    name: String,
+    #[command(desc = "Specify an optional mode for the tracked user")]
+    mode: Option<GameModeOption>,
+}
+
+#[derive(CommandModel, CreateCommand)]
+#[command(
+    name = "list",
+    desc = "List all players whose leaderboard scores are tracked in this channel"
+)]
+pub struct TrackLeaderboardList;
+
+#[derive(CommandModel, CreateCommand)]
+#[command(
+    name = "map",
+    desc = "Watch the global leaderboard of a map",
+    help = "Watch the global leaderboard of a map i.e. periodically store a snapshot \
+    of the map's top 50 and notify a channel when someone enters the top X, \
+    when #1 changes hands, or when a linked member of this server gets sniped."
+)]
+pub enum TrackMap {
+    #[command(name = "add")]
+    Add(TrackMapAdd),
+    #[command(name = "remove")]
+    Remove(TrackMapRemove),
+    #[command(name = "list")]
+    List(TrackMapList),
+    #[command(name = "diff")]
+    Diff(TrackMapDiff),
+}
+
+#[derive(CommandModel, CreateCommand)]
+#[command(
+    name = "add",
+    desc = "Watch the global leaderboard of a map in this channel",
+    help = "Watch the global leaderboard of a map in this channel.\n\
+    The leaderboard is checked every 30 minutes. \
+    The first check only stores a snapshot, notifications start with the second one."
+)]
+pub struct TrackMapAdd {
+    #[command(desc = "Specify a map url or map id")]
+    map: String,
+    #[command(desc = "Specify a mode for converted maps")]
+    mode: Option<GameModeOption>,
+    #[command(
+        min_value = 1,
+        max_value = 50,
+        desc = "Notify when someone enters the top X (1-50; default 10)"
+    )]
+    max_rank: Option<u8>,
+}
+
+#[derive(CommandModel, CreateCommand)]
+#[command(
+    name = "remove",
+    desc = "Stop watching the global leaderboard of a map"
+)]
+pub struct TrackMapRemove {
+    #[command(desc = "Specify a map url or map id")]
+    map: String,
+    #[command(desc = "Specify an optional mode for the watched map")]
+    mode: Option<GameModeOption>,
+}
+
+#[derive(CommandModel, CreateCommand)]
+#[command(
+    name = "list",
+    desc = "List all maps whose leaderboard is watched in this channel"
+)]
+pub struct TrackMapList;
+
+#[derive(CommandModel, CreateCommand)]
+#[command(
+    name = "diff",
+    desc = "Compare two leaderboard snapshots of a watched map",
+    help = "Compare two leaderboard snapshots of a watched map.\n\
+    Snapshots are counted backwards i.e. `1` is the latest snapshot, `2` the one before, and so on. \
+    A new snapshot is only stored if the leaderboard changed."
+)]
+pub struct TrackMapDiff {
+    #[command(desc = "Specify a map url or map id")]
+    map: String,
+    #[command(desc = "Specify a mode for converted maps")]
+    mode: Option<GameModeOption>,
+    #[command(
+        min_value = 1,
+        max_value = 50,
+        desc = "Specify the older snapshot (default 2 i.e. the second latest)"
+    )]
+    older: Option<u8>,
+    #[command(
+        min_value = 1,
+        max_value = 50,
+        desc = "Specify the newer snapshot (default 1 i.e. the latest)"
+    )]
+    newer: Option<u8>,
+}
+
+#[derive(CommandModel, CreateCommand)]
+#[command(
+    name = "mapper",
+    desc = "Follow a mapper's ranking progress",
+    help = "Follow a mapper i.e. notify a channel when one of their mapsets \
+    gets qualified, ranked, or loved.\n\
+    Mapsets are checked every 30 minutes. \
+    Only mapsets hosted by the mapper are considered, guest difficulties are not."
+)]
+pub enum TrackMapper {
+    #[command(name = "add")]
+    Add(TrackMapperAdd),
+    #[command(name = "remove")]
+    Remove(TrackMapperRemove),
+    #[command(name = "list")]
+    List(TrackMapperList),
+}
+
+#[derive(CommandModel, CreateCommand)]
+#[command(name = "add", desc = "Follow a mapper in this channel")]
+pub struct TrackMapperAdd {
+    #[command(desc = "Specify the mapper's username")]
+    name: String,
+}
+
+#[derive(CommandModel, CreateCommand)]
+#[command(name = "remove", desc = "Stop following a mapper in this channel")]
+pub struct TrackMapperRemove {
+    #[command(desc = "Specify the mapper's username")]
+    name: String,
+}
+
+#[derive(CommandModel, CreateCommand)]
+#[command(
+    name = "list",
+    desc = "List all mappers that are followed in this channel"
+)]
+pub struct TrackMapperList;
+
 async fn slash_track(mut command: InteractionCommand) -> Result<()> {
     match Track::from_interaction(command.input_data())? {
-        Track::Add(add) => track((&mut command).into(), add.into()).await,
+        Track::Add(add) => match TrackArgs::try_from(add) {
+            Ok(args) => track((&mut command).into(), args).await,
+            Err(content) => {
+                command.error(content).await?;
+
+                Ok(())
+            }
+        },
         Track::Remove(TrackRemove::User(user)) => untrack((&mut command).into(), user.into()).await,
         Track::Remove(TrackRemove::All(all)) => {
             untrackall((&mut command).into(), all.mode.map(GameMode::from)).await
         }
         Track::List(_) => tracklist((&mut command).into()).await,
+        Track::Leaderboard(TrackLeaderboard::Add(add)) => {
+            track_leaderboard((&mut command).into(), add).await
+        }
+        Track::Leaderboard(TrackLeaderboard::Remove(remove)) => {
+            untrack_leaderboard((&mut command).into(), remove).await
+        }
+        Track::Leaderboard(TrackLeaderboard::List(_)) => {
+            tracklist_leaderboard((&mut command).into()).await
+        }
+        Track::Map(TrackMap::Add(add)) => track_map((&mut command).into(), add).await,
+        Track::Map(TrackMap::Remove(remove)) => untrack_map((&mut command).into(), remove).await,
+        Track::Map(TrackMap::List(_)) => tracklist_map((&mut command).into()).await,
+        Track::Map(TrackMap::Diff(diff)) => track_map_diff((&mut command).into(), diff).await,
+        Track::Mapper(TrackMapper::Add(add)) => track_mapper((&mut command).into(), add).await,
+        Track::Mapper(TrackMapper::Remove(remove)) => {
+            untrack_mapper((&mut command).into(), remove).await
+        }
+        Track::Mapper(TrackMapper::List(_)) => tracklist_mapper((&mut command).into()).await,
     }
 }
 
@@ -171,13 +449,27 @@ struct TrackArgs {
     max_pp: Option<f32>,
     min_combo_percent: Option<f32>,
     max_combo_percent: Option<f32>,
+    mods: Option<ModSelection>,
+    min_stars: Option<f32>,
+    max_stars: Option<f32>,
+    mapper: Option<String>,
+    status: Option<RankStatus>,
     more_names: Vec<String>,
 }
 
 impl TrackArgs {
+    const ERR_PARSE_MODS: &'static str = "Failed to parse mods.\n\
+        If you want included mods, specify it e.g. as `+hrdt`.\n\
+        If you want exact mods, specify it e.g. as `+hdhr!`.\n\
+        And if you want to exclude mods, specify it e.g. as `-hdnf!`.";
+
     async fn args(mode: Option<GameMode>, args: Args<'_>) -> Result<Self, Cow<'static, str>> {
         let mut name = None;
         let mut more_names = Vec::new();
+        let mut mods = None;
+        let mut min_stars = None;
+        let mut mapper = None;
+        let mut status = None;
 
         let mut max_index = match args.num {
             ArgsNum::Value(n) => Some(n.min(100) as u8),
@@ -198,9 +490,34 @@ impl TrackArgs {
                             return Err(content.into());
                         }
                     },
+                    "mods" => match matcher::get_mods(value) {
+                        Some(mods_) => mods = Some(mods_),
+                        None => return Err(Self::ERR_PARSE_MODS.into()),
+                    },
+                    "stars" | "minstars" => match value.parse() {
+                        Ok(num) => min_stars = Some(num),
+                        Err(_) => {
+                            let content = "Failed to parse `stars`. Must be a number.";
+
+                            return Err(content.into());
+                        }
+                    },
+                    "mapper" => mapper = Some(value.to_owned()),
+                    "status" => match value {
+                        "ranked" | "r" => status = Some(RankStatus::Ranked),
+                        "approved" | "a" => status = Some(RankStatus::Approved),
+                        "loved" | "l" => status = Some(RankStatus::Loved),
+                        _ => {
+                            let content = "Failed to parse `status`.\n\
+                            Must be either `ranked`, `approved`, or `loved`.";
+
+                            return Err(content.into());
+                        }
+                    },
                     _ => {
                         let content = format!(
-                            "Unrecognized option `{key}`.\nAvailable options are: `limit`."
+                            "Unrecognized option `{key}`.\nAvailable options are: \
+                            `limit`, `mods`, `stars`, `mapper`, or `status`."
                         );
 
                         return Err(content.into());
@@ -226,6 +543,11 @@ impl TrackArgs {
             max_pp: None,
             min_combo_percent: None,
             max_combo_percent: None,
+            mods,
+            min_stars,
+            max_stars: None,
+            mapper,
+            status,
             more_names,
             mode,
         };
@@ -234,8 +556,10 @@ impl TrackArgs {
     }
 }
 
-impl From<TrackAdd> for TrackArgs {
-    fn from(add: TrackAdd) -> Self {
+impl TryFrom<TrackAdd> for TrackArgs {
+    type Error = &'static str;
+
+    fn try_from(add: TrackAdd) -> Result<Self, Self::Error> {
         let TrackAdd {
             name,
             mode,
@@ -245,6 +569,11 @@ impl From<TrackAdd> for TrackArgs {
             max_pp,
             min_combo_percent,
             max_combo_percent,
+            mods,
+            min_stars,
+            max_stars,
+            mapper,
+            status,
             name2,
             name3,
             name4,
@@ -269,7 +598,13 @@ impl From<TrackAdd> for TrackArgs {
             more_names.push(name);
         }
 
-        Self {
+        let mods = match ModSelection::parse(mods.as_deref()) {
+            ModsResult::Mods(mods) => Some(mods),
+            ModsResult::None => None,
+            ModsResult::Invalid => return Err(Self::ERR_PARSE_MODS),
+        };
+
+        Ok(Self {
             mode: Some(mode.into()),
             name,
             more_names,
@@ -279,7 +614,12 @@ impl From<TrackAdd> for TrackArgs {
             max_pp,
             min_combo_percent,
             max_combo_percent,
-        }
+            mods,
+            min_stars,
+            max_stars,
+            mapper,
+            status: status.map(RankStatus::from),
+        })
     }
 }
 
@@ -297,6 +637,11 @@ impl From<TrackRemoveUser> for TrackArgs {
             max_pp: None,
             min_combo_percent: None,
             max_combo_percent: None,
+            mods: None,
+            min_stars: None,
+            max_stars: None,
+            mapper: None,
+            status: None,
         }
     }
 }
@@ -1,7 +1,10 @@
-use std::fmt::Write;
+use std::{fmt::Write, slice};
 
 use bathbot_macros::command;
-use bathbot_util::{EmbedBuilder, FooterBuilder, MessageBuilder, constants::GENERAL_ISSUE, fields};
+use bathbot_util::{
+    EmbedBuilder, FooterBuilder, MessageBuilder, constants::GENERAL_ISSUE, fields,
+    osu::ModSelection,
+};
 use eyre::{Report, Result};
 use rosu_v2::prelude::{GameMode, OsuError};
 
@@ -25,6 +28,11 @@ pub(super) async fn track(orig: CommandOrigin<'_>, args: TrackArgs) -> Result<()
         max_pp,
         min_combo_percent,
         max_combo_percent,
+        mods,
+        min_stars,
+        max_stars,
+        mapper,
+        status,
     } = args;
 
     more_names.push(name);
@@ -52,10 +60,45 @@ pub(super) async fn track(orig: CommandOrigin<'_>, args: TrackArgs) -> Result<()
         }
     };
 
-    let params = TrackEntryParams::new()
+    let mut params = TrackEntryParams::new()
         .with_index(min_index, max_index)
         .with_pp(min_pp, max_pp)
-        .with_combo_percent(min_combo_percent, max_combo_percent);
+        .with_combo_percent(min_combo_percent, max_combo_percent)
+        .with_stars(min_stars, max_stars);
+
+    params = match mods {
+        Some(ModSelection::Include(mods)) => params.with_mods(Some(mods.bits()), None),
+        Some(ModSelection::Exclude { mods, nomod: false }) => {
+            params.with_mods(None, Some(mods.bits()))
+        }
+        Some(ModSelection::Exclude { nomod: true, .. }) => {
+            let content = "Excluding nomod is not supported for tracking";
+
+            return orig.error(content).await;
+        }
+        Some(ModSelection::Exact(mods)) => params.with_exact_mods(mods.bits()),
+        None => params,
+    };
+
+    let mapper_id = match mapper {
+        Some(ref mapper) => match super::get_names(slice::from_ref(mapper), mode).await {
+            Ok(mapper) => mapper.into_values().next(),
+            Err((UserArgsError::Osu(OsuError::NotFound), name)) => {
+                let content = format!("Mapper `{name}` was not found");
+
+                return orig.error(content).await;
+            }
+            Err((err, _)) => {
+                let _ = orig.error(GENERAL_ISSUE).await;
+                let err = Report::new(err).wrap_err("Failed to get mapper name");
+
+                return Err(err);
+            }
+        },
+        None => None,
+    };
+
+    let params = params.with_mapper(mapper_id).with_status(status);
 
     let channel = orig.channel_id();
     let mut success = Vec::with_capacity(users.len());
@@ -122,13 +165,15 @@ pub(super) async fn track(orig: CommandOrigin<'_>, args: TrackArgs) -> Result<()
         fields![fields { "Failed to track:".to_owned(), value, false }];
     }
 
-    let value = format!(
+    let mut value = format!(
         "`Index: {index}` | `PP: {pp}pp` | `Combo percent: {combo_percent}%`",
         index = params.index(),
         pp = params.pp(),
         combo_percent = params.combo_percent(),
     );
 
+    params.write_map_filters(&mut value, " | ", mapper.as_deref());
+
     fields![fields { "Parameters:".to_owned(), value, false }];
 
     let footer = FooterBuilder::new("").icon_url(Emote::from(mode).url());
@@ -144,7 +189,8 @@ pub(super) async fn track(orig: CommandOrigin<'_>, args: TrackArgs) -> Result<()
     Ok(())
 }
 
-const TRACK_USAGE: &str = "[limit=number] [username1] [username2] ...";
+const TRACK_USAGE: &str = "[limit=number] [mods=+mods] [stars=number] [mapper=name] \
+    [status=ranked/approved/loved] [username1] [username2] ...";
 
 #[command]
 #[desc("Track osu!standard user top scores")]
@@ -157,12 +203,16 @@ const TRACK_USAGE: &str = "[limit=number] [username1] [username2] ...";
     a new score in his top 42.\n\
     Alternatively, you can provide a limit by specifying `limit=number`, \
     e.g. `track limit=42 badewanne3`.\n\
-    The limit must be between 1 and 100, **defaults to 50** if none is given."
+    The limit must be between 1 and 100, **defaults to 50** if none is given.\n\
+    Scores can also be filtered by their mods via `mods=+mods`, `mods=+mods!`, \
+    or `mods=-mods!`, by the map's minimum star rating via `stars=number`, \
+    by the map's mapper via `mapper=name`, and by the map's status via \
+    `status=ranked/approved/loved`."
 )]
 #[usage(TRACK_USAGE)]
 #[examples(
     "badewanne3 \"freddie benson\" peppy limit=23",
-    "limit=45 cookiezi whitecat",
+    "limit=45 mods=+hd stars=7 cookiezi whitecat",
     "\"freddie benson\""
 )]
 #[flags(AUTHORITY, ONLY_GUILDS)]
@@ -189,12 +239,16 @@ async fn prefix_track(msg: &Message, args: Args<'_>) -> Result<()> {
     a new score in his top 42.\n\
     Alternatively, you can provide a limit by specifying `limit=number`, \
     e.g. `trackmania limit=42 badewanne3`.\n\
-    The limit must be between 1 and 100, **defaults to 50** if none is given."
+    The limit must be between 1 and 100, **defaults to 50** if none is given.\n\
+    Scores can also be filtered by their mods via `mods=+mods`, `mods=+mods!`, \
+    or `mods=-mods!`, by the map's minimum star rating via `stars=number`, \
+    by the map's mapper via `mapper=name`, and by the map's status via \
+    `status=ranked/approved/loved`."
 )]
 #[usage(TRACK_USAGE)]
 #[examples(
     "badewanne3 \"freddie benson\" peppy limit=23",
-    "limit=45 cookiezi whitecat",
+    "limit=45 mods=+hd stars=7 cookiezi whitecat",
     "\"freddie benson\""
 )]
 #[flags(AUTHORITY, ONLY_GUILDS)]
@@ -221,12 +275,16 @@ pub async fn prefix_trackmania(msg: &Message, args: Args<'_>) -> Result<()> {
     a new score in his top 42.\n\
     Alternatively, you can provide a limit by specifying `limit=number`, \
     e.g. `tracktaiko limit=42 badewanne3`.\n\
-    The limit must be between 1 and 100, **defaults to 50** if none is given."
+    The limit must be between 1 and 100, **defaults to 50** if none is given.\n\
+    Scores can also be filtered by their mods via `mods=+mods`, `mods=+mods!`, \
+    or `mods=-mods!`, by the map's minimum star rating via `stars=number`, \
+    by the map's mapper via `mapper=name`, and by the map's status via \
+    `status=ranked/approved/loved`."
 )]
 #[usage(TRACK_USAGE)]
 #[examples(
     "badewanne3 \"freddie benson\" peppy limit=23",
-    "limit=45 cookiezi whitecat",
+    "limit=45 mods=+hd stars=7 cookiezi whitecat",
     "\"freddie benson\""
 )]
 #[flags(AUTHORITY, ONLY_GUILDS)]
@@ -253,12 +311,16 @@ pub async fn prefix_tracktaiko(msg: &Message, args: Args<'_>) -> Result<()> {
     a new score in his top 42.\n\
     Alternatively, you can provide a limit by specifying `limit=number`, \
     e.g. `trackctb limit=42 badewanne3`.\n\
-    The limit must be between 1 and 100, **defaults to 50** if none is given."
+    The limit must be between 1 and 100, **defaults to 50** if none is given.\n\
+    Scores can also be filtered by their mods via `mods=+mods`, `mods=+mods!`, \
+    or `mods=-mods!`, by the map's minimum star rating via `stars=number`, \
+    by the map's mapper via `mapper=name`, and by the map's status via \
+    `status=ranked/approved/loved`."
 )]
 #[usage(TRACK_USAGE)]
 #[examples(
     "badewanne3 \"freddie benson\" peppy limit=23",
-    "limit=45 cookiezi whitecat",
+    "limit=45 mods=+hd stars=7 cookiezi whitecat",
     "\"freddie benson\""
 )]
 #[flags(AUTHORITY, ONLY_GUILDS)]
@@ -0,0 +1,243 @@
+use std::{collections::HashMap, fmt::Write};
+
+use bathbot_util::{
+    EmbedBuilder, FooterBuilder, MessageBuilder,
+    constants::{GENERAL_ISSUE, OSU_API_ISSUE},
+    fields,
+};
+use eyre::{Report, Result};
+use rosu_v2::prelude::{GameMode, OsuError, Username};
+
+use super::{TrackLeaderboardAdd, TrackLeaderboardRemove};
+use crate::{
+    Context,
+    active::{ActiveMessages, impls::TrackLeaderboardListPagination},
+    core::commands::CommandOrigin,
+    manager::redis::osu::{UserArgs, UserArgsError},
+    tracking::{LeaderboardTracking, TrackLeaderboardParams},
+    util::Emote,
+};
+
+pub struct TracklistLeaderboardEntry {
+    pub name: Username,
+    pub user_id: u32,
+    pub mode: GameMode,
+    pub params: TrackLeaderboardParams,
+}
+
+pub(super) async fn track_leaderboard(
+    orig: CommandOrigin<'_>,
+    args: TrackLeaderboardAdd,
+) -> Result<()> {
+    let TrackLeaderboardAdd {
+        name,
+        mode,
+        max_rank,
+        name2,
+        name3,
+        name4,
+        name5,
+    } = args;
+
+    let names: Vec<_> = [Some(name), name2, name3, name4, name5]
+        .into_iter()
+        .flatten()
+        .collect();
+
+    if let Some(name) = names.iter().find(|name| name.len() > 15) {
+        let content = format!("`{name}` is too long for an osu! username");
+
+        return orig.error(content).await;
+    }
+
+    let mode = GameMode::from(mode);
+
+    let users = match super::get_names(&names, mode).await {
+        Ok(users) => users,
+        Err((UserArgsError::Osu(OsuError::NotFound), name)) => {
+            let content = format!("User `{name}` was not found");
+
+            return orig.error(content).await;
+        }
+        Err((err, _)) => {
+            let _ = orig.error(GENERAL_ISSUE).await;
+            let err = Report::new(err).wrap_err("Failed to get names");
+
+            return Err(err);
+        }
+    };
+
+    let params = TrackLeaderboardParams::new(max_rank);
+    let channel = orig.channel_id();
+    let mut success = Vec::with_capacity(users.len());
+    let mut failure = Vec::new();
+
+    for (username, user_id) in users {
+        match LeaderboardTracking::add_user(user_id, mode, channel, params).await {
+            Ok(()) => success.push(username),
+            Err(err) => {
+                warn!(?err, "Failed to track leaderboard of osu user");
+                failure.push(username);
+            }
+        }
+    }
+
+    let mut fields = Vec::with_capacity(3);
+    let mut iter = success.iter();
+
+    if let Some(name) = iter.next() {
+        let mut value = String::new();
+        let _ = write!(value, "`{name}`");
+
+        for name in iter {
+            let _ = write!(value, ", `{name}`");
+        }
+
+        fields![fields { "Now tracking:".to_owned(), value, false }];
+    }
+
+    let mut iter = failure.iter();
+
+    if let Some(name) = iter.next() {
+        let mut value = String::new();
+        let _ = write!(value, "`{name}`");
+
+        for name in iter {
+            let _ = write!(value, ", `{name}`");
+        }
+
+        fields![fields { "Failed to track:".to_owned(), value, false }];
+    }
+
+    let value = format!("`Global rank: 1-{}`", params.max_rank());
+    fields![fields { "Parameters:".to_owned(), value, false }];
+
+    let footer = FooterBuilder::new("").icon_url(Emote::from(mode).url());
+
+    let embed = EmbedBuilder::new()
+        .fields(fields)
+        .footer(footer)
+        .title("Leaderboard score tracking");
+
+    let builder = MessageBuilder::new().embed(embed);
+    orig.create_message(builder).await?;
+
+    Ok(())
+}
+
+pub(super) async fn untrack_leaderboard(
+    orig: CommandOrigin<'_>,
+    args: TrackLeaderboardRemove,
+) -> Result<()> {
+    let TrackLeaderboardRemove { name, mode } = args;
+    let mode = mode.map(GameMode::from);
+
+    let user_id = match super::get_names(&[name], mode.unwrap_or(GameMode::Osu)).await {
+        Ok(users) => users.into_iter().next(),
+        Err((UserArgsError::Osu(OsuError::NotFound), name)) => {
+            let content = format!("User `{name}` was not found");
+
+            return orig.error(content).await;
+        }
+        Err((err, _)) => {
+            let _ = orig.error(GENERAL_ISSUE).await;
+            let err = Report::new(err).wrap_err("Failed to get name");
+
+            return Err(err);
+        }
+    };
+
+    let description = match user_id {
+        Some((username, user_id)) => {
+            LeaderboardTracking::remove_user(user_id, mode, orig.channel_id()).await;
+
+            format!("Removed in this channel: `{username}`")
+        }
+        None => "Removed in this channel: None".to_owned(),
+    };
+
+    let embed = EmbedBuilder::new()
+        .title("Leaderboard score tracking")
+        .description(description);
+
+    let builder = MessageBuilder::new().embed(embed);
+    orig.create_message(builder).await?;
+
+    Ok(())
+}
+
+pub(super) async fn tracklist_leaderboard(orig: CommandOrigin<'_>) -> Result<()> {
+    let channel = orig.channel_id();
+
+    let tracked = match LeaderboardTracking::tracked_users_in_channel(channel).await {
+        Ok(tracked) => tracked,
+        Err(err) => {
+            let _ = orig.error(GENERAL_ISSUE).await;
+
+            return Err(err.wrap_err("Failed to get tracked leaderboard users"));
+        }
+    };
+
+    let user_ids: Vec<_> = tracked
+        .iter()
+        .map(|(user_id, ..)| *user_id as i32)
+        .collect();
+
+    // Get all names that are stored in the DB
+    let stored_names = match Context::osu_user().names(&user_ids).await {
+        Ok(map) => map,
+        Err(err) => {
+            warn!(?err, "Failed to get names by user ids");
+
+            HashMap::default()
+        }
+    };
+
+    let mut entries = Vec::with_capacity(tracked.len());
+
+    // Get all missing names from the api
+    for (user_id, mode, params) in tracked {
+        let name = match stored_names.get(&user_id) {
+            Some(name) => name.to_owned(),
+            None => match Context::redis()
+                .osu_user(UserArgs::user_id(user_id, mode))
+                .await
+            {
+                Ok(user) => user.username.as_str().into(),
+                Err(UserArgsError::Osu(OsuError::NotFound)) => {
+                    LeaderboardTracking::remove_user(user_id, None, channel).await;
+
+                    continue;
+                }
+                Err(err) => {
+                    let _ = orig.error(OSU_API_ISSUE).await;
+
+                    return Err(Report::new(err).wrap_err("Failed to get user"));
+                }
+            },
+        };
+
+        entries.push(TracklistLeaderboardEntry {
+            name,
+            user_id,
+            mode,
+            params,
+        });
+    }
+
+    entries.sort_unstable_by(|a, b| {
+        (a.mode as u8)
+            .cmp(&(b.mode as u8))
+            .then(a.name.cmp(&b.name))
+    });
+
+    let pagination = TrackLeaderboardListPagination::builder()
+        .entries(entries.into_boxed_slice())
+        .msg_owner(orig.user_id()?)
+        .build();
+
+    ActiveMessages::builder(pagination)
+        .start_by_update(true)
+        .begin(orig)
+        .await
+}
@@ -19,6 +19,7 @@ pub struct TracklistUserEntry {
     pub user_id: u32,
     pub mode: GameMode,
     pub params: TrackEntryParams,
+    pub mapper: Option<Username>,
 }
 
 #[command]
@@ -74,7 +75,12 @@ async fn get_users(
 ) -> Result<Vec<TracklistUserEntry>, UserArgsError> {
     let user_ids: Vec<_> = tracked
         .iter()
-        .map(|(user_id, ..)| *user_id as i32)
+        .flat_map(|(user_id, _, params)| {
+            [Some(*user_id), params.mapper()]
+                .into_iter()
+                .flatten()
+                .map(|user_id| user_id as i32)
+        })
         .collect();
 
     // Get all names that are stored in the DB
@@ -91,12 +97,18 @@ async fn get_users(
 
     // Get all missing names from the api
     for (user_id, mode, params) in tracked {
+        let mapper = params
+            .mapper()
+            .and_then(|mapper_id| stored_names.get(&mapper_id))
+            .cloned();
+
         let entry = match stored_names.get(&user_id) {
             Some(name) => TracklistUserEntry {
                 name: name.to_owned(),
                 user_id,
                 mode,
                 params,
+                mapper,
             },
             None => {
                 let user_args = UserArgs::user_id(user_id, mode);
@@ -107,6 +119,7 @@ async fn get_users(
                         user_id,
                         mode,
                         params,
+                        mapper,
                     },
                     Err(UserArgsError::Osu(OsuError::NotFound)) => {
                         OsuTracking::remove_user(user_id, None, channel).await;
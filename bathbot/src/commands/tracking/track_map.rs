@@ -0,0 +1,360 @@
+use std::fmt::Write;
+
+use bathbot_util::{
+    EmbedBuilder, FooterBuilder, MessageBuilder,
+    constants::{GENERAL_ISSUE, OSU_BASE},
+    matcher,
+};
+use eyre::Result;
+use rosu_v2::prelude::{GameMode, RankStatus};
+
+use super::{TrackMapAdd, TrackMapDiff, TrackMapRemove};
+use crate::{
+    Context,
+    core::commands::CommandOrigin,
+    manager::{LeaderboardDiff, MapError, OsuMap},
+    util::Emote,
+};
+
+/// Maximum amount of watched maps per channel
+const MAX_WATCHED: usize = 25;
+
+/// Maximum amount of changes that are listed in the diff
+const DIFF_COUNT: usize = 25;
+
+const DEFAULT_MAX_RANK: u8 = 10;
+
+const ERR_PARSE_MAP: &str =
+    "Failed to parse map url. Be sure you specify a valid map id or url to a map.";
+
+pub(super) async fn track_map(orig: CommandOrigin<'_>, args: TrackMapAdd) -> Result<()> {
+    let TrackMapAdd {
+        map,
+        mode,
+        max_rank,
+    } = args;
+
+    let Some(map_id) = matcher::get_osu_map_id(&map) else {
+        return orig.error(ERR_PARSE_MAP).await;
+    };
+
+    let Some(map) = get_map(&orig, map_id).await? else {
+        return Ok(());
+    };
+
+    let mode = match resolve_mode(&map, mode.map(GameMode::from)) {
+        Ok(mode) => mode,
+        Err(content) => return orig.error(content).await,
+    };
+
+    let has_leaderboard = matches!(
+        map.status(),
+        RankStatus::Ranked | RankStatus::Approved | RankStatus::Qualified | RankStatus::Loved
+    );
+
+    if !has_leaderboard {
+        return orig.error("That map has no global leaderboard").await;
+    }
+
+    let channel = orig.channel_id();
+    let manager = Context::map_leaderboards();
+
+    let watched = match manager.watched_in_channel(channel).await {
+        Ok(watched) => watched,
+        Err(err) => {
+            let _ = orig.error(GENERAL_ISSUE).await;
+
+            return Err(err);
+        }
+    };
+
+    let is_new = watched
+        .iter()
+        .all(|watched| watched.map_id != map_id || watched.mode != mode);
+
+    if is_new && watched.len() >= MAX_WATCHED {
+        let content = format!("Channels can watch at most {MAX_WATCHED} maps");
+
+        return orig.error(content).await;
+    }
+
+    let max_rank = max_rank.unwrap_or(DEFAULT_MAX_RANK);
+
+    let watch_fut = manager.watch(map_id, mode, channel, orig.guild_id(), max_rank);
+
+    if let Err(err) = watch_fut.await {
+        let _ = orig.error(GENERAL_ISSUE).await;
+
+        return Err(err);
+    }
+
+    let description = format!(
+        "Now watching the top {max_rank} of [{}]({OSU_BASE}b/{map_id}) in this channel.\n\
+        The first snapshot will be stored within the next 30 minutes.",
+        map_title(&map)
+    );
+
+    let footer = FooterBuilder::new("").icon_url(Emote::from(mode).url());
+
+    let embed = EmbedBuilder::new()
+        .description(description)
+        .footer(footer)
+        .title("Map leaderboard watch");
+
+    let builder = MessageBuilder::new().embed(embed);
+    orig.create_message(builder).await?;
+
+    Ok(())
+}
+
+pub(super) async fn untrack_map(orig: CommandOrigin<'_>, args: TrackMapRemove) -> Result<()> {
+    let Some(map_id) = matcher::get_osu_map_id(&args.map) else {
+        return orig.error(ERR_PARSE_MAP).await;
+    };
+
+    let mode = args.mode.map(GameMode::from);
+    let unwatch_fut = Context::map_leaderboards().unwatch(map_id, mode, orig.channel_id());
+
+    let description = match unwatch_fut.await {
+        Ok(true) => format!("No longer watching map {map_id} in this channel"),
+        Ok(false) => format!("Map {map_id} was not watched in this channel"),
+        Err(err) => {
+            let _ = orig.error(GENERAL_ISSUE).await;
+
+            return Err(err);
+        }
+    };
+
+    let embed = EmbedBuilder::new()
+        .description(description)
+        .title("Map leaderboard watch");
+
+    let builder = MessageBuilder::new().embed(embed);
+    orig.create_message(builder).await?;
+
+    Ok(())
+}
+
+pub(super) async fn tracklist_map(orig: CommandOrigin<'_>) -> Result<()> {
+    let mut watched = match Context::map_leaderboards()
+        .watched_in_channel(orig.channel_id())
+        .await
+    {
+        Ok(watched) => watched,
+        Err(err) => {
+            let _ = orig.error(GENERAL_ISSUE).await;
+
+            return Err(err);
+        }
+    };
+
+    let description = if watched.is_empty() {
+        "No map leaderboards are watched in this channel".to_owned()
+    } else {
+        watched.sort_unstable_by_key(|watched| (watched.mode as u8, watched.map_id));
+
+        let mut description = String::with_capacity(watched.len() * 64);
+
+        for watched in watched {
+            let map_id = watched.map_id;
+
+            let title = match Context::osu_map().map_slim(map_id).await {
+                Ok(map) => format!("{} - {} [{}]", map.artist(), map.title(), map.version()),
+                Err(err) => {
+                    warn!(map_id, ?err, "Failed to get map");
+
+                    format!("Map {map_id}")
+                }
+            };
+
+            let mode = match watched.mode {
+                GameMode::Osu => "osu!",
+                GameMode::Taiko => "taiko",
+                GameMode::Catch => "ctb",
+                GameMode::Mania => "mania",
+            };
+
+            let _ = writeln!(
+                description,
+                "- [{title}]({OSU_BASE}b/{map_id}) ({mode}, top {})",
+                watched.max_rank
+            );
+        }
+
+        description
+    };
+
+    let embed = EmbedBuilder::new()
+        .description(description)
+        .title("Watched map leaderboards");
+
+    let builder = MessageBuilder::new().embed(embed);
+    orig.create_message(builder).await?;
+
+    Ok(())
+}
+
+pub(super) async fn track_map_diff(orig: CommandOrigin<'_>, args: TrackMapDiff) -> Result<()> {
+    let TrackMapDiff {
+        map,
+        mode,
+        older,
+        newer,
+    } = args;
+
+    let Some(map_id) = matcher::get_osu_map_id(&map) else {
+        return orig.error(ERR_PARSE_MAP).await;
+    };
+
+    let older = usize::from(older.unwrap_or(2));
+    let newer = usize::from(newer.unwrap_or(1));
+
+    if older <= newer {
+        let content = "The older snapshot must be further back than the newer one";
+
+        return orig.error(content).await;
+    }
+
+    let Some(map) = get_map(&orig, map_id).await? else {
+        return Ok(());
+    };
+
+    let mode = match resolve_mode(&map, mode.map(GameMode::from)) {
+        Ok(mode) => mode,
+        Err(content) => return orig.error(content).await,
+    };
+
+    let manager = Context::map_leaderboards();
+
+    let snapshots = match manager.snapshots(map_id, mode, older).await {
+        Ok(snapshots) => snapshots,
+        Err(err) => {
+            let _ = orig.error(GENERAL_ISSUE).await;
+
+            return Err(err);
+        }
+    };
+
+    if snapshots.len() < older {
+        let content = match snapshots.len() {
+            0 => "No snapshots are stored for that map yet, \
+                use `/track map add` to watch its leaderboard"
+                .to_owned(),
+            len => format!(
+                "Only {len} snapshot{} stored for that map",
+                if len == 1 { " is" } else { "s are" }
+            ),
+        };
+
+        return orig.error(content).await;
+    }
+
+    let old_snapshot = &snapshots[older - 1];
+    let new_snapshot = &snapshots[newer - 1];
+
+    let scores_fut = tokio::try_join!(
+        manager.snapshot_scores(old_snapshot.snapshot_id),
+        manager.snapshot_scores(new_snapshot.snapshot_id),
+    );
+
+    let (old_scores, new_scores) = match scores_fut {
+        Ok(scores) => scores,
+        Err(err) => {
+            let _ = orig.error(GENERAL_ISSUE).await;
+
+            return Err(err);
+        }
+    };
+
+    let diff = LeaderboardDiff::new(&old_scores, &new_scores);
+
+    let mut description = format!(
+        "Changes from <t:{}:f> to <t:{}:f>:\n",
+        old_snapshot.taken_at.unix_timestamp(),
+        new_snapshot.taken_at.unix_timestamp()
+    );
+
+    if diff.is_empty() {
+        description.push_str("No changes");
+    }
+
+    for entry in diff.entries.iter().take(DIFF_COUNT) {
+        let name = entry.username;
+
+        let _ = match (entry.old_pos, entry.new_pos) {
+            (None, Some(new)) => write!(description, "`#{new}` :new: **{name}**"),
+            (Some(old), Some(new)) if new < old => {
+                write!(description, "`#{new}` :arrow_up: **{name}** (was #{old})")
+            }
+            (Some(old), Some(new)) if new > old => {
+                write!(description, "`#{new}` :arrow_down: **{name}** (was #{old})")
+            }
+            (_, Some(new)) => write!(description, "`#{new}` **{name}**"),
+            (Some(old), None) => write!(description, "`--` :x: **{name}** (was #{old})"),
+            (None, None) => continue,
+        };
+
+        if entry.new_score {
+            description.push_str(" - new score");
+        }
+
+        description.push('\n');
+    }
+
+    if diff.entries.len() > DIFF_COUNT {
+        let _ = write!(
+            description,
+            "...and {} more",
+            diff.entries.len() - DIFF_COUNT
+        );
+    }
+
+    let footer = FooterBuilder::new("").icon_url(Emote::from(mode).url());
+
+    let embed = EmbedBuilder::new()
+        .description(description)
+        .footer(footer)
+        .thumbnail(map.thumbnail())
+        .title(map_title(&map))
+        .url(format!("{OSU_BASE}b/{map_id}"));
+
+    let builder = MessageBuilder::new().embed(embed);
+    orig.create_message(builder).await?;
+
+    Ok(())
+}
+
+/// Returns `None` if the map was not found, in which case the error message
+/// has already been sent.
+async fn get_map(orig: &CommandOrigin<'_>, map_id: u32) -> Result<Option<OsuMap>> {
+    match Context::osu_map().map(map_id, None).await {
+        Ok(map) => Ok(Some(map)),
+        Err(MapError::NotFound { .. }) => {
+            let content = format!(
+                "Could not find beatmap with id `{map_id}`. \
+                Did you give me a mapset id instead of a map id?",
+            );
+
+            orig.error(content).await.map(|_| None)
+        }
+        Err(MapError::Report(err)) => {
+            let _ = orig.error(GENERAL_ISSUE).await;
+
+            Err(err.wrap_err("Failed to get map"))
+        }
+    }
+}
+
+fn resolve_mode(map: &OsuMap, mode: Option<GameMode>) -> Result<GameMode, &'static str> {
+    match mode {
+        Some(mode) if mode != map.mode() && map.mode() != GameMode::Osu => {
+            Err("Only osu!standard maps can be converted to other modes")
+        }
+        Some(mode) => Ok(mode),
+        None => Ok(map.mode()),
+    }
+}
+
+fn map_title(map: &OsuMap) -> String {
+    format!("{} - {} [{}]", map.artist(), map.title(), map.version())
+}
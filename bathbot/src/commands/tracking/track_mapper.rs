@@ -0,0 +1,188 @@
+use std::fmt::Write;
+
+use bathbot_util::{
+    EmbedBuilder, MessageBuilder,
+    constants::{GENERAL_ISSUE, OSU_BASE},
+};
+use eyre::{Report, Result};
+use rosu_v2::prelude::{GameMode, OsuError, Username};
+
+use super::{TrackMapperAdd, TrackMapperRemove};
+use crate::{Context, core::commands::CommandOrigin, manager::redis::osu::UserArgsError};
+
+/// Maximum amount of followed mappers per channel
+const MAX_FOLLOWED: usize = 25;
+
+pub(super) async fn track_mapper(orig: CommandOrigin<'_>, args: TrackMapperAdd) -> Result<()> {
+    let Some((name, mapper_id)) = get_mapper(&orig, args.name).await? else {
+        return Ok(());
+    };
+
+    let channel = orig.channel_id();
+    let manager = Context::mapper_follows();
+
+    let followed = match manager.followed_in_channel(channel).await {
+        Ok(followed) => followed,
+        Err(err) => {
+            let _ = orig.error(GENERAL_ISSUE).await;
+
+            return Err(err);
+        }
+    };
+
+    let is_new = followed
+        .iter()
+        .all(|followed| followed.mapper_id != mapper_id);
+
+    if is_new && followed.len() >= MAX_FOLLOWED {
+        let content = format!("Channels can follow at most {MAX_FOLLOWED} mappers");
+
+        return orig.error(content).await;
+    }
+
+    // Store the current statuses so that only future changes are notified
+    let statuses = match manager.leaderboard_statuses(mapper_id, &name).await {
+        Ok(statuses) => statuses,
+        Err(err) => {
+            let _ = orig.error(GENERAL_ISSUE).await;
+
+            return Err(err);
+        }
+    };
+
+    if let Err(err) = manager.store_statuses(mapper_id, &statuses, false).await {
+        let _ = orig.error(GENERAL_ISSUE).await;
+
+        return Err(err);
+    }
+
+    let follow_fut = manager.follow(mapper_id, channel, orig.guild_id(), orig.user_id()?);
+
+    if let Err(err) = follow_fut.await {
+        let _ = orig.error(GENERAL_ISSUE).await;
+
+        return Err(err);
+    }
+
+    let description = format!(
+        "Now following [{name}]({OSU_BASE}u/{mapper_id}) in this channel.\n\
+        This channel will be notified when one of their mapsets \
+        gets qualified, ranked, or loved."
+    );
+
+    let embed = EmbedBuilder::new()
+        .description(description)
+        .title("Mapper follow");
+
+    let builder = MessageBuilder::new().embed(embed);
+    orig.create_message(builder).await?;
+
+    Ok(())
+}
+
+pub(super) async fn untrack_mapper(orig: CommandOrigin<'_>, args: TrackMapperRemove) -> Result<()> {
+    let Some((name, mapper_id)) = get_mapper(&orig, args.name).await? else {
+        return Ok(());
+    };
+
+    let unfollow_fut = Context::mapper_follows().unfollow(mapper_id, orig.channel_id());
+
+    let description = match unfollow_fut.await {
+        Ok(true) => format!("No longer following `{name}` in this channel"),
+        Ok(false) => format!("`{name}` was not followed in this channel"),
+        Err(err) => {
+            let _ = orig.error(GENERAL_ISSUE).await;
+
+            return Err(err);
+        }
+    };
+
+    let embed = EmbedBuilder::new()
+        .description(description)
+        .title("Mapper follow");
+
+    let builder = MessageBuilder::new().embed(embed);
+    orig.create_message(builder).await?;
+
+    Ok(())
+}
+
+pub(super) async fn tracklist_mapper(orig: CommandOrigin<'_>) -> Result<()> {
+    let manager = Context::mapper_follows();
+
+    let followed = match manager.followed_in_channel(orig.channel_id()).await {
+        Ok(followed) => followed,
+        Err(err) => {
+            let _ = orig.error(GENERAL_ISSUE).await;
+
+            return Err(err);
+        }
+    };
+
+    let description = if followed.is_empty() {
+        "No mappers are followed in this channel".to_owned()
+    } else {
+        let mut entries = Vec::with_capacity(followed.len());
+
+        for followed in followed {
+            let mapper_id = followed.mapper_id;
+
+            let name = match manager.mapper_name(mapper_id).await {
+                Ok(name) => name,
+                Err(err) => {
+                    warn!(mapper_id, ?err, "Failed to get mapper name");
+
+                    format!("User {mapper_id}").into()
+                }
+            };
+
+            entries.push((name, mapper_id));
+        }
+
+        entries
+            .sort_unstable_by(|(a, _), (b, _)| a.to_ascii_lowercase().cmp(&b.to_ascii_lowercase()));
+
+        let mut description = String::with_capacity(entries.len() * 48);
+
+        for (name, mapper_id) in entries {
+            let _ = writeln!(description, "- [{name}]({OSU_BASE}u/{mapper_id})");
+        }
+
+        description
+    };
+
+    let embed = EmbedBuilder::new()
+        .description(description)
+        .title("Followed mappers");
+
+    let builder = MessageBuilder::new().embed(embed);
+    orig.create_message(builder).await?;
+
+    Ok(())
+}
+
+/// Resolve a mapper's name and user id, responding with an error if the
+/// user could not be found.
+async fn get_mapper(orig: &CommandOrigin<'_>, name: String) -> Result<Option<(Username, u32)>> {
+    if name.len() > 15 {
+        let content = format!("`{name}` is too long for an osu! username");
+        orig.error(content).await?;
+
+        return Ok(None);
+    }
+
+    match super::get_names(&[name], GameMode::Osu).await {
+        Ok(users) => Ok(users.into_iter().next()),
+        Err((UserArgsError::Osu(OsuError::NotFound), name)) => {
+            let content = format!("User `{name}` was not found");
+            orig.error(content).await?;
+
+            Ok(None)
+        }
+        Err((err, _)) => {
+            let _ = orig.error(GENERAL_ISSUE).await;
+
+            Err(Report::new(err).wrap_err("Failed to get mapper"))
+        }
+    }
+}
@@ -3,7 +3,11 @@ use bathbot_util::MessageBuilder;
 use eyre::Result;
 use rosu_v2::model::GameMode;
 
-use crate::{core::commands::CommandOrigin, tracking::OsuTracking, util::ChannelExt};
+use crate::{
+    core::commands::CommandOrigin,
+    tracking::{LeaderboardTracking, OsuTracking},
+    util::ChannelExt,
+};
 
 #[command]
 #[desc("Untrack all users in a channel")]
@@ -40,6 +44,7 @@ pub async fn untrackall(orig: CommandOrigin<'_>, mode: Option<GameMode>) -> Resu
     let channel_id = orig.channel_id();
 
     OsuTracking::remove_channel(channel_id, mode).await;
+    LeaderboardTracking::remove_channel(channel_id, mode).await;
 
     let content = "Untracked all users in this channel";
     let builder = MessageBuilder::new().embed(content);
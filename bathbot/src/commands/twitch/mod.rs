@@ -1,12 +1,14 @@
 use bathbot_macros::SlashCommand;
 use eyre::Result;
 use twilight_interactions::command::{CommandModel, CreateCommand};
+use twilight_model::id::{Id, marker::RoleMarker};
 
-pub use self::{addstream::*, removestream::*, tracked::*};
+pub use self::{addstream::*, removestream::*, settings::*, tracked::*};
 use crate::util::{InteractionCommandExt, interaction::InteractionCommand};
 
 pub mod addstream;
 pub mod removestream;
+pub mod settings;
 pub mod tracked;
 
 #[derive(CommandModel, CreateCommand, SlashCommand)]
@@ -24,6 +26,8 @@ pub enum TrackStream {
     Remove(TrackStreamRemove),
     #[command(name = "list")]
     List(TrackStreamList),
+    #[command(name = "settings")]
+    Settings(TrackStreamSettings),
 }
 
 #[derive(CommandModel, CreateCommand)]
@@ -52,6 +56,44 @@ pub struct TrackStreamRemove {
 )]
 pub struct TrackStreamList;
 
+#[derive(CommandModel, CreateCommand)]
+#[command(
+    name = "settings",
+    desc = "Adjust notifications of a tracked twitch stream in this channel",
+    help = "Adjust notifications of a tracked twitch stream in this channel.\n\
+    Only the given options are changed, all others keep their current value.\n\
+    The template is the message content above the notification and may contain \
+    the following placeholders:\n\
+    - `{streamer}`: Name of the streamer\n\
+    - `{title}`: Title of the stream\n\
+    - `{game}`: Category of the stream\n\
+    - `{url}`: Link to the stream\n\
+    - `{role}`: Mention of the specified role\n\
+    Without template but with a role, the notification just mentions the role."
+)]
+pub struct TrackStreamSettings {
+    #[command(desc = "Name of the twitch channel")]
+    name: String,
+    #[command(desc = "Only notify if the stream's category is osu!")]
+    osu_only: Option<bool>,
+    #[command(
+        max_length = 64,
+        desc = "Only notify if the stream's title contains this keyword"
+    )]
+    keyword: Option<String>,
+    #[command(
+        max_length = 1000,
+        desc = "Message content of the notification, check /help for placeholders"
+    )]
+    template: Option<String>,
+    #[command(desc = "Specify a role to mention in the notification")]
+    role: Option<Id<RoleMarker>>,
+    #[command(desc = "Edit the notification to link the VOD once the stream ended")]
+    vod_edit: Option<bool>,
+    #[command(desc = "Reset all settings before applying the given ones")]
+    reset: Option<bool>,
+}
+
 pub async fn slash_trackstream(mut command: InteractionCommand) -> Result<()> {
     match TrackStream::from_interaction(command.input_data())? {
         TrackStream::Add(add) => addstream((&mut command).into(), add.name.as_ref()).await,
@@ -59,5 +101,6 @@ pub async fn slash_trackstream(mut command: InteractionCommand) -> Result<()> {
             removestream((&mut command).into(), remove.name.as_ref()).await
         }
         TrackStream::List(_) => tracked((&mut command).into()).await,
+        TrackStream::Settings(args) => streamsettings((&mut command).into(), args).await,
     }
 }
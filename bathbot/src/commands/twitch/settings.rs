@@ -0,0 +1,142 @@
+use std::fmt::Write;
+
+use bathbot_util::{
+    CowUtils, EmbedBuilder, MessageBuilder,
+    constants::{GENERAL_ISSUE, TWITCH_API_ISSUE},
+};
+use eyre::Result;
+
+use super::TrackStreamSettings;
+use crate::{Context, core::commands::CommandOrigin};
+
+pub async fn streamsettings(orig: CommandOrigin<'_>, args: TrackStreamSettings) -> Result<()> {
+    let TrackStreamSettings {
+        name,
+        osu_only,
+        keyword,
+        template,
+        role,
+        vod_edit,
+        reset,
+    } = args;
+
+    let name = name.cow_to_ascii_lowercase();
+
+    let twitch_id = match Context::client().get_twitch_user(name.as_ref()).await {
+        Ok(Some(user)) => user.user_id,
+        Ok(None) => {
+            let content = format!("Twitch user `{name}` was not found");
+
+            return orig.error(content).await;
+        }
+        Err(err) => {
+            let _ = orig.error(TWITCH_API_ISSUE).await;
+
+            return Err(err.wrap_err("failed to get twitch user"));
+        }
+    };
+
+    let channel = orig.channel_id();
+    let twitch = Context::twitch();
+
+    let mut entry = match twitch.settings(channel, twitch_id).await {
+        Ok(Some(entry)) => entry,
+        Ok(None) => {
+            let content = format!(
+                "Twitch user `{name}` is not tracked in this channel.\n\
+                Use `/trackstream add` to track them first."
+            );
+
+            return orig.error(content).await;
+        }
+        Err(err) => {
+            let _ = orig.error(GENERAL_ISSUE).await;
+
+            return Err(err);
+        }
+    };
+
+    if reset == Some(true) {
+        entry.osu_only = false;
+        entry.keyword = None;
+        entry.template = None;
+        entry.role_id = None;
+        entry.vod_edit = false;
+    }
+
+    if let Some(osu_only) = osu_only {
+        entry.osu_only = osu_only;
+    }
+
+    if let Some(keyword) = keyword {
+        entry.keyword = Some(keyword);
+    }
+
+    if let Some(template) = template {
+        entry.template = Some(template);
+    }
+
+    if let Some(role) = role {
+        entry.role_id = Some(role.get() as i64);
+    }
+
+    if let Some(vod_edit) = vod_edit {
+        entry.vod_edit = vod_edit;
+    }
+
+    if let Err(err) = twitch.update_settings(&entry).await {
+        let _ = orig.error(GENERAL_ISSUE).await;
+
+        return Err(err);
+    }
+
+    let mut description = String::with_capacity(128);
+
+    let _ = writeln!(
+        description,
+        "**Only osu! category:** {}",
+        if entry.osu_only { "Yes" } else { "No" }
+    );
+
+    description.push_str("**Title keyword:** ");
+
+    match entry.keyword {
+        Some(ref keyword) => {
+            let _ = writeln!(description, "`{keyword}`");
+        }
+        None => description.push_str("None\n"),
+    }
+
+    description.push_str("**Role mention:** ");
+
+    match entry.role_id {
+        Some(role_id) => {
+            let _ = writeln!(description, "<@&{role_id}>");
+        }
+        None => description.push_str("None\n"),
+    }
+
+    let _ = writeln!(
+        description,
+        "**Edit to VOD when the stream ends:** {}",
+        if entry.vod_edit { "Yes" } else { "No" }
+    );
+
+    description.push_str("**Template:** ");
+
+    match entry.template {
+        Some(ref template) => {
+            let _ = write!(description, "```\n{template}\n```");
+        }
+        None => description.push_str("None"),
+    }
+
+    let embed = EmbedBuilder::new().description(description).title(format!(
+        "Notification settings for `{name}` in this channel"
+    ));
+
+    let builder = MessageBuilder::new().embed(embed);
+    orig.create_message(builder).await?;
+
+    Ok(())
+}
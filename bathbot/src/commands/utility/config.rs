@@ -2,7 +2,8 @@ use ::time::UtcOffset;
 use bathbot_macros::SlashCommand;
 use bathbot_model::command_fields::{ShowHideOption, TimezoneOption};
 use bathbot_psql::model::configs::{
-    ListSize, OsuUserId, OsuUsername, Retries, ScoreData, UserConfig,
+    CardLayout, CardTheme, Language, ListSize, OsuUserId, OsuUsername, Retries, ScoreData,
+    UserConfig,
 };
 #[cfg(feature = "server")]
 use bathbot_server::AuthenticationStandbyError;
@@ -89,6 +90,22 @@ pub struct Config {
     render_button: Option<ShowHideOption>,
     #[command(desc = SCORE_DATA_DESC, help = SCORE_DATA_HELP)]
     score_data: Option<ScoreData>,
+    #[command(desc = "Specify the color theme of the card command")]
+    card_theme: Option<CardTheme>,
+    #[command(
+        desc = "Specify the layout of the card command",
+        help = "Specify the layout of the `/card` command.\n\
+        `Tall` includes medals and a large avatar while `Compact` only shows the essentials."
+    )]
+    card_layout: Option<CardLayout>,
+    #[command(
+        desc = "Should the card command show extra stats?",
+        help = "Should the `/card` command show extra stats?\n\
+        Extra stats include the playcount, the pp of the top score, and the top mapper."
+    )]
+    card_stats: Option<ShowHideOption>,
+    #[command(desc = LANGUAGE_DESC, help = LANGUAGE_HELP)]
+    language: Option<ConfigLanguage>,
 }
 
 pub const SCORE_DATA_DESC: &str = "Whether scores should be requested as lazer or stable scores";
@@ -96,6 +113,13 @@ pub const SCORE_DATA_DESC: &str = "Whether scores should be requested as lazer o
 pub const SCORE_DATA_HELP: &str = "Whether scores should be requested as lazer or stable scores.\n\
 They have a different score and grade calculation and only lazer adds the new mods.";
 
+const LANGUAGE_DESC: &str = "Specify the language of command responses";
+
+const LANGUAGE_HELP: &str = "Specify the language of command responses.\n\
+If set to `Auto`, the server's language is used or, if the server has none configured, \
+your discord language.\n\
+Not all responses are translated yet; untranslated ones remain in english.";
+
 // FIXME: Some attribute command does not register the #[cfg(feature = "")]
 // tag on fields so we need an entirely new struct for now
 #[cfg(not(feature = "server"))]
@@ -144,6 +168,22 @@ pub struct Config {
     render_button: Option<ShowHideOption>,
     #[command(desc = SCORE_DATA_DESC, help = SCORE_DATA_HELP)]
     score_data: Option<ScoreData>,
+    #[command(desc = "Specify the color theme of the card command")]
+    card_theme: Option<CardTheme>,
+    #[command(
+        desc = "Specify the layout of the card command",
+        help = "Specify the layout of the `/card` command.\n\
+        `Tall` includes medals and a large avatar while `Compact` only shows the essentials."
+    )]
+    card_layout: Option<CardLayout>,
+    #[command(
+        desc = "Should the card command show extra stats?",
+        help = "Should the `/card` command show extra stats?\n\
+        Extra stats include the playcount, the pp of the top score, and the top mapper."
+    )]
+    card_stats: Option<ShowHideOption>,
+    #[command(desc = LANGUAGE_DESC, help = LANGUAGE_HELP)]
+    language: Option<ConfigLanguage>,
 }
 
 #[cfg(feature = "server")]
@@ -169,6 +209,29 @@ pub enum ConfigGameMode {
     Mania,
 }
 
+#[derive(CommandOption, CreateOption)]
+pub enum ConfigLanguage {
+    #[option(name = "Auto", value = "auto")]
+    Auto,
+    #[option(name = "English", value = "en")]
+    English,
+    #[option(name = "Deutsch", value = "de")]
+    German,
+    #[option(name = "Français", value = "fr")]
+    French,
+}
+
+impl From<ConfigLanguage> for Option<Language> {
+    fn from(language: ConfigLanguage) -> Self {
+        match language {
+            ConfigLanguage::Auto => None,
+            ConfigLanguage::English => Some(Language::English),
+            ConfigLanguage::German => Some(Language::German),
+            ConfigLanguage::French => Some(Language::French),
+        }
+    }
+}
+
 impl From<ConfigGameMode> for Option<GameMode> {
     fn from(mode: ConfigGameMode) -> Self {
         match mode {
@@ -200,6 +263,10 @@ pub async fn config(command: InteractionCommand, config: Config) -> Result<()> {
         mut skin_url,
         render_button,
         score_data,
+        card_theme,
+        card_layout,
+        card_stats,
+        language,
     } = config;
 
     if let Some(ref skin_url) = skin_url {
@@ -249,6 +316,22 @@ pub async fn config(command: InteractionCommand, config: Config) -> Result<()> {
         config.score_data = Some(score_data);
     }
 
+    if let Some(card_theme) = card_theme {
+        config.card_theme = Some(card_theme);
+    }
+
+    if let Some(card_layout) = card_layout {
+        config.card_layout = Some(card_layout);
+    }
+
+    if let Some(card_stats) = card_stats {
+        config.card_stats = Some(matches!(card_stats, ShowHideOption::Show));
+    }
+
+    if let Some(language) = language {
+        config.language = language.into();
+    }
+
     #[cfg(feature = "server")]
     if let Some(ConfigLink::Unlink) = osu {
         config.osu.take();
@@ -580,6 +663,10 @@ async fn convert_config(
         timezone,
         render_button,
         score_data,
+        card_theme,
+        card_layout,
+        card_stats,
+        language,
     } = config;
 
     UserConfig {
@@ -592,6 +679,10 @@ async fn convert_config(
         timezone,
         render_button,
         score_data,
+        card_theme,
+        card_layout,
+        card_stats,
+        language,
     }
 }
 
@@ -6,7 +6,7 @@ use bathbot_psql::model::configs::ScoreData;
 use bathbot_util::{
     Authored, CowUtils, MessageOrigin,
     constants::GENERAL_ISSUE,
-    query::{FilterCriteria, Searchable, TopCriteria},
+    query::{FilterCriteria, FilterExpr, Searchable, TopCriteria},
 };
 use eyre::{Report, Result};
 use rosu_pp::model::beatmap::AdjustedBeatmapAttributes;
@@ -205,7 +205,7 @@ async fn exec(
         }
     };
 
-    let mut data = ScoreEmbedDataWrap::new_custom(score, map, 71, Some(7)).await;
+    let mut data = ScoreEmbedDataWrap::new_custom(score, map, Some(71), Some(7)).await;
 
     // Adjusting hitresults to better showcase the "Ratio" value
     if let ScoreEmbedDataStatus::Full(ref mut data) = data.inner {
@@ -278,7 +278,7 @@ impl ScoreEmbedDataWrap {
     pub async fn new_custom(
         score: Score,
         map: OsuMap,
-        pb_idx: usize,
+        pb_idx: Option<usize>,
         global_idx: Option<usize>,
     ) -> Self {
         let PpAttrs {
@@ -317,7 +317,7 @@ impl ScoreEmbedDataWrap {
                 max_pp,
                 replay_score_id: None,
                 miss_analyzer: None,
-                pb_idx: Some(ScoreEmbedDataPersonalBest::from_index(pb_idx)),
+                pb_idx: pb_idx.map(ScoreEmbedDataPersonalBest::from_index),
                 global_idx,
                 if_fc_pp,
                 #[cfg(feature = "twitch")]
@@ -420,6 +420,8 @@ pub struct ScoreEmbedDataHalf {
     pub has_replay: bool,
     pub miss_analyzer_check: MissAnalyzerCheck,
     pub original_idx: Option<usize>,
+    /// Only calculated if the filter query requires it
+    pub if_fc_pp: Option<f32>,
 }
 
 impl ScoreEmbedDataHalf {
@@ -472,6 +474,17 @@ impl ScoreEmbedDataHalf {
             has_replay,
             miss_analyzer_check,
             original_idx: None,
+            if_fc_pp: None,
+        }
+    }
+
+    /// Calculate values that the filter criteria require but that are too
+    /// expensive to calculate by default.
+    pub async fn prepare_criteria(&mut self, criteria: &FilterExpr<TopCriteria<'_>>) {
+        if criteria.any_criteria(|criteria| !criteria.pp_fc.is_empty()) {
+            self.if_fc_pp = IfFc::new(&self.score, &self.map)
+                .await
+                .map(|if_fc| if_fc.pp);
         }
     }
 
@@ -1046,7 +1059,14 @@ impl<'q> Searchable<TopCriteria<'q>> for ScoreEmbedDataHalf {
         matches &= criteria.date.contains(self.score.ended_at.date());
         matches &= criteria.stars.contains(self.stars);
         matches &= criteria.pp.contains(self.score.pp);
+        matches &= criteria
+            .pp_fc
+            .contains(self.if_fc_pp.unwrap_or(self.score.pp));
         matches &= criteria.acc.contains(self.score.accuracy);
+        matches &= criteria.grade.contains_grade(self.score.grade);
+        matches &= criteria.mods.matches(&self.score.mods);
+        matches &= criteria.status.matches(self.map.status());
+        matches &= criteria.lazer.matches(self.score.set_on_lazer);
 
         if !criteria.ranked_date.is_empty() {
             let Some(datetime) = self.map.ranked_date() else {
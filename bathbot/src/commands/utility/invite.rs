@@ -27,10 +27,12 @@ pub async fn slash_invite(mut command: InteractionCommand) -> Result<()> {
 }
 
 async fn invite(orig: CommandOrigin<'_>) -> Result<()> {
+    let locale = orig.locale().await;
+
     let embed = EmbedBuilder::new()
         .description(INVITE_LINK)
-        .footer(FooterBuilder::new("The initial prefix will be <"))
-        .title("Invite me to your server!");
+        .footer(FooterBuilder::new(locale.get("invite.footer")))
+        .title(locale.get("invite.title"));
 
     let builder = MessageBuilder::new().content(BATHBOT_WORKSHOP).embed(embed);
     orig.callback(builder).await?;
@@ -6,8 +6,11 @@ mod embed_builder;
 mod invite;
 mod ping;
 mod prefix;
+mod role_rewards;
 mod roll;
 mod server_config;
+mod server_daily_challenge;
+mod server_digest;
 mod skin;
 
 #[allow(unused_imports)]
@@ -33,13 +33,12 @@ async fn prefix_ping(msg: &Message, permissions: Option<Permissions>) -> Result<
 }
 
 async fn ping(orig: CommandOrigin<'_>) -> Result<()> {
+    let locale = orig.locale().await;
     let builder = MessageBuilder::new().content("Pong");
     let start = Instant::now();
-    let response_raw = orig.callback_with_response(builder).await?;
+    let response = orig.callback_with_response(builder).await?;
     let elapsed = (Instant::now() - start).as_millis();
 
-    let response = response_raw.model().await?;
-
     let response = match orig {
         CommandOrigin::Message { permissions, .. } => {
             CommandOrigin::from_msg(&response, permissions)
@@ -47,7 +46,7 @@ async fn ping(orig: CommandOrigin<'_>) -> Result<()> {
         orig @ CommandOrigin::Interaction { .. } => orig,
     };
 
-    let content = format!(":ping_pong: Pong! ({elapsed}ms)");
+    let content = locale.format("ping.pong", &[("elapsed", &elapsed)]);
     let builder = MessageBuilder::new().content(content);
     response.update(builder).await?;
 
@@ -0,0 +1,381 @@
+use std::fmt::Write;
+
+use bathbot_model::command_fields::GameModeOption;
+use bathbot_psql::model::configs::RoleRewardKind;
+use bathbot_util::{EmbedBuilder, FooterBuilder, MessageBuilder, constants::GENERAL_ISSUE};
+use eyre::{Result, WrapErr};
+use rosu_v2::prelude::GameMode;
+use twilight_interactions::command::{CommandModel, CreateCommand};
+use twilight_model::{
+    guild::Permissions,
+    id::{
+        Id,
+        marker::{GuildMarker, RoleMarker, UserMarker},
+    },
+};
+
+use crate::{
+    Context,
+    core::commands::{
+        CommandOrigin,
+        checks::{RolesLookup, check_guild_permissions},
+    },
+    manager::RoleReward,
+};
+
+/// Maximum amount of role rewards per guild
+const MAX_REWARDS: usize = 25;
+
+/// Maximum amount of changes that are listed in the preview
+const PREVIEW_COUNT: usize = 20;
+
+#[derive(CommandModel, CreateCommand)]
+#[command(
+    name = "roles",
+    desc = "Automatically assign roles based on osu! stats",
+    help = "Automatically assign roles to members based on their osu! stats.\n\
+    Every few hours, linked members are checked against the role rewards and \
+    roles are added or removed accordingly.\n\
+    Only the cached stats are considered, i.e. the stats of the last time the \
+    member's osu! user was retrieved through a command.\n\
+    Note that reward roles are **removed** from members that don't qualify for them, \
+    including unlinked members, so be sure to only use roles dedicated to rewards.\n\
+    The bot requires the `Manage Roles` permission and its highest role must be \
+    above the reward roles.\n\
+    Adding a reward requires the `Manage Roles` permission as well and the role \
+    must be below your own highest role."
+)]
+pub enum ServerConfigRoles {
+    #[command(name = "add")]
+    Add(ServerConfigRolesAdd),
+    #[command(name = "remove")]
+    Remove(ServerConfigRolesRemove),
+    #[command(name = "list")]
+    List(ServerConfigRolesList),
+    #[command(name = "preview")]
+    Preview(ServerConfigRolesPreview),
+}
+
+#[derive(CommandModel, CreateCommand)]
+#[command(
+    name = "add",
+    desc = "Add or replace the reward condition of a role",
+    help = "Add or replace the reward condition of a role.\n\
+    Both bounds are inclusive so e.g. a global rank below 1000 would be `max: 999`.\n\
+    Ranks and pp require a mode, badges and medals don't."
+)]
+pub struct ServerConfigRolesAdd {
+    #[command(desc = "Specify the role that should be rewarded")]
+    role: Id<RoleMarker>,
+    #[command(desc = "Specify which stat the condition is based on")]
+    kind: RoleRewardKind,
+    #[command(desc = "Specify the mode for ranks and pp")]
+    mode: Option<GameModeOption>,
+    #[command(min_value = 0.0, desc = "Specify the minimum value (inclusive)")]
+    min: Option<f64>,
+    #[command(min_value = 0.0, desc = "Specify the maximum value (inclusive)")]
+    max: Option<f64>,
+}
+
+#[derive(CommandModel, CreateCommand)]
+#[command(name = "remove", desc = "Stop rewarding a role")]
+pub struct ServerConfigRolesRemove {
+    #[command(desc = "Specify the role that should no longer be rewarded")]
+    role: Id<RoleMarker>,
+}
+
+#[derive(CommandModel, CreateCommand)]
+#[command(name = "list", desc = "Display all role rewards")]
+pub struct ServerConfigRolesList;
+
+#[derive(CommandModel, CreateCommand)]
+#[command(
+    name = "preview",
+    desc = "Show which roles would currently be added or removed",
+    help = "Show which roles would currently be added or removed.\n\
+    Nothing is modified, this is just a dry run of the next update."
+)]
+pub struct ServerConfigRolesPreview;
+
+pub(super) async fn role_rewards(orig: CommandOrigin<'_>, args: ServerConfigRoles) -> Result<()> {
+    match args {
+        ServerConfigRoles::Add(args) => add(orig, args).await,
+        ServerConfigRoles::Remove(args) => remove(orig, args).await,
+        ServerConfigRoles::List(_) => list(orig).await,
+        ServerConfigRoles::Preview(_) => preview(orig).await,
+    }
+}
+
+async fn add(orig: CommandOrigin<'_>, args: ServerConfigRolesAdd) -> Result<()> {
+    let guild_id = orig.guild_id().unwrap();
+
+    let ServerConfigRolesAdd {
+        role,
+        kind,
+        mode,
+        min,
+        max,
+    } = args;
+
+    if role.cast() == guild_id {
+        return orig
+            .error_callback("The @everyone role cannot be rewarded")
+            .await;
+    }
+
+    match check_assignable(orig.user_id()?, guild_id, role).await {
+        Ok(None) => {}
+        Ok(Some(content)) => return orig.error_callback(content).await,
+        Err(err) => {
+            let _ = orig.error_callback(GENERAL_ISSUE).await;
+
+            return Err(err);
+        }
+    }
+
+    let mode = if kind.requires_mode() {
+        match mode {
+            Some(mode) => Some(GameMode::from(mode)),
+            None => {
+                let content = "Rewards for ranks or pp require a mode";
+
+                return orig.error_callback(content).await;
+            }
+        }
+    } else {
+        None
+    };
+
+    match (min, max) {
+        (None, None) => {
+            let content = "You must specify a minimum value, a maximum value, or both";
+
+            return orig.error_callback(content).await;
+        }
+        (Some(min), Some(max)) if min > max => {
+            let content = "The minimum value must not be greater than the maximum value";
+
+            return orig.error_callback(content).await;
+        }
+        _ => {}
+    }
+
+    let manager = Context::role_rewards();
+
+    let rewards = match manager.get(guild_id).await {
+        Ok(rewards) => rewards,
+        Err(err) => {
+            let _ = orig.error_callback(GENERAL_ISSUE).await;
+
+            return Err(err);
+        }
+    };
+
+    let is_new = rewards.iter().all(|reward| reward.role_id != role);
+
+    if is_new && rewards.len() >= MAX_REWARDS {
+        let content = format!("Servers can have at most {MAX_REWARDS} role rewards");
+
+        return orig.error_callback(content).await;
+    }
+
+    let reward = RoleReward {
+        role_id: role,
+        kind,
+        mode,
+        min,
+        max,
+    };
+
+    if let Err(err) = manager.set(guild_id, &reward).await {
+        let _ = orig.error_callback(GENERAL_ISSUE).await;
+
+        return Err(err);
+    }
+
+    let content = format!(
+        "Members will now be rewarded with {reward}.\n\
+        Use `/serverconfig roles preview` to see which roles would be updated."
+    );
+
+    let builder = MessageBuilder::new().embed(content);
+    orig.callback(builder).await?;
+
+    Ok(())
+}
+
+/// Members must be able to assign the role themselves, otherwise bot
+/// authorities could use rewards to hand out roles above their own.
+async fn check_assignable(
+    author: Id<UserMarker>,
+    guild_id: Id<GuildMarker>,
+    role: Id<RoleMarker>,
+) -> Result<Option<&'static str>> {
+    let (permissions, roles) = check_guild_permissions(author, guild_id).await;
+
+    if !permissions.contains(Permissions::MANAGE_ROLES) {
+        return Ok(Some(
+            "You need the `Manage Roles` permission to add role rewards",
+        ));
+    }
+
+    let guild_roles = Context::http()
+        .roles(guild_id)
+        .await
+        .wrap_err("Failed to request guild roles")?
+        .models()
+        .await
+        .wrap_err("Failed to deserialize guild roles")?;
+
+    let Some(reward_role) = guild_roles.iter().find(|guild_role| guild_role.id == role) else {
+        return Ok(Some("The role could not be found"));
+    };
+
+    if reward_role.managed {
+        return Ok(Some("Roles managed by an integration cannot be rewarded"));
+    }
+
+    // The guild owner is not bound by the role hierarchy
+    let RolesLookup::Found(member) = roles else {
+        return Ok(None);
+    };
+
+    let highest_position = guild_roles
+        .iter()
+        .filter(|guild_role| {
+            member
+                .roles
+                .iter()
+                .any(|id| id.to_native() == guild_role.id)
+        })
+        .map(|guild_role| guild_role.position)
+        .max()
+        .unwrap_or(0);
+
+    if reward_role.position >= highest_position {
+        return Ok(Some("You can only reward roles below your highest role"));
+    }
+
+    Ok(None)
+}
+
+async fn remove(orig: CommandOrigin<'_>, args: ServerConfigRolesRemove) -> Result<()> {
+    let guild_id = orig.guild_id().unwrap();
+
+    let content = match Context::role_rewards().remove(guild_id, args.role).await {
+        Ok(true) => format!("<@&{}> is no longer rewarded", args.role),
+        Ok(false) => format!("<@&{}> was not rewarded in the first place", args.role),
+        Err(err) => {
+            let _ = orig.error_callback(GENERAL_ISSUE).await;
+
+            return Err(err);
+        }
+    };
+
+    let builder = MessageBuilder::new().embed(content);
+    orig.callback(builder).await?;
+
+    Ok(())
+}
+
+async fn list(orig: CommandOrigin<'_>) -> Result<()> {
+    let guild_id = orig.guild_id().unwrap();
+
+    let rewards = match Context::role_rewards().get(guild_id).await {
+        Ok(rewards) => rewards,
+        Err(err) => {
+            let _ = orig.error_callback(GENERAL_ISSUE).await;
+
+            return Err(err);
+        }
+    };
+
+    let description = if rewards.is_empty() {
+        "No role rewards yet, use `/serverconfig roles add` to add some".to_owned()
+    } else {
+        let mut description = String::with_capacity(rewards.len() * 48);
+
+        for reward in rewards {
+            let _ = writeln!(description, "- {reward}");
+        }
+
+        description
+    };
+
+    let embed = EmbedBuilder::new()
+        .description(description)
+        .title("Role rewards");
+
+    let builder = MessageBuilder::new().embed(embed);
+    orig.callback(builder).await?;
+
+    Ok(())
+}
+
+/// The interaction must be deferred beforehand.
+async fn preview(orig: CommandOrigin<'_>) -> Result<()> {
+    let guild_id = orig.guild_id().unwrap();
+    let manager = Context::role_rewards();
+
+    let rewards = match manager.get(guild_id).await {
+        Ok(rewards) => rewards,
+        Err(err) => {
+            let _ = orig.error(GENERAL_ISSUE).await;
+
+            return Err(err);
+        }
+    };
+
+    if rewards.is_empty() {
+        let content = "No role rewards yet, use `/serverconfig roles add` to add some";
+
+        return orig.error(content).await;
+    }
+
+    let changes = match manager.changes(guild_id, &rewards).await {
+        Ok(changes) => changes,
+        Err(err) => {
+            let _ = orig.error(GENERAL_ISSUE).await;
+
+            return Err(err);
+        }
+    };
+
+    let description = if changes.is_empty() {
+        "All members already have the roles they qualify for".to_owned()
+    } else {
+        let added = changes.iter().filter(|change| change.add).count();
+        let removed = changes.len() - added;
+
+        let mut description =
+            format!("The next update would add **{added}** and remove **{removed}** roles:\n");
+
+        for change in changes.iter().take(PREVIEW_COUNT) {
+            let _ = writeln!(
+                description,
+                "`{sign}` <@{user}> <@&{role}>",
+                sign = if change.add { '+' } else { '-' },
+                user = change.user_id,
+                role = change.role_id,
+            );
+        }
+
+        if changes.len() > PREVIEW_COUNT {
+            let _ = write!(description, "...and {} more", changes.len() - PREVIEW_COUNT);
+        }
+
+        description
+    };
+
+    let footer = FooterBuilder::new("Roles are updated every 6 hours, at most 100 per update");
+
+    let embed = EmbedBuilder::new()
+        .description(description)
+        .footer(footer)
+        .title("Role rewards preview");
+
+    let builder = MessageBuilder::new().embed(embed);
+    orig.update(builder).await?;
+
+    Ok(())
+}
@@ -62,10 +62,17 @@ async fn roll(orig: CommandOrigin<'_>, limit: Option<u32>) -> Result<()> {
     let author_id = orig.user_id()?;
     let num = limit.map_or_else(random, |limit| thread_rng().gen_range(1..=limit.max(2)));
 
-    let description = format!(
-        "<@{author_id}> rolls {num} point{} :game_die:",
-        if num == 1 { "" } else { "s" }
-    );
+    let user = format!("<@{author_id}>");
+    let key = if num == 1 {
+        "roll.result_single"
+    } else {
+        "roll.result"
+    };
+
+    let description = orig
+        .locale()
+        .await
+        .format(key, &[("user", &user), ("num", &num)]);
 
     let builder = MessageBuilder::new().embed(description);
     orig.callback(builder).await?;
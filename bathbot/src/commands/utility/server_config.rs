@@ -9,7 +9,12 @@ use twilight_model::{
     id::{Id, marker::RoleMarker},
 };
 
-use super::AuthorityCommandKind;
+use super::{
+    AuthorityCommandKind, ConfigLanguage,
+    role_rewards::{ServerConfigRoles, role_rewards},
+    server_daily_challenge::{ServerConfigDailyChallenge, server_daily_challenge},
+    server_digest::{ServerConfigDigest, server_digest},
+};
 use crate::{
     Context,
     core::commands::CommandOrigin,
@@ -29,6 +34,12 @@ pub enum ServerConfig {
     Authorities(ServerConfigAuthorities),
     #[command(name = "edit")]
     Edit(ServerConfigEdit),
+    #[command(name = "dailychallenge")]
+    DailyChallenge(ServerConfigDailyChallenge),
+    #[command(name = "digest")]
+    Digest(ServerConfigDigest),
+    #[command(name = "roles")]
+    Roles(ServerConfigRoles),
 }
 
 #[derive(CommandModel, CreateCommand)]
@@ -142,6 +153,23 @@ pub struct ServerConfigEdit {
         Applies only if the member has not specified a config for themselves."
     )]
     score_data: Option<ScoreData>,
+    #[command(
+        desc = "Should scores of linked members be archived?",
+        help = "Should scores of linked members be archived?\n\
+        If enabled, all scores that are fetched for members with a linked osu! account \
+        are stored so they remain available after the osu!api stops providing them, \
+        e.g. scores outside of the top 200 or recent scores older than 24 hours.\n\
+        `/top`, `/recent list`, and `/graph top` include these archived scores.\n\
+        Archived scores are removed after a year or once the member leaves."
+    )]
+    score_archive: Option<EnableDisable>,
+    #[command(
+        desc = "Specify the language of command responses",
+        help = "Specify the language of command responses.\n\
+        If set to `Auto`, the discord language of the member is used.\n\
+        Applies only if the member has not specified a language for themselves."
+    )]
+    language: Option<ConfigLanguage>,
 }
 
 impl ServerConfigEdit {
@@ -154,6 +182,8 @@ impl ServerConfigEdit {
             allow_custom_skins,
             hide_medal_solutions,
             score_data,
+            score_archive,
+            language,
         } = self;
 
         song_commands.is_some()
@@ -163,12 +193,19 @@ impl ServerConfigEdit {
             || allow_custom_skins.is_some()
             || hide_medal_solutions.is_some()
             || score_data.is_some()
+            || score_archive.is_some()
+            || language.is_some()
     }
 }
 
 async fn slash_serverconfig(mut command: InteractionCommand) -> Result<()> {
     let args = ServerConfig::from_interaction(command.input_data())?;
 
+    // Determining the changes may take a while
+    if matches!(args, ServerConfig::Roles(ServerConfigRoles::Preview(_))) {
+        command.defer(false).await?;
+    }
+
     serverconfig((&mut command).into(), args).await
 }
 
@@ -210,6 +247,9 @@ async fn serverconfig(orig: CommandOrigin<'_>, args: ServerConfig) -> Result<()>
             return super::authorities(orig, args.into()).await;
         }
         ServerConfig::Edit(edit) => edit,
+        ServerConfig::DailyChallenge(args) => return server_daily_challenge(orig, args).await,
+        ServerConfig::Digest(args) => return server_digest(orig, args).await,
+        ServerConfig::Roles(args) => return role_rewards(orig, args).await,
     };
 
     if args.any() {
@@ -222,6 +262,8 @@ async fn serverconfig(orig: CommandOrigin<'_>, args: ServerConfig) -> Result<()>
                 allow_custom_skins,
                 hide_medal_solutions,
                 score_data,
+                score_archive,
+                language,
             } = args;
 
             if let Some(list_embeds) = list_embeds {
@@ -251,6 +293,14 @@ async fn serverconfig(orig: CommandOrigin<'_>, args: ServerConfig) -> Result<()>
             if let Some(score_data) = score_data {
                 config.score_data = Some(score_data);
             }
+
+            if let Some(score_archive) = score_archive {
+                config.score_archive = Some(score_archive == EnableDisable::Enable);
+            }
+
+            if let Some(language) = language {
+                config.language = language.into();
+            }
         };
 
         if let Err(err) = Context::guild_config().update(guild_id, f).await {
@@ -0,0 +1,69 @@
+use bathbot_model::command_fields::EnableDisable;
+use bathbot_util::{MessageBuilder, constants::GENERAL_ISSUE};
+use eyre::Result;
+use time::UtcDateTime;
+use twilight_interactions::command::{CommandModel, CreateCommand};
+use twilight_model::id::{Id, marker::ChannelMarker};
+
+use crate::{Context, core::commands::CommandOrigin};
+
+#[derive(CommandModel, CreateCommand)]
+#[command(
+    name = "dailychallenge",
+    desc = "Remind the server when a new daily challenge opens",
+    help = "Post a reminder into a channel whenever a new daily challenge opens.\n\
+    The reminder contains the map and required mods of the challenge.\n\
+    The first reminder is posted once the next daily challenge opens.\n\
+    While enabled, daily challenge participation of linked members is recorded \
+    for `/dailychallenge history` and `/dailychallenge server`, even if they \
+    don't place on the leaderboard."
+)]
+pub struct ServerConfigDailyChallenge {
+    #[command(desc = "Enable or disable the reminder")]
+    reminder: EnableDisable,
+    #[command(desc = "Specify the channel for the reminder, defaults to the current channel")]
+    channel: Option<Id<ChannelMarker>>,
+}
+
+pub(super) async fn server_daily_challenge(
+    orig: CommandOrigin<'_>,
+    args: ServerConfigDailyChallenge,
+) -> Result<()> {
+    let guild_id = orig.guild_id().unwrap();
+    let psql = Context::psql();
+
+    if let EnableDisable::Disable = args.reminder {
+        let content = match psql.delete_daily_challenge_reminder(guild_id).await {
+            Ok(true) => "The daily challenge reminder is now disabled",
+            Ok(false) => "There was no daily challenge reminder to disable",
+            Err(err) => {
+                let _ = orig.error_callback(GENERAL_ISSUE).await;
+
+                return Err(err.wrap_err("Failed to delete daily challenge reminder"));
+            }
+        };
+
+        let builder = MessageBuilder::new().embed(content);
+        orig.callback(builder).await?;
+
+        return Ok(());
+    }
+
+    let channel_id = args.channel.unwrap_or_else(|| orig.channel_id());
+
+    // Today's challenge is considered as reminded already
+    let today = UtcDateTime::now().date();
+    let upsert_fut = psql.upsert_daily_challenge_reminder(guild_id, channel_id.get(), today);
+
+    if let Err(err) = upsert_fut.await {
+        let _ = orig.error_callback(GENERAL_ISSUE).await;
+
+        return Err(err.wrap_err("Failed to upsert daily challenge reminder"));
+    }
+
+    let content = format!("New daily challenges will now be announced in <#{channel_id}>");
+    let builder = MessageBuilder::new().embed(content);
+    orig.callback(builder).await?;
+
+    Ok(())
+}
@@ -0,0 +1,90 @@
+use bathbot_psql::model::digest::DigestFrequency;
+use bathbot_util::{MessageBuilder, constants::GENERAL_ISSUE};
+use eyre::Result;
+use twilight_interactions::command::{CommandModel, CommandOption, CreateCommand, CreateOption};
+use twilight_model::id::{Id, marker::ChannelMarker};
+
+use crate::{Context, core::commands::CommandOrigin};
+
+#[derive(CommandModel, CreateCommand)]
+#[command(
+    name = "digest",
+    desc = "Automatically post a server leaderboard digest",
+    help = "Automatically post a server leaderboard digest into a channel.\n\
+    The digest contains the top linked members for each mode, \
+    the members with the biggest pp and rank gains since the previous digest, \
+    and members that were newly linked in the meanwhile.\n\
+    Just like `/serverleaderboard`, the digest is based on the last time \
+    each member's osu! user was retrieved through a command.\n\
+    The first digest is posted one day or week after enabling it."
+)]
+pub struct ServerConfigDigest {
+    #[command(desc = "Specify how often the digest should be posted")]
+    frequency: ServerDigestFrequency,
+    #[command(desc = "Specify the channel for the digest, defaults to the current channel")]
+    channel: Option<Id<ChannelMarker>>,
+}
+
+#[derive(CommandOption, CreateOption)]
+pub enum ServerDigestFrequency {
+    #[option(name = "Daily", value = "daily")]
+    Daily,
+    #[option(name = "Weekly", value = "weekly")]
+    Weekly,
+    #[option(name = "Disable", value = "disable")]
+    Disable,
+}
+
+impl From<ServerDigestFrequency> for Option<DigestFrequency> {
+    #[inline]
+    fn from(frequency: ServerDigestFrequency) -> Self {
+        match frequency {
+            ServerDigestFrequency::Daily => Some(DigestFrequency::Daily),
+            ServerDigestFrequency::Weekly => Some(DigestFrequency::Weekly),
+            ServerDigestFrequency::Disable => None,
+        }
+    }
+}
+
+pub(super) async fn server_digest(orig: CommandOrigin<'_>, args: ServerConfigDigest) -> Result<()> {
+    let guild_id = orig.guild_id().unwrap();
+    let psql = Context::psql();
+
+    let Some(frequency) = Option::<DigestFrequency>::from(args.frequency) else {
+        let content = match psql.delete_guild_digest(guild_id).await {
+            Ok(true) => "The server digest is now disabled",
+            Ok(false) => "There was no server digest to disable",
+            Err(err) => {
+                let _ = orig.error_callback(GENERAL_ISSUE).await;
+
+                return Err(err.wrap_err("Failed to delete guild digest"));
+            }
+        };
+
+        let builder = MessageBuilder::new().embed(content);
+        orig.callback(builder).await?;
+
+        return Ok(());
+    };
+
+    let channel_id = args.channel.unwrap_or_else(|| orig.channel_id());
+
+    let upsert_fut = psql.upsert_guild_digest(guild_id, channel_id.get(), frequency);
+
+    if let Err(err) = upsert_fut.await {
+        let _ = orig.error_callback(GENERAL_ISSUE).await;
+
+        return Err(err.wrap_err("Failed to upsert guild digest"));
+    }
+
+    let frequency = match frequency {
+        DigestFrequency::Daily => "daily",
+        DigestFrequency::Weekly => "weekly",
+    };
+
+    let content = format!("The server digest will now be posted {frequency} in <#{channel_id}>");
+    let builder = MessageBuilder::new().embed(content);
+    orig.callback(builder).await?;
+
+    Ok(())
+}
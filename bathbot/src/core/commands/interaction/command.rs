@@ -59,6 +59,7 @@ pub struct SlashCommand {
 }
 
 pub struct MessageCommand {
+    pub bucket: Option<BucketName>,
     pub create: fn() -> Command,
     pub exec: fn(InteractionCommand) -> CommandResult,
     pub flags: CommandFlags,
@@ -10,10 +10,11 @@ use twilight_model::{
     },
 };
 
+use super::prefix::PrefixResponses;
 use crate::{
-    core::Context,
+    core::{Context, locale::Locale},
     util::{
-        ChannelExt, InteractionCommandExt, InteractionToken, MessageExt,
+        InteractionCommandExt, InteractionToken, MessageExt,
         interaction::{InteractionCommand, InteractionComponent},
     },
 };
@@ -50,6 +51,16 @@ impl CommandOrigin<'_> {
         }
     }
 
+    /// The [`Locale`] in which responses should be written.
+    pub async fn locale(&self) -> Locale {
+        match self {
+            CommandOrigin::Message { msg, .. } => {
+                Locale::resolve(Some(msg.author.id), msg.guild_id, None).await
+            }
+            CommandOrigin::Interaction { command } => command.locale().await,
+        }
+    }
+
     /// Respond to something.
     ///
     /// In case of a message, discard the response message created.
@@ -57,11 +68,12 @@ impl CommandOrigin<'_> {
     /// In case of an interaction, the response will **not** be ephemeral.
     pub async fn callback(&self, builder: MessageBuilder<'_>) -> Result<()> {
         match self {
-            Self::Message { msg, permissions } => msg
-                .create_message(builder, *permissions)
-                .await
-                .map(unit)
-                .wrap_err("failed to create message to callback"),
+            Self::Message { msg, permissions } => {
+                PrefixResponses::respond(msg, builder, *permissions)
+                    .await
+                    .map(unit)
+                    .wrap_err("failed to create message to callback")
+            }
             Self::Interaction { command } => command
                 .callback(builder, false)
                 .await
@@ -73,15 +85,13 @@ impl CommandOrigin<'_> {
     /// Respond to something and return the resulting response message.
     ///
     /// In case of an interaction, the response will **not** be ephemeral.
-    pub async fn callback_with_response(
-        &self,
-        builder: MessageBuilder<'_>,
-    ) -> Result<Response<Message>> {
+    pub async fn callback_with_response(&self, builder: MessageBuilder<'_>) -> Result<Message> {
         match self {
-            Self::Message { msg, permissions } => msg
-                .create_message(builder, *permissions)
-                .await
-                .wrap_err("failed to create message for response callback"),
+            Self::Message { msg, permissions } => {
+                PrefixResponses::respond(msg, builder, *permissions)
+                    .await
+                    .wrap_err("failed to create message for response callback")
+            }
             Self::Interaction { command } => {
                 command
                     .callback(builder, false)
@@ -91,7 +101,10 @@ impl CommandOrigin<'_> {
                 Context::interaction()
                     .response(&command.token)
                     .await
-                    .wrap_err("failed to get response message")
+                    .wrap_err("failed to get response message")?
+                    .model()
+                    .await
+                    .wrap_err("failed to deserialize response message")
             }
         }
     }
@@ -107,11 +120,12 @@ impl CommandOrigin<'_> {
         ephemeral: bool,
     ) -> Result<()> {
         match self {
-            Self::Message { msg, permissions } => msg
-                .create_message(builder, *permissions)
-                .await
-                .map(unit)
-                .wrap_err("failed to create message for flagged callback"),
+            Self::Message { msg, permissions } => {
+                PrefixResponses::respond(msg, builder, *permissions)
+                    .await
+                    .map(unit)
+                    .wrap_err("failed to create message for flagged callback")
+            }
             Self::Interaction { command } => command
                 .callback(builder, ephemeral)
                 .await
@@ -126,16 +140,20 @@ impl CommandOrigin<'_> {
     /// either through deferring or a previous initial response.
     /// Also be sure this is only called once.
     /// Afterwards, use the resulting response message instead.
-    pub async fn create_message(&self, builder: MessageBuilder<'_>) -> Result<Response<Message>> {
+    pub async fn create_message(&self, builder: MessageBuilder<'_>) -> Result<Message> {
         match self {
-            Self::Message { msg, permissions } => msg
-                .create_message(builder, *permissions)
-                .await
-                .wrap_err("failed to create message as response"),
+            Self::Message { msg, permissions } => {
+                PrefixResponses::respond(msg, builder, *permissions)
+                    .await
+                    .wrap_err("failed to create message as response")
+            }
             Self::Interaction { command } => command
                 .update(builder)
                 .await
-                .wrap_err("failed to update as response"),
+                .wrap_err("failed to update as response")?
+                .model()
+                .await
+                .wrap_err("failed to deserialize response"),
         }
     }
 
@@ -159,11 +177,12 @@ impl CommandOrigin<'_> {
     /// In case of an interaction, be sure you already called back beforehand.
     pub async fn error(&self, content: impl Into<String>) -> Result<()> {
         match self {
-            Self::Message { msg, .. } => msg
-                .error(content)
-                .await
-                .map(unit)
-                .wrap_err("failed to respond with error"),
+            Self::Message { msg, .. } => {
+                PrefixResponses::respond(msg, error_builder(content), None)
+                    .await
+                    .map(unit)
+                    .wrap_err("failed to respond with error")
+            }
             Self::Interaction { command } => command
                 .error(content)
                 .await
@@ -178,11 +197,12 @@ impl CommandOrigin<'_> {
     /// call this. The response will not be ephemeral.
     pub async fn error_callback(&self, content: impl Into<String>) -> Result<()> {
         match self {
-            CommandOrigin::Message { msg, .. } => msg
-                .error(content)
-                .await
-                .map(unit)
-                .wrap_err("failed to callback with error"),
+            CommandOrigin::Message { msg, .. } => {
+                PrefixResponses::respond(msg, error_builder(content), None)
+                    .await
+                    .map(unit)
+                    .wrap_err("failed to callback with error")
+            }
             CommandOrigin::Interaction { command } => command
                 .error_callback(content)
                 .await
@@ -276,6 +296,12 @@ impl OwnedCommandOrigin {
 
 fn unit<T>(_: T) {}
 
+fn error_builder(content: impl Into<String>) -> MessageBuilder<'static> {
+    let embed = EmbedBuilder::new().color_red().description(content);
+
+    MessageBuilder::new().embed(embed)
+}
+
 impl From<(Message, Option<Permissions>)> for OwnedCommandOrigin {
     fn from((msg, permissions): (Message, Option<Permissions>)) -> Self {
         Self::Message {
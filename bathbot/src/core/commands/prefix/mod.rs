@@ -12,11 +12,13 @@ use radix_trie::{Trie, TrieCommon};
 pub use self::{
     args::{Args, ArgsNum},
     command::PrefixCommand,
+    responses::PrefixResponses,
 };
 use crate::util::Emote;
 
 mod args;
 mod command;
+mod responses;
 
 #[distributed_slice]
 pub static __PREFIX_COMMANDS: [PrefixCommand] = [..];
@@ -0,0 +1,156 @@
+use std::{
+    collections::HashMap,
+    future::IntoFuture,
+    slice,
+    sync::Mutex,
+    time::{Duration, Instant},
+};
+
+use bathbot_util::{IntHasher, MessageBuilder};
+use eyre::{Result, WrapErr};
+use twilight_http::{Error as HttpError, Response};
+use twilight_model::{
+    channel::Message,
+    guild::Permissions,
+    id::{Id, marker::MessageMarker},
+};
+
+use crate::{core::Context, util::ChannelExt};
+
+/// How long after invoking a prefix command the invoke can be edited to rerun
+/// the command.
+const RERUN_WINDOW: Duration = Duration::from_secs(120);
+
+/// Keeps track of which bot response belongs to which prefix command invoke
+/// so that edited invokes can replace their previous response.
+#[derive(Default)]
+pub struct PrefixResponses {
+    responses: Mutex<HashMap<Id<MessageMarker>, PrefixResponse, IntHasher>>,
+}
+
+struct PrefixResponse {
+    response: Id<MessageMarker>,
+    content: Box<str>,
+    invoked_at: Instant,
+    /// Whether the next response to the invoke should replace the previous one
+    replace: bool,
+}
+
+impl PrefixResponses {
+    /// Create a response to the given invoke.
+    ///
+    /// If the invoke is being rerun, its previous response will be updated
+    /// instead. Otherwise, the first response to an invoke is remembered.
+    pub async fn respond(
+        msg: &Message,
+        builder: MessageBuilder<'_>,
+        permissions: Option<Permissions>,
+    ) -> Result<Message> {
+        let responses = &Context::get().prefix_responses;
+
+        if let Some(response) = responses.take_replace(msg.id) {
+            // The previous response is taken over so its active message
+            // must no longer handle components
+            Context::get().active_msgs.remove(response).await;
+
+            return Self::replace(msg, response, builder, permissions)
+                .await
+                .wrap_err("Failed to replace previous response")?
+                .model()
+                .await
+                .wrap_err("Failed to deserialize response");
+        }
+
+        let response = msg
+            .create_message(builder, permissions)
+            .await
+            .wrap_err("Failed to create message as response")?
+            .model()
+            .await
+            .wrap_err("Failed to deserialize response")?;
+
+        responses.insert(msg, response.id);
+
+        Ok(response)
+    }
+
+    /// Prepare rerunning the invoke of the edited message.
+    ///
+    /// Returns whether the invoke is recent enough and its content changed.
+    pub fn prepare_rerun(&self, msg: &Message) -> bool {
+        let mut responses = self.responses.lock().unwrap();
+
+        let Some(entry) = responses.get_mut(&msg.id) else {
+            return false;
+        };
+
+        if entry.invoked_at.elapsed() > RERUN_WINDOW {
+            responses.remove(&msg.id);
+
+            return false;
+        }
+
+        // Edits also happen when e.g. embeds of links are resolved
+        if *entry.content == msg.content {
+            return false;
+        }
+
+        entry.content = Box::from(msg.content.as_str());
+        entry.replace = true;
+
+        true
+    }
+
+    /// Forget the invoke, e.g. because it was deleted.
+    pub fn remove(&self, msg: Id<MessageMarker>) {
+        self.responses.lock().unwrap().remove(&msg);
+    }
+
+    fn take_replace(&self, msg: Id<MessageMarker>) -> Option<Id<MessageMarker>> {
+        let mut responses = self.responses.lock().unwrap();
+        let entry = responses.get_mut(&msg).filter(|entry| entry.replace)?;
+        entry.replace = false;
+
+        Some(entry.response)
+    }
+
+    fn insert(&self, msg: &Message, response: Id<MessageMarker>) {
+        let mut responses = self.responses.lock().unwrap();
+        responses.retain(|_, entry| entry.invoked_at.elapsed() <= RERUN_WINDOW);
+
+        responses.entry(msg.id).or_insert_with(|| PrefixResponse {
+            response,
+            content: Box::from(msg.content.as_str()),
+            invoked_at: Instant::now(),
+            replace: false,
+        });
+    }
+
+    async fn replace(
+        msg: &Message,
+        response: Id<MessageMarker>,
+        builder: MessageBuilder<'_>,
+        permissions: Option<Permissions>,
+    ) -> Result<Response<Message>, HttpError> {
+        let mut req = Context::http()
+            .update_message(msg.channel_id, response)
+            .content(builder.content.as_deref());
+
+        let embed = builder.embed.build();
+        req = req.embeds(embed.as_option_slice());
+
+        // Clear previous components if there are no new ones
+        req = req.components(Some(builder.components.as_deref().unwrap_or_default()));
+
+        match builder.attachment.as_ref().filter(|_| {
+            permissions.is_none_or(|permissions| permissions.contains(Permissions::ATTACH_FILES))
+        }) {
+            Some(attachment) => {
+                req.attachments(slice::from_ref(attachment))
+                    .into_future()
+                    .await
+            }
+            None => req.keep_attachment_ids(&[]).into_future().await,
+        }
+    }
+}
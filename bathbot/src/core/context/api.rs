@@ -0,0 +1,303 @@
+use std::str::FromStr;
+
+use bathbot_model::{
+    BgGameKind, Countries, HlVersion, UserModeStatsColumn, UserStatsColumn, UserStatsEntries,
+    UserStatsEntry,
+};
+use bathbot_server::{
+    AccuracyPp, ApiError, ApiFuture, ApiKey, ApiProvider, BgScore, GameScores, HlHighscore,
+    LeaderboardEntry, LeaderboardQuery, LeaderboardValue, MapPp, PpQuery, TrackedUser,
+};
+use eyre::{Report, WrapErr};
+use rosu_pp::Performance;
+use rosu_v2::prelude::{GameMode, GameModsIntermode};
+use twilight_model::id::Id;
+
+use super::{CONTEXT, Context};
+use crate::manager::MapError;
+
+/// Accuracies for which pp values are calculated if none is specified
+const DEFAULT_ACCURACIES: [f64; 5] = [95.0, 97.0, 98.0, 99.0, 100.0];
+
+/// Provides the server's `/api/v1` routes with data.
+pub(super) struct BotApiProvider;
+
+impl BotApiProvider {
+    /// The server starts before the [`Context`] is initialized so requests
+    /// need to be rejected until then.
+    fn ready() -> Result<(), ApiError> {
+        match CONTEXT.get() {
+            Some(_) => Ok(()),
+            None => Err(ApiError::Unavailable),
+        }
+    }
+}
+
+impl ApiProvider for BotApiProvider {
+    fn api_key<'a>(&'a self, key: &'a str) -> ApiFuture<'a, Option<ApiKey>> {
+        Box::pin(async move {
+            Self::ready()?;
+
+            let key = Context::psql()
+                .select_api_key(key)
+                .await
+                .wrap_err("Failed to get api key")?
+                .map(|key| ApiKey {
+                    key_id: key.key_id as u32,
+                    name: key.name.into_boxed_str(),
+                    requests_per_minute: key.requests_per_minute as u32,
+                });
+
+            Ok(key)
+        })
+    }
+
+    fn guild_leaderboard(
+        &self,
+        guild_id: u64,
+        query: LeaderboardQuery,
+    ) -> ApiFuture<'_, Vec<LeaderboardEntry>> {
+        Box::pin(async move {
+            Self::ready()?;
+
+            let Some(guild_id) = Id::new_checked(guild_id) else {
+                return Err(ApiError::NotFound("Unknown guild"));
+            };
+
+            let cache = Context::cache();
+
+            match cache.guild(guild_id).await {
+                Ok(Some(_)) => {}
+                Ok(None) => return Err(ApiError::NotFound("Unknown guild")),
+                Err(err) => return Err(Report::new(err).wrap_err("Failed to get guild").into()),
+            }
+
+            let members: Vec<_> = cache
+                .members(guild_id)
+                .await
+                .wrap_err("Failed to get members")?
+                .into_iter()
+                .map(|id| id as i64)
+                .collect();
+
+            let country_code = match query.country {
+                Some(ref country) => match Countries::name(country).to_code() {
+                    Some(code) => Some(code.to_owned()),
+                    None if country.len() == 2 => Some(country.to_ascii_uppercase()),
+                    None => {
+                        let msg = format!("Unknown country `{country}`");
+
+                        return Err(ApiError::BadRequest(msg));
+                    }
+                },
+                None => None,
+            };
+
+            let psql = Context::psql();
+            let unknown_kind = || ApiError::BadRequest(format!("Unknown kind `{}`", query.kind));
+
+            let entries = match query.mode {
+                Some(mode) => {
+                    let column =
+                        UserModeStatsColumn::from_str(&query.kind).map_err(|_| unknown_kind())?;
+
+                    psql.select_osu_user_mode_stats(&members, mode, column, country_code.as_deref())
+                        .await
+                        .wrap_err("Failed to get user mode stats")?
+                }
+                None => {
+                    let column =
+                        UserStatsColumn::from_str(&query.kind).map_err(|_| unknown_kind())?;
+
+                    psql.select_osu_user_stats(&members, column, country_code.as_deref())
+                        .await
+                        .wrap_err("Failed to get user stats")?
+                }
+            };
+
+            Ok(leaderboard_entries(entries))
+        })
+    }
+
+    fn game_scores(&self, user_id: u64) -> ApiFuture<'_, GameScores> {
+        Box::pin(async move {
+            Self::ready()?;
+
+            let Some(user_id) = Id::new_checked(user_id) else {
+                return Ok(GameScores {
+                    bg: Vec::new(),
+                    higherlower: Vec::new(),
+                });
+            };
+
+            let psql = Context::psql();
+
+            let bg = psql
+                .select_bggame_user_scores(user_id)
+                .await
+                .wrap_err("Failed to get bg scores")?
+                .into_iter()
+                .filter_map(|score| {
+                    let kind = BgGameKind::try_from(score.game_kind).ok()?;
+
+                    Some(BgScore {
+                        kind: kind.as_str(),
+                        score: score.score as u32,
+                    })
+                })
+                .collect();
+
+            let higherlower = psql
+                .select_higherlower_user_highscores(user_id)
+                .await
+                .wrap_err("Failed to get higherlower highscores")?
+                .into_iter()
+                .filter_map(|score| {
+                    let version = HlVersion::try_from(score.game_version).ok()?;
+
+                    Some(HlHighscore {
+                        version: version.as_str(),
+                        highscore: score.highscore as u32,
+                    })
+                })
+                .collect();
+
+            Ok(GameScores { bg, higherlower })
+        })
+    }
+
+    fn tracked_users(&self, channel_id: u64) -> ApiFuture<'_, Vec<TrackedUser>> {
+        Box::pin(async move {
+            Self::ready()?;
+
+            let tracked = Context::psql()
+                .select_tracked_osu_users_channel(channel_id)
+                .await
+                .wrap_err("Failed to get tracked users")?;
+
+            let user_ids: Vec<_> = tracked.iter().map(|entry| entry.user_id).collect();
+
+            let mut names = Context::osu_user()
+                .names(&user_ids)
+                .await
+                .wrap_err("Failed to get usernames")?;
+
+            let users = tracked
+                .into_iter()
+                .map(|entry| TrackedUser {
+                    user_id: entry.user_id as u32,
+                    username: names
+                        .remove(&(entry.user_id as u32))
+                        .map(|name| name.into_string()),
+                    mode: GameMode::from(entry.gamemode as u8),
+                    min_index: entry.min_index.map(|index| index as u8),
+                    max_index: entry.max_index.map(|index| index as u8),
+                    min_pp: entry.min_pp,
+                    max_pp: entry.max_pp,
+                    min_combo_percent: entry.min_combo_percent,
+                    max_combo_percent: entry.max_combo_percent,
+                })
+                .collect();
+
+            Ok(users)
+        })
+    }
+
+    fn map_pp(&self, map_id: u32, query: PpQuery) -> ApiFuture<'_, MapPp> {
+        Box::pin(async move {
+            Self::ready()?;
+
+            let map = match Context::osu_map().map(map_id, None).await {
+                Ok(map) => map,
+                Err(MapError::NotFound { .. }) => return Err(ApiError::NotFound("Unknown map")),
+                Err(MapError::Report(err)) => return Err(err.wrap_err("Failed to get map").into()),
+            };
+
+            let mode = query.mode.unwrap_or_else(|| map.mode());
+
+            let mods = match query.mods {
+                Some(ref acronyms) => acronyms
+                    .parse::<GameModsIntermode>()
+                    .map_err(|_| ApiError::BadRequest(format!("Invalid mods `{acronyms}`")))?,
+                None => GameModsIntermode::new(),
+            };
+
+            let mods = match mods.try_with_mode(mode) {
+                Some(mods) if mods.is_valid() => mods,
+                _ => {
+                    let msg = "Mods are incompatible with each other or the mode".to_owned();
+
+                    return Err(ApiError::BadRequest(msg));
+                }
+            };
+
+            let mut calc = Context::pp(&map).mode(mode).mods(mods.clone());
+
+            let Some(max_attrs) = calc.performance().await else {
+                let msg = "Cannot calculate pp for this map".to_owned();
+
+                return Err(ApiError::BadRequest(msg));
+            };
+
+            let accuracies = match query.acc {
+                Some(acc) => vec![acc],
+                None => DEFAULT_ACCURACIES.to_vec(),
+            };
+
+            let pp = accuracies
+                .into_iter()
+                .map(|accuracy| {
+                    let pp = Performance::new(max_attrs.difficulty_attributes())
+                        .mods(mods.clone())
+                        .accuracy(accuracy)
+                        .lazer(true)
+                        .calculate()
+                        .pp();
+
+                    AccuracyPp { accuracy, pp }
+                })
+                .collect();
+
+            Ok(MapPp {
+                map_id,
+                mode,
+                mods: mods.to_string(),
+                stars: max_attrs.stars(),
+                max_pp: max_attrs.pp(),
+                pp,
+            })
+        })
+    }
+}
+
+fn leaderboard_entries(entries: UserStatsEntries) -> Vec<LeaderboardEntry> {
+    fn convert<V>(
+        entries: Vec<UserStatsEntry<V>>,
+        value: impl Fn(V) -> LeaderboardValue,
+    ) -> Vec<LeaderboardEntry> {
+        entries
+            .into_iter()
+            .map(|entry| LeaderboardEntry {
+                username: entry.name,
+                country_code: String::from_utf8_lossy(&entry.country).into_owned(),
+                value: value(entry.value),
+            })
+            .collect()
+    }
+
+    match entries {
+        UserStatsEntries::Accuracy(entries)
+        | UserStatsEntries::Float(entries)
+        | UserStatsEntries::PpF32(entries) => convert(entries, LeaderboardValue::Float),
+        UserStatsEntries::Amount(entries) => {
+            convert(entries, |value| LeaderboardValue::Int(value as i64))
+        }
+        UserStatsEntries::AmountWithNegative(entries) => convert(entries, LeaderboardValue::Int),
+        UserStatsEntries::Date(entries) => convert(entries, |value| {
+            LeaderboardValue::Int(value.unix_timestamp())
+        }),
+        UserStatsEntries::Playtime(entries) | UserStatsEntries::Rank(entries) => {
+            convert(entries, |value| LeaderboardValue::Int(value as i64))
+        }
+    }
+}
@@ -0,0 +1,346 @@
+use std::{fmt::Write, slice};
+
+use bathbot_psql::model::osu::{DbDailyChallengeReminder, DbDailyChallengeResult};
+use bathbot_util::{
+    AuthorBuilder, EmbedBuilder, FooterBuilder, ModsFormatter,
+    constants::{OSU_BASE, UNKNOWN_CHANNEL},
+};
+use eyre::{Result, WrapErr};
+use rkyv::{
+    Deserialize,
+    rancor::{BoxedError, Panic, ResultExt, Strategy},
+};
+use rosu_v2::prelude::{GameMode, GameMods};
+use time::{Date, Duration as TimeDuration, UtcDateTime};
+use tokio::time::{Duration, MissedTickBehavior, interval, sleep};
+use twilight_http::{
+    api_error::{ApiError, GeneralApiError},
+    error::ErrorType,
+};
+use twilight_model::{channel::message::Embed, id::Id};
+
+use super::Context;
+use crate::{manager::redis::osu::UserArgs, util::Emote};
+
+/// Members outside of the leaderboard are checked every this many ticks
+const MEMBER_CHECK_TICKS: u32 = 6;
+
+/// Maximum amount of members whose profile is requested per check. Further
+/// members are checked in the following rounds.
+const MEMBER_CHECK_LIMIT: usize = 50;
+
+/// Delay between requesting the profiles of members
+const MEMBER_CHECK_DELAY: Duration = Duration::from_millis(500);
+
+impl Context {
+    /// Every ten minutes, store the daily challenge results of linked users
+    /// and remind guilds about a newly opened daily challenge.
+    pub async fn daily_challenge_loop() {
+        let mut interval = interval(Duration::from_secs(10 * 60));
+        interval.set_missed_tick_behavior(MissedTickBehavior::Delay);
+
+        let mut prev_date: Option<Date> = None;
+
+        for tick in 0.. {
+            interval.tick().await;
+
+            let today = UtcDateTime::now().date();
+
+            let round = tick / MEMBER_CHECK_TICKS;
+
+            // Results can still change until the room closes so the previous
+            // room is processed one last time once the date changed.
+            if let Some(date) = prev_date.filter(|&date| date != today)
+                && let Err(err) = Self::store_daily_challenge_results(date, Some(round)).await
+            {
+                warn!(%date, ?err, "Failed to store previous daily challenge results");
+            }
+
+            prev_date = Some(today);
+
+            let check_members = (tick % MEMBER_CHECK_TICKS == 0).then_some(round);
+
+            if let Err(err) = Self::store_daily_challenge_results(today, check_members).await {
+                warn!(%today, ?err, "Failed to store daily challenge results");
+
+                // Most likely the room is not open yet
+                continue;
+            }
+
+            if let Err(err) = Self::remind_daily_challenge(today).await {
+                warn!(%today, ?err, "Failed to send daily challenge reminders");
+            }
+        }
+    }
+
+    /// Store the results of the room's leaderboard.
+    ///
+    /// The leaderboard only contains the top 50 so if `check_members` is
+    /// set, linked members of guilds with a daily challenge channel are
+    /// checked through their profile too. The value denotes the check round
+    /// which determines the batch of members to check.
+    async fn store_daily_challenge_results(date: Date, check_members: Option<u32>) -> Result<()> {
+        let room = Context::redis()
+            .daily_challenge(date)
+            .await
+            .wrap_err("Failed to get room")?;
+
+        let room_id = room.room_id.to_native();
+
+        let leaderboard = Context::osu()
+            .room_leaderboard(room_id)
+            .await
+            .wrap_err("Failed to get room leaderboard")?
+            .leaderboard;
+
+        let mut results: Vec<_> = leaderboard
+            .into_iter()
+            .zip(1..)
+            .map(|(item, pos)| DbDailyChallengeResult {
+                user_id: item.user_id as i32,
+                date,
+                username: item.user.username.as_str().to_owned(),
+                total_score: Some(item.score as i64),
+                accuracy: Some(item.accuracy),
+                attempts: Some(item.attempts as i32),
+                pos: Some(pos),
+            })
+            .collect();
+
+        if let Some(round) = check_members {
+            match Self::daily_challenge_participants(date, round, &results).await {
+                Ok(participants) => results.extend(participants),
+                Err(err) => warn!(?err, "Failed to get daily challenge participants"),
+            }
+        }
+
+        Context::psql()
+            .upsert_daily_challenge_results(room_id, &results)
+            .await
+            .wrap_err("Failed to store results")
+    }
+
+    /// Linked members of guilds with a daily challenge channel that played
+    /// the daily challenge but are not on the leaderboard.
+    ///
+    /// At most [`MEMBER_CHECK_LIMIT`] members are checked, rotating through
+    /// all of them across rounds.
+    async fn daily_challenge_participants(
+        date: Date,
+        round: u32,
+        leaderboard: &[DbDailyChallengeResult],
+    ) -> Result<Vec<DbDailyChallengeResult>> {
+        let psql = Context::psql();
+
+        let reminders = psql
+            .select_daily_challenge_reminders()
+            .await
+            .wrap_err("Failed to get reminders")?;
+
+        let mut discord_ids = Vec::new();
+
+        for reminder in reminders {
+            let guild_id = Id::new(reminder.guild_id as u64);
+
+            match Context::cache().members(guild_id).await {
+                Ok(members) => discord_ids.extend(members.into_iter().map(|id| id as i64)),
+                Err(err) => warn!(%guild_id, ?err, "Failed to get members"),
+            }
+        }
+
+        discord_ids.sort_unstable();
+        discord_ids.dedup();
+
+        let user_ids = psql
+            .select_daily_challenge_missing_users(&discord_ids, date)
+            .await
+            .wrap_err("Failed to get users without result")?;
+
+        let mut user_ids: Vec<_> = user_ids
+            .into_iter()
+            .filter(|&user_id| {
+                !leaderboard
+                    .iter()
+                    .any(|result| result.user_id as u32 == user_id)
+            })
+            .collect();
+
+        // Consistent order so that the batches rotate through all members
+        user_ids.sort_unstable();
+
+        let mut participants = Vec::new();
+
+        if user_ids.is_empty() {
+            return Ok(participants);
+        }
+
+        let batches = user_ids.len().div_ceil(MEMBER_CHECK_LIMIT);
+        let batch = round as usize % batches;
+
+        let user_ids = user_ids
+            .into_iter()
+            .skip(batch * MEMBER_CHECK_LIMIT)
+            .take(MEMBER_CHECK_LIMIT);
+
+        for (user_id, i) in user_ids.zip(0..) {
+            if i > 0 {
+                sleep(MEMBER_CHECK_DELAY).await;
+            }
+
+            let args = UserArgs::user_id(user_id, GameMode::Osu);
+
+            let user = match Context::redis().osu_user(args).await {
+                Ok(user) => user,
+                Err(err) => {
+                    warn!(user_id, ?err, "Failed to get user");
+
+                    continue;
+                }
+            };
+
+            let played = user
+                .daily_challenge
+                .last_update
+                .as_ref()
+                .is_some_and(|datetime| {
+                    datetime.try_deserialize::<Panic>().always_ok().date() == date
+                });
+
+            if played {
+                participants.push(DbDailyChallengeResult {
+                    user_id: user_id as i32,
+                    date,
+                    username: user.username.as_str().to_owned(),
+                    total_score: None,
+                    accuracy: None,
+                    attempts: None,
+                    pos: None,
+                });
+            }
+        }
+
+        Ok(participants)
+    }
+
+    async fn remind_daily_challenge(today: Date) -> Result<()> {
+        let psql = Context::psql();
+
+        let reminders: Vec<_> = psql
+            .select_daily_challenge_reminders()
+            .await
+            .wrap_err("Failed to get reminders")?
+            .into_iter()
+            .filter(|reminder| reminder.last_date < today)
+            .collect();
+
+        if reminders.is_empty() {
+            return Ok(());
+        }
+
+        let embed = Self::daily_challenge_reminder_embed(today).await?;
+
+        for DbDailyChallengeReminder {
+            guild_id,
+            channel_id,
+            ..
+        } in reminders
+        {
+            let guild_id = Id::new(guild_id as u64);
+            let channel_id = Id::new(channel_id as u64);
+
+            let msg_fut = Context::http()
+                .create_message(channel_id)
+                .embeds(slice::from_ref(&embed));
+
+            match msg_fut.await {
+                Ok(_) => {
+                    let update_fut = psql.update_daily_challenge_reminder_date(guild_id, today);
+
+                    if let Err(err) = update_fut.await {
+                        warn!(%guild_id, ?err, "Failed to update daily challenge reminder");
+                    }
+                }
+                Err(err) => {
+                    if let ErrorType::Response {
+                        error:
+                            ApiError::General(GeneralApiError {
+                                code: UNKNOWN_CHANNEL,
+                                ..
+                            }),
+                        ..
+                    } = err.kind()
+                    {
+                        if let Err(err) = psql.delete_daily_challenge_reminder(guild_id).await {
+                            warn!(%guild_id, ?err, "Failed to delete daily challenge reminder");
+                        }
+                    } else {
+                        warn!(%channel_id, ?err, "Failed to send daily challenge reminder");
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn daily_challenge_reminder_embed(date: Date) -> Result<Embed> {
+        let room = Context::redis()
+            .daily_challenge(date)
+            .await
+            .wrap_err("Failed to get room")?;
+
+        let Some(playlist_item) = room.current_playlist_item.as_ref() else {
+            bail!("Missing current playlist item for room {}", room.room_id);
+        };
+
+        let map_id = playlist_item.map.map_id.to_native();
+
+        let map = Context::osu_map()
+            .map_slim(map_id)
+            .await
+            .wrap_err("Failed to get map")?;
+
+        let required_mods: GameMods = playlist_item
+            .required_mods
+            .deserialize(Strategy::<_, BoxedError>::wrap(&mut ()))
+            .unwrap();
+
+        let starts_at = room.starts_at.try_deserialize::<BoxedError>().unwrap();
+
+        let ends_at = starts_at + TimeDuration::days(1);
+
+        let mut description = format!(
+            "A new daily challenge is open until <t:{}:f>!",
+            ends_at.unix_timestamp()
+        );
+
+        if !required_mods.is_empty() {
+            let _ = write!(
+                description,
+                "\n**Required mods: {}**",
+                ModsFormatter::new(&required_mods, false)
+            );
+        }
+
+        let author =
+            AuthorBuilder::new(room.name.as_str()).icon_url(Emote::from(playlist_item.mode).url());
+
+        let embed = EmbedBuilder::new()
+            .author(author)
+            .description(description)
+            .footer(FooterBuilder::new(
+                "Check the leaderboard with /dailychallenge today",
+            ))
+            .thumbnail(map.thumbnail())
+            .title(format!(
+                "{} - {} [{}]",
+                map.artist(),
+                map.title(),
+                map.version()
+            ))
+            .url(format!("{OSU_BASE}b/{map_id}"))
+            .build();
+
+        Ok(embed)
+    }
+}
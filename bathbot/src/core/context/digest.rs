@@ -0,0 +1,129 @@
+use bathbot_psql::model::digest::{DbGuildDigest, DigestFrequency};
+use bathbot_util::AuthorBuilder;
+use eyre::{Report, Result, WrapErr};
+use time::OffsetDateTime;
+use tokio::time::{Duration, MissedTickBehavior, interval};
+use twilight_model::id::Id;
+
+use super::Context;
+use crate::embeds::{EmbedData, ServerDigestEmbed};
+
+/// How often posting a digest may fail before it's skipped
+const MAX_FAILED_ATTEMPTS: i16 = 3;
+
+impl Context {
+    /// Once an hour, post all server leaderboard digests that are due.
+    pub async fn guild_digest_loop() {
+        let mut interval = interval(Duration::from_secs(60 * 60));
+        interval.set_missed_tick_behavior(MissedTickBehavior::Delay);
+
+        loop {
+            interval.tick().await;
+
+            let digests = match Context::psql().select_guild_digests().await {
+                Ok(digests) => digests,
+                Err(err) => {
+                    warn!(?err, "Failed to get guild digests");
+
+                    continue;
+                }
+            };
+
+            let now = OffsetDateTime::now_utc();
+
+            for digest in digests {
+                let guild_id = digest.guild_id;
+
+                if let Err(err) = Self::process_guild_digest(digest, now).await {
+                    warn!(guild_id, ?err, "Failed to process guild digest");
+                }
+            }
+        }
+    }
+
+    async fn process_guild_digest(digest: DbGuildDigest, now: OffsetDateTime) -> Result<()> {
+        let Ok(frequency) = DigestFrequency::try_from(digest.frequency) else {
+            bail!("Invalid digest frequency {}", digest.frequency);
+        };
+
+        let due = digest
+            .last_posted
+            .is_none_or(|last_posted| last_posted + frequency.duration() <= now);
+
+        if !due {
+            return Ok(());
+        }
+
+        let guild_id = Id::new(digest.guild_id as u64);
+        let cache = Context::cache();
+        let psql = Context::psql();
+
+        let guild = match cache.guild(guild_id).await {
+            Ok(Some(guild)) => guild,
+            // Likely not in the guild anymore
+            Ok(None) => return Ok(()),
+            Err(err) => return Err(Report::new(err).wrap_err("Failed to get guild")),
+        };
+
+        let members: Vec<_> = cache
+            .members(guild_id)
+            .await
+            .wrap_err("Failed to get members")?
+            .into_iter()
+            .map(|id| id as i64)
+            .collect();
+
+        let current = psql
+            .select_guild_digest_stats(&members)
+            .await
+            .wrap_err("Failed to get current stats")?;
+
+        // The first time around there is nothing to compare against so only
+        // the snapshot is stored and the first digest is posted next time.
+        if digest.last_posted.is_some() {
+            let previous = psql
+                .select_guild_digest_snapshot(guild_id)
+                .await
+                .wrap_err("Failed to get previous snapshot")?;
+
+            let mut author = AuthorBuilder::new(guild.name.as_ref());
+
+            if let Some(hash) = guild.icon.as_ref() {
+                let url = format!(
+                    "https://cdn.discordapp.com/icons/{guild_id}/{hash}.{}",
+                    if hash.animated { "gif" } else { "webp" }
+                );
+
+                author = author.icon_url(url);
+            }
+
+            let embed = ServerDigestEmbed::new(author, frequency, &current, &previous)
+                .build()
+                .build();
+
+            let channel = Id::new(digest.channel_id as u64);
+
+            let send_fut = Context::http().create_message(channel).embeds(&[embed]);
+
+            if let Err(err) = send_fut.await {
+                let failed_attempts = psql
+                    .increment_guild_digest_failures(guild_id)
+                    .await
+                    .wrap_err("Failed to record failed attempt")?;
+
+                // Skip this digest instead of retrying every hour indefinitely
+                if failed_attempts >= MAX_FAILED_ATTEMPTS {
+                    psql.skip_guild_digest(guild_id, now)
+                        .await
+                        .wrap_err("Failed to skip digest")?;
+                }
+
+                return Err(Report::new(err).wrap_err("Failed to send digest"));
+            }
+        }
+
+        psql.insert_guild_digest_snapshot(guild_id, &current, now)
+            .await
+            .wrap_err("Failed to store snapshot")
+    }
+}
@@ -4,15 +4,20 @@ use rosu_v2::prelude::GameMode;
 use super::Context;
 use crate::manager::{
     ApproxManager, BookmarkManager, GameManager, GithubManager, GuildConfigManager,
-    HuismetbenenCountryManager, MapManager, OsuMap, OsuUserManager, PpManager, ReplayManager,
-    ScoresManager, UserConfigManager, redis::RedisManager,
+    HuismetbenenCountryManager, MapLeaderboardManager, MapManager, MapperFollowManager, OsuMap,
+    OsuUserManager, PpManager, ReplayManager, RoleRewardManager, ScoresManager, UserConfigManager,
+    redis::RedisManager,
 };
 
 impl Context {
     pub fn guild_config() -> GuildConfigManager {
         let ctx = Self::get();
 
-        GuildConfigManager::new(&ctx.clients.psql, &ctx.data.guild_configs)
+        GuildConfigManager::new(
+            &ctx.clients.psql,
+            &ctx.data.guild_configs,
+            &ctx.data.score_archive_guilds,
+        )
     }
 
     pub fn user_config() -> UserConfigManager {
@@ -54,6 +59,10 @@ impl Context {
         ReplayManager::new(&ctx.clients.psql, &ctx.clients.osu, &ctx.data.cache)
     }
 
+    pub fn role_rewards() -> RoleRewardManager {
+        RoleRewardManager::new()
+    }
+
     pub fn github() -> GithubManager {
         GithubManager::new()
     }
@@ -70,6 +79,14 @@ impl Context {
         ScoresManager::new()
     }
 
+    pub fn map_leaderboards() -> MapLeaderboardManager {
+        MapLeaderboardManager::new()
+    }
+
+    pub fn mapper_follows() -> MapperFollowManager {
+        MapperFollowManager::new()
+    }
+
     pub fn huismetbenen() -> HuismetbenenCountryManager {
         HuismetbenenCountryManager::new()
     }
@@ -0,0 +1,227 @@
+use std::{collections::HashMap, fmt::Write, slice};
+
+use bathbot_util::{
+    AuthorBuilder, EmbedBuilder, IntHasher,
+    constants::{OSU_BASE, UNKNOWN_CHANNEL},
+};
+use eyre::{Result, WrapErr};
+use rosu_v2::prelude::GameMode;
+use tokio::time::{Duration, MissedTickBehavior, interval};
+use twilight_http::{
+    api_error::{ApiError, GeneralApiError},
+    error::ErrorType,
+};
+use twilight_model::id::{Id, marker::GuildMarker};
+
+use super::Context;
+use crate::manager::{LeaderboardDiff, MapLeaderboardManager, SNAPSHOT_SIZE, WatchedMap};
+
+impl Context {
+    /// Every half hour, snapshot the leaderboards of all watched maps and
+    /// notify the watching channels about changes.
+    pub async fn map_leaderboard_loop() {
+        let mut interval = interval(Duration::from_secs(30 * 60));
+        interval.set_missed_tick_behavior(MissedTickBehavior::Delay);
+
+        loop {
+            interval.tick().await;
+
+            let watched = match Context::map_leaderboards().watched().await {
+                Ok(watched) => watched,
+                Err(err) => {
+                    warn!(?err, "Failed to get watched maps");
+
+                    continue;
+                }
+            };
+
+            for ((map_id, mode), channels) in watched {
+                if let Err(err) = Self::process_watched_map(map_id, mode, channels).await {
+                    warn!(map_id, ?mode, ?err, "Failed to process watched map");
+                }
+            }
+        }
+    }
+
+    async fn process_watched_map(
+        map_id: u32,
+        mode: GameMode,
+        channels: Vec<WatchedMap>,
+    ) -> Result<()> {
+        let manager = Context::map_leaderboards();
+
+        let scores = Context::osu_scores()
+            .map_leaderboard(map_id, mode, None, SNAPSHOT_SIZE as u32, false)
+            .await?;
+
+        let current = MapLeaderboardManager::snapshot_entries(scores);
+        let previous = manager.latest_scores(map_id, mode).await?;
+
+        let unchanged = previous.as_ref().is_some_and(|previous| {
+            previous.len() == current.len()
+                && previous
+                    .iter()
+                    .zip(current.iter())
+                    .all(|(prev, curr)| prev.score_id == curr.score_id)
+        });
+
+        if unchanged {
+            return Ok(());
+        }
+
+        manager.store_snapshot(map_id, mode, &current).await?;
+
+        // The first time around there is nothing to compare against so only
+        // the snapshot is stored.
+        let Some(previous) = previous else {
+            return Ok(());
+        };
+
+        let diff = LeaderboardDiff::new(&previous, &current);
+
+        if diff.is_empty() {
+            return Ok(());
+        }
+
+        let map = Context::osu_map()
+            .map_slim(map_id)
+            .await
+            .wrap_err("Failed to get map")?;
+
+        let title = format!("{} - {} [{}]", map.artist(), map.title(), map.version());
+        let url = format!("{OSU_BASE}b/{map_id}");
+
+        let mut members = HashMap::with_hasher(IntHasher);
+
+        for channel in channels {
+            let member_ids: &[u32] = match channel.guild {
+                Some(guild) => {
+                    if !members.contains_key(&guild) {
+                        let ids = Self::linked_members(guild).await.unwrap_or_else(|err| {
+                            warn!(%guild, ?err, "Failed to get linked members");
+
+                            Vec::new()
+                        });
+
+                        members.insert(guild, ids);
+                    }
+
+                    &members[&guild]
+                }
+                None => &[],
+            };
+
+            let description = notif_description(&diff, channel.max_rank, member_ids);
+
+            if description.is_empty() {
+                continue;
+            }
+
+            let embed = EmbedBuilder::new()
+                .author(AuthorBuilder::new("Leaderboard update:"))
+                .description(description)
+                .thumbnail(map.thumbnail())
+                .title(title.as_str())
+                .url(url.as_str())
+                .build();
+
+            let msg_fut = Context::http()
+                .create_message(channel.channel)
+                .embeds(slice::from_ref(&embed));
+
+            let Err(err) = msg_fut.await else {
+                continue;
+            };
+
+            if let ErrorType::Response {
+                error:
+                    ApiError::General(GeneralApiError {
+                        code: UNKNOWN_CHANNEL,
+                        ..
+                    }),
+                ..
+            } = err.kind()
+            {
+                if let Err(err) = manager.unwatch_channel(channel.channel).await {
+                    warn!(channel = %channel.channel, ?err, "Failed to unwatch unknown channel");
+                }
+            } else {
+                warn!(channel = %channel.channel, ?err, "Failed to send leaderboard notif");
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn linked_members(guild: Id<GuildMarker>) -> Result<Vec<u32>> {
+        let members: Vec<_> = Context::cache()
+            .members(guild)
+            .await
+            .wrap_err("Failed to get members")?
+            .into_iter()
+            .map(|id| id as i64)
+            .collect();
+
+        Context::psql()
+            .select_osu_ids_by_discord_ids(&members)
+            .await
+            .wrap_err("Failed to get osu ids")
+    }
+}
+
+fn notif_description(diff: &LeaderboardDiff<'_>, max_rank: u8, members: &[u32]) -> String {
+    let max_rank = u16::from(max_rank);
+    let mut description = String::new();
+
+    let new_first = diff.prev_first.and_then(|prev_first| {
+        let first = diff.entries.iter().find(|entry| entry.new_pos == Some(1))?;
+
+        let _ = writeln!(
+            description,
+            ":crown: **{}** took #1 from **{}**",
+            first.username, prev_first.username
+        );
+
+        Some(first.user_id)
+    });
+
+    for entry in diff.entered(max_rank) {
+        if Some(entry.user_id) == new_first {
+            continue;
+        }
+
+        let _ = write!(
+            description,
+            ":arrow_up: **{}** entered the top {max_rank} at #{}",
+            entry.username,
+            entry.new_pos.unwrap_or(0)
+        );
+
+        match entry.old_pos {
+            Some(pos) => {
+                let _ = writeln!(description, " (was #{pos})");
+            }
+            None => description.push('\n'),
+        }
+    }
+
+    for (member, sniper) in diff.sniped(max_rank, members) {
+        let _ = write!(
+            description,
+            ":dart: **{}** got sniped by **{}** ",
+            member.username, sniper.username
+        );
+
+        match (member.old_pos, member.new_pos) {
+            (Some(old), Some(new)) => {
+                let _ = writeln!(description, "(#{old} → #{new})");
+            }
+            (Some(old), None) => {
+                let _ = writeln!(description, "(#{old} → out of the top {SNAPSHOT_SIZE})");
+            }
+            _ => description.push('\n'),
+        }
+    }
+
+    description
+}
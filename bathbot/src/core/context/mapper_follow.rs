@@ -0,0 +1,177 @@
+use bathbot_util::{MessageOrigin, constants::UNKNOWN_CHANNEL};
+use eyre::{Report, Result, WrapErr};
+use rosu_v2::prelude::{BeatmapsetExtended, GameMods, GameModsIntermode, RankStatus};
+use tokio::time::{Duration, MissedTickBehavior, interval};
+use twilight_http::{
+    Error as HttpError,
+    api_error::{ApiError, GeneralApiError},
+    error::ErrorType,
+};
+
+use super::Context;
+use crate::{
+    active::{
+        ActiveMessages,
+        impls::{MapPagination, SingleScorePagination},
+    },
+    commands::osu::{CustomAttrs, map_strains_graph, sort_mapset_maps},
+    manager::{FollowedMapper, MapperFollowManager},
+};
+
+impl Context {
+    /// Every half hour, check the mapsets of all followed mappers and notify
+    /// the following channels about newly qualified, ranked, or loved mapsets.
+    pub async fn mapper_follow_loop() {
+        let mut interval = interval(Duration::from_secs(30 * 60));
+        interval.set_missed_tick_behavior(MissedTickBehavior::Delay);
+
+        loop {
+            interval.tick().await;
+
+            let followed = match Context::mapper_follows().followed().await {
+                Ok(followed) => followed,
+                Err(err) => {
+                    warn!(?err, "Failed to get followed mappers");
+
+                    continue;
+                }
+            };
+
+            for (mapper_id, follows) in followed {
+                if let Err(err) = Self::process_followed_mapper(mapper_id, &follows).await {
+                    warn!(mapper_id, ?err, "Failed to process followed mapper");
+                }
+            }
+        }
+    }
+
+    async fn process_followed_mapper(mapper_id: u32, follows: &[FollowedMapper]) -> Result<()> {
+        let manager = Context::mapper_follows();
+
+        let name = manager.mapper_name(mapper_id).await?;
+        let current = manager.leaderboard_statuses(mapper_id, &name).await?;
+        let previous = manager.statuses(mapper_id).await?;
+        let (changed, store) = MapperFollowManager::status_changes(&current, &previous);
+        manager.store_statuses(mapper_id, &store, true).await?;
+
+        for mapset_id in changed {
+            // Search results lack some data so the full mapset is requested
+            let mapset = match Context::osu().beatmapset(mapset_id).await {
+                Ok(mapset) => mapset,
+                Err(err) => {
+                    warn!(mapset_id, ?err, "Failed to get mapset of followed mapper");
+
+                    continue;
+                }
+            };
+
+            Context::osu_map().store(&mapset).await;
+            Self::notify_mapper_follows(mapset, follows).await;
+        }
+
+        Ok(())
+    }
+
+    async fn notify_mapper_follows(mut mapset: BeatmapsetExtended, follows: &[FollowedMapper]) {
+        let Some(mut maps) = mapset.maps.take().filter(|maps| !maps.is_empty()) else {
+            return warn!(
+                mapset_id = mapset.mapset_id,
+                "Mapset of followed mapper has no maps"
+            );
+        };
+
+        sort_mapset_maps(&mut maps);
+
+        // Show the hardest difficulty of the mapset's main mode
+        let map_idx = maps
+            .iter()
+            .rposition(|map| map.mode == maps[0].mode)
+            .unwrap_or(0);
+
+        let graph = match Context::osu_map().pp_map(maps[map_idx].map_id).await {
+            Ok(map) => {
+                let w = SingleScorePagination::IMAGE_W;
+                let h = SingleScorePagination::IMAGE_H;
+
+                match map_strains_graph(&map, GameMods::new(), &mapset.covers.cover, w, h).await {
+                    Ok(graph) => Some(graph),
+                    Err(err) => {
+                        warn!(?err, "Failed to create graph");
+
+                        None
+                    }
+                }
+            }
+            Err(err) => {
+                warn!(?err, "Failed to get pp map");
+
+                None
+            }
+        };
+
+        let status = match mapset.status {
+            RankStatus::Qualified => "qualified",
+            RankStatus::Loved => "loved",
+            _ => "ranked",
+        };
+
+        let content = format!(
+            "A mapset by **{}** just got {status}!",
+            mapset.creator_name.as_str()
+        );
+
+        let maps = maps.into_boxed_slice();
+        let manager = Context::mapper_follows();
+
+        for follow in follows {
+            let mut pagination = MapPagination::builder()
+                .mapset(mapset.clone())
+                .maps(maps.clone())
+                .mods(GameModsIntermode::new())
+                .attrs(CustomAttrs::default())
+                .origin(MessageOrigin::new(follow.guild, follow.channel))
+                .content(content.as_str().into())
+                .msg_owner(follow.added_by)
+                .build();
+
+            pagination.set_index(map_idx);
+
+            let begin_fut = ActiveMessages::builder(pagination)
+                .start_by_update(true)
+                .attachment(
+                    graph
+                        .clone()
+                        .map(|bytes| ("map_graph.png".to_owned(), bytes)),
+                )
+                .begin(follow.channel);
+
+            let Err(err) = begin_fut.await else {
+                continue;
+            };
+
+            if is_unknown_channel(&err) {
+                if let Err(err) = manager.unfollow_channel(follow.channel).await {
+                    warn!(channel = %follow.channel, ?err, "Failed to unfollow unknown channel");
+                }
+            } else {
+                let err = err.wrap_err("Failed to send mapper follow notif");
+                warn!(channel = %follow.channel, ?err);
+            }
+        }
+    }
+}
+
+fn is_unknown_channel(err: &Report) -> bool {
+    err.downcast_ref::<HttpError>().is_some_and(|err| {
+        matches!(
+            err.kind(),
+            ErrorType::Response {
+                error: ApiError::General(GeneralApiError {
+                    code: UNKNOWN_CHANNEL,
+                    ..
+                }),
+                ..
+            }
+        )
+    })
+}
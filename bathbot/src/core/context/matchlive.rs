@@ -1,8 +1,16 @@
-use std::{collections::hash_map::Entry, slice};
+use std::{
+    collections::{HashMap, hash_map::Entry},
+    slice,
+};
 
+use bathbot_util::IntHasher;
 use rosu_v2::prelude::{MatchEvent, OsuError};
+use smallvec::SmallVec;
 use tokio::time::{Duration, interval};
-use twilight_model::id::{Id, marker::ChannelMarker};
+use twilight_model::id::{
+    Id,
+    marker::{ChannelMarker, MessageMarker},
+};
 
 use super::Context;
 use crate::{
@@ -65,6 +73,8 @@ impl Context {
                     }
                 };
 
+                Self::store_match_track(match_id, &channel, embeds.len()).await;
+
                 let id = channel.id;
                 entry.channels.push(channel);
                 *match_live.channel_count.entry(id).or_insert(0) += 1;
@@ -87,6 +97,8 @@ impl Context {
 
                     // Only add to tracking if it's not already disbanded
                     if !matches!(osu_match.events.last(), Some(MatchEvent::Disbanded { .. })) {
+                        Self::store_match_track(match_id, &channel, embeds.len()).await;
+
                         let tracked_match = TrackedMatch::new(osu_match, embeds);
                         let id = channel.id;
                         e.insert(MatchEntry::new(tracked_match, channel));
@@ -119,6 +131,13 @@ impl Context {
             if let Some(idx) = entry.channels.iter().position(|c| c.id == channel) {
                 entry.channels.swap_remove(idx);
 
+                let delete_fut =
+                    Context::psql().delete_tracked_osu_match_channel(match_id, channel.get());
+
+                if let Err(err) = delete_fut.await {
+                    warn!(?err, "Failed to delete tracked match channel");
+                }
+
                 // If no channel is tracking the match, remove the entry
                 if entry.channels.is_empty() {
                     e.remove();
@@ -141,6 +160,10 @@ impl Context {
     async fn remove_all_match_tracks(&self, match_id: u32) -> usize {
         let mut match_live = self.data.matchlive.inner.lock().await;
 
+        if let Err(err) = Context::psql().delete_tracked_osu_match(match_id).await {
+            warn!(?err, "Failed to delete tracked match");
+        }
+
         if let Some(entry) = match_live.match_channels.remove(&match_id) {
             for Channel { id, .. } in &entry.channels {
                 match_live
@@ -155,7 +178,146 @@ impl Context {
         }
     }
 
+    /// Persist the last message of a tracked match in a channel so that
+    /// tracking can be resumed after a restart.
+    async fn store_match_track(match_id: u32, channel: &Channel, embed_count: usize) {
+        let upsert_fut = Context::psql().upsert_tracked_osu_match(
+            match_id,
+            channel.id.get(),
+            channel.msg_id.get(),
+            embed_count,
+        );
+
+        if let Err(err) = upsert_fut.await {
+            warn!(?err, "Failed to store tracked match");
+        }
+    }
+
+    /// Resume tracking the matches that were tracked before the last shutdown
+    /// by updating their previous messages.
+    async fn resume_match_tracks(&self) {
+        let tracked = match Context::psql().select_tracked_osu_matches().await {
+            Ok(tracked) => tracked,
+            Err(err) => return warn!(?err, "Failed to fetch tracked matches"),
+        };
+
+        let mut channels_by_match: HashMap<u32, Vec<_>, IntHasher> = HashMap::default();
+
+        for entry in tracked {
+            channels_by_match
+                .entry(entry.match_id as u32)
+                .or_default()
+                .push(entry);
+        }
+
+        let http = Context::http();
+        let mut resumed = Vec::with_capacity(channels_by_match.len());
+
+        for (match_id, tracked_channels) in channels_by_match {
+            let osu_match = match Context::osu().osu_match(match_id).await {
+                Ok(osu_match) => osu_match,
+                Err(OsuError::NotFound) => {
+                    self.drop_match_track(match_id).await;
+
+                    continue;
+                }
+                Err(OsuError::Response { status, .. }) if status == 401 => {
+                    self.drop_match_track(match_id).await;
+
+                    continue;
+                }
+                Err(err) => {
+                    warn!(?err, match_id, "Failed to request match to resume");
+
+                    continue;
+                }
+            };
+
+            // The match ended while the bot was down
+            if osu_match.end_time.is_some()
+                || matches!(osu_match.events.last(), Some(MatchEvent::Disbanded { .. }))
+            {
+                self.drop_match_track(match_id).await;
+
+                continue;
+            }
+
+            let embeds = MatchLiveEmbed::new(&osu_match).into_vec();
+            let mut channels = SmallVec::new();
+
+            for tracked in tracked_channels {
+                let channel = Id::<ChannelMarker>::new(tracked.channel_id as u64);
+                let mut msg_id = Id::<MessageMarker>::new(tracked.msg_id as u64);
+                let embed_count = (tracked.embed_count.max(1) as usize).min(embeds.len());
+
+                // Bring the previously last message up to date
+                if let Some(embed) = embeds[..embed_count].last() {
+                    let embed = embed.as_embed();
+
+                    let update_fut = http
+                        .update_message(channel, msg_id)
+                        .embeds(Some(slice::from_ref(&embed)));
+
+                    if let Err(err) = update_fut.await {
+                        warn!(?err, "Failed to update msg of resumed match");
+                    }
+                }
+
+                // Send all embeds that were missed in the meantime
+                if embed_count < embeds.len() {
+                    match send_match_messages(channel, &embeds[embed_count..]).await {
+                        Ok(msg) => msg_id = msg,
+                        Err(err) => {
+                            warn!(channel = channel.get(), ?err, "Failed to send missed msgs");
+                        }
+                    }
+                }
+
+                let channel = Channel::new(channel, msg_id);
+                Self::store_match_track(match_id, &channel, embeds.len()).await;
+                channels.push(channel);
+            }
+
+            let tracked_match = TrackedMatch { osu_match, embeds };
+
+            let entry = MatchEntry {
+                tracked: tracked_match,
+                channels,
+            };
+
+            resumed.push((match_id, entry));
+        }
+
+        if resumed.is_empty() {
+            return;
+        }
+
+        let count = resumed.len();
+
+        // Only lock once all requests are done so commands aren't blocked
+        let mut match_live = self.data.matchlive.inner.lock().await;
+
+        for (match_id, entry) in resumed {
+            for channel in entry.channels.iter() {
+                *match_live.channel_count.entry(channel.id).or_insert(0) += 1;
+            }
+
+            match_live.match_channels.insert(match_id, entry);
+        }
+
+        info!("Resumed tracking {count} matches");
+    }
+
+    async fn drop_match_track(&self, match_id: u32) {
+        if let Err(err) = Context::psql().delete_tracked_osu_match(match_id).await {
+            warn!(?err, "Failed to delete tracked match");
+        }
+    }
+
     pub async fn match_live_loop() {
+        let ctx = Context::get();
+        ctx.resume_match_tracks().await;
+
         // Update all matches every 10 seconds
         let mut interval = interval(Duration::from_secs(10));
         interval.tick().await;
@@ -163,7 +325,6 @@ impl Context {
         // Match ids of matches that finished this iteration
         let mut remove = Vec::new();
 
-        let ctx = Context::get();
         let http = Context::http();
 
         loop {
@@ -221,11 +382,21 @@ impl Context {
 
                     // For all new embeds, send them to all channels
                     if let Some(embeds) = new_embeds {
-                        for Channel { id, msg_id } in entry.channels.iter_mut() {
-                            match send_match_messages(*id, &embeds).await {
-                                Ok(msg) => *msg_id = msg,
+                        let match_id = tracked_match.osu_match.match_id;
+                        let embed_count = tracked_match.embeds.len() + embeds.len();
+
+                        for channel in entry.channels.iter_mut() {
+                            match send_match_messages(channel.id, &embeds).await {
+                                Ok(msg) => {
+                                    channel.msg_id = msg;
+                                    Self::store_match_track(match_id, channel, embed_count).await;
+                                }
                                 Err(err) => {
-                                    error!(channel = id.get(), ?err, "Failed to send last msg")
+                                    error!(
+                                        channel = channel.id.get(),
+                                        ?err,
+                                        "Failed to send last msg"
+                                    )
                                 }
                             }
                         }
@@ -244,12 +415,14 @@ impl Context {
         }
     }
 
+    /// Notifies all tracking channels about the reboot. The tracked matches
+    /// remain stored and will be resumed on startup.
     pub async fn notify_match_live_shutdown(&self) -> usize {
         let mut match_live = self.data.matchlive.inner.lock().await;
         match_live.match_channels.clear();
 
-        let content = "I'm about to reboot so the match tracking will be aborted, \
-            you can restart it in just a moment...";
+        let content = "I'm about to reboot so the match tracking will pause for a moment, \
+            it will resume once I'm back up...";
 
         let mut notified = 0;
 
@@ -27,17 +27,28 @@ use twilight_model::id::{
 use twilight_standby::Standby;
 
 use self::osutrack::OsuTrackUserNotifTimestamps;
-use super::{BotConfig, BotMetrics};
+use super::{BotConfig, BotMetrics, commands::prefix::PrefixResponses};
 use crate::{
     active::{ActiveMessages, impls::BackgroundGame},
-    tracking::{Ordr, OsuTracking, ScoresWebSocket, ScoresWebSocketDisconnect},
+    manager::redis::RedisResponseCache,
+    tracking::{
+        LeaderboardTracking, Ordr, OsuTracking, ScoresWebSocket, ScoresWebSocketDisconnect,
+    },
 };
 
+#[cfg(feature = "server")]
+mod api;
+mod daily_challenge;
+mod digest;
 mod discord;
 mod games;
 mod manager;
+mod map_leaderboard;
+mod mapper_follow;
 mod messages;
 mod osutrack;
+mod role_rewards;
+mod score_archive;
 mod set_commands;
 mod shutdown;
 
@@ -50,6 +61,7 @@ mod twitch;
 type GuildShards = PapayaMap<Id<GuildMarker>, u32>;
 type GuildConfigs = PapayaMap<Id<GuildMarker>, GuildConfig, IntHasher>;
 type MissAnalyzerGuilds = RwLock<HashSet<Id<GuildMarker>, IntHasher>>;
+type ScoreArchiveGuilds = RwLock<HashSet<Id<GuildMarker>, IntHasher>>;
 
 #[cfg(feature = "twitchtracking")]
 type TrackedStreams = PapayaMap<u64, Vec<Id<ChannelMarker>>, IntHasher>;
@@ -61,6 +73,7 @@ pub struct Context {
     pub shard_senders: RwLock<HashMap<u32, MessageSender, IntHasher>>,
     pub member_requests: MemberRequests,
     pub active_msgs: ActiveMessages,
+    pub prefix_responses: PrefixResponses,
     pub start_time: OffsetDateTime,
     pub metrics: MetricsReader,
     data: ContextData,
@@ -131,6 +144,10 @@ impl Context {
         &Self::get().data.osu_tracking
     }
 
+    pub fn leaderboard_tracking() -> &'static LeaderboardTracking {
+        &Self::get().data.leaderboard_tracking
+    }
+
     #[cfg(feature = "server")]
     pub fn auth_standby() -> &'static bathbot_server::AuthenticationStandby {
         &Self::get().clients.auth_standby
@@ -230,7 +247,8 @@ impl Context {
 
         let custom_client = client_fut
             .await
-            .wrap_err("Failed to create custom client")?;
+            .wrap_err("Failed to create custom client")?
+            .with_response_cache(RedisResponseCache::response_cache());
 
         let ordr_fut = Ordr::new(
             #[cfg(not(debug_assertions))]
@@ -291,6 +309,7 @@ impl Context {
             buckets: Buckets::new(),
             member_requests: MemberRequests::new(tx),
             active_msgs: ActiveMessages::new(),
+            prefix_responses: PrefixResponses::default(),
             scores_ws_disconnect: Mutex::new(None),
             start_time,
             metrics: reader,
@@ -373,7 +392,9 @@ struct ContextData {
     #[cfg(feature = "twitchtracking")]
     tracked_streams: TrackedStreams,
     osu_tracking: OsuTracking,
+    leaderboard_tracking: LeaderboardTracking,
     guild_configs: GuildConfigs,
+    score_archive_guilds: ScoreArchiveGuilds,
     guild_shards: GuildShards,
     miss_analyzer_guilds: MissAnalyzerGuilds,
     osutrack_user_notif_timestamps: OsuTrackUserNotifTimestamps,
@@ -394,34 +415,55 @@ impl ContextData {
             guild_shards,
             miss_analyzer_guilds,
             osu_tracking,
+            leaderboard_tracking,
         ) = tokio::join!(
             psql.select_guild_configs::<IntHasher>(),
             psql.select_tracked_twitch_streams::<IntHasher>(),
             Self::fetch_guild_shards(&cache),
             Self::fetch_miss_analyzer_guilds(&cache),
             OsuTracking::new(psql),
+            LeaderboardTracking::new(psql),
         );
 
         #[cfg(not(feature = "twitchtracking"))]
-        let (guild_configs_res, guild_shards, miss_analyzer_guilds, osu_tracking) = tokio::join!(
+        let (
+            guild_configs_res,
+            guild_shards,
+            miss_analyzer_guilds,
+            osu_tracking,
+            leaderboard_tracking,
+        ) = tokio::join!(
             psql.select_guild_configs::<IntHasher>(),
             Self::fetch_guild_shards(&cache),
             Self::fetch_miss_analyzer_guilds(&cache),
-            OsuTracking::new(psql)
+            OsuTracking::new(psql),
+            LeaderboardTracking::new(psql),
         );
 
+        let guild_configs: GuildConfigs = guild_configs_res
+            .wrap_err("Failed to get guild configs")?
+            .into_iter()
+            .collect();
+
+        let score_archive_guilds = guild_configs
+            .pin()
+            .iter()
+            .filter(|(_, config)| config.score_archive == Some(true))
+            .map(|(guild_id, _)| *guild_id)
+            .collect();
+
         Ok(Self {
             cache,
-            guild_configs: guild_configs_res
-                .wrap_err("Failed to get guild configs")?
-                .into_iter()
-                .collect(),
+            guild_configs,
+            score_archive_guilds: RwLock::new(score_archive_guilds),
             #[cfg(feature = "twitchtracking")]
             tracked_streams: tracked_streams_res
                 .wrap_err("Failed to get tracked streams")?
                 .into_iter()
                 .collect(),
             osu_tracking: osu_tracking.wrap_err("Failed to create osu! tracking")?,
+            leaderboard_tracking: leaderboard_tracking
+                .wrap_err("Failed to create leaderboard tracking")?,
             application_id,
             games: Games::new(),
             guild_shards,
@@ -513,6 +555,7 @@ async fn bathbot_server(
         twitch_client_id: config.tokens.twitch_client_id.to_string(),
         twitch_token: config.tokens.twitch_token.to_string(),
         redirect_base: config.server.public_url.to_string(),
+        api: Arc::new(api::BotApiProvider),
     };
 
     let (server, standby, tx) = bathbot_server::Server::new(builder)?;
@@ -0,0 +1,44 @@
+use tokio::time::{Duration, MissedTickBehavior, interval};
+
+use super::Context;
+
+impl Context {
+    /// Every few hours, add or remove reward roles of members based on their
+    /// cached osu! stats.
+    pub async fn role_rewards_loop() {
+        let mut interval = interval(Duration::from_secs(6 * 60 * 60));
+        interval.set_missed_tick_behavior(MissedTickBehavior::Delay);
+
+        loop {
+            interval.tick().await;
+
+            let guilds = match Context::role_rewards().get_all().await {
+                Ok(guilds) => guilds,
+                Err(err) => {
+                    warn!(?err, "Failed to get role rewards");
+
+                    continue;
+                }
+            };
+
+            for (guild_id, rewards) in guilds {
+                let manager = Context::role_rewards();
+
+                let changes = match manager.changes(guild_id, &rewards).await {
+                    Ok(changes) => changes,
+                    Err(err) => {
+                        warn!(%guild_id, ?err, "Failed to determine role reward changes");
+
+                        continue;
+                    }
+                };
+
+                match manager.apply(guild_id, &changes).await {
+                    Ok(0) => {}
+                    Ok(count) => info!(%guild_id, count, "Updated reward roles"),
+                    Err(err) => warn!(%guild_id, ?err, "Failed to apply role rewards"),
+                }
+            }
+        }
+    }
+}
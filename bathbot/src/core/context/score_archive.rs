@@ -0,0 +1,41 @@
+use time::{Duration as TimeDuration, OffsetDateTime};
+use tokio::time::{Duration, MissedTickBehavior, interval};
+use twilight_model::id::Id;
+
+use super::Context;
+
+impl Context {
+    /// Once a day, prune the score archive so it doesn't grow indefinitely.
+    pub async fn score_archive_retention_loop() {
+        /// Scores older than this are removed
+        const MAX_AGE: TimeDuration = TimeDuration::days(365);
+
+        /// Only this many most recent scores are kept per user and mode
+        const MAX_PER_USER: usize = 5000;
+
+        let mut interval = interval(Duration::from_secs(24 * 60 * 60));
+        interval.set_missed_tick_behavior(MissedTickBehavior::Delay);
+
+        loop {
+            interval.tick().await;
+
+            let cutoff = OffsetDateTime::now_utc() - MAX_AGE;
+
+            // Scores of guilds that opted out are removed too
+            let guilds: Vec<_> = Context::guild_config()
+                .score_archive_guilds()
+                .into_iter()
+                .map(Id::get)
+                .collect();
+
+            match Context::psql()
+                .delete_outdated_scores(cutoff, MAX_PER_USER, &guilds)
+                .await
+            {
+                Ok(0) => {}
+                Ok(count) => info!(count, "Pruned score archive"),
+                Err(err) => warn!(?err, "Failed to prune score archive"),
+            }
+        }
+    }
+}
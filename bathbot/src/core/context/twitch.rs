@@ -45,15 +45,6 @@ impl Context {
             .collect()
     }
 
-    pub fn tracked_channels_for(twitch_id: u64) -> Option<Vec<Id<ChannelMarker>>> {
-        Context::get()
-            .data
-            .tracked_streams
-            .pin()
-            .get(&twitch_id)
-            .map(|channels| channels.to_vec())
-    }
-
     pub fn tracked_users_in(channel: Id<ChannelMarker>) -> Vec<u64> {
         Context::get()
             .data
@@ -1,6 +1,6 @@
 use std::{mem, time::Instant};
 
-use bathbot_util::Authored;
+use bathbot_util::{Authored, BucketName};
 use eyre::Result;
 
 use crate::{
@@ -64,6 +64,12 @@ async fn process_command(
             }
         },
         InteractionCommandKind::Message(cmd) => {
+            if let Some(bucket) = cmd.bucket
+                && let Some(result) = check_ratelimit(&command, bucket).await?
+            {
+                return Ok(result);
+            }
+
             if cmd.flags.defer() {
                 command.defer(cmd.flags.ephemeral()).await?;
             }
@@ -83,7 +89,7 @@ async fn pre_process_command(
 
     // Only for owner?
     if slash.flags.only_owner() && user_id != BotConfig::get().owner {
-        let content = "That command can only be used by the bot owner";
+        let content = command.locale().await.get("error.only_owner");
         command.error_callback(content).await?;
 
         return Ok(Some(ProcessResult::NoOwner));
@@ -93,7 +99,7 @@ async fn pre_process_command(
     // Using `dm_permission = false` used to be sufficient but apparently
     // that's no longer the case.
     if slash.flags.only_guilds() && command.guild_id.is_none() {
-        let content = "That command is only available in servers";
+        let content = command.locale().await.get("error.only_guilds");
         command.error_callback(content).await?;
 
         return Ok(Some(ProcessResult::NoDM));
@@ -101,14 +107,9 @@ async fn pre_process_command(
 
     // Ratelimited?
     if let Some(bucket) = slash.bucket
-        && let Some(cooldown) = Context::check_ratelimit(user_id, bucket)
+        && let Some(result) = check_ratelimit(command, bucket).await?
     {
-        trace!("Ratelimiting user {user_id} on bucket `{bucket:?}` for {cooldown} seconds");
-
-        let content = format!("Command on cooldown, try again in {cooldown} seconds");
-        command.error_callback(content).await?;
-
-        return Ok(Some(ProcessResult::Ratelimited(bucket)));
+        return Ok(Some(result));
     }
 
     // Only for authorities?
@@ -121,7 +122,7 @@ async fn pre_process_command(
                 return Ok(Some(ProcessResult::NoAuthority));
             }
             Err(err) => {
-                let content = "Error while checking authority status";
+                let content = command.locale().await.get("error.authority_check");
                 let _ = command.error_callback(content).await;
 
                 return Err(err.wrap_err("failed to check authority status"));
@@ -131,3 +132,25 @@ async fn pre_process_command(
 
     Ok(None)
 }
+
+async fn check_ratelimit(
+    command: &InteractionCommand,
+    bucket: BucketName,
+) -> Result<Option<ProcessResult>> {
+    let user_id = command.user_id()?;
+
+    let Some(cooldown) = Context::check_ratelimit(user_id, bucket) else {
+        return Ok(None);
+    };
+
+    trace!("Ratelimiting user {user_id} on bucket `{bucket:?}` for {cooldown} seconds");
+
+    let content = command
+        .locale()
+        .await
+        .format("error.cooldown", &[("seconds", &cooldown)]);
+
+    command.error_callback(content).await?;
+
+    Ok(Some(ProcessResult::Ratelimited(bucket)))
+}
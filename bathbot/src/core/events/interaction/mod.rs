@@ -17,6 +17,7 @@ pub async fn handle_interaction(interaction: Interaction) {
         guild_id,
         id,
         kind,
+        locale,
         member,
         message,
         token,
@@ -33,6 +34,7 @@ pub async fn handle_interaction(interaction: Interaction) {
             let cmd = InteractionCommand {
                 permissions,
                 channel_id,
+                client_locale: locale,
                 data,
                 guild_id,
                 id,
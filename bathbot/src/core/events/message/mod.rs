@@ -72,6 +72,19 @@ pub async fn handle_message(msg: Message) {
     BotMetrics::observe_command("prefix", name, elapsed);
 }
 
+/// Rerun a recently invoked prefix command if its message was edited.
+///
+/// Responses of the rerun will replace the previous response.
+pub async fn handle_message_update(msg: Message) {
+    if msg.author.bot || msg.webhook_id.is_some() {
+        return;
+    }
+
+    if Context::get().prefix_responses.prepare_rerun(&msg) {
+        handle_message(msg).await;
+    }
+}
+
 async fn process_command<'m>(invoke: Invoke<'m>, msg: &'m Message) -> Result<ProcessResult> {
     let Invoke { cmd, args } = invoke;
 
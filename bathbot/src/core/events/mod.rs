@@ -14,7 +14,10 @@ use tokio::{
 use twilight_gateway::{Event, EventTypeFlags, Shard, StreamExt as _};
 use twilight_model::user::User;
 
-use self::{interaction::handle_interaction, message::handle_message};
+use self::{
+    interaction::handle_interaction,
+    message::{handle_message, handle_message_update},
+};
 use super::{BotMetrics, Context};
 
 mod interaction;
@@ -133,6 +136,7 @@ const EVENT_FLAGS: EventTypeFlags = EventTypeFlags::CHANNEL_CREATE
     .union(EventTypeFlags::MESSAGE_CREATE)
     .union(EventTypeFlags::MESSAGE_DELETE)
     .union(EventTypeFlags::MESSAGE_DELETE_BULK)
+    .union(EventTypeFlags::MESSAGE_UPDATE)
     .union(EventTypeFlags::READY)
     .union(EventTypeFlags::ROLE_CREATE)
     .union(EventTypeFlags::ROLE_DELETE)
@@ -252,15 +256,28 @@ async fn handle_event(event: Event, shard_id: u32) {
                     .unwrap()
                     .remove(&e.guild_id);
             }
+            Event::MemberRemove(e) if Context::guild_config().archives_scores(e.guild_id) => {
+                let remove_fut = Context::osu_scores().remove_archive_member(e.guild_id, e.user.id);
+
+                if let Err(err) = remove_fut.await {
+                    warn!(?err, "Failed to remove score archive member");
+                }
+            }
             Event::MessageCreate(msg) => handle_message(msg.0).await,
             Event::MessageDelete(e) => {
-                Context::get().active_msgs.remove(e.id).await;
+                let ctx = Context::get();
+                ctx.active_msgs.remove(e.id).await;
+                ctx.prefix_responses.remove(e.id);
             }
             Event::MessageDeleteBulk(msgs) => {
+                let ctx = Context::get();
+
                 for id in msgs.ids.into_iter() {
-                    Context::get().active_msgs.remove(id).await;
+                    ctx.active_msgs.remove(id).await;
+                    ctx.prefix_responses.remove(id);
                 }
             }
+            Event::MessageUpdate(msg) => handle_message_update(msg.0).await,
             Event::Ready(_) => info!(shard_id, "Shard is ready"),
             Event::Resumed => info!(shard_id, "Shard is resumed"),
             _ => {}
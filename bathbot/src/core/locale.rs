@@ -0,0 +1,203 @@
+//! Translations of slash commands and command responses.
+//!
+//! Each [`Language`] has a file in `bathbot/locales/` that contains a flat
+//! JSON object mapping keys to translated strings.
+//!
+//! Slash commands are localized through keys of the form
+//! `commands.<command>[.<group>][.<subcommand>][.<option>].{name,desc}`.
+//! Note that discord applies the same restrictions to localized names as to
+//! regular names i.e. they must be lowercase and without spaces.
+//!
+//! Responses use arbitrary keys and may contain `{placeholder}`s that are
+//! filled in through [`Locale::format`].
+
+use std::{collections::HashMap, fmt::Display, sync::OnceLock};
+
+use bathbot_psql::model::configs::Language;
+use twilight_interactions::command::{ApplicationCommandData, CommandOptionExtended};
+use twilight_model::id::{
+    Id,
+    marker::{GuildMarker, UserMarker},
+};
+
+use crate::core::Context;
+
+type Translation = HashMap<Box<str>, Box<str>>;
+
+static TRANSLATIONS: OnceLock<Vec<Translation>> = OnceLock::new();
+
+fn translations() -> &'static [Translation] {
+    TRANSLATIONS.get_or_init(|| {
+        Language::ALL
+            .into_iter()
+            .map(|language| {
+                let json = match language {
+                    Language::English => include_str!("../../locales/en-US.json"),
+                    Language::German => include_str!("../../locales/de.json"),
+                    Language::French => include_str!("../../locales/fr.json"),
+                };
+
+                serde_json::from_str(json).unwrap_or_else(|err| {
+                    panic!("invalid translation file for {}: {err}", language.locale())
+                })
+            })
+            .collect()
+    })
+}
+
+fn lookup(language: Language, key: &str) -> Option<&'static str> {
+    translations()[language as usize].get(key).map(Box::as_ref)
+}
+
+/// Handle to look up response strings in a certain language.
+#[derive(Copy, Clone, Debug, Default)]
+pub struct Locale {
+    language: Language,
+}
+
+impl Locale {
+    pub const fn new(language: Language) -> Self {
+        Self { language }
+    }
+
+    /// Determine the language for a user.
+    ///
+    /// A language configured by the user takes precedence over the guild's
+    /// language which in turn takes precedence over the user's discord
+    /// locale.
+    pub async fn resolve(
+        user_id: Option<Id<UserMarker>>,
+        guild_id: Option<Id<GuildMarker>>,
+        client_locale: Option<&str>,
+    ) -> Self {
+        if let Some(user_id) = user_id {
+            match Context::user_config().language(user_id).await {
+                Ok(Some(language)) => return Self::new(language),
+                Ok(None) => {}
+                Err(err) => warn!(?err, "Failed to get user language"),
+            }
+        }
+
+        if let Some(guild_id) = guild_id {
+            let language_fut = Context::guild_config().peek(guild_id, |config| config.language);
+
+            if let Some(language) = language_fut.await {
+                return Self::new(language);
+            }
+        }
+
+        client_locale
+            .and_then(Language::from_locale)
+            .map_or_else(Self::default, Self::new)
+    }
+
+    pub fn language(self) -> Language {
+        self.language
+    }
+
+    /// Get the translation for a key.
+    ///
+    /// Falls back to english if the key is not translated and to the key
+    /// itself if it's missing entirely.
+    pub fn get(self, key: &'static str) -> &'static str {
+        lookup(self.language, key)
+            .or_else(|| lookup(Language::English, key))
+            .unwrap_or_else(|| {
+                warn!(key, "Missing translation");
+
+                key
+            })
+    }
+
+    /// Get the translation for a key and replace its `{placeholder}`s.
+    pub fn format(self, key: &'static str, args: &[(&str, &dyn Display)]) -> String {
+        let mut content = self.get(key).to_owned();
+
+        for (name, value) in args {
+            let placeholder = format!("{{{name}}}");
+
+            if content.contains(&placeholder) {
+                content = content.replace(&placeholder, &value.to_string());
+            }
+        }
+
+        content
+    }
+}
+
+/// Add all available name and description localizations to a command.
+pub fn localize_command(mut command: ApplicationCommandData) -> ApplicationCommandData {
+    let mut key = format!("commands.{}", command.name);
+
+    command.name_localizations = localizations(&key, "name");
+    command.description_localizations = localizations(&key, "desc");
+    localize_options(&mut command.options, &mut key);
+
+    command
+}
+
+fn localize_options(options: &mut [CommandOptionExtended], key: &mut String) {
+    for option in options {
+        let len = key.len();
+        key.push('.');
+        key.push_str(&option.name);
+
+        option.name_localizations = localizations(key, "name");
+        option.description_localizations = localizations(key, "desc");
+
+        if let Some(ref mut options) = option.options {
+            localize_options(options, key);
+        }
+
+        key.truncate(len);
+    }
+}
+
+fn localizations(key: &str, suffix: &str) -> Option<HashMap<String, String>> {
+    let key = format!("{key}.{suffix}");
+
+    let localizations: HashMap<_, _> = Language::ALL
+        .into_iter()
+        .filter(|&language| language != Language::English)
+        .filter_map(|language| {
+            let translated = lookup(language, &key)?;
+
+            Some((language.locale().to_owned(), translated.to_owned()))
+        })
+        .collect();
+
+    (!localizations.is_empty()).then_some(localizations)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{fs, path::Path};
+
+    use super::*;
+
+    #[test]
+    fn locale_files_are_valid() {
+        let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("locales");
+
+        for entry in fs::read_dir(dir).unwrap() {
+            let path = entry.unwrap().path();
+            let json = fs::read_to_string(&path).unwrap();
+
+            let translation: Translation = serde_json::from_str(&json)
+                .unwrap_or_else(|err| panic!("invalid translation file {path:?}: {err}"));
+
+            let names = translation
+                .iter()
+                .filter(|(key, _)| key.starts_with("commands.") && key.ends_with(".name"));
+
+            for (key, name) in names {
+                assert!(
+                    !name.contains(char::is_whitespace) && name.to_lowercase() == name.as_ref(),
+                    "`{key}` in {path:?} must be lowercase without spaces: `{name}`"
+                );
+            }
+        }
+
+        assert_eq!(translations().len(), Language::ALL.len());
+    }
+}
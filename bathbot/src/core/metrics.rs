@@ -14,6 +14,7 @@ const COMMAND_ERRORS: &str = "command_errors";
 const CACHE_ENTRIES: &str = "cache_entries";
 const REDIS_CACHE_HITS: &str = "redis_cache_hits";
 const OSU_TRACKING_HIT: &str = "osu_tracking_hit";
+const OSU_LEADERBOARD_TRACKING_HIT: &str = "osu_leaderboard_tracking_hit";
 
 pub struct BotMetrics;
 
@@ -89,6 +90,10 @@ impl BotMetrics {
         counter!(OSU_TRACKING_HIT, "mode" => mode.as_str()).increment(1);
     }
 
+    pub fn osu_leaderboard_tracking_hit(mode: GameMode) {
+        counter!(OSU_LEADERBOARD_TRACKING_HIT, "mode" => mode.as_str()).increment(1);
+    }
+
     pub fn inc_redis_hit(kind: impl Into<SharedString>) {
         counter!(REDIS_CACHE_HITS, "kind" => kind).increment(1);
     }
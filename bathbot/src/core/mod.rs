@@ -11,4 +11,5 @@ mod events;
 mod metrics;
 
 pub mod commands;
+pub mod locale;
 pub mod logging;
@@ -8,6 +8,7 @@ mod player_snipe_stats;
 mod pp_missing;
 mod profile_compare;
 mod ratio;
+mod server_digest;
 mod sniped;
 mod whatif;
 
@@ -23,7 +24,7 @@ pub use self::match_live::*;
 pub use self::{
     attributes::*, claim_name::*, country_snipe_stats::*, fix_score::*, medal_stats::*,
     osustats_counts::*, player_snipe_stats::*, pp_missing::*, profile_compare::*, ratio::*,
-    sniped::*, whatif::*,
+    server_digest::*, sniped::*, whatif::*,
 };
 
 pub struct ComboFormatter {
@@ -0,0 +1,226 @@
+use std::{
+    collections::{HashMap, HashSet},
+    fmt::Write,
+};
+
+use bathbot_macros::EmbedData;
+use bathbot_psql::model::digest::{DbDigestEntry, DbDigestStatsEntry, DigestFrequency};
+use bathbot_util::{AuthorBuilder, FooterBuilder, numbers::WithComma};
+use rosu_v2::prelude::GameMode;
+use time::OffsetDateTime;
+use twilight_model::channel::message::embed::EmbedField;
+
+/// Amount of members listed per mode
+const TOP_COUNT: usize = 5;
+
+/// Amount of members listed for pp and rank gains
+const GAINS_COUNT: usize = 5;
+
+/// Amount of newly linked members that are listed by name
+const NEW_COUNT: usize = 10;
+
+#[derive(EmbedData)]
+pub struct ServerDigestEmbed {
+    author: AuthorBuilder,
+    description: String,
+    fields: Vec<EmbedField>,
+    footer: FooterBuilder,
+    timestamp: OffsetDateTime,
+    title: &'static str,
+}
+
+impl ServerDigestEmbed {
+    pub fn new(
+        author: AuthorBuilder,
+        frequency: DigestFrequency,
+        current: &[DbDigestStatsEntry],
+        previous: &[DbDigestEntry],
+    ) -> Self {
+        let title = match frequency {
+            DigestFrequency::Daily => "Daily server digest",
+            DigestFrequency::Weekly => "Weekly server digest",
+        };
+
+        let prev_stats: HashMap<_, _> = previous
+            .iter()
+            .map(|entry| ((entry.user_id, entry.gamemode), entry))
+            .collect();
+
+        let mut fields = Vec::with_capacity(7);
+
+        for mode in [
+            GameMode::Osu,
+            GameMode::Taiko,
+            GameMode::Catch,
+            GameMode::Mania,
+        ] {
+            let mut top: Vec<_> = current
+                .iter()
+                .filter(|entry| entry.gamemode == mode as i16 && entry.pp > 0.0)
+                .collect();
+
+            if top.is_empty() {
+                continue;
+            }
+
+            top.sort_unstable_by(|a, b| b.pp.total_cmp(&a.pp));
+
+            let mut value = String::with_capacity(256);
+
+            for (entry, i) in top.into_iter().take(TOP_COUNT).zip(1..) {
+                let _ = writeln!(
+                    value,
+                    "{i}. **{name}**: {pp}pp (#{rank})",
+                    name = entry.username,
+                    pp = WithComma::new(entry.pp),
+                    rank = WithComma::new(entry.global_rank),
+                );
+            }
+
+            let name = match mode {
+                GameMode::Osu => "Top osu!standard",
+                GameMode::Taiko => "Top osu!taiko",
+                GameMode::Catch => "Top osu!ctb",
+                GameMode::Mania => "Top osu!mania",
+            };
+
+            fields.push(EmbedField {
+                inline: false,
+                name: name.to_owned(),
+                value,
+            });
+        }
+
+        let mut pp_gains = Vec::new();
+        let mut rank_gains = Vec::new();
+
+        for entry in current {
+            let Some(prev) = prev_stats.get(&(entry.user_id, entry.gamemode)) else {
+                continue;
+            };
+
+            let pp_gain = entry.pp - prev.pp;
+
+            if pp_gain >= 0.01 {
+                pp_gains.push((entry, pp_gain));
+            }
+
+            // A rank of 0 means the user is inactive
+            if entry.global_rank > 0 && prev.global_rank > 0 {
+                let rank_gain = prev.global_rank - entry.global_rank;
+
+                if rank_gain > 0 {
+                    rank_gains.push((entry, rank_gain));
+                }
+            }
+        }
+
+        pp_gains.sort_unstable_by(|(_, a), (_, b)| b.total_cmp(a));
+        rank_gains.sort_unstable_by(|(_, a), (_, b)| b.cmp(a));
+
+        if !pp_gains.is_empty() {
+            let mut value = String::with_capacity(256);
+
+            for (entry, gain) in pp_gains.into_iter().take(GAINS_COUNT) {
+                let _ = writeln!(
+                    value,
+                    "**{name}** ({mode}): +{gain}pp",
+                    name = entry.username,
+                    mode = mode_str(entry.gamemode),
+                    gain = WithComma::new(gain),
+                );
+            }
+
+            fields.push(EmbedField {
+                inline: false,
+                name: "Biggest pp gains".to_owned(),
+                value,
+            });
+        }
+
+        if !rank_gains.is_empty() {
+            let mut value = String::with_capacity(256);
+
+            for (entry, gain) in rank_gains.into_iter().take(GAINS_COUNT) {
+                let _ = writeln!(
+                    value,
+                    "**{name}** ({mode}): +{gain} ranks (now #{rank})",
+                    name = entry.username,
+                    mode = mode_str(entry.gamemode),
+                    gain = WithComma::new(gain),
+                    rank = WithComma::new(entry.global_rank),
+                );
+            }
+
+            fields.push(EmbedField {
+                inline: false,
+                name: "Biggest rank gains".to_owned(),
+                value,
+            });
+        }
+
+        let prev_users: HashSet<_> = previous.iter().map(|entry| entry.user_id).collect();
+        let mut new_users: Vec<_> = current
+            .iter()
+            .filter(|entry| !prev_users.contains(&entry.user_id))
+            .map(|entry| (entry.user_id, entry.username.as_str()))
+            .collect();
+
+        new_users.sort_unstable();
+        new_users.dedup_by_key(|(user_id, _)| *user_id);
+
+        if !new_users.is_empty() {
+            let mut value = String::with_capacity(128);
+            let mut names = new_users.iter().take(NEW_COUNT);
+
+            if let Some((_, name)) = names.next() {
+                let _ = write!(value, "`{name}`");
+
+                for (_, name) in names {
+                    let _ = write!(value, ", `{name}`");
+                }
+            }
+
+            if new_users.len() > NEW_COUNT {
+                let _ = write!(value, " and {} more", new_users.len() - NEW_COUNT);
+            }
+
+            fields.push(EmbedField {
+                inline: false,
+                name: "Newly linked members".to_owned(),
+                value,
+            });
+        }
+
+        let description = if fields.is_empty() {
+            "No linked members with cached stats yet".to_owned()
+        } else {
+            let since = match frequency {
+                DigestFrequency::Daily => "yesterday",
+                DigestFrequency::Weekly => "last week",
+            };
+
+            format!("Progress of linked members since {since}")
+        };
+
+        let footer = FooterBuilder::new("Based on the last time each member was retrieved");
+
+        Self {
+            author,
+            description,
+            fields,
+            footer,
+            timestamp: OffsetDateTime::now_utc(),
+            title,
+        }
+    }
+}
+
+fn mode_str(mode: i16) -> &'static str {
+    match mode {
+        0 => "osu",
+        1 => "taiko",
+        2 => "ctb",
+        _ => "mania",
+    }
+}
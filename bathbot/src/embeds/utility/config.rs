@@ -1,7 +1,9 @@
 use std::fmt::{Display, Write};
 
 use ::time::UtcOffset;
-use bathbot_psql::model::configs::{ListSize, OsuUsername, Retries, ScoreData, UserConfig};
+use bathbot_psql::model::configs::{
+    CardLayout, CardTheme, Language, ListSize, OsuUsername, Retries, ScoreData, UserConfig,
+};
 use bathbot_util::{AuthorBuilder, EmbedBuilder, FooterBuilder};
 use rosu_v2::prelude::GameMode;
 use twilight_model::{channel::message::embed::EmbedField, user::User};
@@ -108,6 +110,38 @@ impl ConfigEmbed {
                     (Retries::IgnoreMods, "ignore mods"),
                 ],
             ),
+            create_field(
+                "Card theme",
+                config.card_theme.unwrap_or_default(),
+                &[
+                    (CardTheme::Classic, "classic"),
+                    (CardTheme::Midnight, "midnight"),
+                    (CardTheme::Sunset, "sunset"),
+                    (CardTheme::Forest, "forest"),
+                    (CardTheme::Ocean, "ocean"),
+                    (CardTheme::Sakura, "sakura"),
+                ],
+            ),
+            create_field(
+                "Card layout",
+                config.card_layout.unwrap_or_default(),
+                &[(CardLayout::Tall, "tall"), (CardLayout::Compact, "compact")],
+            ),
+            create_field(
+                "Card stats",
+                config.card_stats.unwrap_or(false),
+                &[(true, "show"), (false, "hide")],
+            ),
+            create_field(
+                "Language",
+                config.language,
+                &[
+                    (None, "auto"),
+                    (Some(Language::English), "english"),
+                    (Some(Language::German), "german"),
+                    (Some(Language::French), "french"),
+                ],
+            ),
         ];
 
         if let Some(skin_url) = skin_url {
@@ -3,7 +3,9 @@ use std::fmt::Write;
 use bathbot_cache::model::CachedArchive;
 use bathbot_macros::EmbedData;
 use bathbot_model::twilight::guild::ArchivedCachedGuild;
-use bathbot_psql::model::configs::{GuildConfig, HideSolutions, ListSize, Retries, ScoreData};
+use bathbot_psql::model::configs::{
+    GuildConfig, HideSolutions, Language, ListSize, Retries, ScoreData,
+};
 use bathbot_util::AuthorBuilder;
 use twilight_model::channel::message::embed::EmbedField;
 
@@ -114,6 +116,11 @@ impl ServerConfigEmbed {
                     ],
                 )
             },
+            create_field(
+                "Score archive",
+                config.score_archive.unwrap_or(false),
+                &[(true, "enabled"), (false, "disabled")],
+            ),
             create_field(
                 "Render button",
                 config.render_button.unwrap_or(true),
@@ -128,6 +135,16 @@ impl ServerConfigEmbed {
                     (Retries::IgnoreMods, "ignore mods"),
                 ],
             ),
+            create_field(
+                "Language",
+                config.language,
+                &[
+                    (None, "auto"),
+                    (Some(Language::English), "english"),
+                    (Some(Language::German), "german"),
+                    (Some(Language::French), "french"),
+                ],
+            ),
         ];
 
         Self {
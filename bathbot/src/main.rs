@@ -99,6 +99,24 @@ async fn async_main() -> Result<()> {
     // Request members
     tokio::spawn(Context::request_guild_members(member_rx));
 
+    // Prune archived scores
+    tokio::spawn(Context::score_archive_retention_loop());
+
+    // Post server leaderboard digests
+    tokio::spawn(Context::guild_digest_loop());
+
+    // Reconcile reward roles
+    tokio::spawn(Context::role_rewards_loop());
+
+    // Snapshot watched map leaderboards
+    tokio::spawn(Context::map_leaderboard_loop());
+
+    // Store daily challenge results and send reminders
+    tokio::spawn(Context::daily_challenge_loop());
+
+    // Notify about followed mappers' mapsets
+    tokio::spawn(Context::mapper_follow_loop());
+
     let (reshard_tx, reshard_rx) = broadcast::channel(1);
 
     tokio::spawn(Context::reshard_loop(reshard_tx.clone()));
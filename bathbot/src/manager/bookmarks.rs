@@ -36,4 +36,30 @@ impl BookmarkManager {
             .await
             .wrap_err("Failed to delete user bookmark")
     }
+
+    /// Returns whether the map is bookmarked
+    pub async fn set_folder(
+        self,
+        user: Id<UserMarker>,
+        map_id: u32,
+        folder: Option<&str>,
+    ) -> Result<bool> {
+        self.psql
+            .update_user_bookmark_folder(user, map_id, folder)
+            .await
+            .wrap_err("Failed to update bookmark folder")
+    }
+
+    /// Returns whether the map is bookmarked
+    pub async fn set_note(
+        self,
+        user: Id<UserMarker>,
+        map_id: u32,
+        note: Option<&str>,
+    ) -> Result<bool> {
+        self.psql
+            .update_user_bookmark_note(user, map_id, note)
+            .await
+            .wrap_err("Failed to update bookmark note")
+    }
 }
@@ -1,3 +1,5 @@
+use std::{collections::HashSet, sync::RwLock};
+
 use bathbot_psql::{Database, model::configs::GuildConfig};
 use bathbot_util::IntHasher;
 use eyre::{Result, WrapErr};
@@ -5,18 +7,25 @@ use papaya::HashMap as PapayaMap;
 use twilight_model::id::{Id, marker::GuildMarker};
 
 type GuildConfigs = PapayaMap<Id<GuildMarker>, GuildConfig, IntHasher>;
+type ScoreArchiveGuilds = RwLock<HashSet<Id<GuildMarker>, IntHasher>>;
 
 #[derive(Copy, Clone)]
 pub struct GuildConfigManager {
     psql: &'static Database,
     guild_configs: &'static GuildConfigs,
+    score_archive_guilds: &'static ScoreArchiveGuilds,
 }
 
 impl GuildConfigManager {
-    pub fn new(psql: &'static Database, guild_configs: &'static GuildConfigs) -> Self {
+    pub fn new(
+        psql: &'static Database,
+        guild_configs: &'static GuildConfigs,
+        score_archive_guilds: &'static ScoreArchiveGuilds,
+    ) -> Self {
         Self {
             psql,
             guild_configs,
+            score_archive_guilds,
         }
     }
 
@@ -52,6 +61,24 @@ impl GuildConfigManager {
         prefix_opt.unwrap_or_else(|| GuildConfig::DEFAULT_PREFIX.to_owned())
     }
 
+    /// Guilds that opted into archiving their members' scores.
+    pub fn score_archive_guilds(self) -> Vec<Id<GuildMarker>> {
+        self.score_archive_guilds
+            .read()
+            .unwrap()
+            .iter()
+            .copied()
+            .collect()
+    }
+
+    /// Whether the guild opted into archiving its members' scores.
+    pub fn archives_scores(self, guild_id: Id<GuildMarker>) -> bool {
+        self.score_archive_guilds
+            .read()
+            .unwrap()
+            .contains(&guild_id)
+    }
+
     pub async fn update<F, O>(self, guild_id: Id<GuildMarker>, f: F) -> Result<O>
     where
         F: FnOnce(&mut GuildConfig) -> O,
@@ -74,6 +101,16 @@ impl GuildConfigManager {
             .await
             .wrap_err("failed to store guild config");
 
+        {
+            let mut score_archive_guilds = self.score_archive_guilds.write().unwrap();
+
+            if config.score_archive == Some(true) {
+                score_archive_guilds.insert(guild_id);
+            } else {
+                score_archive_guilds.remove(&guild_id);
+            }
+        }
+
         self.guild_configs.pin().insert(guild_id, config);
 
         res
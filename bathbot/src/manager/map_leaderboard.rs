@@ -0,0 +1,367 @@
+use std::collections::HashMap;
+
+use bathbot_psql::{
+    Database,
+    model::osu::{DbMapLeaderboardScore, DbMapLeaderboardSnapshot, DbTrackedOsuMap},
+};
+use bathbot_util::IntHasher;
+use eyre::{Result, WrapErr};
+use rosu_v2::prelude::{GameMode, Score};
+use twilight_model::id::{
+    Id,
+    marker::{ChannelMarker, GuildMarker},
+};
+
+use crate::core::Context;
+
+/// Amount of leaderboard scores per snapshot
+pub const SNAPSHOT_SIZE: usize = 50;
+
+/// Amount of snapshots that are kept per map
+const SNAPSHOT_RETENTION: usize = 50;
+
+#[derive(Copy, Clone)]
+pub struct MapLeaderboardManager {
+    psql: &'static Database,
+}
+
+impl MapLeaderboardManager {
+    pub fn new() -> Self {
+        Self {
+            psql: Context::psql(),
+        }
+    }
+
+    pub async fn watch(
+        self,
+        map_id: u32,
+        mode: GameMode,
+        channel: Id<ChannelMarker>,
+        guild: Option<Id<GuildMarker>>,
+        max_rank: u8,
+    ) -> Result<()> {
+        self.psql
+            .upsert_tracked_osu_map(map_id, mode, channel.get(), guild.map(Id::get), max_rank)
+            .await
+            .wrap_err("Failed to upsert tracked map")
+    }
+
+    /// Returns whether the map was watched in the channel
+    pub async fn unwatch(
+        self,
+        map_id: u32,
+        mode: Option<GameMode>,
+        channel: Id<ChannelMarker>,
+    ) -> Result<bool> {
+        self.psql
+            .delete_tracked_osu_map(map_id, mode, channel.get())
+            .await
+            .wrap_err("Failed to delete tracked map")
+    }
+
+    pub async fn unwatch_channel(self, channel: Id<ChannelMarker>) -> Result<()> {
+        self.psql
+            .delete_tracked_osu_maps_channel(channel.get())
+            .await
+            .wrap_err("Failed to delete tracked maps of channel")
+    }
+
+    pub async fn watched_in_channel(self, channel: Id<ChannelMarker>) -> Result<Vec<WatchedMap>> {
+        let maps = self
+            .psql
+            .select_tracked_osu_maps_channel(channel.get())
+            .await
+            .wrap_err("Failed to get tracked maps of channel")?
+            .into_iter()
+            .map(WatchedMap::from)
+            .collect();
+
+        Ok(maps)
+    }
+
+    /// All watched maps, grouped by map and mode.
+    pub async fn watched(self) -> Result<HashMap<(u32, GameMode), Vec<WatchedMap>>> {
+        let maps = self
+            .psql
+            .select_tracked_osu_maps()
+            .await
+            .wrap_err("Failed to get tracked maps")?;
+
+        let mut grouped = HashMap::<_, Vec<_>>::new();
+
+        for map in maps {
+            let map = WatchedMap::from(map);
+
+            grouped.entry((map.map_id, map.mode)).or_default().push(map);
+        }
+
+        Ok(grouped)
+    }
+
+    /// The most recent snapshots, latest first.
+    pub async fn snapshots(
+        self,
+        map_id: u32,
+        mode: GameMode,
+        limit: usize,
+    ) -> Result<Vec<DbMapLeaderboardSnapshot>> {
+        self.psql
+            .select_map_leaderboard_snapshots(map_id, mode, limit)
+            .await
+            .wrap_err("Failed to get map leaderboard snapshots")
+    }
+
+    pub async fn snapshot_scores(self, snapshot_id: i32) -> Result<Vec<DbMapLeaderboardScore>> {
+        self.psql
+            .select_map_leaderboard_snapshot_scores(snapshot_id)
+            .await
+            .wrap_err("Failed to get map leaderboard snapshot scores")
+    }
+
+    /// Scores of the latest snapshot, if there is one.
+    pub async fn latest_scores(
+        self,
+        map_id: u32,
+        mode: GameMode,
+    ) -> Result<Option<Vec<DbMapLeaderboardScore>>> {
+        let Some(snapshot) = self.snapshots(map_id, mode, 1).await?.pop() else {
+            return Ok(None);
+        };
+
+        self.snapshot_scores(snapshot.snapshot_id).await.map(Some)
+    }
+
+    pub async fn store_snapshot(
+        self,
+        map_id: u32,
+        mode: GameMode,
+        scores: &[DbMapLeaderboardScore],
+    ) -> Result<()> {
+        self.psql
+            .insert_map_leaderboard_snapshot(map_id, mode, scores, SNAPSHOT_RETENTION)
+            .await
+            .wrap_err("Failed to insert map leaderboard snapshot")
+    }
+
+    /// Turn leaderboard scores into snapshot entries.
+    pub fn snapshot_entries(scores: Vec<Score>) -> Vec<DbMapLeaderboardScore> {
+        scores
+            .into_iter()
+            .take(SNAPSHOT_SIZE)
+            .zip(1..)
+            .map(|(score, pos)| DbMapLeaderboardScore {
+                pos,
+                user_id: score.user_id as i32,
+                username: score.user.map_or_else(
+                    || format!("<user {}>", score.user_id),
+                    |user| user.username.as_str().to_owned(),
+                ),
+                score_id: score.id as i64,
+                score: score.score as i64,
+                pp: score.pp,
+            })
+            .collect()
+    }
+}
+
+pub struct WatchedMap {
+    pub map_id: u32,
+    pub mode: GameMode,
+    pub channel: Id<ChannelMarker>,
+    pub guild: Option<Id<GuildMarker>>,
+    pub max_rank: u8,
+}
+
+impl From<DbTrackedOsuMap> for WatchedMap {
+    fn from(map: DbTrackedOsuMap) -> Self {
+        Self {
+            map_id: map.map_id as u32,
+            mode: GameMode::from(map.gamemode as u8),
+            channel: Id::new(map.channel_id as u64),
+            guild: map.guild_id.map(|id| Id::new(id as u64)),
+            max_rank: map.max_rank as u8,
+        }
+    }
+}
+
+/// Position changes of users between two snapshots.
+///
+/// Leaderboards only contain one score per user so users are compared
+/// instead of scores.
+pub struct LeaderboardDiff<'s> {
+    /// Sorted by new position, users that dropped out come last
+    pub entries: Vec<LeaderboardDiffEntry<'s>>,
+    /// The user that was #1 in the old snapshot if it's a different user now
+    pub prev_first: Option<&'s DbMapLeaderboardScore>,
+}
+
+pub struct LeaderboardDiffEntry<'s> {
+    pub user_id: u32,
+    pub username: &'s str,
+    pub old_pos: Option<u16>,
+    pub new_pos: Option<u16>,
+    /// Whether the user set a new score in the meanwhile
+    pub new_score: bool,
+}
+
+impl<'s> LeaderboardDiff<'s> {
+    pub fn new(old: &'s [DbMapLeaderboardScore], new: &'s [DbMapLeaderboardScore]) -> Self {
+        let old_by_user: HashMap<_, _, IntHasher> =
+            old.iter().map(|score| (score.user_id, score)).collect();
+
+        let mut entries = Vec::new();
+
+        for score in new {
+            let old_score = old_by_user.get(&score.user_id);
+            let old_pos = old_score.map(|old| old.pos as u16);
+            let new_score = old_score.is_none_or(|old| old.score_id != score.score_id);
+
+            if old_pos == Some(score.pos as u16) && !new_score {
+                continue;
+            }
+
+            entries.push(LeaderboardDiffEntry {
+                user_id: score.user_id as u32,
+                username: &score.username,
+                old_pos,
+                new_pos: Some(score.pos as u16),
+                new_score,
+            });
+        }
+
+        for score in old {
+            if new.iter().all(|new| new.user_id != score.user_id) {
+                entries.push(LeaderboardDiffEntry {
+                    user_id: score.user_id as u32,
+                    username: &score.username,
+                    old_pos: Some(score.pos as u16),
+                    new_pos: None,
+                    new_score: false,
+                });
+            }
+        }
+
+        let prev_first = match (old.first(), new.first()) {
+            (Some(old), Some(new)) if old.user_id != new.user_id => Some(old),
+            _ => None,
+        };
+
+        Self {
+            entries,
+            prev_first,
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Users that newly entered the top `max_rank`.
+    pub fn entered(&self, max_rank: u16) -> impl Iterator<Item = &LeaderboardDiffEntry<'s>> {
+        self.entries.iter().filter(move |entry| {
+            entry.new_pos.is_some_and(|pos| pos <= max_rank)
+                && entry.old_pos.is_none_or(|pos| pos > max_rank)
+        })
+    }
+
+    /// Users among `members` that were within the top `max_rank` and got
+    /// passed by someone, paired with the user that passed them.
+    pub fn sniped<'d>(
+        &'d self,
+        max_rank: u16,
+        members: &'d [u32],
+    ) -> impl Iterator<Item = (&'d LeaderboardDiffEntry<'s>, &'d LeaderboardDiffEntry<'s>)> {
+        self.entries
+            .iter()
+            .filter(move |entry| members.contains(&entry.user_id))
+            .filter_map(move |entry| {
+                let old_pos = entry.old_pos.filter(|&pos| pos <= max_rank)?;
+
+                if entry.new_pos.is_some_and(|pos| pos <= old_pos) {
+                    return None;
+                }
+
+                let sniper = self.entries.iter().find(|other| {
+                    other.new_score
+                        && other.new_pos.is_some_and(|pos| pos <= old_pos)
+                        && other.old_pos.is_none_or(|pos| pos > old_pos)
+                })?;
+
+                Some((entry, sniper))
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn score(pos: i16, user_id: i32, score_id: i64) -> DbMapLeaderboardScore {
+        DbMapLeaderboardScore {
+            pos,
+            user_id,
+            username: format!("user{user_id}"),
+            score_id,
+            score: 1_000_000 - pos as i64,
+            pp: None,
+        }
+    }
+
+    #[test]
+    fn new_entry() {
+        let old = [score(1, 1, 10), score(2, 2, 20)];
+        let new = [score(1, 1, 10), score(2, 3, 30), score(3, 2, 20)];
+        let diff = LeaderboardDiff::new(&old, &new);
+
+        let entered: Vec<_> = diff.entered(2).map(|entry| entry.user_id).collect();
+        assert_eq!(entered, [3]);
+
+        let sniped: Vec<_> = diff
+            .sniped(2, &[2])
+            .map(|(entry, sniper)| (entry.user_id, sniper.user_id))
+            .collect();
+        assert_eq!(sniped, [(2, 3)]);
+        assert!(diff.prev_first.is_none());
+    }
+
+    #[test]
+    fn improvement() {
+        let old = [score(1, 1, 10), score(2, 2, 20)];
+        let new = [score(1, 2, 21), score(2, 1, 10)];
+        let diff = LeaderboardDiff::new(&old, &new);
+
+        let improved = diff
+            .entries
+            .iter()
+            .find(|entry| entry.user_id == 2)
+            .unwrap();
+        assert_eq!(improved.old_pos, Some(2));
+        assert_eq!(improved.new_pos, Some(1));
+        assert!(improved.new_score);
+
+        assert_eq!(diff.prev_first.map(|score| score.user_id), Some(1));
+        assert_eq!(diff.entered(2).count(), 0);
+    }
+
+    #[test]
+    fn drop_out() {
+        let old = [score(1, 1, 10), score(2, 2, 20)];
+        let new = [score(1, 3, 30), score(2, 1, 10)];
+        let diff = LeaderboardDiff::new(&old, &new);
+
+        let dropped = diff.entries.last().unwrap();
+        assert_eq!(dropped.user_id, 2);
+        assert_eq!(dropped.old_pos, Some(2));
+        assert_eq!(dropped.new_pos, None);
+        assert!(!dropped.new_score);
+    }
+
+    #[test]
+    fn unchanged() {
+        let old = [score(1, 1, 10), score(2, 2, 20)];
+        let diff = LeaderboardDiff::new(&old, &old);
+
+        assert!(diff.is_empty());
+        assert!(diff.prev_first.is_none());
+    }
+}
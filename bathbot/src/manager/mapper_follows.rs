@@ -0,0 +1,299 @@
+use std::collections::HashMap;
+
+use bathbot_psql::{
+    Database,
+    model::osu::{DbTrackedMapperMapset, DbTrackedOsuMapper},
+};
+use bathbot_util::IntHasher;
+use eyre::{Result, WrapErr};
+use rosu_v2::prelude::{BeatmapsetSearchSort, GameMode, RankStatus, Username};
+use twilight_model::id::{
+    Id,
+    marker::{ChannelMarker, GuildMarker, UserMarker},
+};
+
+use crate::{core::Context, manager::redis::osu::UserArgs};
+
+#[derive(Copy, Clone)]
+pub struct MapperFollowManager {
+    psql: &'static Database,
+}
+
+impl MapperFollowManager {
+    pub fn new() -> Self {
+        Self {
+            psql: Context::psql(),
+        }
+    }
+
+    pub async fn follow(
+        self,
+        mapper_id: u32,
+        channel: Id<ChannelMarker>,
+        guild: Option<Id<GuildMarker>>,
+        added_by: Id<UserMarker>,
+    ) -> Result<()> {
+        self.psql
+            .upsert_tracked_osu_mapper(mapper_id, channel.get(), guild.map(Id::get), added_by.get())
+            .await
+            .wrap_err("Failed to upsert tracked mapper")
+    }
+
+    /// Returns whether the mapper was followed in the channel
+    pub async fn unfollow(self, mapper_id: u32, channel: Id<ChannelMarker>) -> Result<bool> {
+        self.psql
+            .delete_tracked_osu_mapper(mapper_id, channel.get())
+            .await
+            .wrap_err("Failed to delete tracked mapper")
+    }
+
+    pub async fn unfollow_channel(self, channel: Id<ChannelMarker>) -> Result<()> {
+        self.psql
+            .delete_tracked_osu_mappers_channel(channel.get())
+            .await
+            .wrap_err("Failed to delete tracked mappers of channel")
+    }
+
+    pub async fn followed_in_channel(
+        self,
+        channel: Id<ChannelMarker>,
+    ) -> Result<Vec<FollowedMapper>> {
+        let mappers = self
+            .psql
+            .select_tracked_osu_mappers_channel(channel.get())
+            .await
+            .wrap_err("Failed to get tracked mappers of channel")?
+            .into_iter()
+            .map(FollowedMapper::from)
+            .collect();
+
+        Ok(mappers)
+    }
+
+    /// All followed mappers, grouped by mapper.
+    pub async fn followed(self) -> Result<HashMap<u32, Vec<FollowedMapper>, IntHasher>> {
+        let mappers = self
+            .psql
+            .select_tracked_osu_mappers()
+            .await
+            .wrap_err("Failed to get tracked mappers")?;
+
+        let mut grouped = HashMap::<_, Vec<_>, _>::with_hasher(IntHasher);
+
+        for mapper in mappers {
+            let mapper = FollowedMapper::from(mapper);
+
+            grouped.entry(mapper.mapper_id).or_default().push(mapper);
+        }
+
+        Ok(grouped)
+    }
+
+    /// Status of the mapper's mapsets that have a leaderboard i.e. are
+    /// qualified, ranked, approved, or loved, latest first.
+    pub async fn leaderboard_statuses(
+        self,
+        mapper_id: u32,
+        name: &str,
+    ) -> Result<Vec<MapsetStatus>> {
+        let statuses = Context::osu()
+            .beatmapset_search()
+            .query(format!("creator=\"{name}\""))
+            .nsfw(true)
+            .sort(BeatmapsetSearchSort::ApprovedDate, true)
+            .await
+            .wrap_err("Failed to search mapsets")?
+            .mapsets
+            .into_iter()
+            // The creator filter only checks names
+            .filter(|mapset| mapset.creator_id == mapper_id)
+            .map(|mapset| MapsetStatus {
+                mapset_id: mapset.mapset_id,
+                status: mapset.status,
+            })
+            .collect();
+
+        Ok(statuses)
+    }
+
+    /// The rank status of each mapset as of the last check.
+    pub async fn statuses(self, mapper_id: u32) -> Result<HashMap<u32, RankStatus, IntHasher>> {
+        let statuses = self
+            .psql
+            .select_tracked_mapper_mapsets(mapper_id)
+            .await
+            .wrap_err("Failed to get tracked mapper mapsets")?
+            .into_iter()
+            .filter_map(|mapset| {
+                let status = RankStatus::try_from(mapset.rank_status as i8).ok()?;
+
+                Some((mapset.mapset_id as u32, status))
+            })
+            .collect();
+
+        Ok(statuses)
+    }
+
+    /// Store the rank status of the mapsets.
+    ///
+    /// If `overwrite` is `false`, previously stored statuses remain unchanged.
+    pub async fn store_statuses(
+        self,
+        mapper_id: u32,
+        statuses: &[MapsetStatus],
+        overwrite: bool,
+    ) -> Result<()> {
+        let mapsets: Vec<_> = statuses
+            .iter()
+            .map(|status| DbTrackedMapperMapset {
+                mapset_id: status.mapset_id as i32,
+                rank_status: status.status as i16,
+            })
+            .collect();
+
+        self.psql
+            .upsert_tracked_mapper_mapsets(mapper_id, &mapsets, overwrite)
+            .await
+            .wrap_err("Failed to upsert tracked mapper mapsets")
+    }
+
+    /// The mapper's current name, falling back to the osu! API if the name
+    /// is not stored.
+    pub async fn mapper_name(self, mapper_id: u32) -> Result<Username> {
+        match Context::osu_user().name(mapper_id).await {
+            Ok(Some(name)) => return Ok(name),
+            Ok(None) => {}
+            Err(err) => warn!(?err, "Failed to get stored username"),
+        }
+
+        let args = UserArgs::user_id(mapper_id, GameMode::Osu);
+
+        let user = Context::redis()
+            .osu_user(args)
+            .await
+            .wrap_err("Failed to get user")?;
+
+        Ok(user.username.as_str().into())
+    }
+
+    /// Whether a followed mapper's mapset with the given status should be
+    /// notified about.
+    pub fn is_notable(status: RankStatus) -> bool {
+        matches!(
+            status,
+            RankStatus::Qualified | RankStatus::Ranked | RankStatus::Approved | RankStatus::Loved
+        )
+    }
+
+    /// Compare the current statuses with the previously stored ones.
+    ///
+    /// Returns the ids of mapsets that need to be notified about in
+    /// chronological order, as well as the statuses that should be stored.
+    ///
+    /// Disqualified mapsets drop out of the search so their stored status is
+    /// reset to pending. Otherwise, a requalification would go unnoticed.
+    pub fn status_changes(
+        current: &[MapsetStatus],
+        previous: &HashMap<u32, RankStatus, IntHasher>,
+    ) -> (Vec<u32>, Vec<MapsetStatus>) {
+        // Current statuses are sorted latest first but notifications should be
+        // in chronological order
+        let changed = current
+            .iter()
+            .rev()
+            .filter(|curr| {
+                Self::is_notable(curr.status) && previous.get(&curr.mapset_id) != Some(&curr.status)
+            })
+            .map(|curr| curr.mapset_id)
+            .collect();
+
+        let disqualified = previous
+            .iter()
+            .filter(|(_, status)| matches!(status, RankStatus::Qualified))
+            .filter(|(mapset_id, _)| current.iter().all(|curr| curr.mapset_id != **mapset_id))
+            .map(|(&mapset_id, _)| MapsetStatus {
+                mapset_id,
+                status: RankStatus::Pending,
+            });
+
+        let mut store = current.to_vec();
+        store.extend(disqualified);
+
+        (changed, store)
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct MapsetStatus {
+    pub mapset_id: u32,
+    pub status: RankStatus,
+}
+
+pub struct FollowedMapper {
+    pub mapper_id: u32,
+    pub channel: Id<ChannelMarker>,
+    pub guild: Option<Id<GuildMarker>>,
+    pub added_by: Id<UserMarker>,
+}
+
+impl From<DbTrackedOsuMapper> for FollowedMapper {
+    fn from(mapper: DbTrackedOsuMapper) -> Self {
+        Self {
+            mapper_id: mapper.mapper_id as u32,
+            channel: Id::new(mapper.channel_id as u64),
+            guild: mapper.guild_id.map(|id| Id::new(id as u64)),
+            added_by: Id::new(mapper.added_by as u64),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn status(mapset_id: u32, status: RankStatus) -> MapsetStatus {
+        MapsetStatus { mapset_id, status }
+    }
+
+    #[test]
+    fn notifies_new_statuses_chronologically() {
+        let current = [
+            status(3, RankStatus::Qualified),
+            status(2, RankStatus::Ranked),
+            status(1, RankStatus::Loved),
+        ];
+
+        let mut previous = HashMap::with_hasher(IntHasher);
+        previous.insert(1, RankStatus::Loved);
+        previous.insert(2, RankStatus::Qualified);
+
+        let (changed, store) = MapperFollowManager::status_changes(&current, &previous);
+
+        assert_eq!(changed, [2, 3]);
+        assert_eq!(store, current);
+    }
+
+    #[test]
+    fn resets_disqualified() {
+        let current = [status(2, RankStatus::Ranked)];
+
+        let mut previous = HashMap::with_hasher(IntHasher);
+        previous.insert(1, RankStatus::Qualified);
+        previous.insert(2, RankStatus::Ranked);
+
+        let (changed, store) = MapperFollowManager::status_changes(&current, &previous);
+
+        assert!(changed.is_empty());
+        assert_eq!(store, [current[0], status(1, RankStatus::Pending)]);
+
+        // Requalification after the reset
+        previous.insert(1, RankStatus::Pending);
+        let current = [
+            status(1, RankStatus::Qualified),
+            status(2, RankStatus::Ranked),
+        ];
+        let (changed, _) = MapperFollowManager::status_changes(&current, &previous);
+
+        assert_eq!(changed, [1]);
+    }
+}
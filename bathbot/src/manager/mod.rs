@@ -6,12 +6,15 @@ pub use self::{
     github::GithubManager,
     guild_config::GuildConfigManager,
     huismetbenen_country::HuismetbenenCountryManager,
+    map_leaderboard::{LeaderboardDiff, MapLeaderboardManager, SNAPSHOT_SIZE, WatchedMap},
+    mapper_follows::{FollowedMapper, MapperFollowManager},
     osu_map::{MapError, MapManager, OsuMap, OsuMapSlim},
     osu_scores::ScoresManager,
     osu_user::OsuUserManager,
     pp::{Mods, PpManager},
     rank_pp_approx::ApproxManager,
     replay::{ReplayError, ReplayManager, ReplaySettings},
+    role_rewards::{RoleReward, RoleRewardChange, RoleRewardManager},
     user_config::UserConfigManager,
 };
 
@@ -22,12 +25,15 @@ mod games;
 mod github;
 mod guild_config;
 mod huismetbenen_country;
+mod map_leaderboard;
+mod mapper_follows;
 mod osu_map;
 mod osu_scores;
 mod osu_user;
 mod pp;
 mod rank_pp_approx;
 mod replay;
+mod role_rewards;
 mod user_config;
 
 #[cfg(feature = "twitch")]
@@ -450,6 +450,16 @@ impl OsuMapSlim {
         self.mapset.creator.as_str()
     }
 
+    /// User id of the mapset's creator
+    pub fn creator_id(&self) -> u32 {
+        self.mapset.user_id as u32
+    }
+
+    /// User id of the difficulty's mapper
+    pub fn mapper_id(&self) -> u32 {
+        self.map.user_id as u32
+    }
+
     pub fn seconds_drain(&self) -> u32 {
         self.map.seconds_drain as u32
     }
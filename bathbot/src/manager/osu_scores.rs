@@ -6,6 +6,11 @@ use rosu_v2::{
     model::score::BeatmapUserScore,
     prelude::{GameMode, GameModsIntermode, OsuError, Score},
 };
+use time::OffsetDateTime;
+use twilight_model::id::{
+    Id,
+    marker::{GuildMarker, UserMarker},
+};
 
 use super::redis::osu::{CachedUser, UserArgs, UserArgsError, UserArgsSlim};
 use crate::core::Context;
@@ -76,6 +81,7 @@ impl ScoresManager {
             manager: self,
             kind: ScoreKind::Top { limit, offset: 0 },
             legacy_scores,
+            archived: false,
         }
     }
 
@@ -87,6 +93,7 @@ impl ScoresManager {
                 include_fails: true,
             },
             legacy_scores,
+            archived: false,
         }
     }
 
@@ -95,6 +102,7 @@ impl ScoresManager {
             manager: self,
             kind: ScoreKind::Pinned { limit: 100 },
             legacy_scores,
+            archived: false,
         }
     }
 
@@ -103,6 +111,7 @@ impl ScoresManager {
             manager: self,
             kind: ScoreKind::UserMap { map_id },
             legacy_scores,
+            archived: false,
         }
     }
 
@@ -110,6 +119,93 @@ impl ScoresManager {
         if let Err(err) = Context::psql().insert_scores_mapsets(scores).await {
             warn!(?err, "Failed to store scores");
         }
+
+        if let Err(err) = self.archive(scores).await {
+            warn!(?err, "Failed to archive scores");
+        }
+    }
+
+    /// Archive scores of users that are linked to a member of a guild which
+    /// opted into the score archive.
+    async fn archive(self, scores: &[Score]) -> Result<()> {
+        let guilds = Context::guild_config().score_archive_guilds();
+
+        if guilds.is_empty() {
+            return Ok(());
+        }
+
+        let mut user_ids: Vec<_> = scores.iter().map(|score| score.user_id).collect();
+        user_ids.sort_unstable();
+        user_ids.dedup();
+
+        let mut members = Vec::new();
+
+        for user_id in user_ids {
+            for guild_id in Self::archiving_guilds(user_id, &guilds).await? {
+                members.push((user_id, guild_id.get()));
+            }
+        }
+
+        if members.is_empty() {
+            return Ok(());
+        }
+
+        let scores: Vec<_> = scores
+            .iter()
+            .filter(|score| members.iter().any(|(user_id, _)| *user_id == score.user_id))
+            .cloned()
+            .collect();
+
+        Context::psql()
+            .insert_scores(&scores, &members)
+            .await
+            .wrap_err("Failed to insert scores")
+    }
+
+    /// Stop archiving the scores of a member that left an opted-in guild.
+    pub async fn remove_archive_member(
+        self,
+        guild_id: Id<GuildMarker>,
+        user_id: Id<UserMarker>,
+    ) -> Result<()> {
+        let Some(osu_id) = Context::user_config().osu_id(user_id).await? else {
+            return Ok(());
+        };
+
+        Context::psql()
+            .delete_score_archive_user(osu_id, guild_id.get())
+            .await
+            .wrap_err("Failed to delete archive user")
+    }
+
+    /// Opted-in guilds that contain the discord user linked to the osu! user.
+    async fn archiving_guilds(
+        user_id: u32,
+        guilds: &[Id<GuildMarker>],
+    ) -> Result<Vec<Id<GuildMarker>>> {
+        let discord_id_opt = Context::user_config()
+            .discord_from_osu_id(user_id)
+            .await
+            .wrap_err("Failed to get discord id")?;
+
+        let Some(discord_id) = discord_id_opt else {
+            return Ok(Vec::new());
+        };
+
+        let mut archiving = Vec::new();
+
+        for &guild_id in guilds {
+            let member_opt = Context::cache()
+                .member(guild_id, discord_id)
+                .await
+                .wrap_err("Failed to get cached member")?;
+
+            if member_opt.is_some() {
+                archiving.push(guild_id);
+            }
+        }
+
+        Ok(archiving)
     }
 }
 
@@ -118,6 +214,7 @@ pub struct ScoreArgs {
     manager: ScoresManager,
     kind: ScoreKind,
     legacy_scores: bool,
+    archived: bool,
 }
 
 #[derive(Copy, Clone)]
@@ -152,8 +249,20 @@ impl ScoreArgs {
         self
     }
 
+    /// Complement top and recent scores with archived scores that the osu!api
+    /// no longer provides.
+    ///
+    /// Only applies to non-legacy scores of users whose scores are archived
+    /// by any guild.
+    pub fn archived(mut self, archived: bool) -> Self {
+        self.archived = archived;
+
+        self
+    }
+
     pub async fn exec(mut self, user_args: UserArgsSlim) -> OsuResult<Vec<Score>> {
         let UserArgsSlim { user_id, mode } = user_args;
+        let kind = self.kind;
         let mut again = true;
         let mut scores = Vec::new();
 
@@ -250,9 +359,15 @@ impl ScoreArgs {
             }
         }
 
+        let with_archive = self.archived && !self.legacy_scores;
+
         let scores_clone = Box::from(scores.as_slice());
         tokio::spawn(async move { self.manager.store(&scores_clone).await });
 
+        if with_archive && let Err(err) = extend_archived(kind, user_id, mode, &mut scores).await {
+            warn!(?err, "Failed to extend scores with archived scores");
+        }
+
         Ok(scores)
     }
 
@@ -279,3 +394,63 @@ impl ScoreArgs {
         }
     }
 }
+
+async fn extend_archived(
+    kind: ScoreKind,
+    user_id: u32,
+    mode: GameMode,
+    scores: &mut Vec<Score>,
+) -> Result<()> {
+    let psql = Context::psql();
+
+    match kind {
+        ScoreKind::Top { limit, .. } => {
+            // Only if the API's window is full can there be further top scores
+            if scores.len() < limit {
+                return Ok(());
+            }
+
+            let min_pp = scores
+                .iter()
+                .filter_map(|score| score.pp)
+                .fold(f32::MAX, f32::min);
+
+            let mut archived: Vec<_> = psql
+                .select_archived_top_scores(user_id, mode)
+                .await
+                .wrap_err("Failed to get archived top scores")?
+                .into_iter()
+                .map(Score::from)
+                .filter(|archived| archived.pp.is_some_and(|pp| pp <= min_pp))
+                .filter(|archived| scores.iter().all(|score| score.map_id != archived.map_id))
+                .collect();
+
+            archived.sort_unstable_by(|a, b| b.pp.unwrap_or(0.0).total_cmp(&a.pp.unwrap_or(0.0)));
+
+            scores.append(&mut archived);
+        }
+        ScoreKind::Recent {
+            limit,
+            include_fails,
+        } => {
+            let Some(remaining) = limit.checked_sub(scores.len()).filter(|&n| n > 0) else {
+                return Ok(());
+            };
+
+            // The API only provides recent scores of the last 24 hours
+            let before = scores
+                .last()
+                .map_or_else(OffsetDateTime::now_utc, |score| score.ended_at);
+
+            let archived = psql
+                .select_archived_recent_scores(user_id, mode, before, include_fails, remaining)
+                .await
+                .wrap_err("Failed to get archived recent scores")?;
+
+            scores.extend(archived.into_iter().map(Score::from));
+        }
+        ScoreKind::Pinned { .. } | ScoreKind::UserMap { .. } => {}
+    }
+
+    Ok(())
+}
@@ -35,8 +35,12 @@ use crate::{
     util::{interaction::InteractionCommand, osu::MapOrScore},
 };
 
+pub use self::response_cache::RedisResponseCache;
+
 pub mod osu;
 
+mod response_cache;
+
 // type RedisResult<T, A = T, E = Report> = Result<RedisData<T, A>, E>;
 type RedisResult<T> = Result<CachedArchive<T>, RedisError>;
 
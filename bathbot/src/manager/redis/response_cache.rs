@@ -0,0 +1,58 @@
+use std::time::Duration;
+
+use bathbot_client::{CacheBackend, CacheFuture, ResponseCache, Site};
+use eyre::{Result, WrapErr};
+
+use crate::core::Context;
+
+/// Stores responses of [`BathbotClient`](bathbot_client::Client) requests in
+/// redis.
+pub struct RedisResponseCache;
+
+impl RedisResponseCache {
+    /// Response cache for sites whose responses are not already cached as
+    /// archived data through [`RedisManager`](super::RedisManager).
+    ///
+    /// Osekai is left out on purpose since its medals, rarities, badges, and
+    /// rankings are cached by the manager already.
+    pub fn response_cache() -> ResponseCache {
+        const MINUTE: u64 = 60;
+        const HOUR: u64 = 60 * MINUTE;
+
+        let ttl = Duration::from_secs;
+
+        ResponseCache::new(Self)
+            .site_ttl(Site::Flags, ttl(24 * HOUR))
+            .site_ttl(Site::OsuBadge, ttl(24 * HOUR))
+            .site_ttl(Site::OsuMapsetCover, ttl(6 * HOUR))
+            .site_ttl(Site::Huismetbenen, ttl(5 * MINUTE))
+            .site_ttl(Site::Relax, ttl(5 * MINUTE))
+            .site_ttl(Site::Respektive, ttl(5 * MINUTE))
+    }
+}
+
+impl CacheBackend for RedisResponseCache {
+    fn get<'a>(&'a self, key: &'a str) -> CacheFuture<'a, Result<Option<Vec<u8>>>> {
+        Box::pin(async move {
+            match Context::cache().fetch_raw(key).await {
+                Ok(Ok(bytes)) => Ok(Some(bytes)),
+                Ok(Err(_)) => Ok(None),
+                Err(err) => Err(err).wrap_err("Failed to fetch cached response"),
+            }
+        })
+    }
+
+    fn set<'a>(
+        &'a self,
+        key: &'a str,
+        bytes: Vec<u8>,
+        expire: Duration,
+    ) -> CacheFuture<'a, Result<()>> {
+        Box::pin(async move {
+            Context::cache()
+                .store_new(key, &bytes, expire.as_secs())
+                .await
+                .wrap_err("Failed to store response")
+        })
+    }
+}
@@ -0,0 +1,341 @@
+use std::{
+    collections::HashMap,
+    fmt::{Display, Formatter, Result as FmtResult},
+};
+
+use bathbot_model::twilight::id::ArchivedId;
+use bathbot_psql::{
+    Database,
+    model::configs::{DbRoleReward, DbRoleRewardStats, RoleRewardKind},
+};
+use bathbot_util::{IntHasher, numbers::WithComma};
+use eyre::{Report, Result, WrapErr};
+use rosu_v2::prelude::GameMode;
+use tokio::time::{Duration, sleep};
+use twilight_model::id::{
+    Id,
+    marker::{GuildMarker, RoleMarker, UserMarker},
+};
+
+use crate::core::Context;
+
+/// Maximum amount of role updates per guild and run.
+/// Remaining updates are handled in the next run.
+const MAX_UPDATES: usize = 100;
+
+/// Delay between two role updates so guilds with many members don't
+/// exhaust the ratelimit for everything else.
+const UPDATE_DELAY: Duration = Duration::from_millis(500);
+
+#[derive(Copy, Clone)]
+pub struct RoleRewardManager {
+    psql: &'static Database,
+}
+
+impl RoleRewardManager {
+    pub fn new() -> Self {
+        Self {
+            psql: Context::psql(),
+        }
+    }
+
+    pub async fn get(self, guild_id: Id<GuildMarker>) -> Result<Vec<RoleReward>> {
+        let rewards = self
+            .psql
+            .select_guild_role_rewards(guild_id)
+            .await
+            .wrap_err("Failed to get guild role rewards")?
+            .into_iter()
+            .filter_map(RoleReward::try_from_db)
+            .collect();
+
+        Ok(rewards)
+    }
+
+    pub async fn get_all(self) -> Result<HashMap<Id<GuildMarker>, Vec<RoleReward>, IntHasher>> {
+        let rewards = self
+            .psql
+            .select_all_role_rewards()
+            .await
+            .wrap_err("Failed to get all role rewards")?;
+
+        let mut guilds = HashMap::with_hasher(IntHasher);
+
+        for reward in rewards {
+            let guild_id = Id::new(reward.guild_id as u64);
+
+            if let Some(reward) = RoleReward::try_from_db(reward) {
+                guilds.entry(guild_id).or_insert_with(Vec::new).push(reward);
+            }
+        }
+
+        Ok(guilds)
+    }
+
+    pub async fn set(self, guild_id: Id<GuildMarker>, reward: &RoleReward) -> Result<()> {
+        self.psql
+            .upsert_role_reward(
+                guild_id,
+                reward.role_id,
+                reward.kind,
+                reward.mode.map(|mode| mode as i16),
+                reward.min,
+                reward.max,
+            )
+            .await
+            .wrap_err("Failed to upsert role reward")
+    }
+
+    /// Returns whether a reward was removed
+    pub async fn remove(self, guild_id: Id<GuildMarker>, role_id: Id<RoleMarker>) -> Result<bool> {
+        self.psql
+            .delete_role_reward(guild_id, role_id)
+            .await
+            .wrap_err("Failed to delete role reward")
+    }
+
+    /// Compare the roles of all members against the rewards they qualify for.
+    ///
+    /// Members that are not linked or whose stats have not been cached yet
+    /// don't qualify for any reward.
+    pub async fn changes(
+        self,
+        guild_id: Id<GuildMarker>,
+        rewards: &[RoleReward],
+    ) -> Result<Vec<RoleRewardChange>> {
+        if rewards.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let cache = Context::cache();
+
+        let members = cache
+            .members(guild_id)
+            .await
+            .wrap_err("Failed to get members")?;
+
+        let discord_ids: Vec<_> = members.iter().map(|&id| id as i64).collect();
+
+        let rows = self
+            .psql
+            .select_role_reward_stats(&discord_ids)
+            .await
+            .wrap_err("Failed to get role reward stats")?;
+
+        let stats = MemberStats::collect(rows);
+        let mut changes = Vec::new();
+
+        for member_id in members {
+            let user_id = Id::new(member_id);
+
+            let member = match cache.member(guild_id, user_id).await {
+                Ok(Some(member)) => member,
+                Ok(None) => continue,
+                Err(err) => {
+                    return Err(Report::new(err).wrap_err("Failed to get member"));
+                }
+            };
+
+            let member_stats = stats.get(&member_id);
+
+            for reward in rewards {
+                let qualifies = member_stats.is_some_and(|stats| reward.matches(stats));
+
+                let has_role = member
+                    .roles
+                    .iter()
+                    .copied()
+                    .map(ArchivedId::to_native)
+                    .any(|role| role == reward.role_id);
+
+                if qualifies != has_role {
+                    changes.push(RoleRewardChange {
+                        user_id,
+                        role_id: reward.role_id,
+                        add: qualifies,
+                    });
+                }
+            }
+        }
+
+        Ok(changes)
+    }
+
+    /// Apply role changes one by one with a delay in between.
+    ///
+    /// Stops at the first failure since it's most likely due to missing
+    /// permissions which would make all other updates fail as well.
+    /// Returns the amount of applied changes.
+    pub async fn apply(
+        self,
+        guild_id: Id<GuildMarker>,
+        changes: &[RoleRewardChange],
+    ) -> Result<usize> {
+        let http = Context::http();
+
+        for (change, i) in changes.iter().take(MAX_UPDATES).zip(0..) {
+            if i > 0 {
+                sleep(UPDATE_DELAY).await;
+            }
+
+            let res = if change.add {
+                http.add_guild_member_role(guild_id, change.user_id, change.role_id)
+                    .await
+            } else {
+                http.remove_guild_member_role(guild_id, change.user_id, change.role_id)
+                    .await
+            };
+
+            if let Err(err) = res {
+                return Err(Report::new(err).wrap_err(format!(
+                    "Failed to update role {} of user {} after {i} updates",
+                    change.role_id, change.user_id
+                )));
+            }
+        }
+
+        Ok(changes.len().min(MAX_UPDATES))
+    }
+}
+
+pub struct RoleReward {
+    pub role_id: Id<RoleMarker>,
+    pub kind: RoleRewardKind,
+    /// Always set if the kind requires a mode
+    pub mode: Option<GameMode>,
+    /// Inclusive lower bound
+    pub min: Option<f64>,
+    /// Inclusive upper bound
+    pub max: Option<f64>,
+}
+
+impl RoleReward {
+    fn try_from_db(reward: DbRoleReward) -> Option<Self> {
+        let kind = RoleRewardKind::try_from(reward.kind).ok()?;
+        let mode = reward.gamemode.map(|mode| GameMode::from(mode as u8));
+
+        if kind.requires_mode() && mode.is_none() {
+            return None;
+        }
+
+        Some(Self {
+            role_id: Id::new(reward.role_id as u64),
+            kind,
+            mode,
+            min: reward.min_value,
+            max: reward.max_value,
+        })
+    }
+
+    fn matches(&self, stats: &MemberStats) -> bool {
+        let value = match self.kind {
+            RoleRewardKind::Badges => Some(stats.badges as f64),
+            RoleRewardKind::Medals => Some(stats.medals as f64),
+            RoleRewardKind::GlobalRank | RoleRewardKind::CountryRank | RoleRewardKind::Pp => self
+                .mode
+                .and_then(|mode| stats.modes[mode as usize].as_ref())
+                .and_then(|mode_stats| match self.kind {
+                    // A rank of 0 means the user is inactive
+                    RoleRewardKind::GlobalRank => {
+                        (mode_stats.global_rank > 0).then_some(mode_stats.global_rank as f64)
+                    }
+                    RoleRewardKind::CountryRank => {
+                        (mode_stats.country_rank > 0).then_some(mode_stats.country_rank as f64)
+                    }
+                    _ => Some(mode_stats.pp as f64),
+                }),
+        };
+
+        value.is_some_and(|value| {
+            self.min.is_none_or(|min| min <= value) && self.max.is_none_or(|max| value <= max)
+        })
+    }
+}
+
+impl Display for RoleReward {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        let kind = match self.kind {
+            RoleRewardKind::GlobalRank => "Global rank",
+            RoleRewardKind::CountryRank => "Country rank",
+            RoleRewardKind::Pp => "PP",
+            RoleRewardKind::Badges => "Badges",
+            RoleRewardKind::Medals => "Medals",
+        };
+
+        write!(f, "<@&{}>: {kind}", self.role_id)?;
+
+        if let Some(mode) = self.mode {
+            let mode = match mode {
+                GameMode::Osu => "osu!",
+                GameMode::Taiko => "taiko",
+                GameMode::Catch => "ctb",
+                GameMode::Mania => "mania",
+            };
+
+            write!(f, " ({mode})")?;
+        }
+
+        match (self.min, self.max) {
+            (Some(min), Some(max)) => {
+                write!(
+                    f,
+                    " between {} and {}",
+                    WithComma::new(min),
+                    WithComma::new(max)
+                )
+            }
+            (Some(min), None) => write!(f, " of at least {}", WithComma::new(min)),
+            (None, Some(max)) => write!(f, " of at most {}", WithComma::new(max)),
+            (None, None) => f.write_str(" of any value"),
+        }
+    }
+}
+
+pub struct RoleRewardChange {
+    pub user_id: Id<UserMarker>,
+    pub role_id: Id<RoleMarker>,
+    /// Whether the role is added or removed
+    pub add: bool,
+}
+
+struct MemberStats {
+    badges: i32,
+    medals: i32,
+    modes: [Option<ModeStats>; 4],
+}
+
+struct ModeStats {
+    pp: f32,
+    global_rank: i32,
+    country_rank: i32,
+}
+
+impl MemberStats {
+    fn collect(rows: Vec<DbRoleRewardStats>) -> HashMap<u64, Self, IntHasher> {
+        let mut stats = HashMap::with_capacity_and_hasher(rows.len(), IntHasher);
+
+        for row in rows {
+            let entry = stats.entry(row.discord_id as u64).or_insert_with(|| Self {
+                badges: row.badges,
+                medals: row.medals,
+                modes: [None, None, None, None],
+            });
+
+            let (Some(mode), Some(pp), Some(global_rank), Some(country_rank)) =
+                (row.gamemode, row.pp, row.global_rank, row.country_rank)
+            else {
+                continue;
+            };
+
+            if let Some(slot) = entry.modes.get_mut(mode as usize) {
+                *slot = Some(ModeStats {
+                    pp,
+                    global_rank,
+                    country_rank,
+                });
+            }
+        }
+
+        stats
+    }
+}
@@ -1,8 +1,11 @@
-use bathbot_psql::Database;
+use bathbot_psql::{Database, model::tracked_stream::DbTrackedTwitchStream};
 use bathbot_util::CowUtils;
 use eyre::{Result, WrapErr};
 use rosu_v2::request::UserId;
-use twilight_model::id::{Id, marker::ChannelMarker};
+use twilight_model::id::{
+    Id,
+    marker::{ChannelMarker, MessageMarker},
+};
 
 use crate::core::Context;
 
@@ -58,4 +61,43 @@ impl TwitchManager {
             .await
             .wrap_err("failed to remove tracked twitch streams")
     }
+
+    /// Tracking entries of all channels that track the twitch user
+    pub async fn channels(self, twitch_id: u64) -> Result<Vec<DbTrackedTwitchStream>> {
+        self.psql
+            .select_tracked_twitch_stream_channels(twitch_id)
+            .await
+            .wrap_err("failed to get tracked twitch stream channels")
+    }
+
+    pub async fn settings(
+        self,
+        channel: Id<ChannelMarker>,
+        twitch_id: u64,
+    ) -> Result<Option<DbTrackedTwitchStream>> {
+        self.psql
+            .select_tracked_twitch_stream(channel, twitch_id)
+            .await
+            .wrap_err("failed to get tracked twitch stream")
+    }
+
+    pub async fn update_settings(self, entry: &DbTrackedTwitchStream) -> Result<()> {
+        self.psql
+            .update_tracked_twitch_stream_settings(entry)
+            .await
+            .wrap_err("failed to update tracked twitch stream settings")
+    }
+
+    /// Remember or forget the notification of an ongoing stream
+    pub async fn set_notif(
+        self,
+        channel: Id<ChannelMarker>,
+        twitch_id: u64,
+        msg: Option<Id<MessageMarker>>,
+    ) -> Result<()> {
+        self.psql
+            .update_tracked_twitch_stream_notif(channel, twitch_id, msg.map(Id::get))
+            .await
+            .wrap_err("failed to update tracked twitch stream notif")
+    }
 }
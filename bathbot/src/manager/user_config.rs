@@ -1,7 +1,7 @@
 use bathbot_model::embed_builder::ScoreEmbedSettings;
 use bathbot_psql::{
     Database,
-    model::configs::{OsuUserId, SkinEntry, UserConfig},
+    model::configs::{Language, OsuUserId, SkinEntry, UserConfig},
 };
 use bathbot_util::CowUtils;
 use eyre::{Result, WrapErr};
@@ -48,6 +48,13 @@ impl UserConfigManager {
             .wrap_err("Failed to get user mode from DB")
     }
 
+    pub async fn language(self, user_id: Id<UserMarker>) -> Result<Option<Language>> {
+        self.psql
+            .select_user_language(user_id)
+            .await
+            .wrap_err("Failed to get user language from DB")
+    }
+
     pub async fn osu_id(self, user_id: Id<UserMarker>) -> Result<Option<u32>> {
         self.psql
             .select_osu_id_by_discord_id(user_id)
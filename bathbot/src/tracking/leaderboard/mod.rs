@@ -0,0 +1,189 @@
+use std::{collections::HashMap, num::NonZeroU64, sync::RwLock};
+
+use bathbot_psql::Database;
+use bathbot_util::IntHasher;
+use eyre::{Result, WrapErr};
+use rosu_v2::{
+    model::GameMode,
+    prelude::{RankStatus, Score},
+};
+use twilight_model::id::{Id, marker::ChannelMarker};
+
+pub use self::params::TrackLeaderboardParams;
+use crate::core::Context;
+
+mod params;
+mod process_score;
+
+type Channels = HashMap<NonZeroU64, TrackLeaderboardParams, IntHasher>;
+type TrackedUsers = RwLock<HashMap<u32, [Channels; 4], IntHasher>>;
+
+/// Tracks users' scores that land on a map's global leaderboard, regardless
+/// of whether they're in the user's top scores.
+pub struct LeaderboardTracking {
+    users: TrackedUsers,
+}
+
+impl LeaderboardTracking {
+    // `Context` won't be initialized at this point so we require an explicit
+    // `Database` argument.
+    pub async fn new(psql: &Database) -> Result<Self> {
+        let data = psql
+            .select_tracked_osu_leaderboard_users()
+            .await
+            .wrap_err("Failed to fetch tracked leaderboard users")?;
+
+        let mut users = HashMap::<u32, [Channels; 4], IntHasher>::default();
+
+        for user in data {
+            let Some(channel_id) = NonZeroU64::new(user.channel_id as u64) else {
+                continue;
+            };
+
+            let params = TrackLeaderboardParams::new(Some(user.max_rank as u8));
+
+            users.entry(user.user_id as u32).or_default()[user.gamemode as usize]
+                .insert(channel_id, params);
+        }
+
+        Ok(Self {
+            users: RwLock::new(users),
+        })
+    }
+
+    fn users() -> &'static TrackedUsers {
+        &Context::leaderboard_tracking().users
+    }
+
+    pub(super) fn process_score(score: &Score) {
+        if !score.passed {
+            return;
+        }
+
+        // Skip unranked maps before requesting anything
+        let ranked = match score.map {
+            Some(ref map) => matches!(map.status, RankStatus::Ranked | RankStatus::Approved),
+            None => score.ranked != Some(false),
+        };
+
+        if !ranked {
+            return;
+        }
+
+        let channels = Self::users()
+            .read()
+            .unwrap()
+            .get(&score.user_id)
+            .map(|modes| modes[score.mode as usize].clone())
+            .filter(|channels| !channels.is_empty());
+
+        let Some(channels) = channels else {
+            return;
+        };
+
+        log!(info:
+            user = score.user_id,
+            score_id = score.id,
+            map = score.map_id,
+            "Checking leaderboard for tracked score",
+        );
+
+        tokio::spawn(process_score::process_score(score.clone(), channels));
+    }
+
+    pub async fn remove_channel(channel: Id<ChannelMarker>, mode: Option<GameMode>) {
+        let channel_id = channel.into_nonzero();
+
+        for modes in Self::users().write().unwrap().values_mut() {
+            match mode {
+                Some(mode) => {
+                    modes[mode as usize].remove(&channel_id);
+                }
+                None => {
+                    for channels in modes.iter_mut() {
+                        channels.remove(&channel_id);
+                    }
+                }
+            }
+        }
+
+        let delete_fut =
+            Context::psql().delete_tracked_osu_leaderboard_channel(channel.get(), mode);
+
+        if let Err(err) = delete_fut.await {
+            error!(
+                %channel,
+                ?mode,
+                ?err,
+                "Failed to remove tracked leaderboard users of channel"
+            );
+        }
+    }
+
+    pub async fn remove_user(user_id: u32, mode: Option<GameMode>, channel: Id<ChannelMarker>) {
+        let channel_id = channel.into_nonzero();
+
+        if let Some(modes) = Self::users().write().unwrap().get_mut(&user_id) {
+            match mode {
+                Some(mode) => {
+                    modes[mode as usize].remove(&channel_id);
+                }
+                None => {
+                    for channels in modes.iter_mut() {
+                        channels.remove(&channel_id);
+                    }
+                }
+            }
+        }
+
+        let delete_fut =
+            Context::psql().delete_tracked_osu_leaderboard_user(user_id, mode, channel.get());
+
+        if let Err(err) = delete_fut.await {
+            error!(
+                user_id,
+                ?mode,
+                %channel,
+                ?err,
+                "Failed to delete tracked leaderboard user"
+            );
+        }
+    }
+
+    pub async fn add_user(
+        user_id: u32,
+        mode: GameMode,
+        channel: Id<ChannelMarker>,
+        params: TrackLeaderboardParams,
+    ) -> Result<()> {
+        Context::psql()
+            .upsert_tracked_osu_leaderboard_user(user_id, mode, channel.get(), params.max_rank())
+            .await
+            .wrap_err("Failed to upsert tracked leaderboard user")?;
+
+        Self::users().write().unwrap().entry(user_id).or_default()[mode as usize]
+            .insert(channel.into_nonzero(), params);
+
+        Ok(())
+    }
+
+    pub async fn tracked_users_in_channel(
+        channel: Id<ChannelMarker>,
+    ) -> Result<Vec<(u32, GameMode, TrackLeaderboardParams)>> {
+        let entries = Context::psql()
+            .select_tracked_osu_leaderboard_users_channel(channel.get())
+            .await
+            .wrap_err("Failed to fetch users")?
+            .into_iter()
+            .map(|entry| {
+                let user_id = entry.user_id as u32;
+                let mode = GameMode::from(entry.gamemode as u8);
+                let params = TrackLeaderboardParams::new(Some(entry.max_rank as u8));
+
+                (user_id, mode, params)
+            })
+            .collect();
+
+        Ok(entries)
+    }
+}
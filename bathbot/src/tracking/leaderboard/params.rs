@@ -0,0 +1,33 @@
+#[derive(Copy, Clone)]
+pub struct TrackLeaderboardParams {
+    /// `1..=100`
+    max_rank: u8,
+}
+
+impl TrackLeaderboardParams {
+    pub const DEFAULT_MAX_RANK: u8 = 1;
+    pub const MAX_RANK: u8 = 100;
+
+    pub fn new(max_rank: Option<u8>) -> Self {
+        Self {
+            max_rank: max_rank
+                .unwrap_or(Self::DEFAULT_MAX_RANK)
+                .clamp(1, Self::MAX_RANK),
+        }
+    }
+
+    pub const fn max_rank(&self) -> u8 {
+        self.max_rank
+    }
+
+    /// Whether the 1-indexed global leaderboard rank is good enough.
+    pub const fn matches(&self, rank: usize) -> bool {
+        rank <= self.max_rank as usize
+    }
+}
+
+impl Default for TrackLeaderboardParams {
+    fn default() -> Self {
+        Self::new(None)
+    }
+}
@@ -0,0 +1,148 @@
+use std::slice;
+
+use bathbot_util::constants::UNKNOWN_CHANNEL;
+use rosu_v2::prelude::{RankStatus, Score};
+use twilight_http::{
+    api_error::{ApiError, GeneralApiError},
+    error::ErrorType as TwilightErrorType,
+};
+use twilight_model::id::Id;
+
+use super::{Channels, LeaderboardTracking};
+use crate::{
+    core::{BotMetrics, Context},
+    manager::redis::osu::{UserArgs, UserArgsSlim},
+    tracking::notif::{claim_notif, jitter, score_embed},
+};
+
+pub async fn process_score(score: Score, channels: Channels) {
+    // Add delay so that the leaderboard will most likely contain the score
+    // already. The jitter in the delay should improve db & api congestion.
+    tokio::time::sleep(jitter()).await;
+
+    let user_id = score.user_id;
+    let score_id = score.id;
+    let map_id = score.map_id;
+    let mode = score.mode;
+
+    let checksum = score.map.as_ref().and_then(|map| map.checksum.as_deref());
+
+    let map = match Context::osu_map().map(map_id, checksum).await {
+        Ok(map) => map,
+        Err(err) => {
+            log!(warn:
+                map = map_id,
+                user = user_id,
+                score_id,
+                ?err,
+                "Failed to get map"
+            );
+
+            return;
+        }
+    };
+
+    if !matches!(map.status(), RankStatus::Ranked | RankStatus::Approved) {
+        return;
+    }
+
+    let limit = channels
+        .values()
+        .map(|params| params.max_rank())
+        .max()
+        .unwrap_or(1);
+
+    let user_args = UserArgsSlim::user_id(user_id).mode(mode);
+    let user_fut = Context::redis().osu_user(UserArgs::Args(user_args));
+
+    let leaderboard_fut =
+        Context::osu_scores().map_leaderboard(map_id, mode, None, u32::from(limit), false);
+
+    let (user, leaderboard) = match tokio::join!(user_fut, leaderboard_fut) {
+        (Ok(user), Ok(leaderboard)) => (user, leaderboard),
+        (Err(err), _) => {
+            log!(warn: user = user_id, ?mode, score_id, ?err, "Failed to get user");
+
+            return;
+        }
+        (_, Err(err)) => {
+            log!(warn:
+                map = map_id,
+                user = user_id,
+                score_id,
+                ?err,
+                "Failed to get map leaderboard"
+            );
+
+            return;
+        }
+    };
+
+    let Some(idx) = leaderboard.iter().position(|s| s.id == score_id) else {
+        log!(info:
+            user = user_id,
+            map = map_id,
+            score_id,
+            "Not on leaderboard",
+        );
+
+        return;
+    };
+
+    let rank = idx + 1;
+
+    let channels: Vec<_> = channels
+        .iter()
+        .filter_map(|(channel_id, params)| params.matches(rank).then_some(*channel_id))
+        .collect();
+
+    if channels.is_empty() {
+        return;
+    }
+
+    BotMetrics::osu_leaderboard_tracking_hit(mode);
+
+    log!(info:
+        user = user_id,
+        map = map_id,
+        score_id,
+        rank,
+        "New leaderboard score",
+    );
+
+    let (builder, ..) = score_embed(&user, score, map, None, Some(rank)).await;
+    let embed = builder.build();
+    let embeds = slice::from_ref(&embed);
+
+    let http = Context::http();
+
+    for channel_id in channels {
+        let channel = Id::new(channel_id.get());
+
+        if !claim_notif(score_id, channel) {
+            continue;
+        }
+
+        let Err(err) = http.create_message(channel).embeds(embeds).await else {
+            continue;
+        };
+
+        let TwilightErrorType::Response { error, .. } = err.kind() else {
+            log!(warn: %channel, ?err, "Error while sending notif");
+
+            continue;
+        };
+
+        let ApiError::General(GeneralApiError {
+            code: UNKNOWN_CHANNEL,
+            ..
+        }) = error
+        else {
+            log!(warn: %channel, ?error, "Error from API while sending notif");
+
+            continue;
+        };
+
+        LeaderboardTracking::remove_channel(channel, None).await;
+    }
+}
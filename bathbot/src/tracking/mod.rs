@@ -1,13 +1,23 @@
+/// Logs an event and sets its target to `"tracking"`.
+macro_rules! log {
+    ( $level:ident: $( $arg:tt )* ) => {
+        tracing::$level!(target: "tracking", $( $arg )*);
+    };
+}
+
 #[cfg(feature = "twitch")]
 pub use self::twitch::online_streams::OnlineTwitchStreams;
 #[cfg(feature = "twitchtracking")]
 pub use self::twitch::twitch_loop::twitch_tracking_loop;
 pub use self::{
+    leaderboard::{LeaderboardTracking, TrackLeaderboardParams},
     ordr::{Ordr, OrdrReceivers},
     osu::{OsuTracking, TrackEntryParams},
     scores_ws::{ScoresWebSocket, ScoresWebSocketDisconnect},
 };
 
+mod leaderboard;
+mod notif;
 mod ordr;
 mod osu;
 mod scores_ws;
@@ -0,0 +1,244 @@
+use std::{
+    sync::Mutex,
+    time::{Duration, Instant},
+};
+
+use bathbot_model::embed_builder::{
+    ComboValue, HitresultsValue, ScoreEmbedSettings, SettingValue, SettingsButtons, SettingsImage,
+    Value,
+};
+use bathbot_psql::model::configs::ScoreData;
+use bathbot_util::EmbedBuilder;
+use rand::Rng;
+use rosu_v2::{model::GameMode, prelude::Score};
+use twilight_model::id::{Id, marker::ChannelMarker};
+
+use crate::{
+    active::{
+        IActiveMessage,
+        impls::{SingleScoreContent, SingleScorePagination},
+    },
+    commands::utility::ScoreEmbedDataWrap,
+    manager::{OsuMap, redis::osu::CachedUser},
+};
+
+/// Random [`Duration`] between 30s and 60s
+pub(super) fn jitter() -> Duration {
+    rand::thread_rng().gen_range(Duration::from_secs(30)..Duration::from_secs(60))
+}
+
+/// Score ids and channels of recent notifications alongside their time.
+///
+/// A score may be both a new top score and on a map's leaderboard so both
+/// trackings could notify the same channel.
+static NOTIFIED: Mutex<Vec<(u64, Id<ChannelMarker>, Instant)>> = Mutex::new(Vec::new());
+
+/// Returns `false` if the channel was already notified about the score.
+pub(super) fn claim_notif(score_id: u64, channel: Id<ChannelMarker>) -> bool {
+    // Longer than the jitter so that both trackings are covered
+    const EXPIRE: Duration = Duration::from_secs(5 * 60);
+
+    let mut notified = NOTIFIED.lock().unwrap();
+    notified.retain(|(.., at)| at.elapsed() < EXPIRE);
+
+    if notified
+        .iter()
+        .any(|(id, notified_channel, _)| *id == score_id && *notified_channel == channel)
+    {
+        return false;
+    }
+
+    notified.push((score_id, channel, Instant::now()));
+
+    true
+}
+
+/// Builds the embed of a score notification.
+///
+/// Also returns the map's max combo and star rating.
+pub(super) async fn score_embed(
+    user: &CachedUser,
+    score: Score,
+    map: OsuMap,
+    pb_idx: Option<usize>,
+    global_idx: Option<usize>,
+) -> (EmbedBuilder, Option<u32>, Option<f32>) {
+    let settings = match score.mode {
+        GameMode::Mania => create_mania_settings(),
+        _ => create_settings(),
+    };
+
+    let score_data = ScoreData::Lazer;
+    let msg_owner = Id::new(1);
+    let content = SingleScoreContent::None;
+
+    let embed_data = ScoreEmbedDataWrap::new_custom(score, map, pb_idx, global_idx).await;
+
+    // This is always `Some` considering `ScoreEmbedDataWrap::new_custom`
+    // creates *full* data but let's map regardless to be extra sure.
+    let max_combo = embed_data.try_get().map(|data| data.max_combo);
+    let stars = embed_data.try_get().map(|data| data.stars);
+
+    let entries = Box::<[_]>::from([embed_data]);
+
+    let mut pagination =
+        SingleScorePagination::new(user, entries, settings, score_data, msg_owner, content);
+
+    match pagination.build_page().await {
+        Ok(data) => (data.into_embed(), max_combo, stars),
+        // Unreachable because `async_build_page` can only fail while
+        // converting to full score data but it already starts off as
+        // full.
+        Err(_) => Default::default(),
+    }
+}
+
+fn create_settings() -> ScoreEmbedSettings {
+    ScoreEmbedSettings {
+        values: vec![
+            SettingValue {
+                inner: Value::Grade,
+                y: 0,
+            },
+            SettingValue {
+                inner: Value::Mods,
+                y: 0,
+            },
+            SettingValue {
+                inner: Value::Score,
+                y: 0,
+            },
+            SettingValue {
+                inner: Value::Accuracy,
+                y: 0,
+            },
+            SettingValue {
+                inner: Value::Combo(Default::default()),
+                y: 0,
+            },
+            SettingValue {
+                inner: Value::Pp(Default::default()),
+                y: 1,
+            },
+            SettingValue {
+                inner: Value::Hitresults(HitresultsValue::Full),
+                y: 1,
+            },
+            SettingValue {
+                inner: Value::Length,
+                y: 2,
+            },
+            SettingValue {
+                inner: Value::Cs,
+                y: 2,
+            },
+            SettingValue {
+                inner: Value::Ar,
+                y: 2,
+            },
+            SettingValue {
+                inner: Value::Od,
+                y: 2,
+            },
+            SettingValue {
+                inner: Value::Hp,
+                y: 2,
+            },
+            SettingValue {
+                inner: Value::Bpm(Default::default()),
+                y: 2,
+            },
+            SettingValue {
+                inner: Value::Mapper(Default::default()),
+                y: SettingValue::FOOTER_Y,
+            },
+            SettingValue {
+                inner: Value::ScoreDate,
+                y: SettingValue::FOOTER_Y,
+            },
+        ],
+        show_artist: true,
+        show_sr_in_title: true,
+        image: SettingsImage::Thumbnail,
+        buttons: SettingsButtons {
+            pagination: false,
+            render: false,
+            miss_analyzer: false,
+        },
+    }
+}
+
+fn create_mania_settings() -> ScoreEmbedSettings {
+    ScoreEmbedSettings {
+        values: vec![
+            SettingValue {
+                inner: Value::Grade,
+                y: 0,
+            },
+            SettingValue {
+                inner: Value::Mods,
+                y: 0,
+            },
+            SettingValue {
+                inner: Value::Score,
+                y: 0,
+            },
+            SettingValue {
+                inner: Value::Accuracy,
+                y: 0,
+            },
+            SettingValue {
+                inner: Value::Combo(ComboValue { max: false }),
+                y: 0,
+            },
+            SettingValue {
+                inner: Value::Ratio,
+                y: 0,
+            },
+            SettingValue {
+                inner: Value::Pp(Default::default()),
+                y: 1,
+            },
+            SettingValue {
+                inner: Value::Hitresults(HitresultsValue::Full),
+                y: 1,
+            },
+            SettingValue {
+                inner: Value::Length,
+                y: 2,
+            },
+            SettingValue {
+                inner: Value::Od,
+                y: 2,
+            },
+            SettingValue {
+                inner: Value::Hp,
+                y: 2,
+            },
+            SettingValue {
+                inner: Value::Bpm(Default::default()),
+                y: 2,
+            },
+            SettingValue {
+                inner: Value::CountSliders(Default::default()),
+                y: 2,
+            },
+            SettingValue {
+                inner: Value::Mapper(Default::default()),
+                y: SettingValue::FOOTER_Y,
+            },
+            SettingValue {
+                inner: Value::ScoreDate,
+                y: SettingValue::FOOTER_Y,
+            },
+        ],
+        show_artist: true,
+        show_sr_in_title: true,
+        image: SettingsImage::Thumbnail,
+        buttons: SettingsButtons {
+            pagination: false,
+            render: false,
+            miss_analyzer: false,
+        },
+    }
+}
@@ -10,7 +10,10 @@ use std::{
 
 use bathbot_psql::model::osu::DbTrackedOsuUser;
 use bathbot_util::IntHasher;
-use rosu_v2::{model::GameMode, prelude::Score};
+use rosu_v2::{
+    model::GameMode,
+    prelude::{RankStatus, Score},
+};
 use time::OffsetDateTime;
 
 use super::TrackEntryParams;
@@ -112,7 +115,17 @@ impl TrackEntry {
                 user.max_index.map(|n| n as u8),
             )
             .with_pp(user.min_pp, user.max_pp)
-            .with_combo_percent(user.min_combo_percent, user.max_combo_percent);
+            .with_combo_percent(user.min_combo_percent, user.max_combo_percent)
+            .with_stars(user.min_stars, user.max_stars)
+            .with_mods(
+                user.mods_include.map(|bits| bits as u32),
+                user.mods_exclude.map(|bits| bits as u32),
+            )
+            .with_mapper(user.mapper_id.map(|mapper_id| mapper_id as u32))
+            .with_status(
+                user.map_status
+                    .and_then(|status| RankStatus::try_from(status as i8).ok()),
+            );
 
         self.channels.write().unwrap().insert(channel_id, params);
     }
@@ -1,10 +1,3 @@
-/// Logs an event and sets its target to `"tracking"`.
-macro_rules! log {
-    ( $level:ident: $( $arg:tt )* ) => {
-        tracing::$level!(target: "tracking", $( $arg )*);
-    };
-}
-
 use std::{collections::HashMap, sync::RwLock};
 
 use bathbot_psql::Database;
@@ -1,7 +1,13 @@
-use std::fmt::{Display, Formatter, Result as FmtResult};
+use std::{
+    fmt::{Display, Formatter, Result as FmtResult, Write},
+    num::NonZeroU32,
+};
 
 use bathbot_psql::model::osu::DbTrackedOsuUserInChannel;
-use rosu_v2::model::GameMode;
+use rosu_v2::{
+    model::GameMode,
+    prelude::{GameModsIntermode, RankStatus},
+};
 
 #[derive(Copy, Clone)]
 pub struct TrackEntryParams {
@@ -11,18 +17,32 @@ pub struct TrackEntryParams {
     pp: Range<f32>,
     /// 0.0..=100.0
     combo_percent: Range<f32>,
+    /// `0.0..`
+    stars: Range<f32>,
+    /// Legacy bits of mods that a score must contain
+    mods_include: u32,
+    /// Legacy bits of mods that a score must not contain
+    mods_exclude: u32,
+    /// User id of a mapper that must have created the mapset or the difficulty
+    mapper: Option<NonZeroU32>,
+    status: Option<RankStatus>,
 }
 
 impl TrackEntryParams {
     pub const DEFAULT_MAX_COMBO_PERCENT: f32 = 100.0;
     pub const DEFAULT_MAX_INDEX: u8 = 100;
     pub const DEFAULT_MAX_PP: f32 = f32::INFINITY;
+    pub const DEFAULT_MAX_STARS: f32 = f32::INFINITY;
     pub const DEFAULT_MIN_COMBO_PERCENT: f32 = 0.0;
     pub const DEFAULT_MIN_INDEX: u8 = 1;
     pub const DEFAULT_MIN_PP: f32 = 0.0;
-    // Compile-time assertion that `TrackEntryParams` is no larger than 20
+    pub const DEFAULT_MIN_STARS: f32 = 0.0;
+    /// Legacy bits of all mods. The sign bit is never used so that the bits
+    /// can be stored as non-negative `INT4`.
+    const ALL_MODS: u32 = i32::MAX as u32;
+    // Compile-time assertion that `TrackEntryParams` is no larger than 48
     // bytes so that its `Copy` impl is justified.
-    const _SMALL_ENOUGH: [(); 0] = [(); 0 - { 1 - (size_of::<TrackEntryParams>() <= 20) as usize }];
+    const _SMALL_ENOUGH: [(); 0] = [(); 0 - { 1 - (size_of::<TrackEntryParams>() <= 48) as usize }];
 
     pub const fn new() -> Self {
         Self {
@@ -32,6 +52,11 @@ impl TrackEntryParams {
                 Self::DEFAULT_MIN_COMBO_PERCENT,
                 Self::DEFAULT_MAX_COMBO_PERCENT,
             ),
+            stars: Range::new_raw(Self::DEFAULT_MIN_STARS, Self::DEFAULT_MAX_STARS),
+            mods_include: 0,
+            mods_exclude: 0,
+            mapper: None,
+            status: None,
         }
     }
 
@@ -61,6 +86,58 @@ impl TrackEntryParams {
         }
     }
 
+    pub fn with_stars(self, min: Option<f32>, max: Option<f32>) -> Self {
+        Self {
+            stars: Range::<f32>::new(min, max, Self::DEFAULT_MIN_STARS, Self::DEFAULT_MAX_STARS),
+            ..self
+        }
+    }
+
+    /// Specify legacy mod bits that must be included or excluded.
+    ///
+    /// Mods that are both included and excluded will be neither.
+    pub const fn with_mods(self, include: Option<u32>, exclude: Option<u32>) -> Self {
+        let include = match include {
+            Some(bits) => bits,
+            None => 0,
+        };
+
+        let exclude = match exclude {
+            Some(bits) => bits,
+            None => 0,
+        };
+
+        let both = include & exclude;
+
+        Self {
+            mods_include: include & !both,
+            mods_exclude: exclude & !both,
+            ..self
+        }
+    }
+
+    /// Only match scores whose mods are exactly the given legacy mod bits.
+    pub const fn with_exact_mods(self, mods: u32) -> Self {
+        Self {
+            mods_include: mods,
+            mods_exclude: Self::ALL_MODS & !TrackScoreProps::normalize_mods(mods),
+            ..self
+        }
+    }
+
+    pub const fn with_mapper(self, mapper_id: Option<u32>) -> Self {
+        let mapper = match mapper_id {
+            Some(mapper_id) => NonZeroU32::new(mapper_id),
+            None => None,
+        };
+
+        Self { mapper, ..self }
+    }
+
+    pub const fn with_status(self, status: Option<RankStatus>) -> Self {
+        Self { status, ..self }
+    }
+
     pub const fn index(&self) -> Range<u8> {
         self.index
     }
@@ -73,14 +150,110 @@ impl TrackEntryParams {
         self.combo_percent
     }
 
-    pub const fn matches(&self, idx: u8, pp: f32, combo_percent: Option<f32>) -> bool {
-        self.index.contains(idx)
-            && self.pp.contains(pp)
-            && match combo_percent {
+    pub const fn stars(&self) -> Range<f32> {
+        self.stars
+    }
+
+    pub const fn mapper(&self) -> Option<u32> {
+        match self.mapper {
+            Some(mapper_id) => Some(mapper_id.get()),
+            None => None,
+        }
+    }
+
+    pub const fn status(&self) -> Option<RankStatus> {
+        self.status
+    }
+
+    pub fn mods_include(&self) -> GameModsIntermode {
+        GameModsIntermode::from_bits(self.mods_include)
+    }
+
+    pub fn mods_exclude(&self) -> GameModsIntermode {
+        GameModsIntermode::from_bits(self.mods_exclude)
+    }
+
+    /// Whether all mods except for the included ones are excluded.
+    const fn is_exact_mods(&self) -> bool {
+        self.mods_exclude == Self::ALL_MODS & !TrackScoreProps::normalize_mods(self.mods_include)
+    }
+
+    pub const fn matches(&self, props: &TrackScoreProps) -> bool {
+        self.index.contains(props.idx)
+            && self.pp.contains(props.pp)
+            && match props.combo_percent {
                 // Manual `Option::is_some_and` to preserve const-ness
                 Some(percent) => self.combo_percent.contains(percent),
                 None => false,
             }
+            && self.matches_map(props)
+    }
+
+    const fn matches_map(&self, props: &TrackScoreProps) -> bool {
+        let stars_match = match props.stars {
+            Some(stars) => self.stars.contains(stars),
+            // Only reject missing stars if they've been filtered for
+            None => {
+                self.stars.start <= Self::DEFAULT_MIN_STARS
+                    && self.stars.end >= Self::DEFAULT_MAX_STARS
+            }
+        };
+
+        let mods = props.normalized_mods();
+
+        let mods_match =
+            (mods & self.mods_include) == self.mods_include && (mods & self.mods_exclude) == 0;
+
+        let mapper_match = match self.mapper {
+            Some(mapper_id) => {
+                mapper_id.get() == props.creator_id || mapper_id.get() == props.mapper_id
+            }
+            None => true,
+        };
+
+        let status_match = match self.status {
+            Some(status) => status as i8 == props.status as i8,
+            None => true,
+        };
+
+        stars_match && mods_match && mapper_match && status_match
+    }
+
+    /// Writes all filters on stars, mods, mapper, and map status that differ
+    /// from their default into `buf`.
+    ///
+    /// Each filter is prefixed with `separator`.
+    pub fn write_map_filters(&self, buf: &mut String, separator: &str, mapper_name: Option<&str>) {
+        if self.stars.start > Self::DEFAULT_MIN_STARS || self.stars.end < Self::DEFAULT_MAX_STARS {
+            let _ = write!(buf, "{separator}`Stars: {}★`", self.stars);
+        }
+
+        if self.is_exact_mods() {
+            let _ = write!(buf, "{separator}`Mods: ={}`", self.mods_include());
+        } else {
+            if self.mods_include > 0 {
+                let _ = write!(buf, "{separator}`Mods: +{}`", self.mods_include());
+            }
+
+            if self.mods_exclude > 0 {
+                let _ = write!(buf, "{separator}`Mods: -{}`", self.mods_exclude());
+            }
+        }
+
+        if let Some(mapper_id) = self.mapper() {
+            match mapper_name {
+                Some(name) => {
+                    let _ = write!(buf, "{separator}`Mapper: {name}`");
+                }
+                None => {
+                    let _ = write!(buf, "{separator}`Mapper id: {mapper_id}`");
+                }
+            }
+        }
+
+        if let Some(status) = self.status {
+            let _ = write!(buf, "{separator}`Status: {}`", StatusName(status));
+        }
     }
 
     pub(super) const fn into_db_entry(
@@ -88,6 +261,10 @@ impl TrackEntryParams {
         user_id: u32,
         mode: GameMode,
     ) -> DbTrackedOsuUserInChannel {
+        const fn non_zero(bits: u32) -> Option<i32> {
+            if bits > 0 { Some(bits as i32) } else { None }
+        }
+
         DbTrackedOsuUserInChannel {
             user_id: user_id as i32,
             gamemode: mode as i16,
@@ -97,6 +274,18 @@ impl TrackEntryParams {
             max_pp: Some(self.pp.end),
             min_combo_percent: Some(self.combo_percent.start),
             max_combo_percent: Some(self.combo_percent.end),
+            mods_include: non_zero(self.mods_include),
+            mods_exclude: non_zero(self.mods_exclude),
+            min_stars: Some(self.stars.start),
+            max_stars: Some(self.stars.end),
+            mapper_id: match self.mapper {
+                Some(mapper_id) => Some(mapper_id.get() as i32),
+                None => None,
+            },
+            map_status: match self.status {
+                Some(status) => Some(status as i16),
+                None => None,
+            },
         }
     }
 }
@@ -114,6 +303,17 @@ impl From<DbTrackedOsuUserInChannel> for TrackEntryParams {
             .with_index(map_as_u8(entry.min_index), map_as_u8(entry.max_index))
             .with_pp(entry.min_pp, entry.max_pp)
             .with_combo_percent(entry.min_combo_percent, entry.max_combo_percent)
+            .with_stars(entry.min_stars, entry.max_stars)
+            .with_mods(
+                entry.mods_include.map(|bits| bits as u32),
+                entry.mods_exclude.map(|bits| bits as u32),
+            )
+            .with_mapper(entry.mapper_id.map(|mapper_id| mapper_id as u32))
+            .with_status(
+                entry
+                    .map_status
+                    .and_then(|status| RankStatus::try_from(status as i8).ok()),
+            )
     }
 }
 
@@ -123,6 +323,64 @@ impl Default for TrackEntryParams {
     }
 }
 
+/// Properties of a top score that are checked against [`TrackEntryParams`].
+pub struct TrackScoreProps {
+    /// `1..=100`
+    pub idx: u8,
+    pub pp: f32,
+    pub combo_percent: Option<f32>,
+    pub stars: Option<f32>,
+    /// Legacy bits of the score's mods
+    pub mods: u32,
+    /// User id of the mapset's creator
+    pub creator_id: u32,
+    /// User id of the difficulty's mapper
+    pub mapper_id: u32,
+    pub status: RankStatus,
+}
+
+impl TrackScoreProps {
+    const DT: u32 = 1 << 6;
+    const NC: u32 = 1 << 9;
+    const PF: u32 = 1 << 14;
+    const SD: u32 = 1 << 5;
+
+    const fn normalized_mods(&self) -> u32 {
+        Self::normalize_mods(self.mods)
+    }
+
+    /// Mod bits where NC implies DT and PF implies SD
+    const fn normalize_mods(mut mods: u32) -> u32 {
+        if mods & Self::NC > 0 {
+            mods |= Self::DT;
+        }
+
+        if mods & Self::PF > 0 {
+            mods |= Self::SD;
+        }
+
+        mods
+    }
+}
+
+struct StatusName(RankStatus);
+
+impl Display for StatusName {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        let name = match self.0 {
+            RankStatus::Graveyard => "Graveyard",
+            RankStatus::WIP => "WIP",
+            RankStatus::Pending => "Pending",
+            RankStatus::Ranked => "Ranked",
+            RankStatus::Approved => "Approved",
+            RankStatus::Qualified => "Qualified",
+            RankStatus::Loved => "Loved",
+        };
+
+        f.write_str(name)
+    }
+}
+
 macro_rules! const_helpers {
     ( $ty:ty, $unwrap_or:ident, $clamp:ident ) => {
         impl Range<$ty> {
@@ -1,31 +1,18 @@
-use std::{slice, sync::Arc, time::Duration};
+use std::{slice, sync::Arc};
 
-use bathbot_model::embed_builder::{
-    ComboValue, HitresultsValue, ScoreEmbedSettings, SettingValue, SettingsButtons, SettingsImage,
-    Value,
-};
-use bathbot_psql::model::configs::ScoreData;
-use bathbot_util::{EmbedBuilder, constants::UNKNOWN_CHANNEL};
-use rand::Rng;
-use rosu_v2::{model::GameMode, prelude::Score};
+use bathbot_util::constants::UNKNOWN_CHANNEL;
+use rosu_v2::prelude::Score;
 use twilight_http::{
     api_error::{ApiError, GeneralApiError},
     error::ErrorType as TwilightErrorType,
 };
 use twilight_model::id::Id;
 
-use super::{OsuTracking, entry::TrackEntry};
+use super::{OsuTracking, entry::TrackEntry, params::TrackScoreProps};
 use crate::{
-    active::{
-        IActiveMessage,
-        impls::{SingleScoreContent, SingleScorePagination},
-    },
-    commands::utility::ScoreEmbedDataWrap,
     core::{BotMetrics, Context},
-    manager::{
-        OsuMap,
-        redis::osu::{CachedUser, UserArgs, UserArgsSlim},
-    },
+    manager::redis::osu::{UserArgs, UserArgsSlim},
+    tracking::notif::{claim_notif, jitter, score_embed},
 };
 
 pub async fn process_score(score: Score, entry: Arc<TrackEntry>) {
@@ -96,12 +83,27 @@ pub async fn process_score(score: Score, entry: Arc<TrackEntry>) {
     BotMetrics::osu_tracking_hit(score.mode);
 
     let combo = score.max_combo;
-    let (builder, max_combo) = embed_builder(&user, score, map, idx).await;
+    let mods = score.mods.bits();
+    let creator_id = map.creator_id();
+    let mapper_id = map.mapper_id();
+    let status = map.status();
+    let (builder, max_combo, stars) = score_embed(&user, score, map, Some(idx), None).await;
     let idx = idx as u8 + 1;
     let embed = builder.build();
     let embeds = slice::from_ref(&embed);
     let combo_percent = max_combo.map(|max| 100.0 * combo as f32 / max as f32);
 
+    let props = TrackScoreProps {
+        idx,
+        pp,
+        combo_percent,
+        stars,
+        mods,
+        creator_id,
+        mapper_id,
+        status,
+    };
+
     log!(info:
         user = user_id,
         map = map_id,
@@ -109,6 +111,7 @@ pub async fn process_score(score: Score, entry: Arc<TrackEntry>) {
         idx,
         pp,
         combo_percent,
+        stars,
         "New top score",
     );
 
@@ -117,16 +120,16 @@ pub async fn process_score(score: Score, entry: Arc<TrackEntry>) {
     let channels: Vec<_> = entry
         .channels()
         .iter()
-        .filter_map(|(channel_id, params)| {
-            params
-                .matches(idx, pp, combo_percent)
-                .then_some(*channel_id)
-        })
+        .filter_map(|(channel_id, params)| params.matches(&props).then_some(*channel_id))
         .collect();
 
     for channel_id in channels {
         let channel = Id::new(channel_id.get());
 
+        if !claim_notif(score_id, channel) {
+            continue;
+        }
+
         let Err(err) = http.create_message(channel).embeds(embeds).await else {
             continue;
         };
@@ -150,193 +153,3 @@ pub async fn process_score(score: Score, entry: Arc<TrackEntry>) {
         OsuTracking::remove_channel(channel, None).await;
     }
 }
-
-/// Random [`Duration`] between 30s and 60s
-fn jitter() -> Duration {
-    rand::thread_rng().gen_range(Duration::from_secs(30)..Duration::from_secs(60))
-}
-
-async fn embed_builder(
-    user: &CachedUser,
-    score: Score,
-    map: OsuMap,
-    idx: usize,
-) -> (EmbedBuilder, Option<u32>) {
-    let settings = match score.mode {
-        GameMode::Mania => create_mania_settings(),
-        _ => create_settings(),
-    };
-
-    let score_data = ScoreData::Lazer;
-    let msg_owner = Id::new(1);
-    let content = SingleScoreContent::None;
-
-    let embed_data = ScoreEmbedDataWrap::new_custom(score, map, idx, None).await;
-
-    // This is always `Some` considering `ScoreEmbedDataWrap::new_custom`
-    // creates *full* data but let's map regardless to be extra sure.
-    let max_combo = embed_data.try_get().map(|data| data.max_combo);
-
-    let entries = Box::<[_]>::from([embed_data]);
-
-    let mut pagination =
-        SingleScorePagination::new(user, entries, settings, score_data, msg_owner, content);
-
-    match pagination.build_page().await {
-        Ok(data) => (data.into_embed(), max_combo),
-        // Unreachable because `async_build_page` can only fail while
-        // converting to full score data but it already starts off as
-        // full.
-        Err(_) => Default::default(),
-    }
-}
-
-fn create_settings() -> ScoreEmbedSettings {
-    ScoreEmbedSettings {
-        values: vec![
-            SettingValue {
-                inner: Value::Grade,
-                y: 0,
-            },
-            SettingValue {
-                inner: Value::Mods,
-                y: 0,
-            },
-            SettingValue {
-                inner: Value::Score,
-                y: 0,
-            },
-            SettingValue {
-                inner: Value::Accuracy,
-                y: 0,
-            },
-            SettingValue {
-                inner: Value::Combo(Default::default()),
-                y: 0,
-            },
-            SettingValue {
-                inner: Value::Pp(Default::default()),
-                y: 1,
-            },
-            SettingValue {
-                inner: Value::Hitresults(HitresultsValue::Full),
-                y: 1,
-            },
-            SettingValue {
-                inner: Value::Length,
-                y: 2,
-            },
-            SettingValue {
-                inner: Value::Cs,
-                y: 2,
-            },
-            SettingValue {
-                inner: Value::Ar,
-                y: 2,
-            },
-            SettingValue {
-                inner: Value::Od,
-                y: 2,
-            },
-            SettingValue {
-                inner: Value::Hp,
-                y: 2,
-            },
-            SettingValue {
-                inner: Value::Bpm(Default::default()),
-                y: 2,
-            },
-            SettingValue {
-                inner: Value::Mapper(Default::default()),
-                y: SettingValue::FOOTER_Y,
-            },
-            SettingValue {
-                inner: Value::ScoreDate,
-                y: SettingValue::FOOTER_Y,
-            },
-        ],
-        show_artist: true,
-        show_sr_in_title: true,
-        image: SettingsImage::Thumbnail,
-        buttons: SettingsButtons {
-            pagination: false,
-            render: false,
-            miss_analyzer: false,
-        },
-    }
-}
-
-fn create_mania_settings() -> ScoreEmbedSettings {
-    ScoreEmbedSettings {
-        values: vec![
-            SettingValue {
-                inner: Value::Grade,
-                y: 0,
-            },
-            SettingValue {
-                inner: Value::Mods,
-                y: 0,
-            },
-            SettingValue {
-                inner: Value::Score,
-                y: 0,
-            },
-            SettingValue {
-                inner: Value::Accuracy,
-                y: 0,
-            },
-            SettingValue {
-                inner: Value::Combo(ComboValue { max: false }),
-                y: 0,
-            },
-            SettingValue {
-                inner: Value::Ratio,
-                y: 0,
-            },
-            SettingValue {
-                inner: Value::Pp(Default::default()),
-                y: 1,
-            },
-            SettingValue {
-                inner: Value::Hitresults(HitresultsValue::Full),
-                y: 1,
-            },
-            SettingValue {
-                inner: Value::Length,
-                y: 2,
-            },
-            SettingValue {
-                inner: Value::Od,
-                y: 2,
-            },
-            SettingValue {
-                inner: Value::Hp,
-                y: 2,
-            },
-            SettingValue {
-                inner: Value::Bpm(Default::default()),
-                y: 2,
-            },
-            SettingValue {
-                inner: Value::CountSliders(Default::default()),
-                y: 2,
-            },
-            SettingValue {
-                inner: Value::Mapper(Default::default()),
-                y: SettingValue::FOOTER_Y,
-            },
-            SettingValue {
-                inner: Value::ScoreDate,
-                y: SettingValue::FOOTER_Y,
-            },
-        ],
-        show_artist: true,
-        show_sr_in_title: true,
-        image: SettingsImage::Thumbnail,
-        buttons: SettingsButtons {
-            pagination: false,
-            render: false,
-            miss_analyzer: false,
-        },
-    }
-}
@@ -6,7 +6,10 @@ use rosu_v2::prelude::Score;
 use tokio::{net::TcpStream, sync::oneshot};
 use tokio_tungstenite::{MaybeTlsStream, WebSocketStream, tungstenite::Message};
 
-use crate::{core::BotConfig, tracking::OsuTracking};
+use crate::{
+    core::BotConfig,
+    tracking::{LeaderboardTracking, OsuTracking},
+};
 
 type WebSocket = WebSocketStream<MaybeTlsStream<TcpStream>>;
 
@@ -122,6 +125,7 @@ impl ScoresWebSocket {
                 Self::store_resume_id(score.id);
             }
 
+            LeaderboardTracking::process_score(&score);
             OsuTracking::process_score(score);
         }
     }
@@ -4,7 +4,8 @@ use std::{
     slice,
 };
 
-use bathbot_model::TwitchUser;
+use bathbot_model::{TwitchStream, TwitchUser};
+use bathbot_psql::model::tracked_stream::DbTrackedTwitchStream;
 use bathbot_util::{
     AuthorBuilder, EmbedBuilder, IntHasher,
     constants::{TWITCH_BASE, UNKNOWN_CHANNEL},
@@ -15,7 +16,13 @@ use twilight_http::{
     api_error::{ApiError, GeneralApiError},
     error::ErrorType,
 };
-use twilight_model::id::{Id, marker::ChannelMarker};
+use twilight_model::{
+    channel::message::AllowedMentions,
+    id::{
+        Id,
+        marker::{ChannelMarker, MessageMarker, RoleMarker},
+    },
+};
 
 use crate::Context;
 
@@ -67,6 +74,11 @@ pub async fn twitch_tracking_loop() {
             continue;
         }
 
+        // Edit notifications of streams that went offline
+        for &user_id in online_streams.difference(&now_online) {
+            process_ended_stream(user_id).await;
+        }
+
         // Filter streams whether its already known they're live
         streams.retain(|stream| !online_streams.contains(&stream.user_id));
 
@@ -104,8 +116,17 @@ pub async fn twitch_tracking_loop() {
 
         // Process each stream by notifying all corresponding channels
         for mut stream in streams {
-            let Some(channels) = Context::tracked_channels_for(stream.user_id) else {
-                continue;
+            let entries = match Context::twitch().channels(stream.user_id).await {
+                Ok(entries) => entries,
+                Err(err) => {
+                    warn!(
+                        user = stream.user_id,
+                        ?err,
+                        "Failed to get tracking channels"
+                    );
+
+                    continue;
+                }
             };
 
             // Adjust streams' thumbnail url
@@ -114,6 +135,7 @@ pub async fn twitch_tracking_loop() {
             let _ = write!(stream.thumbnail_url, "{width}x{height}.jpg");
 
             let user = &users[&stream.user_id];
+            let url = format!("{TWITCH_BASE}{}", user.display_name);
 
             let embed = EmbedBuilder::new()
                 .author(AuthorBuilder::new("Now live on twitch:"))
@@ -121,18 +143,30 @@ pub async fn twitch_tracking_loop() {
                 .image(&stream.thumbnail_url)
                 .thumbnail(user.image_url.as_ref())
                 .title(stream.username.as_ref())
-                .url(format!("{TWITCH_BASE}{}", user.display_name));
+                .url(url.as_str());
 
-            let mut channels = channels.into_iter();
-            let last = channels.next_back();
+            for entry in entries {
+                if !passes_filters(&entry, &stream) {
+                    continue;
+                }
 
-            for channel in channels {
-                send_notif(embed.clone(), channel).await;
-            }
+                let channel = Id::new(entry.channel_id as u64);
+                let role = entry.role_id.map(|role_id| Id::new(role_id as u64));
+                let content = notif_content(&entry, &stream, &url);
+
+                let Some(msg) = send_notif(embed.clone(), content, role, channel).await else {
+                    continue;
+                };
+
+                if !entry.vod_edit {
+                    continue;
+                }
+
+                let set_fut = Context::twitch().set_notif(channel, stream.user_id, Some(msg));
 
-            // doing last one separately so we don't clone embed
-            if let Some(channel) = last {
-                send_notif(embed, channel).await;
+                if let Err(err) = set_fut.await {
+                    warn!(%channel, ?err, "Failed to store twitch notif");
+                }
             }
         }
 
@@ -140,43 +174,166 @@ pub async fn twitch_tracking_loop() {
     }
 }
 
-async fn send_notif(embed: EmbedBuilder, channel: Id<ChannelMarker>) {
+fn passes_filters(entry: &DbTrackedTwitchStream, stream: &TwitchStream) -> bool {
+    if entry.osu_only && !stream.game_name.eq_ignore_ascii_case("osu!") {
+        return false;
+    }
+
+    entry.keyword.as_deref().is_none_or(|keyword| {
+        stream
+            .title
+            .to_lowercase()
+            .contains(&keyword.to_lowercase())
+    })
+}
+
+/// Fill in the placeholders of the entry's template or, if there is no
+/// template, just mention the role.
+fn notif_content(entry: &DbTrackedTwitchStream, stream: &TwitchStream, url: &str) -> String {
+    let role = entry
+        .role_id
+        .map_or_else(String::new, |role_id| format!("<@&{role_id}>"));
+
+    let Some(ref template) = entry.template else {
+        return role;
+    };
+
+    template
+        .replace("{streamer}", &stream.username)
+        .replace("{title}", &stream.title)
+        .replace("{game}", &stream.game_name)
+        .replace("{url}", url)
+        .replace("{role}", &role)
+}
+
+/// Edit the notifications of an ended stream to link its VOD.
+async fn process_ended_stream(user_id: u64) {
+    let entries = match Context::twitch().channels(user_id).await {
+        Ok(entries) => entries,
+        Err(err) => {
+            warn!(user = user_id, ?err, "Failed to get tracking channels");
+
+            return;
+        }
+    };
+
+    let notifs: Vec<_> = entries
+        .into_iter()
+        .filter_map(|entry| {
+            let msg = entry.notif_msg_id?;
+
+            Some((
+                Id::new(entry.channel_id as u64),
+                Id::new(msg as u64),
+                entry.vod_edit,
+            ))
+        })
+        .collect();
+
+    if notifs.is_empty() {
+        return;
+    }
+
+    let vod = if notifs.iter().any(|(.., vod_edit)| *vod_edit) {
+        match Context::client().get_last_twitch_vod(user_id).await {
+            Ok(vod) => vod,
+            Err(err) => {
+                warn!(user = user_id, ?err, "Failed to get last twitch vod");
+
+                None
+            }
+        }
+    } else {
+        None
+    };
+
+    for (channel, msg, vod_edit) in notifs {
+        if let Some(vod) = vod.as_ref().filter(|_| vod_edit) {
+            let embed = EmbedBuilder::new()
+                .author(AuthorBuilder::new("Stream ended, watch the VOD:"))
+                .description(vod.title.as_ref())
+                .title(vod.username.as_ref())
+                .url(vod.url.as_ref())
+                .build();
+
+            let update_fut = Context::http()
+                .update_message(channel, msg)
+                .embeds(Some(slice::from_ref(&embed)));
+
+            if let Err(err) = update_fut.await {
+                warn!(%channel, ?err, "Failed to edit twitch notif");
+            }
+        }
+
+        if let Err(err) = Context::twitch().set_notif(channel, user_id, None).await {
+            warn!(%channel, ?err, "Failed to clear twitch notif");
+        }
+    }
+}
+
+/// Returns the id of the created message on success.
+async fn send_notif(
+    embed: EmbedBuilder,
+    content: String,
+    role: Option<Id<RoleMarker>>,
+    channel: Id<ChannelMarker>,
+) -> Option<Id<MessageMarker>> {
     let embed = embed.build();
+
+    let mentions = AllowedMentions {
+        roles: role.into_iter().collect(),
+        ..Default::default()
+    };
+
     let msg_fut = Context::http()
         .create_message(channel)
+        .content(&content)
+        .allowed_mentions(Some(&mentions))
         .embeds(slice::from_ref(&embed));
 
-    if let Err(err) = msg_fut.await {
-        if let ErrorType::Response { error, .. } = err.kind() {
-            match error {
-                ApiError::General(GeneralApiError {
-                    code: UNKNOWN_CHANNEL,
-                    ..
-                }) => {
-                    if let Err(err) = Context::twitch().untrack_all(channel).await {
-                        warn!(
-                            %channel,
-                            ?err,
-                            "Failed to remove stream tracks from unknown channel"
-                        );
-                    } else {
-                        debug!("Removed twitch tracking of unknown channel {channel}");
-                    }
+    let err = match msg_fut.await {
+        Ok(response) => match response.model().await {
+            Ok(msg) => return Some(msg.id),
+            Err(err) => {
+                warn!(%channel, ?err, "Failed to deserialize twitch notif");
+
+                return None;
+            }
+        },
+        Err(err) => err,
+    };
+
+    if let ErrorType::Response { error, .. } = err.kind() {
+        match error {
+            ApiError::General(GeneralApiError {
+                code: UNKNOWN_CHANNEL,
+                ..
+            }) => {
+                if let Err(err) = Context::twitch().untrack_all(channel).await {
+                    warn!(
+                        %channel,
+                        ?err,
+                        "Failed to remove stream tracks from unknown channel"
+                    );
+                } else {
+                    debug!("Removed twitch tracking of unknown channel {channel}");
                 }
-                err => warn!(
-                    %channel,
-                    ?err,
-                    "Error from API while sending twitch notif"
-                ),
             }
-        } else {
-            warn!(
+            err => warn!(
                 %channel,
                 ?err,
-                "Error while sending twitch notif"
-            );
+                "Error from API while sending twitch notif"
+            ),
         }
+    } else {
+        warn!(
+            %channel,
+            ?err,
+            "Error while sending twitch notif"
+        );
     }
+
+    None
 }
 
 struct TwitchUserCompact {
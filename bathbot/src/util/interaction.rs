@@ -9,15 +9,19 @@ use twilight_model::{
     guild::{PartialMember, Permissions},
     id::{
         Id,
-        marker::{ChannelMarker, GuildMarker, InteractionMarker},
+        marker::{ChannelMarker, GuildMarker, InteractionMarker, UserMarker},
     },
     user::User,
 };
 
+use crate::core::locale::Locale;
+
 #[derive(Debug)]
 pub struct InteractionCommand {
     pub permissions: Option<Permissions>,
     pub channel_id: Id<ChannelMarker>,
+    /// The locale of the user's discord client
+    pub client_locale: Option<String>,
     pub data: Box<CommandData>,
     pub guild_id: Option<Id<GuildMarker>>,
     pub id: Id<InteractionMarker>,
@@ -83,3 +87,26 @@ impl_authored! {
     InteractionComponent,
     InteractionModal,
 }
+
+impl InteractionCommand {
+    /// The [`Locale`] in which responses should be written.
+    pub async fn locale(&self) -> Locale {
+        let user_id = self.user_id().ok();
+        let client_locale = self.client_locale.as_deref();
+
+        Locale::resolve(user_id, self.guild_id, client_locale).await
+    }
+
+    /// The message on which a message command was used.
+    pub fn target_message(&self) -> Option<&Message> {
+        self.data
+            .resolved
+            .as_ref()
+            .and_then(|resolved| resolved.messages.values().next())
+    }
+
+    /// The user on which a user command was used.
+    pub fn target_user(&self) -> Option<Id<UserMarker>> {
+        self.data.target_id.map(Id::cast)
+    }
+}